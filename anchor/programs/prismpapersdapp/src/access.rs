@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::ErrorCodes,
+    events::ReceiptClosed,
+    states::{
+        AccessReceipt, Institution, InstitutionMember, ReceiptStatus, ResearchPaper, Subscription,
+        SubscriptionPlan,
+    },
+};
+
+//Checks `reader` can read `research_paper`, as only readers can review it.
+//Access is either a personal receipt, a site license bought by the reader's institution,
+//or an unexpired subscription to a plan that includes the paper.
+pub fn check_reader_access<'info>(
+    reader: Pubkey,
+    research_paper: &Account<'info, ResearchPaper>,
+    access_receipt: Option<&Account<'info, AccessReceipt>>,
+    institution: Option<&Account<'info, Institution>>,
+    institution_member: Option<&Account<'info, InstitutionMember>>,
+    subscription_plan: Option<&Account<'info, SubscriptionPlan>>,
+    subscription: Option<&Account<'info, Subscription>>,
+) -> Result<()> {
+    match access_receipt {
+        Some(access_receipt) => {
+            check_license(reader, access_receipt, institution, institution_member)?;
+            require!(
+                access_receipt.status != ReceiptStatus::Revoked,
+                ErrorCodes::ReceiptRevoked
+            );
+            require!(
+                research_paper.grants_access(access_receipt.revision),
+                ErrorCodes::RevisionNotCovered
+            );
+            Ok(())
+        }
+        None => check_subscription(reader, research_paper, subscription_plan, subscription),
+    }
+}
+
+//a personal receipt must belong to the reader, a site license needs a seat at its institution
+fn check_license(
+    reader: Pubkey,
+    access_receipt: &AccessReceipt,
+    institution: Option<&Account<Institution>>,
+    institution_member: Option<&Account<InstitutionMember>>,
+) -> Result<()> {
+    if access_receipt.seats == 0 {
+        require!(
+            access_receipt.buyer == reader,
+            ErrorCodes::PaperNotPurchased
+        );
+        return Ok(());
+    }
+    let (Some(institution), Some(institution_member)) = (institution, institution_member) else {
+        return err!(ErrorCodes::InstitutionMembershipMissing);
+    };
+    require!(
+        institution.key() == access_receipt.buyer
+            && institution_member.institution == institution.key()
+            && institution_member.member == reader,
+        ErrorCodes::InstitutionMembershipInvalid
+    );
+    require!(
+        institution.members <= access_receipt.seats,
+        ErrorCodes::LicenseSeatsExceeded
+    );
+    Ok(())
+}
+
+//subscribers always read the current revision, for as long as the subscription runs
+fn check_subscription(
+    reader: Pubkey,
+    research_paper: &Account<ResearchPaper>,
+    subscription_plan: Option<&Account<SubscriptionPlan>>,
+    subscription: Option<&Account<Subscription>>,
+) -> Result<()> {
+    let (Some(subscription_plan), Some(subscription)) = (subscription_plan, subscription) else {
+        return err!(ErrorCodes::PaperNotPurchased);
+    };
+    require!(
+        subscription.subscriber == reader
+            && subscription.plan == subscription_plan.key()
+            && subscription_plan.covers(research_paper),
+        ErrorCodes::SubscriptionNotCovering
+    );
+    require!(
+        Clock::get()?.unix_timestamp < subscription.expires_at,
+        ErrorCodes::SubscriptionExpired
+    );
+    Ok(())
+}
+
+//Checks a receipt can be closed and lets go of it: the payment must be out of escrow, and the author
+//must not still owe a live buyer their key. A live paper stops owing the buyer the keys of later
+//revisions. Shared by `close_receipt` and `close_license_receipt`.
+pub fn release_receipt(
+    access_receipt: &Account<AccessReceipt>,
+    research_paper: &AccountInfo,
+) -> Result<()> {
+    let status = access_receipt.status;
+    require!(
+        status == ReceiptStatus::Settled || status == ReceiptStatus::Revoked,
+        ErrorCodes::ReceiptNotFinalized
+    );
+    require!(
+        access_receipt.key_revision.is_some() || status == ReceiptStatus::Revoked,
+        ErrorCodes::KeyNotDelivered
+    );
+
+    if status != ReceiptStatus::Revoked
+        && research_paper.owner == &crate::ID
+        && !research_paper.data_is_empty()
+    {
+        let mut paper =
+            ResearchPaper::try_deserialize(&mut &research_paper.try_borrow_data()?[..])?;
+        if paper.owes_key(access_receipt) {
+            paper.pending_deliveries = paper
+                .pending_deliveries
+                .checked_sub(1u32)
+                .ok_or(ErrorCodes::MathOverflow)?;
+        }
+        paper.live_receipts = paper
+            .live_receipts
+            .checked_sub(1u32)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        paper.try_serialize(&mut &mut research_paper.try_borrow_mut_data()?[..])?;
+    }
+
+    emit!(ReceiptClosed {
+        receipt: access_receipt.key(),
+        paper: access_receipt.purchased_paper,
+        buyer: access_receipt.buyer,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -1,27 +1,50 @@
-use anchor_lang::prelude::*;
-
 pub const ANCHOR_DISCRIMINATOR: usize = 8;
 
-pub const ADMIN_PUBKEYS: [Pubkey; 2] = [
-    pubkey!("MNG3SoboXMyjse4ggiyBWJreNhfxyni5VJFxSLmXM5n"),
-    pubkey!("AHibwaXG2EVnD1jTvD93166tunfNecvMcVQhcQKL3UZv"),
-];
-// Logic: fee_amount = (price * GLOBAL_FEE_PERCENTAGE) / 100
-pub const GLOBAL_FEE_PERCENTAGE: u64 = 5; // a 5% platform fee on all transactions, to be collected by the platform
+pub const MAX_ADMINS: usize = 5; //upper bound on the admin set stored in the Config account
+pub const MAX_CO_AUTHORS: usize = 5; //authors sharing a paper's earnings, the author included
+
+// Logic: fee_amount = max((amount * fee_bps) / BPS_DENOMINATOR, min_fee_lamports), see fees::split_fee
+pub const BPS_DENOMINATOR: u64 = 10_000; // 100% expressed in basis points
 
 pub const PAPER_INIT_STAT: u32 = 0; //initial sale, review, and purchase etc of a paper
 pub const USER_INIT_STAT: u16 = 0; //initial sale, review, and purchase etc of a paper
 
+pub const FLAGGED_REVIEW_WEIGHT: u64 = 3; //rejections a flagged review counts as in the reputation score
+
+pub const DEFAULT_REVIEW_RESPONSE_WINDOW: i64 = 14 * 24 * 60 * 60; //time an author has to accept or reject a review
+pub const REVIEW_RESPONSE_WINDOW_MAX: i64 = 90 * 24 * 60 * 60;
+
+pub const REVIEW_CLOSE_COOLDOWN: i64 = 7 * 24 * 60 * 60; //how long a rejected review stays on-chain before it can be closed, and can still be flagged
+
 pub const USER_SEED: &[u8] = b"user";
 pub const PAPER_SEED: &[u8] = b"paper";
+pub const PAPER_COUNTER_SEED: &[u8] = b"paper_counter";
 pub const REVIEW_SEED: &[u8] = b"review";
+pub const ROUND_SEED: &[u8] = b"round";
+pub const VOTER_SEED: &[u8] = b"voter";
 pub const RECEIPT_SEED: &[u8] = b"receipt";
+pub const KEY_DELIVERY_SEED: &[u8] = b"key_delivery";
+pub const REVISION_SEED: &[u8] = b"revision";
 pub const VAULT_SEED_USER: &[u8] = b"vault_user";
 pub const VAULT_SEED_ADMIN: &[u8] = b"vault_admin";
+pub const VAULT_SEED_BOUNTY: &[u8] = b"vault_bounty";
+pub const VAULT_SEED_ESCROW: &[u8] = b"vault_escrow";
+pub const VAULT_SEED_STAKE: &[u8] = b"vault_stake"; //also escrows an anonymous review's reward until it is revealed
+pub const INSTITUTION_SEED: &[u8] = b"institution";
+pub const MEMBER_SEED: &[u8] = b"member";
+pub const PLAN_SEED: &[u8] = b"plan";
+pub const BUNDLE_SEED: &[u8] = b"bundle";
+pub const SUBSCRIPTION_SEED: &[u8] = b"subscription";
+pub const CONFIG_SEED: &[u8] = b"config";
 
 pub const USER_NAME_MAX_LENGTH: usize = 50;
+pub const INSTITUTION_NAME_MAX_LENGTH: usize = 100;
 pub const PAPER_TITLE_MAX_LENGTH: usize = 100;
 pub const PAPER_DESCRIPTION_MAX_LENGTH: usize = 400;
 pub const PAPER_URL_MAX_LENGTH: usize = 200;
-pub const ENCRYPTION_KEY_MAX_LENGTH: usize = 300;
+pub const ENCRYPTION_KEY_MAX_LENGTH: usize = 300; //max size of a per-buyer wrapped content key
 pub const REVIEW_URL_MAX_LENGTH: usize = 200;
+pub const REVIEW_SCORE_MIN: u8 = 1; //lowest novelty, rigor, clarity and confidence score
+pub const REVIEW_SCORE_MAX: u8 = 5;
+pub const PLAN_MAX_PAPERS: usize = 20; //papers a subscription plan can list individually
+pub const BUNDLE_MAX_PAPERS: usize = 10; //papers per bundle purchase, bounded by transaction size
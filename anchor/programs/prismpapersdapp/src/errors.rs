@@ -14,7 +14,7 @@ pub enum ErrorCodes {
     #[msg("Research Paper URL/CID cannot be empty")]
     PaperUrlEmptyOrTooLong,
 
-    #[msg("Protocol Encryption Key cannot be empty")]
+    #[msg("Wrapped content key cannot be empty or too long")]
     EncryptionKeyEmptyOrTooLong,
 
     #[msg("Review Link/CID cannot be empty")]
@@ -58,4 +58,288 @@ pub enum ErrorCodes {
 
     #[msg("You must purchase the paper before reviewing it")]
     PaperNotPurchased,
+
+    #[msg("The review does not belong to this research paper")]
+    ReviewPaperMismatch,
+
+    #[msg("The program is paused by the platform admins")]
+    ProgramPaused,
+
+    #[msg("Fee basis points cannot exceed 10000")]
+    InvalidFee,
+
+    #[msg("Admin set cannot be empty or exceed the maximum number of admins")]
+    InvalidAdminSet,
+
+    #[msg("Bounty amount must be greater than zero")]
+    BountyAmountInvalid,
+
+    #[msg("Bounty deadline must be in the future and cannot be moved earlier")]
+    BountyDeadlineInvalid,
+
+    #[msg("The proposed reward exceeds the paper's remaining bounty")]
+    RewardExceedsBounty,
+
+    #[msg("The review bounty for this paper has expired")]
+    BountyExpired,
+
+    #[msg("The bounty cannot be reclaimed before its deadline")]
+    BountyStillActive,
+
+    #[msg("Token accounts are required for papers priced in an SPL token")]
+    TokenAccountsMissing,
+
+    #[msg("The payment mint does not match the paper's accepted mint")]
+    PaymentMintMismatch,
+
+    #[msg("Buyer encryption public key cannot be empty")]
+    BuyerEncryptionKeyInvalid,
+
+    #[msg("The access receipt does not belong to this research paper")]
+    ReceiptPaperMismatch,
+
+    #[msg("The content key has already been delivered for this receipt")]
+    KeyAlreadyDelivered,
+
+    #[msg("Key deliveries must be passed as (receipt, key delivery) account pairs")]
+    InvalidDeliveryAccounts,
+
+    #[msg("This action is not allowed in the paper's current status")]
+    InvalidPaperStatus,
+
+    #[msg("This paper is not published for sale")]
+    PaperNotForSale,
+
+    #[msg("This paper is not open for peer review")]
+    PaperNotUnderReview,
+
+    #[msg("This paper has been retracted")]
+    PaperIsRetracted,
+
+    #[msg("Only the author or a platform admin can retract this paper")]
+    UnauthorizedRetraction,
+
+    #[msg("Retraction reason code cannot be zero")]
+    RetractionReasonInvalid,
+
+    #[msg("Only unsold or retracted papers can be closed")]
+    PaperNotClosable,
+
+    #[msg("The paper's bounty escrow must be empty first")]
+    BountyNotEmpty,
+
+    #[msg("All purchased keys must be delivered first")]
+    DeliveriesOutstanding,
+
+    #[msg("Only rejected or flagged reviews can be closed")]
+    ReviewNotClosable,
+
+    #[msg("Rejected reviews can only be closed after the cooling period")]
+    ReviewCooldownActive,
+
+    #[msg("The content key for this receipt has not been delivered yet")]
+    KeyNotDelivered,
+
+    #[msg("The user's earnings must be withdrawn first")]
+    UserVaultNotEmpty,
+
+    #[msg("The user still has open papers, pending reviews or rejected reviews to close")]
+    UserHasOpenItems,
+
+    #[msg("Content hash cannot be all zeroes")]
+    ContentHashInvalid,
+
+    #[msg("This receipt does not grant access to the paper's current revision")]
+    RevisionNotCovered,
+
+    #[msg("Review hash cannot be all zeroes")]
+    ReviewHashInvalid,
+
+    #[msg("Refund window cannot be negative")]
+    RefundWindowInvalid,
+
+    #[msg("The refund window for this purchase has closed")]
+    RefundWindowClosed,
+
+    #[msg("The refund window for this purchase is still open")]
+    RefundWindowOpen,
+
+    #[msg("This purchase is not held in escrow")]
+    ReceiptNotEscrowed,
+
+    #[msg("No refund has been requested for this purchase")]
+    ReceiptNotDisputed,
+
+    #[msg("Only the author or a platform admin can resolve this refund")]
+    UnauthorizedRefund,
+
+    #[msg("This access receipt has been revoked")]
+    ReceiptRevoked,
+
+    #[msg("Escrowed purchase payments must be settled or refunded first")]
+    EscrowNotEmpty,
+
+    #[msg("This purchase has not been settled or refunded yet")]
+    ReceiptNotFinalized,
+
+    #[msg("Institution name must be between 1 and 100 characters")]
+    InstitutionNameInvalid,
+
+    #[msg("An institution needs at least one seat, and no fewer than its members")]
+    InstitutionSeatsInvalid,
+
+    #[msg("Every seat at this institution is taken")]
+    InstitutionSeatsFull,
+
+    #[msg("Only the institution admin can do this")]
+    UnauthorizedInstitutionAdmin,
+
+    #[msg("Institutional licenses need the institution and a membership proof")]
+    InstitutionMembershipMissing,
+
+    #[msg("This membership does not belong to the licensed institution")]
+    InstitutionMembershipInvalid,
+
+    #[msg("The institution has more members than this license paid for")]
+    LicenseSeatsExceeded,
+
+    #[msg("Subscription duration must be positive")]
+    PlanDurationInvalid,
+
+    #[msg("Subscription plan lists too many papers")]
+    PlanPapersTooMany,
+
+    #[msg("This subscription plan is not active")]
+    PlanInactive,
+
+    #[msg("This subscription has expired")]
+    SubscriptionExpired,
+
+    #[msg("This subscription does not cover the paper")]
+    SubscriptionNotCovering,
+
+    #[msg("This subscription has not expired yet")]
+    SubscriptionStillActive,
+
+    #[msg("A bundle needs between 1 and 10 papers")]
+    BundlePapersInvalid,
+
+    #[msg("Bundle accounts must come as (paper, receipt, author user, author vault) groups plus co-author pairs")]
+    InvalidBundleAccounts,
+
+    #[msg("The papers passed do not match the bundle")]
+    BundleMismatch,
+
+    #[msg("This bundle is not for sale")]
+    BundleInactive,
+
+    #[msg("Bundle discount must be below 100%")]
+    BundleDiscountInvalid,
+
+    #[msg("Papers priced in an SPL token must be bought individually")]
+    BundleTokenPaper,
+
+    #[msg("Co-authors must include the author once each, up to 5, with positive shares totalling 10000 bps")]
+    CoAuthorsInvalid,
+
+    #[msg("Co-author accounts are missing or do not match the paper's co-authors")]
+    CoAuthorAccountsInvalid,
+
+    #[msg("Every co-author must sign to join or change the revenue split")]
+    CoAuthorSignatureMissing,
+
+    #[msg("Only pending reviews, or rejected ones still in their cooldown, can be flagged")]
+    ReviewNotFlaggable,
+
+    #[msg("Review stake must be 0 or at least the rent-exempt minimum of an empty account")]
+    ReviewStakeInvalid,
+
+    #[msg("Minimum reputation cannot exceed 10000 bps")]
+    MinReputationInvalid,
+
+    #[msg("Reviewer reputation is below the paper's minimum")]
+    ReputationTooLow,
+
+    #[msg("Round needs 1 <= quorum <= target reviewers, an approval threshold of at most 10000 bps and a future deadline")]
+    ReviewRoundInvalid,
+
+    #[msg("This review round has already been finalized")]
+    ReviewRoundFinalized,
+
+    #[msg("A round that has reviews can only be finalized")]
+    ReviewRoundHasReviews,
+
+    #[msg("The review round cannot be finalized before its deadline unless it is full")]
+    ReviewRoundNotOver,
+
+    #[msg("The review round is closed to new reviews")]
+    ReviewRoundClosed,
+
+    #[msg("Review round does not match the paper or the review")]
+    ReviewRoundMismatch,
+
+    #[msg("Review scores and confidence must be between 1 and 5")]
+    ReviewScoresInvalid,
+
+    #[msg("Anonymous reviews need a non-zero review stake and cannot be submitted to papers with a minimum reputation")]
+    AnonymousReviewNotAllowed,
+
+    #[msg("Anonymous rewards can only be escrowed in lamports")]
+    AnonymousTokenReward,
+
+    #[msg("Identity commitment cannot be empty")]
+    IdentityCommitmentInvalid,
+
+    #[msg("The reviewer's user account is required for a named review")]
+    ReviewerAccountMissing,
+
+    #[msg(
+        "Only an anonymous review that the author has decided on can be revealed, and only once"
+    )]
+    ReviewNotRevealable,
+
+    #[msg("The wallet and salt do not match the review's identity commitment")]
+    IdentityCommitmentMismatch,
+
+    #[msg("Review response window must be between 1 second and 90 days")]
+    ReviewResponseWindowInvalid,
+
+    #[msg("Auto-accept can only pay rewards in lamports")]
+    AutoAcceptTokenPaper,
+
+    #[msg("The author's response window for this review has passed")]
+    ReviewResponseWindowPassed,
+
+    #[msg("The author can still respond to this review")]
+    ReviewResponseWindowOpen,
+
+    #[msg("A counter-offer must be lower than the proposed reward")]
+    CounterOfferInvalid,
+
+    #[msg("This review has no counter-offer to respond to")]
+    ReviewNotNegotiating,
+
+    #[msg("Every pending or negotiating review must be decided first")]
+    ReviewsOutstanding,
+
+    #[msg("The key must be for the revision the buyer is owed")]
+    KeyRevisionMismatch,
+
+    #[msg("The revision policy cannot change once the paper has buyers")]
+    RevisionPolicyLocked,
+
+    #[msg("An anonymous review is credited to a User account only when it is revealed")]
+    AnonymousReviewerAccount,
+
+    #[msg("The buyer's user account is required to refund a personal purchase")]
+    BuyerAccountMissing,
+
+    #[msg("The author or an admin can still resolve this dispute")]
+    DisputeWindowOpen,
+
+    #[msg("The paper must be closed first")]
+    PaperNotClosed,
+
+    #[msg("The key delivery does not belong to this research paper")]
+    KeyDeliveryPaperMismatch,
 }
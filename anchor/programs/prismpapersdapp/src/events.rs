@@ -0,0 +1,524 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{
+    CoAuthor, ReviewExpiryPolicy, ReviewScores, ReviewStatus, RevisionAccessPolicy, RoundOutcome,
+};
+
+//every state transition emits one of these, so indexers don't have to diff accounts
+
+#[event]
+pub struct ConfigUpdated {
+    pub updated_by: Pubkey,
+    pub admins: Vec<Pubkey>,
+    pub purchase_fee_bps: u16,
+    pub review_fee_bps: u16,
+    pub min_fee_lamports: u64,
+    pub treasury: Pubkey,
+    pub refund_window: i64,
+    pub review_stake: u64,
+    pub paused: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UserCreated {
+    pub user: Pubkey,
+    pub owner: Pubkey,
+    pub name: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PaperCreated {
+    pub paper: Pubkey,
+    pub author: Pubkey,
+    pub index: u16,
+    pub price: u64,
+    pub payment_mint: Option<Pubkey>,
+    pub content_hash: [u8; 32],
+    pub co_authors: Vec<CoAuthor>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PaperPublished {
+    pub paper: Pubkey,
+    pub author: Pubkey,
+    pub price: u64,
+    pub payment_mint: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PaperUpdated {
+    pub paper: Pubkey,
+    pub author: Pubkey,
+    pub revision: u32,
+    pub content_hash: [u8; 32],
+    pub old_price: u64,
+    pub new_price: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReviewRoundOpened {
+    pub paper: Pubkey,
+    pub author: Pubkey,
+    pub round: Pubkey,
+    pub round_id: u16,
+    pub target_reviewers: u16,
+    pub quorum: u16,
+    pub approval_bps: u16,
+    pub deadline: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReviewRoundClosed {
+    pub paper: Pubkey,
+    pub author: Pubkey,
+    pub round: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReviewRoundFinalized {
+    pub paper: Pubkey,
+    pub round: Pubkey,
+    pub reviews: u16,
+    pub approvals: u16,
+    pub outcome: RoundOutcome,
+    pub peer_reviewed: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PaperRetracted {
+    pub paper: Pubkey,
+    pub author: Pubkey,
+    pub retracted_by: Pubkey,
+    pub reason_code: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccessPurchased {
+    pub receipt: Pubkey,
+    pub paper: Pubkey,
+    pub buyer: Pubkey,
+    pub author: Pubkey,
+    pub payment_mint: Option<Pubkey>,
+    pub price: u64,
+    pub platform_fee: u64,
+    pub author_earning: u64,
+    pub refund_deadline: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct KeyDelivered {
+    pub key_delivery: Pubkey,
+    pub receipt: Pubkey,
+    pub paper: Pubkey,
+    pub revision: u32,
+    pub buyer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BountyFunded {
+    pub paper: Pubkey,
+    pub author: Pubkey,
+    pub payment_mint: Option<Pubkey>,
+    pub amount: u64,
+    pub bounty: u64,
+    pub deadline: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BountyReclaimed {
+    pub paper: Pubkey,
+    pub author: Pubkey,
+    pub payment_mint: Option<Pubkey>,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReviewSubmitted {
+    pub review: Pubkey,
+    pub paper: Pubkey,
+    pub reviewer: Pubkey,
+    pub round: Pubkey,
+    pub review_hash: [u8; 32],
+    pub scores: ReviewScores,
+    pub proposed_reward: u64,
+    pub stake: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AnonymousReviewSubmitted {
+    pub review: Pubkey,
+    pub paper: Pubkey,
+    pub pseudonym: Pubkey,
+    pub round: Pubkey,
+    pub review_hash: [u8; 32],
+    pub identity_commitment: [u8; 32],
+    pub scores: ReviewScores,
+    pub proposed_reward: u64,
+    pub stake: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReviewRevealed {
+    pub review: Pubkey,
+    pub paper: Pubkey,
+    pub pseudonym: Pubkey,
+    pub reviewer: Pubkey,
+    pub status: ReviewStatus,
+    pub reward_claimed: u64,
+    pub reviewer_reputation: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReviewVerdict {
+    pub review: Pubkey,
+    pub paper: Pubkey,
+    pub reviewer: Pubkey,
+    pub author: Pubkey,
+    pub status: ReviewStatus,
+    pub payment_mint: Option<Pubkey>,
+    pub reward: u64,
+    pub platform_fee: u64,
+    pub reviewer_earning: u64,
+    pub stake_returned: u64,
+    pub reviewer_reputation: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReviewCounterOffered {
+    pub review: Pubkey,
+    pub paper: Pubkey,
+    pub reviewer: Pubkey,
+    pub author: Pubkey,
+    pub proposed_reward: u64,
+    pub counter_offer: u64,
+    pub respond_by: i64, //the reviewer's deadline to answer
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CounterOfferResolved {
+    pub review: Pubkey,
+    pub paper: Pubkey,
+    pub reviewer: Pubkey,
+    pub status: ReviewStatus, //Accepted when the reviewer agreed, Withdrawn otherwise
+    pub payment_mint: Option<Pubkey>,
+    pub reward: u64,
+    pub platform_fee: u64,
+    pub reviewer_earning: u64,
+    pub stake_returned: u64,
+    pub reviewer_reputation: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PaperScoresUpdated {
+    pub paper: Pubkey,
+    pub scored_reviews: u32,
+    pub average_novelty: u16, //averages are in hundredths of a point
+    pub average_rigor: u16,
+    pub average_clarity: u16,
+    pub average_confidence: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UserWithdrawal {
+    pub user: Pubkey,
+    pub payment_mint: Option<Pubkey>,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminWithdrawal {
+    pub admin: Pubkey,
+    pub treasury: Pubkey,
+    pub payment_mint: Option<Pubkey>,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PaperClosed {
+    pub paper: Pubkey,
+    pub author: Pubkey,
+    pub timestamp: i64,
+}
+
+//A revision snapshot or key delivery of a closed paper was closed
+#[event]
+pub struct PaperRecordClosed {
+    pub paper: Pubkey,
+    pub record: Pubkey, //the PaperRevision or KeyDelivery account
+    pub revision: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReviewClosed {
+    pub review: Pubkey,
+    pub paper: Pubkey,
+    pub reviewer: Pubkey,
+    pub stake_returned: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReviewFlagged {
+    pub review: Pubkey,
+    pub paper: Pubkey,
+    pub reviewer: Pubkey,
+    pub flagged_by: Pubkey,
+    pub stake: u64,
+    pub slashed_to: Pubkey,
+    pub reviewer_reputation: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MinReputationUpdated {
+    pub paper: Pubkey,
+    pub author: Pubkey,
+    pub min_reputation: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReviewStakeUpdated {
+    pub paper: Pubkey,
+    pub author: Pubkey,
+    pub stake: Option<u64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReviewResponsePolicyUpdated {
+    pub paper: Pubkey,
+    pub author: Pubkey,
+    pub response_window: i64,
+    pub expiry_policy: ReviewExpiryPolicy,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReviewExpired {
+    pub review: Pubkey,
+    pub paper: Pubkey,
+    pub reviewer: Pubkey,
+    pub author: Pubkey,
+    pub expiry_policy: ReviewExpiryPolicy,
+    pub status: ReviewStatus, //Withdrawn for a counter-offer the reviewer never answered, Expired otherwise
+    pub reward: u64,
+    pub platform_fee: u64,
+    pub reviewer_earning: u64,
+    pub stake_returned: u64,
+    pub author_reputation: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReceiptClosed {
+    pub receipt: Pubkey,
+    pub paper: Pubkey,
+    pub buyer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UserClosed {
+    pub user: Pubkey,
+    pub owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RevisionPolicyUpdated {
+    pub paper: Pubkey,
+    pub author: Pubkey,
+    pub policy: RevisionAccessPolicy,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RefundRequested {
+    pub receipt: Pubkey,
+    pub paper: Pubkey,
+    pub buyer: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RefundResolved {
+    pub receipt: Pubkey,
+    pub paper: Pubkey,
+    pub buyer: Pubkey,
+    pub resolved_by: Pubkey,
+    pub refunded: bool,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PurchaseSettled {
+    pub receipt: Pubkey,
+    pub paper: Pubkey,
+    pub author: Pubkey,
+    pub payment_mint: Option<Pubkey>,
+    pub platform_fee: u64,
+    pub author_earning: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InstitutionCreated {
+    pub institution: Pubkey,
+    pub admin: Pubkey,
+    pub name: String,
+    pub seats: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InstitutionSeatsUpdated {
+    pub institution: Pubkey,
+    pub admin: Pubkey,
+    pub old_seats: u32,
+    pub new_seats: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InstitutionMemberAdded {
+    pub institution: Pubkey,
+    pub member: Pubkey,
+    pub members: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InstitutionMemberRemoved {
+    pub institution: Pubkey,
+    pub member: Pubkey,
+    pub members: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LicensePurchased {
+    pub receipt: Pubkey,
+    pub paper: Pubkey,
+    pub institution: Pubkey,
+    pub admin: Pubkey,
+    pub author: Pubkey,
+    pub payment_mint: Option<Pubkey>,
+    pub seats: u32,
+    pub price: u64,
+    pub platform_fee: u64,
+    pub author_earning: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PlanCreated {
+    pub plan: Pubkey,
+    pub author: Pubkey,
+    pub plan_id: u16,
+    pub price: u64,
+    pub payment_mint: Option<Pubkey>,
+    pub duration: i64,
+    pub papers: Vec<Pubkey>,
+    pub co_authors: Vec<CoAuthor>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PlanUpdated {
+    pub plan: Pubkey,
+    pub author: Pubkey,
+    pub plan_id: u16,
+    pub price: u64,
+    pub payment_mint: Option<Pubkey>,
+    pub duration: i64,
+    pub papers: Vec<Pubkey>,
+    pub active: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct Subscribed {
+    pub subscription: Pubkey,
+    pub plan: Pubkey,
+    pub subscriber: Pubkey,
+    pub author: Pubkey,
+    pub payment_mint: Option<Pubkey>,
+    pub price: u64,
+    pub platform_fee: u64,
+    pub author_earning: u64,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SubscriptionClosed {
+    pub subscription: Pubkey,
+    pub plan: Pubkey,
+    pub subscriber: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BundleCreated {
+    pub bundle: Pubkey,
+    pub author: Pubkey,
+    pub bundle_id: u16,
+    pub papers: Vec<Pubkey>,
+    pub discount_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BundleUpdated {
+    pub bundle: Pubkey,
+    pub author: Pubkey,
+    pub bundle_id: u16,
+    pub papers: Vec<Pubkey>,
+    pub discount_bps: u16,
+    pub active: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BundlePurchased {
+    pub buyer: Pubkey,
+    pub bundle: Option<Pubkey>,
+    pub papers: u8,
+    pub price: u64,
+    pub platform_fee: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CoAuthorsUpdated {
+    pub paper: Pubkey,
+    pub author: Pubkey,
+    pub co_authors: Vec<CoAuthor>,
+    pub timestamp: i64,
+}
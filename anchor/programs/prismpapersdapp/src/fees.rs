@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::BPS_DENOMINATOR, errors::ErrorCodes};
+
+//Splits `amount` into (platform_fee, remainder).
+//The fee is `amount * fee_bps / 10_000`, raised to `min_fee_lamports` when that is higher,
+//and never more than `amount` itself. A `min_fee_lamports` of 0 disables the minimum.
+pub fn split_fee(amount: u64, fee_bps: u16, min_fee_lamports: u64) -> Result<(u64, u64)> {
+    require!(
+        u64::from(fee_bps) <= BPS_DENOMINATOR,
+        ErrorCodes::InvalidFee
+    );
+
+    let bps_fee = u128::from(amount)
+        .checked_mul(u128::from(fee_bps))
+        .ok_or(ErrorCodes::MathOverflow)?
+        .checked_div(u128::from(BPS_DENOMINATOR))
+        .ok_or(ErrorCodes::MathOverflow)?;
+    //bps_fee <= amount because fee_bps <= BPS_DENOMINATOR
+    let bps_fee = u64::try_from(bps_fee).map_err(|_| ErrorCodes::MathOverflow)?;
+
+    let platform_fee = bps_fee.max(min_fee_lamports).min(amount);
+    let remainder = amount
+        .checked_sub(platform_fee)
+        .ok_or(ErrorCodes::MathOverflow)?;
+
+    Ok((platform_fee, remainder))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_by_basis_points() {
+        assert_eq!(
+            split_fee(1_000_000_000, 500, 0).unwrap(),
+            (50_000_000, 950_000_000)
+        );
+        assert_eq!(split_fee(10_000, 1, 0).unwrap(), (1, 9_999));
+        assert_eq!(split_fee(10_000, 0, 0).unwrap(), (0, 10_000));
+        assert_eq!(split_fee(10_000, 10_000, 0).unwrap(), (10_000, 0));
+    }
+
+    #[test]
+    fn applies_minimum_fee_to_small_amounts() {
+        //500 bps of 19 lamports rounds down to 0
+        assert_eq!(split_fee(19, 500, 0).unwrap(), (0, 19));
+        assert_eq!(split_fee(19, 500, 5).unwrap(), (5, 14));
+        //the minimum does not lower a larger bps fee
+        assert_eq!(split_fee(1_000_000, 500, 5).unwrap(), (50_000, 950_000));
+    }
+
+    #[test]
+    fn caps_minimum_fee_at_amount() {
+        assert_eq!(split_fee(3, 500, 5).unwrap(), (3, 0));
+        assert_eq!(split_fee(0, 500, 5).unwrap(), (0, 0));
+    }
+
+    #[test]
+    fn does_not_overflow_on_large_amounts() {
+        assert_eq!(split_fee(u64::MAX, 10_000, 0).unwrap(), (u64::MAX, 0));
+        let (fee, remainder) = split_fee(u64::MAX, 500, 0).unwrap();
+        assert_eq!(fee + remainder, u64::MAX);
+    }
+
+    #[test]
+    fn rejects_fee_above_one_hundred_percent() {
+        assert!(split_fee(100, 10_001, 0).is_err());
+    }
+}
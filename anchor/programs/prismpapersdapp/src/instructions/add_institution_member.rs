@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{ANCHOR_DISCRIMINATOR, INSTITUTION_SEED, MEMBER_SEED},
+    errors::ErrorCodes,
+    events::InstitutionMemberAdded,
+    states::{Institution, InstitutionMember},
+};
+
+#[derive(Accounts)]
+#[instruction(member: Pubkey)]
+pub struct AddInstitutionMember<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin @ ErrorCodes::UnauthorizedInstitutionAdmin,
+        seeds = [INSTITUTION_SEED, admin.key().as_ref()],
+        bump=institution.bump
+    )]
+    pub institution: Account<'info, Institution>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = ANCHOR_DISCRIMINATOR + InstitutionMember::INIT_SPACE,
+        seeds = [MEMBER_SEED, institution.key().as_ref(), member.as_ref()],
+        bump
+    )]
+    pub institution_member: Account<'info, InstitutionMember>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'a> AddInstitutionMember<'a> {
+    pub fn add_institution_member(
+        &mut self,
+        member: Pubkey,
+        bumps: &AddInstitutionMemberBumps,
+    ) -> Result<()> {
+        require!(
+            self.institution.members < self.institution.seats,
+            ErrorCodes::InstitutionSeatsFull
+        );
+
+        let institution = self.institution.key();
+        let timestamp = Clock::get()?.unix_timestamp;
+        self.institution_member.set_inner(InstitutionMember {
+            institution,
+            member,
+            timestamp,
+            bump: bumps.institution_member,
+        });
+        self.institution.members = self
+            .institution
+            .members
+            .checked_add(1u32)
+            .ok_or(ErrorCodes::MathOverflow)?;
+
+        emit!(InstitutionMemberAdded {
+            institution,
+            member,
+            members: self.institution.members,
+            timestamp,
+        });
+        Ok(())
+    }
+}
@@ -2,10 +2,17 @@ use anchor_lang::{
     prelude::*,
     system_program::{transfer, Transfer},
 };
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
 
 use crate::{
-    constants::{ADMIN_PUBKEYS, VAULT_SEED_ADMIN},
+    constants::{CONFIG_SEED, VAULT_SEED_ADMIN},
     errors::ErrorCodes,
+    events::AdminWithdrawal,
+    payments::transfer_tokens,
+    states::Config,
 };
 
 #[derive(Accounts)]
@@ -13,6 +20,12 @@ pub struct AdminWithdraw<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
 
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
     #[account(
         mut,
         seeds = [VAULT_SEED_ADMIN],
@@ -20,31 +33,108 @@ pub struct AdminWithdraw<'info> {
     )]
     pub admin_vault: SystemAccount<'info>,
 
+    //platform fees are always paid out to the configured treasury
+    #[account(
+        mut,
+        address = config.treasury
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    //token accounts, pass them to withdraw SPL token fees instead of SOL
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = admin_vault,
+        associated_token::token_program = token_program
+    )]
+    pub admin_token_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = payment_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program
+    )]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
     pub system_program: Program<'info, System>,
 }
 
 impl<'a> AdminWithdraw<'a> {
     pub fn admin_withdraw(&mut self, amount: u64, bumps: &AdminWithdrawBumps) -> Result<()> {
         require!(
-            self.admin_vault.lamports() >= amount,
-            ErrorCodes::InsufficientFundsInVault
+            self.config.is_admin(&self.admin.key()),
+            ErrorCodes::UnauthorizedAdmin
         );
+        let signer_seeds: &[&[&[u8]]] = &[&[VAULT_SEED_ADMIN, &[bumps.admin_vault]]];
+
+        if self.payment_mint.is_some() {
+            self.withdraw_tokens(amount, signer_seeds)?;
+            return self.emit_withdrawal(amount);
+        }
+
         require!(
-            ADMIN_PUBKEYS.contains(&self.admin.key()),
-            ErrorCodes::UnauthorizedAdmin
+            self.admin_vault.lamports() >= amount,
+            ErrorCodes::InsufficientFundsInVault
         );
 
         let cpi_program = self.system_program.to_account_info();
         let admin_vault = self.admin_vault.to_account_info();
-        let admin = self.admin.to_account_info();
+        let treasury = self.treasury.to_account_info();
         let cpi_account_options = Transfer {
             from: admin_vault,
-            to: admin,
+            to: treasury,
         };
-        let signer_seeds: &[&[&[u8]]] = &[&[VAULT_SEED_ADMIN, &[bumps.admin_vault]]];
         let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_account_options, signer_seeds);
         transfer(cpi_ctx, amount)?;
 
+        self.emit_withdrawal(amount)
+    }
+
+    fn emit_withdrawal(&self, amount: u64) -> Result<()> {
+        emit!(AdminWithdrawal {
+            admin: self.admin.key(),
+            treasury: self.treasury.key(),
+            payment_mint: self.payment_mint.as_ref().map(|mint| mint.key()),
+            amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
         Ok(())
     }
+
+    fn withdraw_tokens(&mut self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let (
+            Some(mint),
+            Some(admin_token_vault),
+            Some(treasury_token_account),
+            Some(token_program),
+        ) = (
+            &self.payment_mint,
+            &self.admin_token_vault,
+            &self.treasury_token_account,
+            &self.token_program,
+        )
+        else {
+            return err!(ErrorCodes::TokenAccountsMissing);
+        };
+        require!(
+            admin_token_vault.amount >= amount,
+            ErrorCodes::InsufficientFundsInVault
+        );
+
+        transfer_tokens(
+            admin_token_vault,
+            treasury_token_account,
+            self.admin_vault.to_account_info(),
+            mint,
+            token_program,
+            amount,
+            signer_seeds,
+        )
+    }
 }
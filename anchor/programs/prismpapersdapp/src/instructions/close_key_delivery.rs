@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::PAPER_SEED, errors::ErrorCodes, events::PaperRecordClosed, states::KeyDelivery,
+};
+
+//Lets the author reclaim a delivered key, to a buyer or a subscriber, once its paper is closed
+#[derive(Accounts)]
+pub struct CloseKeyDelivery<'info> {
+    #[account(mut)]
+    pub author: Signer<'info>,
+
+    /// CHECK: the author's closed paper, checked against `paper_index` and to hold no data anymore
+    pub research_paper: UncheckedAccount<'info>,
+
+    //the author paid for every key delivery, so the rent goes back to them
+    #[account(
+        mut,
+        close = author,
+        constraint = key_delivery.paper == research_paper.key() @ ErrorCodes::KeyDeliveryPaperMismatch
+    )]
+    pub key_delivery: Account<'info, KeyDelivery>,
+}
+
+impl<'a> CloseKeyDelivery<'a> {
+    pub fn close_key_delivery(&mut self, paper_index: u16) -> Result<()> {
+        //the paper PDA is derived from the signer, so only its author gets past this
+        let (expected_paper, _) = Pubkey::find_program_address(
+            &[
+                PAPER_SEED,
+                self.author.key().as_ref(),
+                paper_index.to_le_bytes().as_ref(),
+            ],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            self.research_paper.key(),
+            expected_paper,
+            ErrorCodes::UnauthorizedUpdate
+        );
+        require!(
+            self.research_paper.data_is_empty(),
+            ErrorCodes::PaperNotClosed
+        );
+
+        emit!(PaperRecordClosed {
+            paper: self.research_paper.key(),
+            record: self.key_delivery.key(),
+            revision: self.key_delivery.revision,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    access::release_receipt,
+    constants::{INSTITUTION_SEED, RECEIPT_SEED},
+    errors::ErrorCodes,
+    states::{AccessReceipt, Institution},
+};
+
+//`close_receipt` for a site license, whose buyer is the Institution PDA: the institution admin
+//who bought it closes it and gets the rent back
+#[derive(Accounts)]
+pub struct CloseLicenseReceipt<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        has_one = admin @ ErrorCodes::UnauthorizedInstitutionAdmin,
+        seeds = [INSTITUTION_SEED, admin.key().as_ref()],
+        bump=institution.bump
+    )]
+    pub institution: Account<'info, Institution>,
+
+    //closing the license withdraws every member's access to the paper
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            RECEIPT_SEED,
+            institution.key().as_ref(),
+            access_receipt.purchased_paper.key().as_ref()
+        ],
+        bump=access_receipt.bump
+    )]
+    pub access_receipt: Account<'info, AccessReceipt>,
+
+    /// CHECK: the licensed paper, which may already be closed; it is only deserialized while it still exists
+    #[account(mut, address = access_receipt.purchased_paper)]
+    pub research_paper: UncheckedAccount<'info>,
+}
+
+impl<'a> CloseLicenseReceipt<'a> {
+    pub fn close_license_receipt(&mut self) -> Result<()> {
+        release_receipt(&self.access_receipt, &self.research_paper.to_account_info())
+    }
+}
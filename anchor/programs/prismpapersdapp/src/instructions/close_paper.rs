@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{PAPER_SEED, USER_SEED},
+    errors::ErrorCodes,
+    events::PaperClosed,
+    splits::track_live_papers,
+    states::{CoAuthor, PaperStatus, ResearchPaper, User},
+};
+
+//`remaining_accounts` holds the User account of every co-author other than the author, in list order
+#[derive(Accounts)]
+pub struct ClosePaper<'info> {
+    #[account(mut)]
+    pub author: Signer<'info>,
+
+    #[account(
+        mut,
+        close = author,
+        seeds = [
+            PAPER_SEED,
+            research_paper.author.key().as_ref(),
+            research_paper.index.to_le_bytes().as_ref()
+        ],
+        bump=research_paper.bump
+    )]
+    pub research_paper: Account<'info, ResearchPaper>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, author.key().as_ref()],
+        bump=author_user_account.bump
+    )]
+    pub author_user_account: Account<'info, User>,
+}
+
+impl<'a> ClosePaper<'a> {
+    pub fn close_paper(&mut self, remaining_accounts: &'a [AccountInfo<'a>]) -> Result<()> {
+        require!(
+            self.author.key() == self.research_paper.author,
+            ErrorCodes::UnauthorizedUpdate
+        );
+        //buyers of a live paper keep relying on it, so only unsold or retracted papers can go
+        require!(
+            self.research_paper.sales == 0 || self.research_paper.status == PaperStatus::Retracted,
+            ErrorCodes::PaperNotClosable
+        );
+        //undecided reviews still hold a stake and a reserved reward against this paper
+        require!(
+            self.research_paper.open_reviews == 0,
+            ErrorCodes::ReviewsOutstanding
+        );
+        require!(self.research_paper.bounty == 0, ErrorCodes::BountyNotEmpty);
+        require!(
+            self.research_paper.escrowed == 0,
+            ErrorCodes::EscrowNotEmpty
+        );
+        require!(
+            self.research_paper.pending_deliveries == 0,
+            ErrorCodes::DeliveriesOutstanding
+        );
+
+        //updating the states
+        self.author_user_account.live_papers = self
+            .author_user_account
+            .live_papers
+            .checked_sub(1u16)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        let other_co_authors: Vec<CoAuthor> =
+            self.research_paper.other_co_authors().cloned().collect();
+        track_live_papers(remaining_accounts, &other_co_authors, false)?;
+
+        emit!(PaperClosed {
+            paper: self.research_paper.key(),
+            author: self.research_paper.author,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{access::release_receipt, constants::RECEIPT_SEED, states::AccessReceipt};
+
+#[derive(Accounts)]
+pub struct CloseReceipt<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    //closing the receipt gives up the on-chain proof of purchase
+    #[account(
+        mut,
+        close = buyer,
+        seeds = [
+            RECEIPT_SEED,
+            buyer.key().as_ref(),
+            access_receipt.purchased_paper.key().as_ref()
+        ],
+        bump=access_receipt.bump
+    )]
+    pub access_receipt: Account<'info, AccessReceipt>,
+
+    /// CHECK: the purchased paper, which may already be closed; it is only deserialized while it still exists
+    #[account(mut, address = access_receipt.purchased_paper)]
+    pub research_paper: UncheckedAccount<'info>,
+}
+
+impl<'a> CloseReceipt<'a> {
+    pub fn close_receipt(&mut self) -> Result<()> {
+        release_receipt(&self.access_receipt, &self.research_paper.to_account_info())
+    }
+}
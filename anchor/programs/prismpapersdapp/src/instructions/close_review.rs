@@ -0,0 +1,100 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+
+use crate::{
+    constants::{REVIEW_CLOSE_COOLDOWN, REVIEW_SEED, USER_SEED, VAULT_SEED_STAKE},
+    errors::ErrorCodes,
+    events::ReviewClosed,
+    states::{PeerReview, ReviewExpiryPolicy, ReviewStatus, User},
+};
+
+#[derive(Accounts)]
+pub struct CloseReview<'info> {
+    #[account(mut)]
+    pub reviewer: Signer<'info>,
+
+    #[account(
+        mut,
+        close = reviewer,
+        seeds = [REVIEW_SEED, reviewer.key().as_ref(), peer_review.reviewed_paper.key().as_ref()],
+        bump=peer_review.bump
+    )]
+    pub peer_review: Account<'info, PeerReview>,
+
+    //required to close a named rejected review, which still counts as open on the reviewer's account
+    #[account(
+        mut,
+        seeds = [USER_SEED, reviewer.key().as_ref()],
+        bump=reviewer_user_account.bump
+    )]
+    pub reviewer_user_account: Option<Account<'info, User>>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED_STAKE, peer_review.key().as_ref()],
+        bump
+    )]
+    pub stake_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'a> CloseReview<'a> {
+    pub fn close_review(&mut self, bumps: &CloseReviewBumps) -> Result<()> {
+        //a flagged review already lost its stake, so there is nothing left to wait for
+        let now = Clock::get()?.unix_timestamp;
+        match self.peer_review.status {
+            ReviewStatus::Rejected => {
+                //the rejection stays visible on-chain for a while before the rent can be reclaimed
+                let closable_at = self
+                    .peer_review
+                    .decided_at
+                    .checked_add(REVIEW_CLOSE_COOLDOWN)
+                    .ok_or(ErrorCodes::MathOverflow)?;
+                require!(now >= closable_at, ErrorCodes::ReviewCooldownActive);
+                self.peer_review
+                    .check_reviewer_account(self.reviewer_user_account.is_some())?;
+                if let Some(user) = &mut self.reviewer_user_account {
+                    user.rejected_open = user
+                        .rejected_open
+                        .checked_sub(1u16)
+                        .ok_or(ErrorCodes::MathOverflow)?;
+                }
+            }
+            ReviewStatus::Flagged => {}
+            //a released review was never decided and its stake is already back
+            ReviewStatus::Expired
+                if self.peer_review.expiry_policy == ReviewExpiryPolicy::Release => {}
+            //so was a review withdrawn over a counter-offer
+            ReviewStatus::Withdrawn => {}
+            _ => return err!(ErrorCodes::ReviewNotClosable),
+        }
+
+        //a rejection that was never flagged was in good faith, so the stake goes back
+        let stake_returned = self.peer_review.stake;
+        if stake_returned > 0 {
+            let peer_review = self.peer_review.key();
+            let signer_seeds: &[&[&[u8]]] =
+                &[&[VAULT_SEED_STAKE, peer_review.as_ref(), &[bumps.stake_vault]]];
+            let cpi_program = self.system_program.to_account_info();
+            let cpi_account_options = Transfer {
+                from: self.stake_vault.to_account_info(),
+                to: self.reviewer.to_account_info(),
+            };
+            let cpi_ctx =
+                CpiContext::new_with_signer(cpi_program, cpi_account_options, signer_seeds);
+            transfer(cpi_ctx, stake_returned)?;
+        }
+
+        emit!(ReviewClosed {
+            review: self.peer_review.key(),
+            paper: self.peer_review.reviewed_paper,
+            reviewer: self.peer_review.reviewer,
+            stake_returned,
+            timestamp: now,
+        });
+        Ok(())
+    }
+}
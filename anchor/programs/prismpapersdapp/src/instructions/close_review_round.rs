@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{PAPER_SEED, ROUND_SEED},
+    errors::ErrorCodes,
+    events::ReviewRoundClosed,
+    states::{PaperStatus, ResearchPaper, ReviewRound},
+};
+
+//Cancels a round nobody has reviewed yet. Once reviews are in, only `finalize_review_round` ends it,
+//so an author cannot walk away from critical reviews.
+#[derive(Accounts)]
+pub struct CloseReviewRound<'info> {
+    #[account(mut)]
+    pub author: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PAPER_SEED,
+            research_paper.author.key().as_ref(),
+            research_paper.index.to_le_bytes().as_ref()
+        ],
+        bump=research_paper.bump
+    )]
+    pub research_paper: Account<'info, ResearchPaper>,
+
+    #[account(
+        mut,
+        close = author,
+        seeds = [
+            ROUND_SEED,
+            research_paper.key().as_ref(),
+            review_round.round_id.to_le_bytes().as_ref()
+        ],
+        bump = review_round.bump
+    )]
+    pub review_round: Account<'info, ReviewRound>,
+}
+
+impl<'a> CloseReviewRound<'a> {
+    pub fn close_review_round(&mut self) -> Result<()> {
+        require!(
+            self.author.key() == self.research_paper.author,
+            ErrorCodes::UnauthorizedUpdate
+        );
+        //the paper stays on sale once the round is closed
+        require!(
+            self.research_paper.status == PaperStatus::UnderReview,
+            ErrorCodes::InvalidPaperStatus
+        );
+        require!(
+            self.review_round.outcome.is_none(),
+            ErrorCodes::ReviewRoundFinalized
+        );
+        require!(
+            self.review_round.reviews == 0,
+            ErrorCodes::ReviewRoundHasReviews
+        );
+
+        self.research_paper.status = PaperStatus::Published;
+
+        emit!(ReviewRoundClosed {
+            paper: self.research_paper.key(),
+            author: self.research_paper.author,
+            round: self.review_round.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{PAPER_SEED, REVISION_SEED},
+    errors::ErrorCodes,
+    events::PaperRecordClosed,
+    states::PaperRevision,
+};
+
+//Lets the author reclaim a revision snapshot once its paper is closed
+#[derive(Accounts)]
+pub struct CloseRevision<'info> {
+    #[account(mut)]
+    pub author: Signer<'info>,
+
+    /// CHECK: the author's closed paper, checked against `paper_index` and to hold no data anymore
+    pub research_paper: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = author,
+        seeds = [
+            REVISION_SEED,
+            research_paper.key().as_ref(),
+            paper_revision.revision.to_le_bytes().as_ref()
+        ],
+        bump=paper_revision.bump
+    )]
+    pub paper_revision: Account<'info, PaperRevision>,
+}
+
+impl<'a> CloseRevision<'a> {
+    pub fn close_revision(&mut self, paper_index: u16) -> Result<()> {
+        //the paper PDA is derived from the signer, so only its author gets past this
+        let (expected_paper, _) = Pubkey::find_program_address(
+            &[
+                PAPER_SEED,
+                self.author.key().as_ref(),
+                paper_index.to_le_bytes().as_ref(),
+            ],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            self.research_paper.key(),
+            expected_paper,
+            ErrorCodes::UnauthorizedUpdate
+        );
+        //buyers of a live paper can still check its content against the snapshot
+        require!(
+            self.research_paper.data_is_empty(),
+            ErrorCodes::PaperNotClosed
+        );
+
+        emit!(PaperRecordClosed {
+            paper: self.research_paper.key(),
+            record: self.paper_revision.key(),
+            revision: self.paper_revision.revision,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+}
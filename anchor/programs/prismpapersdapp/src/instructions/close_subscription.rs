@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::SUBSCRIPTION_SEED, errors::ErrorCodes, events::SubscriptionClosed,
+    states::Subscription,
+};
+
+#[derive(Accounts)]
+pub struct CloseSubscription<'info> {
+    #[account(mut)]
+    pub subscriber: Signer<'info>,
+
+    #[account(
+        mut,
+        close = subscriber,
+        seeds = [
+            SUBSCRIPTION_SEED,
+            subscriber.key().as_ref(),
+            subscription.plan.key().as_ref()
+        ],
+        bump=subscription.bump
+    )]
+    pub subscription: Account<'info, Subscription>,
+}
+
+impl<'a> CloseSubscription<'a> {
+    pub fn close_subscription(&mut self) -> Result<()> {
+        //closing early would throw away paid-for time
+        let timestamp = Clock::get()?.unix_timestamp;
+        require!(
+            timestamp >= self.subscription.expires_at,
+            ErrorCodes::SubscriptionStillActive
+        );
+
+        emit!(SubscriptionClosed {
+            subscription: self.subscription.key(),
+            plan: self.subscription.plan,
+            subscriber: self.subscriber.key(),
+            timestamp,
+        });
+        Ok(())
+    }
+}
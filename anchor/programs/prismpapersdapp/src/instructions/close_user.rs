@@ -0,0 +1,74 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+
+use crate::{
+    constants::{USER_SEED, VAULT_SEED_USER},
+    errors::ErrorCodes,
+    events::UserClosed,
+    states::User,
+};
+
+#[derive(Accounts)]
+pub struct CloseUser<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [USER_SEED, owner.key().as_ref()],
+        bump=user_account.bump
+    )]
+    pub user_account: Account<'info, User>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED_USER, owner.key().as_ref()],
+        bump
+    )]
+    pub user_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'a> CloseUser<'a> {
+    pub fn close_user(&mut self, bumps: &CloseUserBumps) -> Result<()> {
+        require!(
+            self.user_account.earning == 0,
+            ErrorCodes::UserVaultNotEmpty
+        );
+        //every paper must be closed and every review decided first, and rejected reviews closed,
+        //since flagging them needs this account
+        require!(
+            self.user_account.live_papers == 0
+                && self.user_account.pending_reviews == 0
+                && self.user_account.rejected_open == 0,
+            ErrorCodes::UserHasOpenItems
+        );
+
+        //whatever is left in the vault is owed to nobody else (anyone can top it up), so it goes to the owner
+        let leftover = self.user_vault.lamports();
+        if leftover > 0 {
+            let binding = self.owner.key();
+            let signer_seeds: &[&[&[u8]]] =
+                &[&[VAULT_SEED_USER, binding.as_ref(), &[bumps.user_vault]]];
+            let cpi_program = self.system_program.to_account_info();
+            let cpi_account_options = Transfer {
+                from: self.user_vault.to_account_info(),
+                to: self.owner.to_account_info(),
+            };
+            let cpi_ctx =
+                CpiContext::new_with_signer(cpi_program, cpi_account_options, signer_seeds);
+            transfer(cpi_ctx, leftover)?;
+        }
+
+        emit!(UserClosed {
+            user: self.user_account.key(),
+            owner: self.owner.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+}
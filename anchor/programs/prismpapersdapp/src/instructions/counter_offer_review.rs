@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{CONFIG_SEED, PAPER_SEED, REVIEW_SEED, USER_SEED},
+    errors::ErrorCodes,
+    events::ReviewCounterOffered,
+    states::{Config, PeerReview, ResearchPaper, ReviewStatus, User},
+};
+
+//Instead of taking the full proposed reward or rejecting, the author offers a lower one.
+//The review stays undecided until the reviewer accepts or withdraws with `respond_to_counter_offer`.
+#[derive(Accounts)]
+pub struct CounterOfferReview<'info> {
+    pub author: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [REVIEW_SEED, peer_review.reviewer.key().as_ref(), peer_review.reviewed_paper.key().as_ref()],
+        bump=peer_review.bump
+    )]
+    pub peer_review: Account<'info, PeerReview>,
+
+    #[account(
+        seeds = [
+            PAPER_SEED,
+            research_paper.author.key().as_ref(),
+            research_paper.index.to_le_bytes().as_ref()
+        ],
+        bump=research_paper.bump,
+        constraint = peer_review.reviewed_paper == research_paper.key() @ ErrorCodes::ReviewPaperMismatch
+    )]
+    pub research_paper: Account<'info, ResearchPaper>,
+
+    //a counter-offer answers the review in time, which counts towards the author's score
+    #[account(
+        mut,
+        seeds = [USER_SEED, author.key().as_ref()],
+        bump=author_user_account.bump
+    )]
+    pub author_user_account: Account<'info, User>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl<'a> CounterOfferReview<'a> {
+    pub fn counter_offer_review(&mut self, counter_offer: u64) -> Result<()> {
+        require!(!self.config.paused, ErrorCodes::ProgramPaused);
+        require!(
+            self.peer_review.status == ReviewStatus::Pending,
+            ErrorCodes::ReviewNotPending
+        );
+        require!(
+            self.author.key() == self.research_paper.author,
+            ErrorCodes::UnauthorizedUpdate
+        );
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            now < self.peer_review.response_deadline(&self.config, now)?,
+            ErrorCodes::ReviewResponseWindowPassed
+        );
+        require!(
+            counter_offer < self.peer_review.proposed_reward,
+            ErrorCodes::CounterOfferInvalid
+        );
+
+        //the proposed reward stays on record next to the counter-offer, and the reviewer
+        //gets the paper's response window to answer it before `expire_review` can withdraw it
+        let respond_by = now
+            .checked_add(self.research_paper.review_response_window)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        self.peer_review.status = ReviewStatus::Negotiating;
+        self.peer_review.counter_offer = Some(counter_offer);
+        self.peer_review.countered_at = now;
+        self.peer_review.respond_by = respond_by;
+        self.peer_review.paused_offset = self.config.paused_seconds_at(now)?;
+        self.author_user_account.reviews_answered = self
+            .author_user_account
+            .reviews_answered
+            .checked_add(1u16)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        self.author_user_account.refresh_author_reputation();
+
+        emit!(ReviewCounterOffered {
+            review: self.peer_review.key(),
+            paper: self.research_paper.key(),
+            reviewer: self.peer_review.reviewer,
+            author: self.author.key(),
+            proposed_reward: self.peer_review.proposed_reward,
+            counter_offer,
+            respond_by,
+            timestamp: now,
+        });
+        Ok(())
+    }
+}
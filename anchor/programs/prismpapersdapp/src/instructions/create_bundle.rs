@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{ANCHOR_DISCRIMINATOR, BPS_DENOMINATOR, BUNDLE_MAX_PAPERS, BUNDLE_SEED, USER_SEED},
+    errors::ErrorCodes,
+    events::BundleCreated,
+    states::{Bundle, User},
+};
+
+#[derive(Accounts)]
+#[instruction(bundle_id: u16)]
+pub struct CreateBundle<'info> {
+    #[account(mut)]
+    pub author: Signer<'info>,
+
+    //only registered users can sell bundles
+    #[account(
+        seeds = [USER_SEED, author.key().as_ref()],
+        bump=user_account.bump
+    )]
+    pub user_account: Account<'info, User>,
+
+    #[account(
+        init,
+        payer = author,
+        space = ANCHOR_DISCRIMINATOR + Bundle::INIT_SPACE,
+        seeds = [BUNDLE_SEED, author.key().as_ref(), bundle_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub bundle: Account<'info, Bundle>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'a> CreateBundle<'a> {
+    pub fn create_bundle(
+        &mut self,
+        bundle_id: u16,
+        papers: Vec<Pubkey>,
+        discount_bps: u16,
+        bumps: &CreateBundleBumps,
+    ) -> Result<()> {
+        require!(
+            !papers.is_empty() && papers.len() <= BUNDLE_MAX_PAPERS,
+            ErrorCodes::BundlePapersInvalid
+        );
+        require!(
+            u64::from(discount_bps) < BPS_DENOMINATOR,
+            ErrorCodes::BundleDiscountInvalid
+        );
+
+        let author = self.author.key();
+        let timestamp = Clock::get()?.unix_timestamp;
+        emit!(BundleCreated {
+            bundle: self.bundle.key(),
+            author,
+            bundle_id,
+            papers: papers.clone(),
+            discount_bps,
+            timestamp,
+        });
+        self.bundle.set_inner(Bundle {
+            author,
+            bundle_id,
+            papers,
+            discount_bps,
+            active: true,
+            sales: 0u32,
+            timestamp,
+            bump: bumps.bundle,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    constants::{ANCHOR_DISCRIMINATOR, PLAN_MAX_PAPERS, PLAN_SEED, USER_SEED},
+    errors::ErrorCodes,
+    events::PlanCreated,
+    splits::validate_co_authors,
+    states::{CoAuthor, SubscriptionPlan, User},
+};
+
+#[derive(Accounts)]
+#[instruction(plan_id: u16)]
+pub struct CreatePlan<'info> {
+    #[account(mut)]
+    pub author: Signer<'info>,
+
+    //only registered users can sell subscriptions
+    #[account(
+        seeds = [USER_SEED, author.key().as_ref()],
+        bump=user_account.bump
+    )]
+    pub user_account: Account<'info, User>,
+
+    #[account(
+        init,
+        payer = author,
+        space = ANCHOR_DISCRIMINATOR + SubscriptionPlan::INIT_SPACE,
+        seeds = [PLAN_SEED, author.key().as_ref(), plan_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub subscription_plan: Account<'info, SubscriptionPlan>,
+
+    //optional SPL mint the plan is priced in, lamports when omitted
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'a> CreatePlan<'a> {
+    pub fn create_plan(
+        &mut self,
+        plan_id: u16,
+        price: u64,
+        duration: i64,
+        papers: Vec<Pubkey>,
+        co_authors: Vec<CoAuthor>,
+        bumps: &CreatePlanBumps,
+    ) -> Result<()> {
+        require!(price > 0, ErrorCodes::ResearchPriceInvalid);
+        require!(duration > 0, ErrorCodes::PlanDurationInvalid);
+        require!(
+            papers.len() <= PLAN_MAX_PAPERS,
+            ErrorCodes::PlanPapersTooMany
+        );
+
+        let author = self.author.key();
+        validate_co_authors(author, &co_authors)?;
+        let payment_mint = self.payment_mint.as_ref().map(|mint| mint.key());
+        let timestamp = Clock::get()?.unix_timestamp;
+        emit!(PlanCreated {
+            plan: self.subscription_plan.key(),
+            author,
+            plan_id,
+            price,
+            payment_mint,
+            duration,
+            papers: papers.clone(),
+            co_authors: co_authors.clone(),
+            timestamp,
+        });
+        self.subscription_plan.set_inner(SubscriptionPlan {
+            author,
+            plan_id,
+            price,
+            payment_mint,
+            duration,
+            papers,
+            co_authors,
+            active: true,
+            subscribers: 0u32,
+            timestamp,
+            bump: bumps.subscription_plan,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{
+        ANCHOR_DISCRIMINATOR, ENCRYPTION_KEY_MAX_LENGTH, KEY_DELIVERY_SEED, PAPER_SEED,
+        RECEIPT_SEED,
+    },
+    errors::ErrorCodes,
+    events::KeyDelivered,
+    states::{AccessReceipt, KeyDelivery, ReceiptStatus, ResearchPaper},
+};
+
+#[derive(Accounts)]
+#[instruction(revision: u32)]
+pub struct DeliverKey<'info> {
+    #[account(mut)]
+    pub author: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PAPER_SEED,
+            research_paper.author.key().as_ref(),
+            research_paper.index.to_le_bytes().as_ref()
+        ],
+        bump=research_paper.bump
+    )]
+    pub research_paper: Account<'info, ResearchPaper>,
+
+    #[account(
+        mut,
+        seeds = [
+            RECEIPT_SEED,
+            access_receipt.buyer.key().as_ref(),
+            research_paper.key().as_ref()
+        ],
+        bump=access_receipt.bump
+    )]
+    pub access_receipt: Account<'info, AccessReceipt>,
+
+    #[account(
+        init,
+        payer = author,
+        space = ANCHOR_DISCRIMINATOR + KeyDelivery::INIT_SPACE,
+        seeds = [
+            KEY_DELIVERY_SEED,
+            access_receipt.key().as_ref(),
+            revision.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub key_delivery: Account<'info, KeyDelivery>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'a> DeliverKey<'a> {
+    pub fn deliver_key(
+        &mut self,
+        revision: u32,
+        wrapped_key: Vec<u8>,
+        bumps: &DeliverKeyBumps,
+    ) -> Result<()> {
+        require!(
+            self.author.key() == self.research_paper.author,
+            ErrorCodes::UnauthorizedUpdate
+        );
+        require!(
+            !wrapped_key.is_empty() && wrapped_key.len() < ENCRYPTION_KEY_MAX_LENGTH,
+            ErrorCodes::EncryptionKeyEmptyOrTooLong
+        );
+        require!(
+            self.access_receipt.status != ReceiptStatus::Revoked,
+            ErrorCodes::ReceiptRevoked
+        );
+        //a receipt is owed the key of its own revision, or of the latest one under AllRevisions
+        require!(
+            revision
+                == self
+                    .research_paper
+                    .owed_key_revision(self.access_receipt.revision),
+            ErrorCodes::KeyRevisionMismatch
+        );
+        require!(
+            self.research_paper.owes_key(&self.access_receipt),
+            ErrorCodes::KeyAlreadyDelivered
+        );
+
+        //storing the wrapped key for the buyer
+        let timestamp = Clock::get()?.unix_timestamp;
+        self.key_delivery.set_inner(KeyDelivery {
+            access_receipt: self.access_receipt.key(),
+            buyer: self.access_receipt.buyer,
+            paper: self.research_paper.key(),
+            revision,
+            wrapped_key,
+            timestamp,
+            bump: bumps.key_delivery,
+        });
+
+        //updating the states
+        self.access_receipt.key_revision = Some(revision);
+        self.research_paper.pending_deliveries = self
+            .research_paper
+            .pending_deliveries
+            .checked_sub(1u32)
+            .ok_or(ErrorCodes::MathOverflow)?;
+
+        emit!(KeyDelivered {
+            key_delivery: self.key_delivery.key(),
+            receipt: self.access_receipt.key(),
+            paper: self.research_paper.key(),
+            revision,
+            buyer: self.access_receipt.buyer,
+            timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{ANCHOR_DISCRIMINATOR, ENCRYPTION_KEY_MAX_LENGTH, KEY_DELIVERY_SEED, PAPER_SEED},
+    errors::ErrorCodes,
+    events::KeyDelivered,
+    payments::create_pda_account,
+    states::{AccessReceipt, KeyDelivery, ReceiptStatus, ResearchPaper},
+};
+
+//Bulk fallback for `deliver_key`: fulfils many pending buyers of one paper in a single transaction.
+//`remaining_accounts` holds one (access_receipt, key_delivery) pair per entry of `wrapped_keys`.
+#[derive(Accounts)]
+pub struct DeliverKeys<'info> {
+    #[account(mut)]
+    pub author: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PAPER_SEED,
+            research_paper.author.key().as_ref(),
+            research_paper.index.to_le_bytes().as_ref()
+        ],
+        bump=research_paper.bump
+    )]
+    pub research_paper: Account<'info, ResearchPaper>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'a> DeliverKeys<'a> {
+    pub fn deliver_keys(
+        &mut self,
+        wrapped_keys: Vec<Vec<u8>>,
+        remaining_accounts: &'a [AccountInfo<'a>],
+    ) -> Result<()> {
+        require!(
+            self.author.key() == self.research_paper.author,
+            ErrorCodes::UnauthorizedUpdate
+        );
+        require!(
+            !wrapped_keys.is_empty() && remaining_accounts.len() == wrapped_keys.len() * 2,
+            ErrorCodes::InvalidDeliveryAccounts
+        );
+
+        let paper = self.research_paper.key();
+        let space = ANCHOR_DISCRIMINATOR + KeyDelivery::INIT_SPACE;
+        let timestamp = Clock::get()?.unix_timestamp;
+
+        for (accounts, wrapped_key) in remaining_accounts.chunks(2).zip(wrapped_keys) {
+            let (receipt_info, delivery_info) = (&accounts[0], &accounts[1]);
+            require!(
+                !wrapped_key.is_empty() && wrapped_key.len() < ENCRYPTION_KEY_MAX_LENGTH,
+                ErrorCodes::EncryptionKeyEmptyOrTooLong
+            );
+
+            //the receipt must be a live receipt for this paper, still waiting for its key
+            let mut access_receipt = Account::<AccessReceipt>::try_from(receipt_info)?;
+            require_keys_eq!(
+                access_receipt.purchased_paper,
+                paper,
+                ErrorCodes::ReceiptPaperMismatch
+            );
+            require!(
+                access_receipt.status != ReceiptStatus::Revoked,
+                ErrorCodes::ReceiptRevoked
+            );
+            require!(
+                self.research_paper.owes_key(&access_receipt),
+                ErrorCodes::KeyAlreadyDelivered
+            );
+
+            //each buyer gets the key of the revision they are owed right now
+            let revision = self
+                .research_paper
+                .owed_key_revision(access_receipt.revision);
+            let revision_bytes = revision.to_le_bytes();
+            let receipt = receipt_info.key();
+            let (expected_delivery, bump) = Pubkey::find_program_address(
+                &[KEY_DELIVERY_SEED, receipt.as_ref(), revision_bytes.as_ref()],
+                &crate::ID,
+            );
+            require_keys_eq!(
+                delivery_info.key(),
+                expected_delivery,
+                ErrorCodes::InvalidDeliveryAccounts
+            );
+
+            //creating the key delivery PDA, the same account `deliver_key` would init
+            let signer_seeds: &[&[&[u8]]] = &[&[
+                KEY_DELIVERY_SEED,
+                receipt.as_ref(),
+                revision_bytes.as_ref(),
+                &[bump],
+            ]];
+            create_pda_account(
+                &self.author.to_account_info(),
+                delivery_info,
+                &self.system_program.to_account_info(),
+                space,
+                signer_seeds,
+            )?;
+
+            let key_delivery = KeyDelivery {
+                access_receipt: receipt,
+                buyer: access_receipt.buyer,
+                paper,
+                revision,
+                wrapped_key,
+                timestamp,
+                bump,
+            };
+            key_delivery.try_serialize(&mut &mut delivery_info.try_borrow_mut_data()?[..])?;
+
+            //updating the states
+            access_receipt.key_revision = Some(revision);
+            access_receipt.exit(&crate::ID)?;
+            self.research_paper.pending_deliveries = self
+                .research_paper
+                .pending_deliveries
+                .checked_sub(1u32)
+                .ok_or(ErrorCodes::MathOverflow)?;
+
+            emit!(KeyDelivered {
+                key_delivery: expected_delivery,
+                receipt,
+                paper,
+                revision,
+                buyer: access_receipt.buyer,
+                timestamp,
+            });
+        }
+
+        Ok(())
+    }
+}
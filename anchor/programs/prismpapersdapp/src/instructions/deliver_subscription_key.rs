@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{
+        ANCHOR_DISCRIMINATOR, ENCRYPTION_KEY_MAX_LENGTH, KEY_DELIVERY_SEED, PAPER_SEED, PLAN_SEED,
+        SUBSCRIPTION_SEED,
+    },
+    errors::ErrorCodes,
+    events::KeyDelivered,
+    states::{KeyDelivery, ResearchPaper, Subscription, SubscriptionPlan},
+};
+
+//Wraps the content key of a paper's current revision to an active subscriber.
+//Subscriptions hold no receipt, so the delivery's `access_receipt` field stores the subscription.
+#[derive(Accounts)]
+#[instruction(revision: u32)]
+pub struct DeliverSubscriptionKey<'info> {
+    #[account(mut)]
+    pub author: Signer<'info>,
+
+    #[account(
+        seeds = [
+            PAPER_SEED,
+            research_paper.author.key().as_ref(),
+            research_paper.index.to_le_bytes().as_ref()
+        ],
+        bump=research_paper.bump
+    )]
+    pub research_paper: Account<'info, ResearchPaper>,
+
+    #[account(
+        seeds = [
+            PLAN_SEED,
+            subscription_plan.author.key().as_ref(),
+            subscription_plan.plan_id.to_le_bytes().as_ref()
+        ],
+        bump=subscription_plan.bump
+    )]
+    pub subscription_plan: Account<'info, SubscriptionPlan>,
+
+    #[account(
+        seeds = [
+            SUBSCRIPTION_SEED,
+            subscription.subscriber.key().as_ref(),
+            subscription_plan.key().as_ref()
+        ],
+        bump=subscription.bump
+    )]
+    pub subscription: Account<'info, Subscription>,
+
+    #[account(
+        init,
+        payer = author,
+        space = ANCHOR_DISCRIMINATOR + KeyDelivery::INIT_SPACE,
+        seeds = [
+            KEY_DELIVERY_SEED,
+            subscription.key().as_ref(),
+            research_paper.key().as_ref(),
+            revision.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub key_delivery: Account<'info, KeyDelivery>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'a> DeliverSubscriptionKey<'a> {
+    pub fn deliver_subscription_key(
+        &mut self,
+        revision: u32,
+        wrapped_key: Vec<u8>,
+        bumps: &DeliverSubscriptionKeyBumps,
+    ) -> Result<()> {
+        require!(
+            self.author.key() == self.research_paper.author,
+            ErrorCodes::UnauthorizedUpdate
+        );
+        require!(
+            !wrapped_key.is_empty() && wrapped_key.len() < ENCRYPTION_KEY_MAX_LENGTH,
+            ErrorCodes::EncryptionKeyEmptyOrTooLong
+        );
+        require!(
+            self.subscription_plan.covers(&self.research_paper),
+            ErrorCodes::SubscriptionNotCovering
+        );
+        let timestamp = Clock::get()?.unix_timestamp;
+        require!(
+            timestamp < self.subscription.expires_at,
+            ErrorCodes::SubscriptionExpired
+        );
+        //subscribers are owed the key of whatever revision is current while they subscribe
+        require!(
+            revision == self.research_paper.revision,
+            ErrorCodes::KeyRevisionMismatch
+        );
+
+        //storing the wrapped key for the subscriber
+        let subscriber = self.subscription.subscriber;
+        self.key_delivery.set_inner(KeyDelivery {
+            access_receipt: self.subscription.key(),
+            buyer: subscriber,
+            paper: self.research_paper.key(),
+            revision,
+            wrapped_key,
+            timestamp,
+            bump: bumps.key_delivery,
+        });
+
+        emit!(KeyDelivered {
+            key_delivery: self.key_delivery.key(),
+            receipt: self.subscription.key(),
+            paper: self.research_paper.key(),
+            revision,
+            buyer: subscriber,
+            timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,198 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{
+        CONFIG_SEED, PAPER_SEED, REVIEW_SEED, USER_SEED, VAULT_SEED_ADMIN, VAULT_SEED_BOUNTY,
+        VAULT_SEED_STAKE, VAULT_SEED_USER,
+    },
+    errors::ErrorCodes,
+    events::ReviewExpired,
+    rewards::{pay_reward_in_sol, record_scores, return_stake},
+    states::{Config, PeerReview, ResearchPaper, ReviewExpiryPolicy, ReviewStatus, User},
+};
+
+//Permissionless crank for a review left unanswered past its response window.
+//A review the author left pending gets the expiry policy it was submitted under and is marked `Expired`.
+//A counter-offer the reviewer left unanswered lapses, and the review is withdrawn as if they had declined.
+#[derive(Accounts)]
+pub struct ExpireReview<'info> {
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [REVIEW_SEED, peer_review.reviewer.key().as_ref(), peer_review.reviewed_paper.key().as_ref()],
+        bump=peer_review.bump
+    )]
+    pub peer_review: Account<'info, PeerReview>,
+
+    #[account(
+        mut,
+        seeds = [
+            PAPER_SEED,
+            research_paper.author.key().as_ref(),
+            research_paper.index.to_le_bytes().as_ref()
+        ],
+        bump=research_paper.bump,
+        constraint = peer_review.reviewed_paper == research_paper.key() @ ErrorCodes::ReviewPaperMismatch
+    )]
+    pub research_paper: Account<'info, ResearchPaper>,
+
+    //receives the stake back
+    #[account(
+        mut,
+        address = peer_review.reviewer
+    )]
+    pub reviewer: SystemAccount<'info>,
+
+    //required for named reviews and rejected for anonymous ones, which are only credited by `reveal_review`
+    #[account(
+        mut,
+        seeds = [USER_SEED, peer_review.reviewer.key().as_ref()],
+        bump=reviewer_user_account.bump
+    )]
+    pub reviewer_user_account: Option<Account<'info, User>>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED_USER, peer_review.reviewer.key().as_ref()],
+        bump
+    )]
+    pub reviewer_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, research_paper.author.key().as_ref()],
+        bump=author_user_account.bump
+    )]
+    pub author_user_account: Account<'info, User>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED_STAKE, peer_review.key().as_ref()],
+        bump
+    )]
+    pub stake_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED_BOUNTY, research_paper.key().as_ref()],
+        bump
+    )]
+    pub bounty_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED_ADMIN],
+        bump
+    )]
+    pub admin_vault: SystemAccount<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'a> ExpireReview<'a> {
+    pub fn expire_review(&mut self, bumps: &ExpireReviewBumps) -> Result<()> {
+        require!(!self.config.paused, ErrorCodes::ProgramPaused);
+        require!(
+            matches!(
+                self.peer_review.status,
+                ReviewStatus::Pending | ReviewStatus::Negotiating
+            ),
+            ErrorCodes::ReviewNotPending
+        );
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            now >= self.peer_review.response_deadline(&self.config, now)?,
+            ErrorCodes::ReviewResponseWindowOpen
+        );
+        self.peer_review
+            .check_reviewer_account(self.reviewer_user_account.is_some())?;
+
+        let (mut total_amount, mut platform_fee, mut reviewer_earning) = (0u64, 0u64, 0u64);
+        let status = match (&self.peer_review.status, self.peer_review.expiry_policy) {
+            //the reviewer let the counter-offer lapse, settled like a declined one
+            (ReviewStatus::Negotiating, _) => ReviewStatus::Withdrawn,
+            (_, ReviewExpiryPolicy::AutoAccept) => {
+                //paid the way verify_review pays an accepted review, out of its reservation
+                total_amount = self.peer_review.proposed_reward;
+                //auto-accept is only offered on lamport-priced papers, see set_review_response_policy
+                require!(
+                    self.research_paper.payment_mint.is_none(),
+                    ErrorCodes::AutoAcceptTokenPaper
+                );
+                (platform_fee, reviewer_earning) = pay_reward_in_sol(
+                    &mut self.peer_review,
+                    self.reviewer_user_account.as_deref_mut(),
+                    self.research_paper.key(),
+                    &self.bounty_vault.to_account_info(),
+                    &self.reviewer_vault.to_account_info(),
+                    &self.stake_vault.to_account_info(),
+                    &self.admin_vault.to_account_info(),
+                    &self.system_program.to_account_info(),
+                    &self.config,
+                    total_amount,
+                    bumps.bounty_vault,
+                )?;
+                if let Some(user) = &mut self.reviewer_user_account {
+                    user.reviews_accepted = user
+                        .reviews_accepted
+                        .checked_add(1u16)
+                        .ok_or(ErrorCodes::MathOverflow)?;
+                }
+                record_scores(&mut self.research_paper, &self.peer_review)?;
+                ReviewStatus::Expired
+            }
+            (_, ReviewExpiryPolicy::Release) => {
+                //the reviewer walks away unpaid but unharmed, the silence costs the author's score
+                self.author_user_account.reviews_expired = self
+                    .author_user_account
+                    .reviews_expired
+                    .checked_add(1u16)
+                    .ok_or(ErrorCodes::MathOverflow)?;
+                self.author_user_account.refresh_author_reputation();
+                ReviewStatus::Expired
+            }
+        };
+        self.research_paper
+            .decide_review(self.peer_review.proposed_reward, total_amount)?;
+        let stake_returned = return_stake(
+            &mut self.peer_review,
+            &self.stake_vault.to_account_info(),
+            &self.reviewer.to_account_info(),
+            &self.system_program.to_account_info(),
+            bumps.stake_vault,
+        )?;
+
+        self.peer_review.status = status;
+        self.peer_review.decided_at = now;
+        if let Some(user) = &mut self.reviewer_user_account {
+            user.refresh_reputation();
+            user.pending_reviews = user
+                .pending_reviews
+                .checked_sub(1u16)
+                .ok_or(ErrorCodes::MathOverflow)?;
+        }
+
+        emit!(ReviewExpired {
+            review: self.peer_review.key(),
+            paper: self.research_paper.key(),
+            reviewer: self.peer_review.reviewer,
+            author: self.research_paper.author,
+            expiry_policy: self.peer_review.expiry_policy,
+            status: self.peer_review.status.clone(),
+            reward: total_amount,
+            platform_fee,
+            reviewer_earning,
+            stake_returned,
+            author_reputation: self.author_user_account.author_reputation,
+            timestamp: now,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{PAPER_SEED, ROUND_SEED},
+    errors::ErrorCodes,
+    events::ReviewRoundFinalized,
+    states::{PaperStatus, ResearchPaper, ReviewRound},
+};
+
+//Permissionless crank that tallies a round once its deadline has passed or every reviewer slot is
+//filled, and records the outcome on the paper.
+#[derive(Accounts)]
+pub struct FinalizeReviewRound<'info> {
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PAPER_SEED,
+            research_paper.author.key().as_ref(),
+            research_paper.index.to_le_bytes().as_ref()
+        ],
+        bump=research_paper.bump
+    )]
+    pub research_paper: Account<'info, ResearchPaper>,
+
+    #[account(
+        mut,
+        seeds = [
+            ROUND_SEED,
+            research_paper.key().as_ref(),
+            review_round.round_id.to_le_bytes().as_ref()
+        ],
+        bump = review_round.bump
+    )]
+    pub review_round: Account<'info, ReviewRound>,
+}
+
+impl<'a> FinalizeReviewRound<'a> {
+    pub fn finalize_review_round(&mut self) -> Result<()> {
+        require!(
+            self.review_round.outcome.is_none(),
+            ErrorCodes::ReviewRoundFinalized
+        );
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            now >= self.review_round.deadline || self.review_round.is_full(),
+            ErrorCodes::ReviewRoundNotOver
+        );
+
+        let outcome = self.review_round.tally();
+        self.review_round.outcome = Some(outcome);
+        self.review_round.finalized_at = now;
+        self.research_paper.review_outcome = Some(outcome);
+        //a paper retracted during the round stays retracted
+        if self.research_paper.status == PaperStatus::UnderReview {
+            self.research_paper.status = PaperStatus::Published;
+        }
+
+        emit!(ReviewRoundFinalized {
+            paper: self.research_paper.key(),
+            round: self.review_round.key(),
+            reviews: self.review_round.reviews,
+            approvals: self.review_round.approvals,
+            outcome,
+            peer_reviewed: self.research_paper.is_peer_reviewed(),
+            timestamp: now,
+        });
+        Ok(())
+    }
+}
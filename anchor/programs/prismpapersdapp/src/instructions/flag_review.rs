@@ -0,0 +1,240 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+
+use crate::{
+    constants::{
+        CONFIG_SEED, PAPER_SEED, REVIEW_CLOSE_COOLDOWN, REVIEW_SEED, ROUND_SEED, USER_SEED,
+        VAULT_SEED_STAKE, VAULT_SEED_USER,
+    },
+    errors::ErrorCodes,
+    events::ReviewFlagged,
+    splits::{pay_co_authors_sol, split_earning},
+    states::{CoAuthor, Config, PeerReview, ResearchPaper, ReviewRound, ReviewStatus, User},
+};
+
+//An admin acting as arbiter marks a review as abusive and slashes its stake,
+//either to the paper's authors, split like a sale, or to the platform treasury.
+//When slashing to the authors, `remaining_accounts` holds a (User account, user vault) pair for
+//every co-author other than the author, in list order.
+#[derive(Accounts)]
+pub struct FlagReview<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [REVIEW_SEED, peer_review.reviewer.key().as_ref(), peer_review.reviewed_paper.key().as_ref()],
+        bump=peer_review.bump
+    )]
+    pub peer_review: Account<'info, PeerReview>,
+
+    #[account(
+        mut,
+        seeds = [
+            PAPER_SEED,
+            research_paper.author.key().as_ref(),
+            research_paper.index.to_le_bytes().as_ref()
+        ],
+        bump=research_paper.bump,
+        constraint = peer_review.reviewed_paper == research_paper.key() @ ErrorCodes::ReviewPaperMismatch
+    )]
+    pub research_paper: Account<'info, ResearchPaper>,
+
+    #[account(
+        mut,
+        seeds = [
+            ROUND_SEED,
+            research_paper.key().as_ref(),
+            review_round.round_id.to_le_bytes().as_ref()
+        ],
+        bump = review_round.bump,
+        constraint = peer_review.round == review_round.key() @ ErrorCodes::ReviewRoundMismatch
+    )]
+    pub review_round: Account<'info, ReviewRound>,
+
+    //required for named reviews and rejected for anonymous ones, a flagged anonymous review can no longer be revealed
+    #[account(
+        mut,
+        seeds = [USER_SEED, peer_review.reviewer.key().as_ref()],
+        bump=reviewer_user_account.bump
+    )]
+    pub reviewer_user_account: Option<Account<'info, User>>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, research_paper.author.key().as_ref()],
+        bump=author_user_account.bump
+    )]
+    pub author_user_account: Account<'info, User>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED_USER, research_paper.author.key().as_ref()],
+        bump
+    )]
+    pub author_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED_STAKE, peer_review.key().as_ref()],
+        bump
+    )]
+    pub stake_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        address = config.treasury
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'a> FlagReview<'a> {
+    pub fn flag_review(
+        &mut self,
+        slash_to_author: bool,
+        remaining_accounts: &'a [AccountInfo<'a>],
+        bumps: &FlagReviewBumps,
+    ) -> Result<()> {
+        require!(
+            self.config.is_admin(&self.admin.key()),
+            ErrorCodes::UnauthorizedAdmin
+        );
+        self.peer_review
+            .check_reviewer_account(self.reviewer_user_account.is_some())?;
+
+        //undecided reviews can be flagged right away, rejected ones until their stake is returned
+        let now = Clock::get()?.unix_timestamp;
+        match self.peer_review.status {
+            ReviewStatus::Pending | ReviewStatus::Negotiating => {
+                //an abusive review is never paid, its reward goes back to the open bounty
+                self.research_paper
+                    .decide_review(self.peer_review.proposed_reward, 0)?;
+                if let Some(user) = &mut self.reviewer_user_account {
+                    user.pending_reviews = user
+                        .pending_reviews
+                        .checked_sub(1u16)
+                        .ok_or(ErrorCodes::MathOverflow)?;
+                }
+            }
+            ReviewStatus::Rejected => {
+                let closable_at = self
+                    .peer_review
+                    .decided_at
+                    .checked_add(REVIEW_CLOSE_COOLDOWN)
+                    .ok_or(ErrorCodes::MathOverflow)?;
+                require!(now < closable_at, ErrorCodes::ReviewNotFlaggable);
+                if let Some(user) = &mut self.reviewer_user_account {
+                    user.rejected_open = user
+                        .rejected_open
+                        .checked_sub(1u16)
+                        .ok_or(ErrorCodes::MathOverflow)?;
+                }
+            }
+            _ => return err!(ErrorCodes::ReviewNotFlaggable),
+        }
+
+        //slashing the stake, an author payout lands in the authors' vaults as regular earnings
+        let stake = self.peer_review.stake;
+        let slashed_to = if slash_to_author {
+            self.author_vault.key()
+        } else {
+            self.treasury.key()
+        };
+        if stake > 0 {
+            let peer_review = self.peer_review.key();
+            let signer_seeds: &[&[&[u8]]] =
+                &[&[VAULT_SEED_STAKE, peer_review.as_ref(), &[bumps.stake_vault]]];
+            let (amount, to) = if slash_to_author {
+                let (author_share, co_author_shares) = split_earning(
+                    stake,
+                    self.research_paper.author,
+                    &self.research_paper.co_authors,
+                )?;
+                let co_authors: Vec<CoAuthor> =
+                    self.research_paper.other_co_authors().cloned().collect();
+                pay_co_authors_sol(
+                    &self.stake_vault.to_account_info(),
+                    &self.system_program.to_account_info(),
+                    remaining_accounts,
+                    &co_authors,
+                    &co_author_shares,
+                    signer_seeds,
+                )?;
+                self.author_user_account.earning = self
+                    .author_user_account
+                    .earning
+                    .checked_add(author_share)
+                    .ok_or(ErrorCodes::MathOverflow)?;
+                (author_share, self.author_vault.to_account_info())
+            } else {
+                (stake, self.treasury.to_account_info())
+            };
+            let cpi_program = self.system_program.to_account_info();
+            let cpi_account_options = Transfer {
+                from: self.stake_vault.to_account_info(),
+                to,
+            };
+            let cpi_ctx =
+                CpiContext::new_with_signer(cpi_program, cpi_account_options, signer_seeds);
+            transfer(cpi_ctx, amount)?;
+        }
+
+        //an abusive review loses its vote while the round is still open
+        if self.review_round.outcome.is_none() && self.peer_review.counts_in_round() {
+            self.review_round.reviews = self
+                .review_round
+                .reviews
+                .checked_sub(1u16)
+                .ok_or(ErrorCodes::MathOverflow)?;
+            if self
+                .peer_review
+                .scores
+                .recommendation
+                .recommends_acceptance()
+            {
+                self.review_round.approvals = self
+                    .review_round
+                    .approvals
+                    .checked_sub(1u16)
+                    .ok_or(ErrorCodes::MathOverflow)?;
+            }
+        }
+
+        //a flag on top of a rejection weighs on the reviewer's reputation twice
+        if let Some(user) = &mut self.reviewer_user_account {
+            user.reviews_flagged = user
+                .reviews_flagged
+                .checked_add(1u16)
+                .ok_or(ErrorCodes::MathOverflow)?;
+            user.refresh_reputation();
+        }
+
+        self.peer_review.status = ReviewStatus::Flagged;
+        self.peer_review.stake = 0;
+        self.peer_review.decided_at = now;
+
+        emit!(ReviewFlagged {
+            review: self.peer_review.key(),
+            paper: self.research_paper.key(),
+            reviewer: self.peer_review.reviewer,
+            flagged_by: self.admin.key(),
+            stake,
+            slashed_to,
+            reviewer_reputation: self
+                .reviewer_user_account
+                .as_ref()
+                .map_or(0, |user| user.reputation),
+            timestamp: now,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,153 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    constants::{PAPER_SEED, VAULT_SEED_BOUNTY},
+    errors::ErrorCodes,
+    events::BountyFunded,
+    payments::transfer_tokens,
+    states::{PaperStatus, ResearchPaper},
+};
+
+#[derive(Accounts)]
+pub struct FundBounty<'info> {
+    #[account(mut)]
+    pub author: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PAPER_SEED,
+            research_paper.author.key().as_ref(),
+            research_paper.index.to_le_bytes().as_ref()
+        ],
+        bump=research_paper.bump
+    )]
+    pub research_paper: Account<'info, ResearchPaper>,
+
+    //paper-scoped escrow that accepted reviews are paid from
+    #[account(
+        mut,
+        seeds = [VAULT_SEED_BOUNTY, research_paper.key().as_ref()],
+        bump
+    )]
+    pub bounty_vault: SystemAccount<'info>,
+
+    //token accounts, only required when the paper is priced in an SPL token
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = author,
+        associated_token::token_program = token_program
+    )]
+    pub author_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = author,
+        associated_token::mint = payment_mint,
+        associated_token::authority = bounty_vault,
+        associated_token::token_program = token_program
+    )]
+    pub bounty_token_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'a> FundBounty<'a> {
+    pub fn fund_bounty(&mut self, amount: u64, deadline: i64) -> Result<()> {
+        require!(
+            self.author.key() == self.research_paper.author,
+            ErrorCodes::UnauthorizedUpdate
+        );
+        require!(
+            self.research_paper.status != PaperStatus::Retracted,
+            ErrorCodes::PaperIsRetracted
+        );
+        require!(amount > 0, ErrorCodes::BountyAmountInvalid);
+        require!(
+            deadline > Clock::get()?.unix_timestamp
+                && deadline >= self.research_paper.bounty_deadline,
+            ErrorCodes::BountyDeadlineInvalid
+        );
+
+        //the bounty is escrowed in the same currency the paper is priced in
+        match self.research_paper.payment_mint {
+            None => self.escrow_sol(amount)?,
+            Some(payment_mint) => self.escrow_tokens(payment_mint, amount)?,
+        }
+
+        //updating the states
+        self.research_paper.bounty = self
+            .research_paper
+            .bounty
+            .checked_add(amount)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        self.research_paper.bounty_deadline = deadline;
+
+        emit!(BountyFunded {
+            paper: self.research_paper.key(),
+            author: self.research_paper.author,
+            payment_mint: self.research_paper.payment_mint,
+            amount,
+            bounty: self.research_paper.bounty,
+            deadline,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    fn escrow_sol(&mut self, amount: u64) -> Result<()> {
+        require!(
+            self.author.lamports() >= amount,
+            ErrorCodes::InsufficientFundsInWallet
+        );
+
+        let cpi_program = self.system_program.to_account_info();
+        let author = self.author.to_account_info();
+        let bounty_vault = self.bounty_vault.to_account_info();
+        let cpi_account_options = Transfer {
+            from: author,
+            to: bounty_vault,
+        };
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_account_options);
+        transfer(cpi_ctx, amount)
+    }
+
+    fn escrow_tokens(&mut self, payment_mint: Pubkey, amount: u64) -> Result<()> {
+        let (Some(mint), Some(author_token_account), Some(bounty_token_vault), Some(token_program)) = (
+            &self.payment_mint,
+            &self.author_token_account,
+            &self.bounty_token_vault,
+            &self.token_program,
+        ) else {
+            return err!(ErrorCodes::TokenAccountsMissing);
+        };
+        require_keys_eq!(mint.key(), payment_mint, ErrorCodes::PaymentMintMismatch);
+        require!(
+            author_token_account.amount >= amount,
+            ErrorCodes::InsufficientFundsInWallet
+        );
+
+        transfer_tokens(
+            author_token_account,
+            bounty_token_vault,
+            self.author.to_account_info(),
+            mint,
+            token_program,
+            amount,
+            &[],
+        )
+    }
+}
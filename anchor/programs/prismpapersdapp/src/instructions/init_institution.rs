@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{ANCHOR_DISCRIMINATOR, INSTITUTION_NAME_MAX_LENGTH, INSTITUTION_SEED},
+    errors::ErrorCodes,
+    events::InstitutionCreated,
+    states::Institution,
+};
+
+#[derive(Accounts)]
+pub struct InitInstitution<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = ANCHOR_DISCRIMINATOR + Institution::INIT_SPACE,
+        seeds = [INSTITUTION_SEED, admin.key().as_ref()],
+        bump
+    )]
+    pub institution: Account<'info, Institution>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'a> InitInstitution<'a> {
+    pub fn init_institution(
+        &mut self,
+        name: String,
+        seats: u32,
+        bumps: &InitInstitutionBumps,
+    ) -> Result<()> {
+        require!(
+            !name.is_empty() && name.len() < INSTITUTION_NAME_MAX_LENGTH,
+            ErrorCodes::InstitutionNameInvalid
+        );
+        require!(seats > 0, ErrorCodes::InstitutionSeatsInvalid);
+        let admin = self.admin.key();
+        let timestamp = Clock::get()?.unix_timestamp;
+
+        emit!(InstitutionCreated {
+            institution: self.institution.key(),
+            admin,
+            name: name.clone(),
+            seats,
+            timestamp,
+        });
+        self.institution.set_inner(Institution {
+            admin,
+            name,
+            seats,
+            members: 0u32,
+            licenses: 0u32,
+            timestamp,
+            bump: bumps.institution,
+        });
+
+        Ok(())
+    }
+}
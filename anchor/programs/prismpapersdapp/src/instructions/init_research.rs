@@ -1,86 +1,192 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
 
 use crate::{
     constants::{
-        ANCHOR_DISCRIMINATOR, ENCRYPTION_KEY_MAX_LENGTH, PAPER_DESCRIPTION_MAX_LENGTH,
-        PAPER_INIT_STAT, PAPER_SEED, PAPER_TITLE_MAX_LENGTH, PAPER_URL_MAX_LENGTH, USER_SEED,
+        ANCHOR_DISCRIMINATOR, DEFAULT_REVIEW_RESPONSE_WINDOW, PAPER_COUNTER_SEED,
+        PAPER_DESCRIPTION_MAX_LENGTH, PAPER_INIT_STAT, PAPER_SEED, PAPER_TITLE_MAX_LENGTH,
+        PAPER_URL_MAX_LENGTH, REVISION_SEED, USER_SEED,
     },
     errors::ErrorCodes,
-    states::{ResearchPaper, User},
+    events::PaperCreated,
+    splits::{require_co_author_signatures, track_live_papers, validate_co_authors},
+    states::{
+        CoAuthor, PaperCounter, PaperRevision, PaperStatus, ResearchPaper, ReviewAggregate,
+        ReviewExpiryPolicy, RevisionAccessPolicy, User,
+    },
 };
 
+//`remaining_accounts` holds, for the co-authors other than the author: their wallets as signers,
+//then their User accounts, each in list order
 #[derive(Accounts)]
 #[instruction(title: String)]
 pub struct InitResearch<'info> {
     #[account(mut)]
     pub author: Signer<'info>,
 
+    #[account(
+        mut,
+        seeds = [USER_SEED, author.key().as_ref()],
+        bump=user_account.bump
+    )]
+    pub user_account: Account<'info, User>,
+
+    #[account(
+        init_if_needed,
+        payer = author,
+        space = ANCHOR_DISCRIMINATOR + PaperCounter::INIT_SPACE,
+        seeds = [PAPER_COUNTER_SEED, author.key().as_ref()],
+        bump
+    )]
+    pub paper_counter: Account<'info, PaperCounter>,
+
+    //each author gets a sequence of papers, indexed by their paper counter
     #[account(
         init,
         payer = author,
         space = ANCHOR_DISCRIMINATOR + ResearchPaper::INIT_SPACE,
-        seeds = [PAPER_SEED, author.key().as_ref()],
+        seeds = [
+            PAPER_SEED,
+            author.key().as_ref(),
+            paper_counter.next_index.to_le_bytes().as_ref()
+        ],
         bump
     )]
     pub research_paper: Account<'info, ResearchPaper>,
 
+    //revision 0, the content the paper is created with
     #[account(
-        mut,
-        seeds = [USER_SEED, user_account.owner.key().as_ref()],
-        bump=user_account.bump
+        init,
+        payer = author,
+        space = ANCHOR_DISCRIMINATOR + PaperRevision::INIT_SPACE,
+        seeds = [REVISION_SEED, research_paper.key().as_ref(), 0u32.to_le_bytes().as_ref()],
+        bump
     )]
-    pub user_account: Account<'info, User>,
+    pub paper_revision: Account<'info, PaperRevision>,
+
+    //the SPL token the paper is priced in, omit to price the paper in SOL
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
 
     pub system_program: Program<'info, System>,
 }
 
 impl<'a> InitResearch<'a> {
+    #[allow(clippy::too_many_arguments)]
     pub fn initiate_research(
         &mut self,
         title: String,
         description: String,
         price: u64,
         encrypted_url: String,
-        encryption_key: String,
+        content_hash: [u8; 32],
+        ciphertext_hash: Option<[u8; 32]>,
+        co_authors: Vec<CoAuthor>,
+        remaining_accounts: &'a [AccountInfo<'a>],
         bumps: &InitResearchBumps,
     ) -> Result<()> {
         require!(
-            title.len() > 0 && title.len() < PAPER_TITLE_MAX_LENGTH,
+            !title.is_empty() && title.len() < PAPER_TITLE_MAX_LENGTH,
             ErrorCodes::PaperTitleInvalid
         );
         require!(
-            description.len() > 0 && description.len() < PAPER_DESCRIPTION_MAX_LENGTH,
+            !description.is_empty() && description.len() < PAPER_DESCRIPTION_MAX_LENGTH,
             ErrorCodes::PaperDescriptionInvalid
         );
         require!(
-            encrypted_url.len() > 0 && encrypted_url.len() < PAPER_URL_MAX_LENGTH,
+            !encrypted_url.is_empty() && encrypted_url.len() < PAPER_URL_MAX_LENGTH,
             ErrorCodes::PaperUrlEmptyOrTooLong
         );
+        require!(price > 0, ErrorCodes::ResearchPriceInvalid);
+        require!(content_hash != [0u8; 32], ErrorCodes::ContentHashInvalid);
         require!(
-            encryption_key.len() > 0 && encryption_key.len() < ENCRYPTION_KEY_MAX_LENGTH,
-            ErrorCodes::EncryptionKeyEmptyOrTooLong
+            ciphertext_hash != Some([0u8; 32]),
+            ErrorCodes::ContentHashInvalid
         );
-        require!(price > 0, ErrorCodes::ResearchPriceInvalid);
 
         let author = self.author.key();
+        validate_co_authors(author, &co_authors)?;
+        let index = self.paper_counter.next_index;
+        let payment_mint = self.payment_mint.as_ref().map(|mint| mint.key());
+        let timestamp = Clock::get()?.unix_timestamp;
 
         //updating the states
+        self.paper_counter.author = author;
+        self.paper_counter.next_index = index.checked_add(1u16).ok_or(ErrorCodes::MathOverflow)?;
+        self.paper_counter.bump = bumps.paper_counter;
         self.research_paper.set_inner(ResearchPaper {
             author,
+            index,
             title,
             description,
             price,
+            payment_mint,
             sales: PAPER_INIT_STAT,
             reviews: PAPER_INIT_STAT,
-            encrypted_url,
-            encryption_key,
-            timestamp: Clock::get()?.unix_timestamp,
+            open_reviews: PAPER_INIT_STAT,
+            bounty: 0u64,
+            bounty_reserved: 0u64,
+            bounty_deadline: 0i64,
+            encrypted_url: encrypted_url.clone(),
+            content_hash,
+            ciphertext_hash,
+            pending_deliveries: PAPER_INIT_STAT,
+            live_receipts: PAPER_INIT_STAT,
+            escrowed: 0u64,
+            status: PaperStatus::Draft,
+            retraction_reason: 0u8,
+            revision: 0u32,
+            revision_policy: RevisionAccessPolicy::AllRevisions,
+            co_authors: co_authors.clone(),
+            review_stake: None,
+            min_reputation: 0u16,
+            review_rounds: 0u16,
+            review_outcome: None,
+            review_scores: ReviewAggregate::default(),
+            review_response_window: DEFAULT_REVIEW_RESPONSE_WINDOW,
+            review_expiry_policy: ReviewExpiryPolicy::Release,
+            timestamp,
             bump: bumps.research_paper,
         });
-        self.user_account
+        self.paper_revision.set_inner(PaperRevision {
+            paper: self.research_paper.key(),
+            revision: 0u32,
+            content_hash,
+            ciphertext_hash,
+            encrypted_url,
+            price,
+            timestamp,
+            bump: bumps.paper_revision,
+        });
+        self.user_account.published = self
+            .user_account
             .published
             .checked_add(1u16)
             .ok_or(ErrorCodes::MathOverflow)?;
+        self.user_account.live_papers = self
+            .user_account
+            .live_papers
+            .checked_add(1u16)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        let other_co_authors: Vec<CoAuthor> =
+            self.research_paper.other_co_authors().cloned().collect();
+        require!(
+            remaining_accounts.len() == other_co_authors.len() * 2,
+            ErrorCodes::CoAuthorAccountsInvalid
+        );
+        let (signers, user_infos) = remaining_accounts.split_at(other_co_authors.len());
+        require_co_author_signatures(signers, &other_co_authors)?;
+        track_live_papers(user_infos, &other_co_authors, true)?;
+
+        emit!(PaperCreated {
+            paper: self.research_paper.key(),
+            author,
+            index,
+            price,
+            payment_mint,
+            content_hash,
+            co_authors,
+            timestamp,
+        });
         Ok(())
     }
 }
@@ -5,6 +5,8 @@ use crate::{
         ANCHOR_DISCRIMINATOR, USER_INIT_STAT, USER_NAME_MAX_LENGTH, USER_SEED, VAULT_SEED_USER,
     },
     errors::ErrorCodes,
+    events::UserCreated,
+    reputation::reputation_score,
     states::User,
 };
 
@@ -34,11 +36,18 @@ pub struct InitUser<'info> {
 impl<'a> InitUser<'a> {
     pub fn initiate_user(&mut self, name: String, bumps: &InitUserBumps) -> Result<()> {
         require!(
-            name.len() > 0 && name.len() < USER_NAME_MAX_LENGTH,
+            !name.is_empty() && name.len() < USER_NAME_MAX_LENGTH,
             ErrorCodes::UserNameInvalid
         );
         let owner = self.owner.key();
+        let timestamp = Clock::get()?.unix_timestamp;
 
+        emit!(UserCreated {
+            user: self.user_account.key(),
+            owner,
+            name: name.clone(),
+            timestamp,
+        });
         self.user_account.set_inner(User {
             owner,
             name,
@@ -46,8 +55,20 @@ impl<'a> InitUser<'a> {
             purchased: USER_INIT_STAT,
             reviewed: USER_INIT_STAT,
             sold: USER_INIT_STAT,
+            live_papers: USER_INIT_STAT,
+            pending_reviews: USER_INIT_STAT,
+            rejected_open: USER_INIT_STAT,
+            reviews_accepted: USER_INIT_STAT,
+            reviews_rejected: USER_INIT_STAT,
+            reviews_flagged: USER_INIT_STAT,
+            reviews_answered: USER_INIT_STAT,
+            reviews_expired: USER_INIT_STAT,
+            author_reputation: reputation_score(USER_INIT_STAT, USER_INIT_STAT, USER_INIT_STAT),
+            review_rewards: 0u64,
+            reputation: reputation_score(USER_INIT_STAT, USER_INIT_STAT, USER_INIT_STAT),
             earning: 0u64,
-            timestamp: Clock::get()?.unix_timestamp,
+            withdrawn: 0u64,
+            timestamp,
             bump: bumps.user_account,
         });
 
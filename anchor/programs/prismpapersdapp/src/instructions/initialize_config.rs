@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{ANCHOR_DISCRIMINATOR, BPS_DENOMINATOR, CONFIG_SEED, MAX_ADMINS},
+    errors::ErrorCodes,
+    events::ConfigUpdated,
+    program::Prismpapersdapp,
+    states::Config,
+};
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + Config::INIT_SPACE,
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    //only the program's upgrade authority can bootstrap the config
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, Prismpapersdapp>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key())
+            @ ErrorCodes::UnauthorizedAdmin
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'a> InitializeConfig<'a> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize_config(
+        &mut self,
+        admins: Vec<Pubkey>,
+        purchase_fee_bps: u16,
+        review_fee_bps: u16,
+        min_fee_lamports: u64,
+        treasury: Pubkey,
+        refund_window: i64,
+        review_stake: u64,
+        bumps: &InitializeConfigBumps,
+    ) -> Result<()> {
+        require!(
+            !admins.is_empty() && admins.len() <= MAX_ADMINS,
+            ErrorCodes::InvalidAdminSet
+        );
+        require!(
+            u64::from(purchase_fee_bps) <= BPS_DENOMINATOR
+                && u64::from(review_fee_bps) <= BPS_DENOMINATOR,
+            ErrorCodes::InvalidFee
+        );
+        require!(refund_window >= 0, ErrorCodes::RefundWindowInvalid);
+        //the stake vault is an empty system account, so a smaller stake could never be deposited
+        require!(
+            review_stake == 0 || review_stake >= Rent::get()?.minimum_balance(0),
+            ErrorCodes::ReviewStakeInvalid
+        );
+
+        self.config.set_inner(Config {
+            admins,
+            purchase_fee_bps,
+            review_fee_bps,
+            min_fee_lamports,
+            treasury,
+            refund_window,
+            review_stake,
+            paused: false,
+            paused_at: 0i64,
+            paused_seconds: 0i64,
+            bump: bumps.config,
+        });
+
+        emit!(ConfigUpdated {
+            updated_by: self.authority.key(),
+            admins: self.config.admins.clone(),
+            purchase_fee_bps,
+            review_fee_bps,
+            min_fee_lamports,
+            treasury,
+            refund_window,
+            review_stake,
+            paused: self.config.paused,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+}
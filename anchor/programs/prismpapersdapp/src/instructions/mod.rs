@@ -1,16 +1,106 @@
+pub mod add_institution_member;
 pub mod admin_withdraw;
+pub mod close_key_delivery;
+pub mod close_license_receipt;
+pub mod close_paper;
+pub mod close_receipt;
+pub mod close_review;
+pub mod close_review_round;
+pub mod close_revision;
+pub mod close_subscription;
+pub mod close_user;
+pub mod counter_offer_review;
+pub mod create_bundle;
+pub mod create_plan;
+pub mod deliver_key;
+pub mod deliver_keys;
+pub mod deliver_subscription_key;
+pub mod expire_review;
+pub mod finalize_review_round;
+pub mod flag_review;
+pub mod fund_bounty;
+pub mod init_institution;
 pub mod init_research;
 pub mod init_user;
-pub mod review_paper;
+pub mod initialize_config;
+pub mod open_review_round;
+pub mod publish_paper;
 pub mod purchase_access;
+pub mod purchase_bundle;
+pub mod purchase_license;
+pub mod reclaim_bounty;
+pub mod remove_institution_member;
+pub mod request_refund;
+pub mod resolve_refund;
+pub mod respond_to_counter_offer;
+pub mod retract_paper;
+pub mod reveal_review;
+pub mod review_paper;
+pub mod review_paper_anonymously;
+pub mod set_co_authors;
+pub mod set_institution_seats;
+pub mod set_min_reputation;
+pub mod set_review_response_policy;
+pub mod set_review_stake;
+pub mod set_revision_policy;
+pub mod settle_purchase;
+pub mod subscribe;
+pub mod update_bundle;
+pub mod update_config;
+pub mod update_plan;
 pub mod update_research;
 pub mod user_withdraw;
 pub mod verify_review;
+pub use add_institution_member::*;
 pub use admin_withdraw::*;
+pub use close_key_delivery::*;
+pub use close_license_receipt::*;
+pub use close_paper::*;
+pub use close_receipt::*;
+pub use close_review::*;
+pub use close_review_round::*;
+pub use close_revision::*;
+pub use close_subscription::*;
+pub use close_user::*;
+pub use counter_offer_review::*;
+pub use create_bundle::*;
+pub use create_plan::*;
+pub use deliver_key::*;
+pub use deliver_keys::*;
+pub use deliver_subscription_key::*;
+pub use expire_review::*;
+pub use finalize_review_round::*;
+pub use flag_review::*;
+pub use fund_bounty::*;
+pub use init_institution::*;
 pub use init_research::*;
 pub use init_user::*;
-pub use review_paper::*;
+pub use initialize_config::*;
+pub use open_review_round::*;
+pub use publish_paper::*;
 pub use purchase_access::*;
+pub use purchase_bundle::*;
+pub use purchase_license::*;
+pub use reclaim_bounty::*;
+pub use remove_institution_member::*;
+pub use request_refund::*;
+pub use resolve_refund::*;
+pub use respond_to_counter_offer::*;
+pub use retract_paper::*;
+pub use reveal_review::*;
+pub use review_paper::*;
+pub use review_paper_anonymously::*;
+pub use set_co_authors::*;
+pub use set_institution_seats::*;
+pub use set_min_reputation::*;
+pub use set_review_response_policy::*;
+pub use set_review_stake::*;
+pub use set_revision_policy::*;
+pub use settle_purchase::*;
+pub use subscribe::*;
+pub use update_bundle::*;
+pub use update_config::*;
+pub use update_plan::*;
 pub use update_research::*;
 pub use user_withdraw::*;
 pub use verify_review::*;
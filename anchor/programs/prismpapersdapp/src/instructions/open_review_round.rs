@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{ANCHOR_DISCRIMINATOR, BPS_DENOMINATOR, PAPER_SEED, ROUND_SEED},
+    errors::ErrorCodes,
+    events::ReviewRoundOpened,
+    states::{PaperStatus, ResearchPaper, ReviewRound},
+};
+
+#[derive(Accounts)]
+pub struct OpenReviewRound<'info> {
+    #[account(mut)]
+    pub author: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PAPER_SEED,
+            research_paper.author.key().as_ref(),
+            research_paper.index.to_le_bytes().as_ref()
+        ],
+        bump=research_paper.bump
+    )]
+    pub research_paper: Account<'info, ResearchPaper>,
+
+    #[account(
+        init,
+        payer = author,
+        space = ANCHOR_DISCRIMINATOR + ReviewRound::INIT_SPACE,
+        seeds = [
+            ROUND_SEED,
+            research_paper.key().as_ref(),
+            research_paper.review_rounds.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub review_round: Account<'info, ReviewRound>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'a> OpenReviewRound<'a> {
+    pub fn open_review_round(
+        &mut self,
+        target_reviewers: u16,
+        quorum: u16,
+        approval_bps: u16,
+        deadline: i64,
+        bumps: &OpenReviewRoundBumps,
+    ) -> Result<()> {
+        require!(
+            self.author.key() == self.research_paper.author,
+            ErrorCodes::UnauthorizedUpdate
+        );
+        //peer reviews are only accepted while a round is open
+        require!(
+            self.research_paper.status == PaperStatus::Published,
+            ErrorCodes::InvalidPaperStatus
+        );
+        let timestamp = Clock::get()?.unix_timestamp;
+        require!(
+            quorum > 0
+                && quorum <= target_reviewers
+                && approval_bps > 0
+                && u64::from(approval_bps) <= BPS_DENOMINATOR
+                && deadline > timestamp,
+            ErrorCodes::ReviewRoundInvalid
+        );
+
+        let paper = self.research_paper.key();
+        let round_id = self.research_paper.review_rounds;
+        self.review_round.set_inner(ReviewRound {
+            paper,
+            round_id,
+            target_reviewers,
+            quorum,
+            approval_bps,
+            deadline,
+            reviews: 0u16,
+            approvals: 0u16,
+            outcome: None,
+            finalized_at: 0i64,
+            timestamp,
+            bump: bumps.review_round,
+        });
+        self.research_paper.review_rounds =
+            round_id.checked_add(1u16).ok_or(ErrorCodes::MathOverflow)?;
+        self.research_paper.status = PaperStatus::UnderReview;
+
+        emit!(ReviewRoundOpened {
+            paper,
+            author: self.research_paper.author,
+            round: self.review_round.key(),
+            round_id,
+            target_reviewers,
+            quorum,
+            approval_bps,
+            deadline,
+            timestamp,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::PAPER_SEED,
+    errors::ErrorCodes,
+    events::PaperPublished,
+    states::{PaperStatus, ResearchPaper},
+};
+
+#[derive(Accounts)]
+pub struct PublishPaper<'info> {
+    pub author: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PAPER_SEED,
+            research_paper.author.key().as_ref(),
+            research_paper.index.to_le_bytes().as_ref()
+        ],
+        bump=research_paper.bump
+    )]
+    pub research_paper: Account<'info, ResearchPaper>,
+}
+
+impl<'a> PublishPaper<'a> {
+    pub fn publish_paper(&mut self) -> Result<()> {
+        require!(
+            self.author.key() == self.research_paper.author,
+            ErrorCodes::UnauthorizedUpdate
+        );
+        //drafts become visible and purchasable
+        require!(
+            self.research_paper.status == PaperStatus::Draft,
+            ErrorCodes::InvalidPaperStatus
+        );
+
+        self.research_paper.status = PaperStatus::Published;
+
+        emit!(PaperPublished {
+            paper: self.research_paper.key(),
+            author: self.research_paper.author,
+            price: self.research_paper.price,
+            payment_mint: self.research_paper.payment_mint,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+}
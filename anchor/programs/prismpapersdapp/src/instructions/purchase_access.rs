@@ -2,14 +2,21 @@ use anchor_lang::{
     prelude::*,
     system_program::{transfer, Transfer},
 };
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
 
 use crate::{
     constants::{
-        ANCHOR_DISCRIMINATOR, GLOBAL_FEE_PERCENTAGE, PAPER_SEED, RECEIPT_SEED, USER_SEED,
-        VAULT_SEED_ADMIN, VAULT_SEED_USER,
+        ANCHOR_DISCRIMINATOR, CONFIG_SEED, PAPER_SEED, RECEIPT_SEED, USER_SEED, VAULT_SEED_ESCROW,
+        VAULT_SEED_USER,
     },
     errors::ErrorCodes,
-    states::{AccessReceipt, ResearchPaper, User},
+    events::AccessPurchased,
+    fees::split_fee,
+    payments::transfer_tokens,
+    states::{AccessReceipt, Config, ReceiptStatus, ResearchPaper, User},
 };
 
 #[derive(Accounts)]
@@ -19,14 +26,18 @@ pub struct PurchaseAccess<'info> {
 
     #[account(
         mut,
-        seeds = [PAPER_SEED, research_paper.author.key().as_ref()],
+        seeds = [
+            PAPER_SEED,
+            research_paper.author.key().as_ref(),
+            research_paper.index.to_le_bytes().as_ref()
+        ],
         bump=research_paper.bump
     )]
     pub research_paper: Account<'info, ResearchPaper>,
 
     #[account(
         mut,
-        seeds = [USER_SEED, buyer_user_account.owner.key().as_ref()],
+        seeds = [USER_SEED, buyer.key().as_ref()],
         bump=buyer_user_account.bump
     )]
     pub buyer_user_account: Account<'info, User>,
@@ -44,19 +55,20 @@ pub struct PurchaseAccess<'info> {
         bump=author_user_account.bump
     )]
     pub author_user_account: Account<'info, User>,
+
+    //the payment is held here until the refund window closes, see settle_purchase
     #[account(
         mut,
-        seeds = [VAULT_SEED_USER, research_paper.author.key().as_ref()],
+        seeds = [VAULT_SEED_ESCROW, research_paper.key().as_ref()],
         bump
     )]
-    pub author_vault: SystemAccount<'info>,
+    pub escrow_vault: SystemAccount<'info>,
 
     #[account(
-        mut,
-        seeds = [VAULT_SEED_ADMIN],
-        bump
+        seeds = [CONFIG_SEED],
+        bump = config.bump
     )]
-    pub admin_vault: SystemAccount<'info>,
+    pub config: Account<'info, Config>,
 
     #[account(
         init,
@@ -67,11 +79,42 @@ pub struct PurchaseAccess<'info> {
     )]
     pub access_receipt: Account<'info, AccessReceipt>,
 
+    //token accounts, only required when the paper is priced in an SPL token
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = buyer,
+        associated_token::token_program = token_program
+    )]
+    pub buyer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::mint = payment_mint,
+        associated_token::authority = escrow_vault,
+        associated_token::token_program = token_program
+    )]
+    pub escrow_token_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
     pub system_program: Program<'info, System>,
 }
 
 impl<'a> PurchaseAccess<'a> {
-    pub fn purchase_access(&mut self, bumps: &PurchaseAccessBumps) -> Result<()> {
+    pub fn purchase_access(
+        &mut self,
+        buyer_encryption_key: [u8; 32],
+        bumps: &PurchaseAccessBumps,
+    ) -> Result<()> {
+        require!(!self.config.paused, ErrorCodes::ProgramPaused);
+        require!(
+            self.research_paper.status.is_purchasable(),
+            ErrorCodes::PaperNotForSale
+        );
         require!(
             self.research_paper.price > 0,
             ErrorCodes::ResearchPriceInvalid
@@ -81,70 +124,135 @@ impl<'a> PurchaseAccess<'a> {
             ErrorCodes::AuthorCantBuySelf
         );
         require!(
-            self.buyer.lamports() >= self.research_paper.price,
-            ErrorCodes::InsufficientFundsInWallet
+            buyer_encryption_key != [0u8; 32],
+            ErrorCodes::BuyerEncryptionKeyInvalid
         );
 
-        //calculating the platform fee and author amount
         let total_amount = self.research_paper.price;
-        let platform_fee = total_amount
-            .checked_mul(GLOBAL_FEE_PERCENTAGE) // e.g., 100 * 5 = 500
-            .ok_or(ErrorCodes::MathOverflow)?
-            .checked_div(100) // e.g., 500 / 100 = 5
-            .ok_or(ErrorCodes::MathOverflow)?;
-        let author_earning = total_amount
-            .checked_sub(platform_fee)
-            .ok_or(ErrorCodes::MathOverflow)?;
-
-        //transferring the author amount to the author vault
-        let cpi_program = self.system_program.to_account_info();
-        let buyer = self.buyer.to_account_info();
-        let author_vault = self.author_vault.to_account_info();
-        let cpi_account_options_author = Transfer {
-            from: buyer,
-            to: author_vault,
-        };
-        let cpi_ctx_author = CpiContext::new(cpi_program, cpi_account_options_author);
-        transfer(cpi_ctx_author, author_earning)?;
-
-        //transferring the platform fee to the admin vault
-        let cpi_program = self.system_program.to_account_info();
-        let buyer = self.buyer.to_account_info();
-        let admin_vault = self.admin_vault.to_account_info();
-        let cpi_account_options_admin = Transfer {
-            from: buyer.to_account_info(),
-            to: admin_vault,
+        match self.research_paper.payment_mint {
+            None => self.pay_in_sol(total_amount)?,
+            Some(payment_mint) => self.pay_in_tokens(payment_mint, total_amount)?,
+        }
+        //the split is fixed now, but only paid out when the purchase is settled
+        let min_fee = match self.research_paper.payment_mint {
+            None => self.config.min_fee_lamports,
+            //the lamport minimum fee does not apply to token amounts
+            Some(_) => 0,
         };
-        let cpi_ctx_admin = CpiContext::new(cpi_program, cpi_account_options_admin);
-        transfer(cpi_ctx_admin, platform_fee)?;
+        let (platform_fee, author_earning) =
+            split_fee(total_amount, self.config.purchase_fee_bps, min_fee)?;
 
         //storing the receipt
         let buyer = self.buyer.key();
         let purchased_paper = self.research_paper.key();
+        let timestamp = Clock::get()?.unix_timestamp;
+        let refund_deadline = timestamp
+            .checked_add(self.config.refund_window)
+            .ok_or(ErrorCodes::MathOverflow)?;
         self.access_receipt.set_inner(AccessReceipt {
             buyer,
+            payer: buyer,
             purchased_paper,
-            timestamp: Clock::get()?.unix_timestamp,
+            revision: self.research_paper.revision,
+            seats: 0u32,
+            buyer_encryption_key,
+            key_revision: None,
+            amount_paid: total_amount,
+            platform_fee,
+            refund_deadline,
+            status: ReceiptStatus::Escrowed,
+            timestamp,
             bump: bumps.access_receipt,
         });
         //updating the states
-        self.buyer_user_account
+        self.buyer_user_account.purchased = self
+            .buyer_user_account
             .purchased
             .checked_add(1u16)
             .ok_or(ErrorCodes::MathOverflow)?;
-        self.research_paper
+        self.research_paper.sales = self
+            .research_paper
             .sales
             .checked_add(1u32)
             .ok_or(ErrorCodes::MathOverflow)?;
-        self.author_user_account
-            .earning
-            .checked_add(author_earning)
+        self.research_paper.escrowed = self
+            .research_paper
+            .escrowed
+            .checked_add(total_amount)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        self.research_paper.pending_deliveries = self
+            .research_paper
+            .pending_deliveries
+            .checked_add(1u32)
             .ok_or(ErrorCodes::MathOverflow)?;
-        self.author_user_account
+        self.research_paper.live_receipts = self
+            .research_paper
+            .live_receipts
+            .checked_add(1u32)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        self.author_user_account.sold = self
+            .author_user_account
             .sold
             .checked_add(1u16)
             .ok_or(ErrorCodes::MathOverflow)?;
 
+        emit!(AccessPurchased {
+            receipt: self.access_receipt.key(),
+            paper: purchased_paper,
+            buyer,
+            author: self.research_paper.author,
+            payment_mint: self.research_paper.payment_mint,
+            price: total_amount,
+            platform_fee,
+            author_earning,
+            refund_deadline,
+            timestamp,
+        });
         Ok(())
     }
+
+    //SOL purchases pay the full price into the paper's escrow vault
+    fn pay_in_sol(&mut self, total_amount: u64) -> Result<()> {
+        require!(
+            self.buyer.lamports() >= total_amount,
+            ErrorCodes::InsufficientFundsInWallet
+        );
+
+        let cpi_program = self.system_program.to_account_info();
+        let buyer = self.buyer.to_account_info();
+        let escrow_vault = self.escrow_vault.to_account_info();
+        let cpi_account_options = Transfer {
+            from: buyer,
+            to: escrow_vault,
+        };
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_account_options);
+        transfer(cpi_ctx, total_amount)
+    }
+
+    //token purchases pay the full price into the escrow vault's token account
+    fn pay_in_tokens(&mut self, payment_mint: Pubkey, total_amount: u64) -> Result<()> {
+        let (Some(mint), Some(buyer_token_account), Some(escrow_token_vault), Some(token_program)) = (
+            &self.payment_mint,
+            &self.buyer_token_account,
+            &self.escrow_token_vault,
+            &self.token_program,
+        ) else {
+            return err!(ErrorCodes::TokenAccountsMissing);
+        };
+        require_keys_eq!(mint.key(), payment_mint, ErrorCodes::PaymentMintMismatch);
+        require!(
+            buyer_token_account.amount >= total_amount,
+            ErrorCodes::InsufficientFundsInWallet
+        );
+
+        transfer_tokens(
+            buyer_token_account,
+            escrow_token_vault,
+            self.buyer.to_account_info(),
+            mint,
+            token_program,
+            total_amount,
+            &[],
+        )
+    }
 }
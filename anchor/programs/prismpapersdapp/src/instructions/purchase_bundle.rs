@@ -0,0 +1,286 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+
+use crate::{
+    constants::{
+        ANCHOR_DISCRIMINATOR, BUNDLE_MAX_PAPERS, BUNDLE_SEED, CONFIG_SEED, PAPER_SEED,
+        RECEIPT_SEED, USER_SEED, VAULT_SEED_ESCROW,
+    },
+    errors::ErrorCodes,
+    events::{AccessPurchased, BundlePurchased},
+    fees::split_fee,
+    payments::create_pda_account,
+    states::{AccessReceipt, Bundle, Config, ReceiptStatus, ResearchPaper, User},
+};
+
+//Buys several SOL-priced papers in one transaction, optionally as an author's discounted bundle.
+//`remaining_accounts` holds one (research_paper, access_receipt, author_user_account, escrow_vault)
+//group per paper. Every purchase is escrowed like `purchase_access` and paid out by `settle_purchase`.
+#[derive(Accounts)]
+pub struct PurchaseBundle<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, buyer.key().as_ref()],
+        bump=buyer_user_account.bump
+    )]
+    pub buyer_user_account: Account<'info, User>,
+
+    //only required when buying an author-defined bundle
+    #[account(
+        mut,
+        seeds = [
+            BUNDLE_SEED,
+            bundle.author.key().as_ref(),
+            bundle.bundle_id.to_le_bytes().as_ref()
+        ],
+        bump=bundle.bump
+    )]
+    pub bundle: Option<Account<'info, Bundle>>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'a> PurchaseBundle<'a> {
+    pub fn purchase_bundle(
+        &mut self,
+        buyer_encryption_key: [u8; 32],
+        remaining_accounts: &'a [AccountInfo<'a>],
+    ) -> Result<()> {
+        require!(!self.config.paused, ErrorCodes::ProgramPaused);
+        require!(
+            buyer_encryption_key != [0u8; 32],
+            ErrorCodes::BuyerEncryptionKeyInvalid
+        );
+        require!(
+            !remaining_accounts.is_empty() && remaining_accounts.len().is_multiple_of(4),
+            ErrorCodes::InvalidBundleAccounts
+        );
+
+        //an author bundle must be bought whole, in its own order
+        let discount_bps = match &self.bundle {
+            Some(bundle) => {
+                require!(bundle.active, ErrorCodes::BundleInactive);
+                bundle.discount_bps
+            }
+            None => 0u16,
+        };
+
+        let buyer = self.buyer.key();
+        let space = ANCHOR_DISCRIMINATOR + AccessReceipt::INIT_SPACE;
+        let timestamp = Clock::get()?.unix_timestamp;
+        let refund_deadline = timestamp
+            .checked_add(self.config.refund_window)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        let mut total_price = 0u64;
+        let mut total_fee = 0u64;
+        let mut count = 0usize;
+
+        for accounts in remaining_accounts.chunks(4) {
+            require!(count < BUNDLE_MAX_PAPERS, ErrorCodes::BundlePapersInvalid);
+            let (paper_info, receipt_info, author_user_info, vault_info) =
+                (&accounts[0], &accounts[1], &accounts[2], &accounts[3]);
+
+            //the paper must be a program-owned paper PDA that is for sale in lamports
+            let mut research_paper = Account::<ResearchPaper>::try_from(paper_info)?;
+            let paper = paper_info.key();
+            let expected_paper = Pubkey::create_program_address(
+                &[
+                    PAPER_SEED,
+                    research_paper.author.as_ref(),
+                    research_paper.index.to_le_bytes().as_ref(),
+                    &[research_paper.bump],
+                ],
+                &crate::ID,
+            )
+            .map_err(|_| ErrorCodes::InvalidBundleAccounts)?;
+            require_keys_eq!(paper, expected_paper, ErrorCodes::InvalidBundleAccounts);
+            if let Some(bundle) = &self.bundle {
+                require!(
+                    bundle.papers.get(count) == Some(&paper),
+                    ErrorCodes::BundleMismatch
+                );
+                require_keys_eq!(
+                    research_paper.author,
+                    bundle.author,
+                    ErrorCodes::BundleMismatch
+                );
+            }
+            require!(
+                research_paper.status.is_purchasable(),
+                ErrorCodes::PaperNotForSale
+            );
+            require!(research_paper.price > 0, ErrorCodes::ResearchPriceInvalid);
+            require!(
+                buyer != research_paper.author,
+                ErrorCodes::AuthorCantBuySelf
+            );
+            require!(
+                research_paper.payment_mint.is_none(),
+                ErrorCodes::BundleTokenPaper
+            );
+            //co-authors already hold the paper
+            require!(
+                !research_paper
+                    .co_authors
+                    .iter()
+                    .any(|co_author| co_author.wallet == buyer),
+                ErrorCodes::AuthorCantBuySelf
+            );
+
+            //User accounts only ever live at their owner's PDA, so the owner field pins it down
+            let author = research_paper.author;
+            let mut author_user_account = Account::<User>::try_from(author_user_info)?;
+            require_keys_eq!(
+                author_user_account.owner,
+                author,
+                ErrorCodes::InvalidBundleAccounts
+            );
+            let (expected_vault, _) =
+                Pubkey::find_program_address(&[VAULT_SEED_ESCROW, paper.as_ref()], &crate::ID);
+            require_keys_eq!(
+                vault_info.key(),
+                expected_vault,
+                ErrorCodes::InvalidBundleAccounts
+            );
+            let (expected_receipt, bump) = Pubkey::find_program_address(
+                &[RECEIPT_SEED, buyer.as_ref(), paper.as_ref()],
+                &crate::ID,
+            );
+            require_keys_eq!(
+                receipt_info.key(),
+                expected_receipt,
+                ErrorCodes::InvalidBundleAccounts
+            );
+
+            //the discount comes off the price before the platform fee is taken
+            let (_, price) = split_fee(research_paper.price, discount_bps, 0)?;
+            let (platform_fee, author_earning) = split_fee(
+                price,
+                self.config.purchase_fee_bps,
+                self.config.min_fee_lamports,
+            )?;
+
+            //the discounted price is held in escrow until settle_purchase splits it
+            self.pay_sol(vault_info, price)?;
+            research_paper.escrowed = research_paper
+                .escrowed
+                .checked_add(price)
+                .ok_or(ErrorCodes::MathOverflow)?;
+
+            //creating the receipt PDA, the same account `purchase_access` would init
+            let signer_seeds: &[&[&[u8]]] =
+                &[&[RECEIPT_SEED, buyer.as_ref(), paper.as_ref(), &[bump]]];
+            create_pda_account(
+                &self.buyer.to_account_info(),
+                receipt_info,
+                &self.system_program.to_account_info(),
+                space,
+                signer_seeds,
+            )?;
+
+            let access_receipt = AccessReceipt {
+                buyer,
+                payer: buyer,
+                purchased_paper: paper,
+                revision: research_paper.revision,
+                seats: 0u32,
+                buyer_encryption_key,
+                key_revision: None,
+                amount_paid: price,
+                platform_fee,
+                refund_deadline,
+                status: ReceiptStatus::Escrowed,
+                timestamp,
+                bump,
+            };
+            access_receipt.try_serialize(&mut &mut receipt_info.try_borrow_mut_data()?[..])?;
+
+            //updating the states
+            research_paper.sales = research_paper
+                .sales
+                .checked_add(1u32)
+                .ok_or(ErrorCodes::MathOverflow)?;
+            research_paper.pending_deliveries = research_paper
+                .pending_deliveries
+                .checked_add(1u32)
+                .ok_or(ErrorCodes::MathOverflow)?;
+            research_paper.live_receipts = research_paper
+                .live_receipts
+                .checked_add(1u32)
+                .ok_or(ErrorCodes::MathOverflow)?;
+            research_paper.exit(&crate::ID)?;
+            author_user_account.sold = author_user_account
+                .sold
+                .checked_add(1u16)
+                .ok_or(ErrorCodes::MathOverflow)?;
+            author_user_account.exit(&crate::ID)?;
+            count += 1;
+            total_price = total_price
+                .checked_add(price)
+                .ok_or(ErrorCodes::MathOverflow)?;
+            total_fee = total_fee
+                .checked_add(platform_fee)
+                .ok_or(ErrorCodes::MathOverflow)?;
+
+            emit!(AccessPurchased {
+                receipt: expected_receipt,
+                paper,
+                buyer,
+                author,
+                payment_mint: None,
+                price,
+                platform_fee,
+                author_earning,
+                refund_deadline,
+                timestamp,
+            });
+        }
+
+        if let Some(bundle) = &self.bundle {
+            require!(bundle.papers.len() == count, ErrorCodes::BundleMismatch);
+        }
+
+        self.buyer_user_account.purchased = self
+            .buyer_user_account
+            .purchased
+            .checked_add(count as u16)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        if let Some(bundle) = &mut self.bundle {
+            bundle.sales = bundle
+                .sales
+                .checked_add(1u32)
+                .ok_or(ErrorCodes::MathOverflow)?;
+        }
+
+        emit!(BundlePurchased {
+            buyer,
+            bundle: self.bundle.as_ref().map(|bundle| bundle.key()),
+            papers: count as u8,
+            price: total_price,
+            platform_fee: total_fee,
+            timestamp,
+        });
+        Ok(())
+    }
+
+    fn pay_sol(&self, to: &AccountInfo<'a>, amount: u64) -> Result<()> {
+        let cpi_program = self.system_program.to_account_info();
+        let cpi_account_options = Transfer {
+            from: self.buyer.to_account_info(),
+            to: to.clone(),
+        };
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_account_options);
+        transfer(cpi_ctx, amount)
+    }
+}
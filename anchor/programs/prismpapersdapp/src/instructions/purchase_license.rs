@@ -0,0 +1,258 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    constants::{
+        ANCHOR_DISCRIMINATOR, CONFIG_SEED, INSTITUTION_SEED, PAPER_SEED, RECEIPT_SEED, USER_SEED,
+        VAULT_SEED_ESCROW,
+    },
+    errors::ErrorCodes,
+    events::LicensePurchased,
+    fees::split_fee,
+    payments::transfer_tokens,
+    states::{AccessReceipt, Config, Institution, ReceiptStatus, ResearchPaper, User},
+};
+
+//A site license is an AccessReceipt owned by the institution, covering every member seat
+#[derive(Accounts)]
+pub struct PurchaseLicense<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin @ ErrorCodes::UnauthorizedInstitutionAdmin,
+        seeds = [INSTITUTION_SEED, admin.key().as_ref()],
+        bump=institution.bump
+    )]
+    pub institution: Account<'info, Institution>,
+
+    #[account(
+        mut,
+        seeds = [
+            PAPER_SEED,
+            research_paper.author.key().as_ref(),
+            research_paper.index.to_le_bytes().as_ref()
+        ],
+        bump=research_paper.bump
+    )]
+    pub research_paper: Account<'info, ResearchPaper>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, research_paper.author.key().as_ref()],
+        bump=author_user_account.bump
+    )]
+    pub author_user_account: Account<'info, User>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED_ESCROW, research_paper.key().as_ref()],
+        bump
+    )]
+    pub escrow_vault: SystemAccount<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = ANCHOR_DISCRIMINATOR + AccessReceipt::INIT_SPACE,
+        seeds = [RECEIPT_SEED, institution.key().as_ref(), research_paper.key().as_ref()],
+        bump
+    )]
+    pub access_receipt: Account<'info, AccessReceipt>,
+
+    //token accounts, only required when the paper is priced in an SPL token
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = admin,
+        associated_token::token_program = token_program
+    )]
+    pub admin_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = payment_mint,
+        associated_token::authority = escrow_vault,
+        associated_token::token_program = token_program
+    )]
+    pub escrow_token_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'a> PurchaseLicense<'a> {
+    pub fn purchase_license(
+        &mut self,
+        buyer_encryption_key: [u8; 32],
+        bumps: &PurchaseLicenseBumps,
+    ) -> Result<()> {
+        require!(!self.config.paused, ErrorCodes::ProgramPaused);
+        require!(
+            self.research_paper.status.is_purchasable(),
+            ErrorCodes::PaperNotForSale
+        );
+        require!(
+            self.research_paper.price > 0,
+            ErrorCodes::ResearchPriceInvalid
+        );
+        require!(
+            self.admin.key() != self.research_paper.author,
+            ErrorCodes::AuthorCantBuySelf
+        );
+        require!(
+            buyer_encryption_key != [0u8; 32],
+            ErrorCodes::BuyerEncryptionKeyInvalid
+        );
+
+        //a site license costs one purchase per seat
+        let seats = self.institution.seats;
+        let total_amount = self
+            .research_paper
+            .price
+            .checked_mul(seats as u64)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        match self.research_paper.payment_mint {
+            None => self.pay_in_sol(total_amount)?,
+            Some(payment_mint) => self.pay_in_tokens(payment_mint, total_amount)?,
+        }
+        let min_fee = match self.research_paper.payment_mint {
+            None => self.config.min_fee_lamports,
+            //the lamport minimum fee does not apply to token amounts
+            Some(_) => 0,
+        };
+        let (platform_fee, author_earning) =
+            split_fee(total_amount, self.config.purchase_fee_bps, min_fee)?;
+
+        //the institution PDA can't sign, so its admin requests and receives refunds of the license
+        let institution = self.institution.key();
+        let purchased_paper = self.research_paper.key();
+        let timestamp = Clock::get()?.unix_timestamp;
+        let refund_deadline = timestamp
+            .checked_add(self.config.refund_window)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        self.access_receipt.set_inner(AccessReceipt {
+            buyer: institution,
+            payer: self.admin.key(),
+            purchased_paper,
+            revision: self.research_paper.revision,
+            seats,
+            buyer_encryption_key,
+            key_revision: None,
+            amount_paid: total_amount,
+            platform_fee,
+            refund_deadline,
+            status: ReceiptStatus::Escrowed,
+            timestamp,
+            bump: bumps.access_receipt,
+        });
+        //updating the states
+        self.institution.licenses = self
+            .institution
+            .licenses
+            .checked_add(1u32)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        self.research_paper.sales = self
+            .research_paper
+            .sales
+            .checked_add(1u32)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        self.research_paper.escrowed = self
+            .research_paper
+            .escrowed
+            .checked_add(total_amount)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        self.research_paper.pending_deliveries = self
+            .research_paper
+            .pending_deliveries
+            .checked_add(1u32)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        self.research_paper.live_receipts = self
+            .research_paper
+            .live_receipts
+            .checked_add(1u32)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        self.author_user_account.sold = self
+            .author_user_account
+            .sold
+            .checked_add(1u16)
+            .ok_or(ErrorCodes::MathOverflow)?;
+
+        emit!(LicensePurchased {
+            receipt: self.access_receipt.key(),
+            paper: purchased_paper,
+            institution,
+            admin: self.admin.key(),
+            author: self.research_paper.author,
+            payment_mint: self.research_paper.payment_mint,
+            seats,
+            price: total_amount,
+            platform_fee,
+            author_earning,
+            timestamp,
+        });
+        Ok(())
+    }
+
+    //SOL licenses pay the full price into the paper's escrow vault
+    fn pay_in_sol(&mut self, total_amount: u64) -> Result<()> {
+        require!(
+            self.admin.lamports() >= total_amount,
+            ErrorCodes::InsufficientFundsInWallet
+        );
+
+        let cpi_program = self.system_program.to_account_info();
+        let admin = self.admin.to_account_info();
+        let escrow_vault = self.escrow_vault.to_account_info();
+        let cpi_account_options = Transfer {
+            from: admin,
+            to: escrow_vault,
+        };
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_account_options);
+        transfer(cpi_ctx, total_amount)
+    }
+
+    //token licenses pay the full price into the escrow vault's token account
+    fn pay_in_tokens(&mut self, payment_mint: Pubkey, total_amount: u64) -> Result<()> {
+        let (Some(mint), Some(admin_token_account), Some(escrow_token_vault), Some(token_program)) = (
+            &self.payment_mint,
+            &self.admin_token_account,
+            &self.escrow_token_vault,
+            &self.token_program,
+        ) else {
+            return err!(ErrorCodes::TokenAccountsMissing);
+        };
+        require_keys_eq!(mint.key(), payment_mint, ErrorCodes::PaymentMintMismatch);
+        require!(
+            admin_token_account.amount >= total_amount,
+            ErrorCodes::InsufficientFundsInWallet
+        );
+
+        transfer_tokens(
+            admin_token_account,
+            escrow_token_vault,
+            self.admin.to_account_info(),
+            mint,
+            token_program,
+            total_amount,
+            &[],
+        )
+    }
+}
@@ -0,0 +1,154 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    constants::{PAPER_SEED, VAULT_SEED_BOUNTY},
+    errors::ErrorCodes,
+    events::BountyReclaimed,
+    payments::transfer_tokens,
+    states::ResearchPaper,
+};
+
+#[derive(Accounts)]
+pub struct ReclaimBounty<'info> {
+    #[account(mut)]
+    pub author: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PAPER_SEED,
+            research_paper.author.key().as_ref(),
+            research_paper.index.to_le_bytes().as_ref()
+        ],
+        bump=research_paper.bump
+    )]
+    pub research_paper: Account<'info, ResearchPaper>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED_BOUNTY, research_paper.key().as_ref()],
+        bump
+    )]
+    pub bounty_vault: SystemAccount<'info>,
+
+    //token accounts, only required when the paper is priced in an SPL token
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = bounty_vault,
+        associated_token::token_program = token_program
+    )]
+    pub bounty_token_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = author,
+        associated_token::mint = payment_mint,
+        associated_token::authority = author,
+        associated_token::token_program = token_program
+    )]
+    pub author_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'a> ReclaimBounty<'a> {
+    pub fn reclaim_bounty(&mut self, bumps: &ReclaimBountyBumps) -> Result<()> {
+        require!(
+            self.author.key() == self.research_paper.author,
+            ErrorCodes::UnauthorizedUpdate
+        );
+        require!(
+            Clock::get()?.unix_timestamp >= self.research_paper.bounty_deadline,
+            ErrorCodes::BountyStillActive
+        );
+        //rewards reserved by undecided reviews stay behind until those reviews are decided
+        let amount = self.research_paper.unreserved_bounty();
+        require!(amount > 0, ErrorCodes::BountyAmountInvalid);
+
+        let research_paper = self.research_paper.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            VAULT_SEED_BOUNTY,
+            research_paper.as_ref(),
+            &[bumps.bounty_vault],
+        ]];
+        match self.research_paper.payment_mint {
+            None => {
+                require!(
+                    self.bounty_vault.lamports() >= amount,
+                    ErrorCodes::InsufficientFundsInVault
+                );
+
+                let cpi_program = self.system_program.to_account_info();
+                let bounty_vault = self.bounty_vault.to_account_info();
+                let author = self.author.to_account_info();
+                let cpi_account_options = Transfer {
+                    from: bounty_vault,
+                    to: author,
+                };
+                let cpi_ctx =
+                    CpiContext::new_with_signer(cpi_program, cpi_account_options, signer_seeds);
+                transfer(cpi_ctx, amount)?;
+            }
+            Some(payment_mint) => {
+                let (
+                    Some(mint),
+                    Some(bounty_token_vault),
+                    Some(author_token_account),
+                    Some(token_program),
+                ) = (
+                    &self.payment_mint,
+                    &self.bounty_token_vault,
+                    &self.author_token_account,
+                    &self.token_program,
+                )
+                else {
+                    return err!(ErrorCodes::TokenAccountsMissing);
+                };
+                require_keys_eq!(mint.key(), payment_mint, ErrorCodes::PaymentMintMismatch);
+                require!(
+                    bounty_token_vault.amount >= amount,
+                    ErrorCodes::InsufficientFundsInVault
+                );
+
+                transfer_tokens(
+                    bounty_token_vault,
+                    author_token_account,
+                    self.bounty_vault.to_account_info(),
+                    mint,
+                    token_program,
+                    amount,
+                    signer_seeds,
+                )?;
+            }
+        }
+
+        //updating the states
+        self.research_paper.bounty = self
+            .research_paper
+            .bounty
+            .checked_sub(amount)
+            .ok_or(ErrorCodes::MathOverflow)?;
+
+        emit!(BountyReclaimed {
+            paper: self.research_paper.key(),
+            author: self.research_paper.author,
+            payment_mint: self.research_paper.payment_mint,
+            amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{INSTITUTION_SEED, MEMBER_SEED},
+    errors::ErrorCodes,
+    events::InstitutionMemberRemoved,
+    states::{Institution, InstitutionMember},
+};
+
+#[derive(Accounts)]
+pub struct RemoveInstitutionMember<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin @ ErrorCodes::UnauthorizedInstitutionAdmin,
+        seeds = [INSTITUTION_SEED, admin.key().as_ref()],
+        bump=institution.bump
+    )]
+    pub institution: Account<'info, Institution>,
+
+    //the seat's rent goes back to the admin who paid for it
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            MEMBER_SEED,
+            institution.key().as_ref(),
+            institution_member.member.as_ref()
+        ],
+        bump=institution_member.bump
+    )]
+    pub institution_member: Account<'info, InstitutionMember>,
+}
+
+impl<'a> RemoveInstitutionMember<'a> {
+    pub fn remove_institution_member(&mut self) -> Result<()> {
+        self.institution.members = self
+            .institution
+            .members
+            .checked_sub(1u32)
+            .ok_or(ErrorCodes::MathOverflow)?;
+
+        emit!(InstitutionMemberRemoved {
+            institution: self.institution.key(),
+            member: self.institution_member.member,
+            members: self.institution.members,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+}
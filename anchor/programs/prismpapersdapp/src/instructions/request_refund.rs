@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{CONFIG_SEED, PAPER_SEED, RECEIPT_SEED},
+    errors::ErrorCodes,
+    events::RefundRequested,
+    states::{AccessReceipt, Config, ReceiptStatus, ResearchPaper},
+};
+
+#[derive(Accounts)]
+pub struct RequestRefund<'info> {
+    //the wallet that paid, the institution admin for a site license
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            PAPER_SEED,
+            research_paper.author.key().as_ref(),
+            research_paper.index.to_le_bytes().as_ref()
+        ],
+        bump=research_paper.bump
+    )]
+    pub research_paper: Account<'info, ResearchPaper>,
+
+    #[account(
+        mut,
+        seeds = [
+            RECEIPT_SEED,
+            access_receipt.buyer.key().as_ref(),
+            research_paper.key().as_ref()
+        ],
+        bump=access_receipt.bump,
+        has_one = payer @ ErrorCodes::UnauthorizedRefund
+    )]
+    pub access_receipt: Account<'info, AccessReceipt>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl<'a> RequestRefund<'a> {
+    pub fn request_refund(&mut self) -> Result<()> {
+        require!(
+            self.access_receipt.status == ReceiptStatus::Escrowed,
+            ErrorCodes::ReceiptNotEscrowed
+        );
+        let timestamp = Clock::get()?.unix_timestamp;
+        require!(
+            timestamp < self.access_receipt.refund_deadline,
+            ErrorCodes::RefundWindowClosed
+        );
+
+        //the payment stays in escrow until the author or an admin resolves the dispute, who get as
+        //long again as the refund window before anyone can refund it
+        self.access_receipt.status = ReceiptStatus::Disputed;
+        self.access_receipt.refund_deadline = timestamp
+            .checked_add(self.config.refund_window)
+            .ok_or(ErrorCodes::MathOverflow)?;
+
+        emit!(RefundRequested {
+            receipt: self.access_receipt.key(),
+            paper: self.research_paper.key(),
+            buyer: self.access_receipt.buyer,
+            amount: self.access_receipt.amount_paid,
+            timestamp,
+        });
+        Ok(())
+    }
+}
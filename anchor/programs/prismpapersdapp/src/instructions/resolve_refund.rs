@@ -0,0 +1,263 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    constants::{CONFIG_SEED, PAPER_SEED, RECEIPT_SEED, USER_SEED, VAULT_SEED_ESCROW},
+    errors::ErrorCodes,
+    events::RefundResolved,
+    payments::transfer_tokens,
+    states::{AccessReceipt, Config, ReceiptStatus, ResearchPaper, User},
+};
+
+//Shared by `approve_refund` (the author gives in), `arbitrate_refund` (an admin decides) and
+//`lapse_refund` (anyone refunds a dispute nobody resolved in time)
+#[derive(Accounts)]
+pub struct ResolveRefund<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PAPER_SEED,
+            research_paper.author.key().as_ref(),
+            research_paper.index.to_le_bytes().as_ref()
+        ],
+        bump=research_paper.bump
+    )]
+    pub research_paper: Account<'info, ResearchPaper>,
+
+    #[account(
+        mut,
+        seeds = [
+            RECEIPT_SEED,
+            access_receipt.buyer.key().as_ref(),
+            research_paper.key().as_ref()
+        ],
+        bump=access_receipt.bump
+    )]
+    pub access_receipt: Account<'info, AccessReceipt>,
+
+    //refunds go to the wallet that paid, the institution admin for a site license
+    #[account(
+        mut,
+        address = access_receipt.payer
+    )]
+    pub buyer: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, research_paper.author.key().as_ref()],
+        bump=author_user_account.bump
+    )]
+    pub author_user_account: Account<'info, User>,
+
+    //the buyer's profile, whose purchase count a refund takes back; None for a site license,
+    //where the buyer is the Institution PDA
+    #[account(
+        mut,
+        seeds = [USER_SEED, access_receipt.buyer.key().as_ref()],
+        bump=buyer_user_account.bump
+    )]
+    pub buyer_user_account: Option<Account<'info, User>>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED_ESCROW, research_paper.key().as_ref()],
+        bump
+    )]
+    pub escrow_vault: SystemAccount<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    //token accounts, only required when the paper is priced in an SPL token
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = escrow_vault,
+        associated_token::token_program = token_program
+    )]
+    pub escrow_token_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = buyer,
+        associated_token::token_program = token_program
+    )]
+    pub buyer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'a> ResolveRefund<'a> {
+    pub fn approve_refund(&mut self, bumps: &ResolveRefundBumps) -> Result<()> {
+        require!(
+            self.authority.key() == self.research_paper.author,
+            ErrorCodes::UnauthorizedRefund
+        );
+        require!(
+            self.access_receipt.status == ReceiptStatus::Disputed,
+            ErrorCodes::ReceiptNotDisputed
+        );
+        self.refund(bumps)
+    }
+
+    pub fn arbitrate_refund(&mut self, refund: bool, bumps: &ResolveRefundBumps) -> Result<()> {
+        require!(
+            self.config.is_admin(&self.authority.key()),
+            ErrorCodes::UnauthorizedRefund
+        );
+        require!(
+            self.access_receipt.status == ReceiptStatus::Disputed,
+            ErrorCodes::ReceiptNotDisputed
+        );
+        if refund {
+            return self.refund(bumps);
+        }
+
+        //a denied dispute closes the refund window, the purchase can be settled right away
+        let timestamp = Clock::get()?.unix_timestamp;
+        self.access_receipt.status = ReceiptStatus::Escrowed;
+        self.access_receipt.refund_deadline = timestamp;
+
+        self.emit_resolution(false, timestamp)
+    }
+
+    pub fn lapse_refund(&mut self, bumps: &ResolveRefundBumps) -> Result<()> {
+        require!(!self.config.paused, ErrorCodes::ProgramPaused);
+        require!(
+            self.access_receipt.status == ReceiptStatus::Disputed,
+            ErrorCodes::ReceiptNotDisputed
+        );
+        //nobody answered the dispute in time, so it stands
+        require!(
+            Clock::get()?.unix_timestamp >= self.access_receipt.refund_deadline,
+            ErrorCodes::DisputeWindowOpen
+        );
+        self.refund(bumps)
+    }
+
+    //reverses the purchase: the escrowed price goes back to the buyer and the receipt is revoked
+    fn refund(&mut self, bumps: &ResolveRefundBumps) -> Result<()> {
+        let amount = self.access_receipt.amount_paid;
+        let research_paper = self.research_paper.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            VAULT_SEED_ESCROW,
+            research_paper.as_ref(),
+            &[bumps.escrow_vault],
+        ]];
+
+        match self.research_paper.payment_mint {
+            None => {
+                require!(
+                    self.escrow_vault.lamports() >= amount,
+                    ErrorCodes::InsufficientFundsInVault
+                );
+                let cpi_program = self.system_program.to_account_info();
+                let escrow_vault = self.escrow_vault.to_account_info();
+                let buyer = self.buyer.to_account_info();
+                let cpi_account_options = Transfer {
+                    from: escrow_vault,
+                    to: buyer,
+                };
+                let cpi_ctx =
+                    CpiContext::new_with_signer(cpi_program, cpi_account_options, signer_seeds);
+                transfer(cpi_ctx, amount)?;
+            }
+            Some(payment_mint) => {
+                let (
+                    Some(mint),
+                    Some(escrow_token_vault),
+                    Some(buyer_token_account),
+                    Some(token_program),
+                ) = (
+                    &self.payment_mint,
+                    &self.escrow_token_vault,
+                    &self.buyer_token_account,
+                    &self.token_program,
+                )
+                else {
+                    return err!(ErrorCodes::TokenAccountsMissing);
+                };
+                require_keys_eq!(mint.key(), payment_mint, ErrorCodes::PaymentMintMismatch);
+                require!(
+                    escrow_token_vault.amount >= amount,
+                    ErrorCodes::InsufficientFundsInVault
+                );
+                transfer_tokens(
+                    escrow_token_vault,
+                    buyer_token_account,
+                    self.escrow_vault.to_account_info(),
+                    mint,
+                    token_program,
+                    amount,
+                    signer_seeds,
+                )?;
+            }
+        }
+
+        //updating the states
+        let owed_key = self.research_paper.owes_key(&self.access_receipt);
+        self.access_receipt.status = ReceiptStatus::Revoked;
+        self.research_paper.escrowed = self
+            .research_paper
+            .escrowed
+            .checked_sub(amount)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        self.research_paper.sales = self
+            .research_paper
+            .sales
+            .checked_sub(1u32)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        self.author_user_account.sold = self
+            .author_user_account
+            .sold
+            .checked_sub(1u16)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        if self.access_receipt.seats == 0 {
+            let Some(buyer_user_account) = self.buyer_user_account.as_deref_mut() else {
+                return err!(ErrorCodes::BuyerAccountMissing);
+            };
+            //a profile closed and created again since the purchase starts counting from zero
+            buyer_user_account.purchased = buyer_user_account.purchased.saturating_sub(1u16);
+        }
+        self.research_paper.live_receipts = self
+            .research_paper
+            .live_receipts
+            .checked_sub(1u32)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        //a revoked buyer is no longer owed a key
+        if owed_key {
+            self.research_paper.pending_deliveries = self
+                .research_paper
+                .pending_deliveries
+                .checked_sub(1u32)
+                .ok_or(ErrorCodes::MathOverflow)?;
+        }
+
+        self.emit_resolution(true, Clock::get()?.unix_timestamp)
+    }
+
+    fn emit_resolution(&self, refunded: bool, timestamp: i64) -> Result<()> {
+        emit!(RefundResolved {
+            receipt: self.access_receipt.key(),
+            paper: self.research_paper.key(),
+            buyer: self.access_receipt.buyer,
+            resolved_by: self.authority.key(),
+            refunded,
+            amount: self.access_receipt.amount_paid,
+            timestamp,
+        });
+        Ok(())
+    }
+}
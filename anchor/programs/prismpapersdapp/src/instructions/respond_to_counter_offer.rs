@@ -0,0 +1,224 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    constants::{
+        CONFIG_SEED, PAPER_SEED, REVIEW_SEED, USER_SEED, VAULT_SEED_ADMIN, VAULT_SEED_BOUNTY,
+        VAULT_SEED_STAKE, VAULT_SEED_USER,
+    },
+    errors::ErrorCodes,
+    events::CounterOfferResolved,
+    rewards::{pay_reward_in_sol, pay_reward_in_tokens, record_scores, return_stake},
+    states::{Config, PeerReview, ResearchPaper, ReviewStatus, User},
+};
+
+//The reviewer answers an author's counter-offer. Agreeing settles the review as accepted at the
+//counter-offered reward, withdrawing gives the stake back and leaves both reputations alone.
+#[derive(Accounts)]
+pub struct RespondToCounterOffer<'info> {
+    #[account(mut)]
+    pub reviewer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [REVIEW_SEED, reviewer.key().as_ref(), peer_review.reviewed_paper.key().as_ref()],
+        bump=peer_review.bump
+    )]
+    pub peer_review: Account<'info, PeerReview>,
+
+    #[account(
+        mut,
+        seeds = [
+            PAPER_SEED,
+            research_paper.author.key().as_ref(),
+            research_paper.index.to_le_bytes().as_ref()
+        ],
+        bump=research_paper.bump,
+        constraint = peer_review.reviewed_paper == research_paper.key() @ ErrorCodes::ReviewPaperMismatch
+    )]
+    pub research_paper: Account<'info, ResearchPaper>,
+
+    //required for named reviews and rejected for anonymous ones, which are only credited by `reveal_review`
+    #[account(
+        mut,
+        seeds = [USER_SEED, reviewer.key().as_ref()],
+        bump=reviewer_user_account.bump
+    )]
+    pub reviewer_user_account: Option<Account<'info, User>>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED_USER, reviewer.key().as_ref()],
+        bump
+    )]
+    pub reviewer_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED_STAKE, peer_review.key().as_ref()],
+        bump
+    )]
+    pub stake_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED_BOUNTY, research_paper.key().as_ref()],
+        bump
+    )]
+    pub bounty_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED_ADMIN],
+        bump
+    )]
+    pub admin_vault: SystemAccount<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    //token accounts, only required when the paper is priced in an SPL token
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = bounty_vault,
+        associated_token::token_program = token_program
+    )]
+    pub bounty_token_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = reviewer,
+        associated_token::mint = payment_mint,
+        associated_token::authority = reviewer_vault,
+        associated_token::token_program = token_program
+    )]
+    pub reviewer_token_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = reviewer,
+        associated_token::mint = payment_mint,
+        associated_token::authority = admin_vault,
+        associated_token::token_program = token_program
+    )]
+    pub admin_token_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'a> RespondToCounterOffer<'a> {
+    pub fn respond_to_counter_offer(
+        &mut self,
+        accept_counter_offer: bool,
+        bumps: &RespondToCounterOfferBumps,
+    ) -> Result<()> {
+        require!(!self.config.paused, ErrorCodes::ProgramPaused);
+        let (ReviewStatus::Negotiating, Some(counter_offer)) =
+            (&self.peer_review.status, self.peer_review.counter_offer)
+        else {
+            return err!(ErrorCodes::ReviewNotNegotiating);
+        };
+        self.peer_review
+            .check_reviewer_account(self.reviewer_user_account.is_some())?;
+        //past the window the counter-offer lapses and `expire_review` withdraws the review
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            now < self.peer_review.response_deadline(&self.config, now)?,
+            ErrorCodes::ReviewResponseWindowPassed
+        );
+
+        let (mut total_amount, mut platform_fee, mut reviewer_earning) = (0u64, 0u64, 0u64);
+        if accept_counter_offer {
+            //settled like an accepted review in verify_review, at the agreed figure
+            total_amount = counter_offer;
+            (platform_fee, reviewer_earning) = match self.research_paper.payment_mint {
+                None => pay_reward_in_sol(
+                    &mut self.peer_review,
+                    self.reviewer_user_account.as_deref_mut(),
+                    self.research_paper.key(),
+                    &self.bounty_vault.to_account_info(),
+                    &self.reviewer_vault.to_account_info(),
+                    &self.stake_vault.to_account_info(),
+                    &self.admin_vault.to_account_info(),
+                    &self.system_program.to_account_info(),
+                    &self.config,
+                    total_amount,
+                    bumps.bounty_vault,
+                )?,
+                Some(payment_mint) => pay_reward_in_tokens(
+                    payment_mint,
+                    &self.payment_mint,
+                    &self.bounty_token_vault,
+                    &self.reviewer_token_vault,
+                    &self.admin_token_vault,
+                    &self.token_program,
+                    self.research_paper.key(),
+                    self.bounty_vault.to_account_info(),
+                    &self.config,
+                    total_amount,
+                    bumps.bounty_vault,
+                )?,
+            };
+            self.peer_review.status = ReviewStatus::Accepted;
+            if let Some(user) = &mut self.reviewer_user_account {
+                user.reviews_accepted = user
+                    .reviews_accepted
+                    .checked_add(1u16)
+                    .ok_or(ErrorCodes::MathOverflow)?;
+            }
+            record_scores(&mut self.research_paper, &self.peer_review)?;
+        } else {
+            //the review keeps its vote in the round, only the payment fell through
+            self.peer_review.status = ReviewStatus::Withdrawn;
+        }
+        //the counter-offer is below the proposed reward, so it always fits in the reservation
+        self.research_paper
+            .decide_review(self.peer_review.proposed_reward, total_amount)?;
+        let stake_returned = return_stake(
+            &mut self.peer_review,
+            &self.stake_vault.to_account_info(),
+            &self.reviewer.to_account_info(),
+            &self.system_program.to_account_info(),
+            bumps.stake_vault,
+        )?;
+
+        self.peer_review.decided_at = now;
+        if let Some(user) = &mut self.reviewer_user_account {
+            user.refresh_reputation();
+            user.pending_reviews = user
+                .pending_reviews
+                .checked_sub(1u16)
+                .ok_or(ErrorCodes::MathOverflow)?;
+        }
+
+        emit!(CounterOfferResolved {
+            review: self.peer_review.key(),
+            paper: self.research_paper.key(),
+            reviewer: self.peer_review.reviewer,
+            status: self.peer_review.status.clone(),
+            payment_mint: self.research_paper.payment_mint,
+            reward: total_amount,
+            platform_fee,
+            reviewer_earning,
+            stake_returned,
+            //0 for anonymous reviews, their reputation moves when they are revealed
+            reviewer_reputation: self
+                .reviewer_user_account
+                .as_ref()
+                .map_or(0, |user| user.reputation),
+            timestamp: self.peer_review.decided_at,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{CONFIG_SEED, PAPER_SEED},
+    errors::ErrorCodes,
+    events::PaperRetracted,
+    states::{Config, PaperStatus, ResearchPaper},
+};
+
+#[derive(Accounts)]
+pub struct RetractPaper<'info> {
+    //either the author or a platform admin acting as editor
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PAPER_SEED,
+            research_paper.author.key().as_ref(),
+            research_paper.index.to_le_bytes().as_ref()
+        ],
+        bump=research_paper.bump
+    )]
+    pub research_paper: Account<'info, ResearchPaper>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl<'a> RetractPaper<'a> {
+    pub fn retract_paper(&mut self, reason_code: u8) -> Result<()> {
+        let authority = self.authority.key();
+        require!(
+            authority == self.research_paper.author || self.config.is_admin(&authority),
+            ErrorCodes::UnauthorizedRetraction
+        );
+        require!(
+            self.research_paper.status != PaperStatus::Retracted,
+            ErrorCodes::PaperIsRetracted
+        );
+        require!(reason_code != 0, ErrorCodes::RetractionReasonInvalid);
+
+        self.research_paper.status = PaperStatus::Retracted;
+        self.research_paper.retraction_reason = reason_code;
+
+        emit!(PaperRetracted {
+            paper: self.research_paper.key(),
+            author: self.research_paper.author,
+            retracted_by: authority,
+            reason_code,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+}
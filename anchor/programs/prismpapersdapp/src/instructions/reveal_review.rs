@@ -0,0 +1,219 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::hash::hashv,
+    system_program::{transfer, Transfer},
+};
+
+use crate::{
+    constants::{
+        ANCHOR_DISCRIMINATOR, PAPER_SEED, REVIEW_SEED, ROUND_SEED, USER_SEED, VAULT_SEED_STAKE,
+        VAULT_SEED_USER, VOTER_SEED,
+    },
+    errors::ErrorCodes,
+    events::ReviewRevealed,
+    states::{PeerReview, ResearchPaper, ReviewRound, ReviewStatus, RoundVoter, User},
+};
+
+//Links an anonymous review to the reviewer's wallet once the author has accepted or rejected it,
+//or it expired.
+//The verdict counts towards the wallet's reputation and an escrowed reward moves to its user vault,
+//with the stake that was kept alongside it. The review's vote joins its round's tally if the round
+//is still open; an unrevealed review never counts. A wallet that already voted in the round, named or
+//through another pseudonym, can't reveal a second review there.
+#[derive(Accounts)]
+pub struct RevealReview<'info> {
+    #[account(mut)]
+    pub reviewer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [REVIEW_SEED, peer_review.reviewer.key().as_ref(), peer_review.reviewed_paper.key().as_ref()],
+        bump=peer_review.bump
+    )]
+    pub peer_review: Account<'info, PeerReview>,
+
+    #[account(
+        seeds = [
+            PAPER_SEED,
+            research_paper.author.key().as_ref(),
+            research_paper.index.to_le_bytes().as_ref()
+        ],
+        bump=research_paper.bump,
+        constraint = peer_review.reviewed_paper == research_paper.key() @ ErrorCodes::ReviewPaperMismatch
+    )]
+    pub research_paper: Account<'info, ResearchPaper>,
+
+    #[account(
+        mut,
+        seeds = [
+            ROUND_SEED,
+            research_paper.key().as_ref(),
+            review_round.round_id.to_le_bytes().as_ref()
+        ],
+        bump = review_round.bump,
+        constraint = peer_review.round == review_round.key() @ ErrorCodes::ReviewRoundMismatch
+    )]
+    pub review_round: Account<'info, ReviewRound>,
+
+    //the same marker `review_paper` creates, so the wallet holds one vote per round however it reviewed
+    #[account(
+        init,
+        payer = reviewer,
+        space = ANCHOR_DISCRIMINATOR + RoundVoter::INIT_SPACE,
+        seeds = [VOTER_SEED, review_round.key().as_ref(), reviewer.key().as_ref()],
+        bump
+    )]
+    pub round_voter: Account<'info, RoundVoter>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, reviewer.key().as_ref()],
+        bump=reviewer_user_account.bump
+    )]
+    pub reviewer_user_account: Account<'info, User>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED_USER, reviewer.key().as_ref()],
+        bump
+    )]
+    pub reviewer_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED_STAKE, peer_review.key().as_ref()],
+        bump
+    )]
+    pub stake_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'a> RevealReview<'a> {
+    pub fn reveal_review(&mut self, salt: [u8; 32], bumps: &RevealReviewBumps) -> Result<()> {
+        //flagged reviews stay anonymous, their stake is already gone
+        let Some(identity_commitment) = self.peer_review.identity_commitment else {
+            return err!(ErrorCodes::ReviewNotRevealable);
+        };
+        require!(
+            self.peer_review.revealed_reviewer.is_none()
+                && matches!(
+                    self.peer_review.status,
+                    ReviewStatus::Accepted
+                        | ReviewStatus::Rejected
+                        | ReviewStatus::Expired
+                        | ReviewStatus::Withdrawn
+                ),
+            ErrorCodes::ReviewNotRevealable
+        );
+        let reviewer = self.reviewer.key();
+        require!(
+            hashv(&[reviewer.as_ref(), salt.as_ref()]).to_bytes() == identity_commitment,
+            ErrorCodes::IdentityCommitmentMismatch
+        );
+        require!(
+            !self.research_paper.is_author(reviewer),
+            ErrorCodes::AuthorCantReviewSelf
+        );
+
+        //handing over the escrowed reward, the platform fee was taken when the review was accepted
+        let reward_claimed = self.peer_review.unclaimed_reward;
+        let peer_review = self.peer_review.key();
+        let signer_seeds: &[&[&[u8]]] =
+            &[&[VAULT_SEED_STAKE, peer_review.as_ref(), &[bumps.stake_vault]]];
+        if reward_claimed > 0 {
+            let cpi_program = self.system_program.to_account_info();
+            let cpi_account_options = Transfer {
+                from: self.stake_vault.to_account_info(),
+                to: self.reviewer_vault.to_account_info(),
+            };
+            let cpi_ctx =
+                CpiContext::new_with_signer(cpi_program, cpi_account_options, signer_seeds);
+            transfer(cpi_ctx, reward_claimed)?;
+
+            self.reviewer_user_account.earning = self
+                .reviewer_user_account
+                .earning
+                .checked_add(reward_claimed)
+                .ok_or(ErrorCodes::MathOverflow)?;
+            self.reviewer_user_account.review_rewards = self
+                .reviewer_user_account
+                .review_rewards
+                .checked_add(reward_claimed)
+                .ok_or(ErrorCodes::MathOverflow)?;
+
+            //the stake stayed in the vault to keep the escrow above the rent minimum
+            let stake = self.peer_review.stake;
+            let cpi_program = self.system_program.to_account_info();
+            let cpi_account_options = Transfer {
+                from: self.stake_vault.to_account_info(),
+                to: self.reviewer.to_account_info(),
+            };
+            let cpi_ctx =
+                CpiContext::new_with_signer(cpi_program, cpi_account_options, signer_seeds);
+            transfer(cpi_ctx, stake)?;
+            self.peer_review.stake = 0;
+        }
+
+        //updating the states as verify_review would have for a named review
+        let user = &mut self.reviewer_user_account;
+        user.reviewed = user
+            .reviewed
+            .checked_add(1u16)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        //a released or withdrawn review was never decided, so it only counts as reviewed
+        if self.peer_review.is_accepted() {
+            user.reviews_accepted = user
+                .reviews_accepted
+                .checked_add(1u16)
+                .ok_or(ErrorCodes::MathOverflow)?;
+        } else if self.peer_review.status == ReviewStatus::Rejected {
+            user.reviews_rejected = user
+                .reviews_rejected
+                .checked_add(1u16)
+                .ok_or(ErrorCodes::MathOverflow)?;
+        }
+        user.refresh_reputation();
+        self.peer_review.revealed_reviewer = Some(reviewer);
+        self.round_voter.set_inner(RoundVoter {
+            round: self.review_round.key(),
+            voter: reviewer,
+            review: self.peer_review.key(),
+            bump: bumps.round_voter,
+        });
+        self.peer_review.unclaimed_reward = 0;
+
+        //the vote only counts from now on, provided the round has not been tallied yet
+        if self.review_round.outcome.is_none() {
+            self.review_round.reviews = self
+                .review_round
+                .reviews
+                .checked_add(1u16)
+                .ok_or(ErrorCodes::MathOverflow)?;
+            if self
+                .peer_review
+                .scores
+                .recommendation
+                .recommends_acceptance()
+            {
+                self.review_round.approvals = self
+                    .review_round
+                    .approvals
+                    .checked_add(1u16)
+                    .ok_or(ErrorCodes::MathOverflow)?;
+            }
+        }
+
+        emit!(ReviewRevealed {
+            review: self.peer_review.key(),
+            paper: self.research_paper.key(),
+            pseudonym: self.peer_review.reviewer,
+            reviewer,
+            status: self.peer_review.status.clone(),
+            reward_claimed,
+            reviewer_reputation: self.reviewer_user_account.reputation,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+}
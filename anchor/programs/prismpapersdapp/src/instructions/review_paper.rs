@@ -1,9 +1,22 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
 
 use crate::{
-    constants::{ANCHOR_DISCRIMINATOR, PAPER_SEED, RECEIPT_SEED, REVIEW_SEED, REVIEW_URL_MAX_LENGTH, USER_SEED},
+    access::check_reader_access,
+    constants::{
+        ANCHOR_DISCRIMINATOR, CONFIG_SEED, INSTITUTION_SEED, MEMBER_SEED, PAPER_SEED, PLAN_SEED,
+        RECEIPT_SEED, REVIEW_SEED, REVIEW_URL_MAX_LENGTH, ROUND_SEED, SUBSCRIPTION_SEED, USER_SEED,
+        VAULT_SEED_STAKE, VOTER_SEED,
+    },
     errors::ErrorCodes,
-    states::{AccessReceipt, PeerReview, ResearchPaper, ReviewStatus, User},
+    events::ReviewSubmitted,
+    states::{
+        AccessReceipt, Config, Institution, InstitutionMember, PaperStatus, PeerReview,
+        ResearchPaper, ReviewRound, ReviewScores, ReviewStatus, RoundVoter, Subscription,
+        SubscriptionPlan, User,
+    },
 };
 
 #[derive(Accounts)]
@@ -13,25 +26,81 @@ pub struct ReviewPaper<'info> {
 
     #[account(
         mut,
-        seeds = [PAPER_SEED, research_paper.author.key().as_ref()],
+        seeds = [
+            PAPER_SEED,
+            research_paper.author.key().as_ref(),
+            research_paper.index.to_le_bytes().as_ref()
+        ],
         bump=research_paper.bump
     )]
     pub research_paper: Account<'info, ResearchPaper>,
 
-    //checking if the reviewer has already purchased the paper, as only purchasers can review
+    //the paper's open round, every review counts towards it
+    #[account(
+        mut,
+        seeds = [
+            ROUND_SEED,
+            research_paper.key().as_ref(),
+            review_round.round_id.to_le_bytes().as_ref()
+        ],
+        bump = review_round.bump
+    )]
+    pub review_round: Account<'info, ReviewRound>,
+
+    //checking if the reviewer has access to the paper, as only readers can review
+    //either a personal receipt, a site license bought by the reviewer's institution,
+    //or an unexpired subscription to a plan that includes the paper
     #[account(
         seeds = [
             RECEIPT_SEED, 
-            reviewer.key().as_ref(), 
+            access_receipt.buyer.key().as_ref(), 
             research_paper.key().as_ref()
         ],
         bump = access_receipt.bump
     )]
-    pub access_receipt: Account<'info, AccessReceipt>,
+    pub access_receipt: Option<Account<'info, AccessReceipt>>,
+
+    //only required when reviewing under a site license
+    #[account(
+        seeds = [INSTITUTION_SEED, institution.admin.key().as_ref()],
+        bump = institution.bump
+    )]
+    pub institution: Option<Account<'info, Institution>>,
+
+    #[account(
+        seeds = [
+            MEMBER_SEED,
+            institution_member.institution.key().as_ref(),
+            reviewer.key().as_ref()
+        ],
+        bump = institution_member.bump
+    )]
+    pub institution_member: Option<Account<'info, InstitutionMember>>,
+
+    //only required when reviewing under a subscription
+    #[account(
+        seeds = [
+            PLAN_SEED,
+            subscription_plan.author.key().as_ref(),
+            subscription_plan.plan_id.to_le_bytes().as_ref()
+        ],
+        bump = subscription_plan.bump
+    )]
+    pub subscription_plan: Option<Account<'info, SubscriptionPlan>>,
+
+    #[account(
+        seeds = [
+            SUBSCRIPTION_SEED,
+            reviewer.key().as_ref(),
+            subscription.plan.key().as_ref()
+        ],
+        bump = subscription.bump
+    )]
+    pub subscription: Option<Account<'info, Subscription>>,
 
     #[account(
         mut,
-        seeds = [USER_SEED, reviewer_user_account.owner.key().as_ref()],
+        seeds = [USER_SEED, reviewer.key().as_ref()],
         bump=reviewer_user_account.bump
     )]
     pub reviewer_user_account: Account<'info, User>,
@@ -45,45 +114,179 @@ pub struct ReviewPaper<'info> {
     )]
     pub peer_review: Account<'info, PeerReview>,
 
+    //one vote per wallet and round, creating it fails if the reviewer already voted in this round
+    #[account(
+        init,
+        payer = reviewer,
+        space = ANCHOR_DISCRIMINATOR + RoundVoter::INIT_SPACE,
+        seeds = [VOTER_SEED, review_round.key().as_ref(), reviewer.key().as_ref()],
+        bump
+    )]
+    pub round_voter: Account<'info, RoundVoter>,
+
+    //holds the reviewer's stake until the review is accepted, closed or flagged
+    #[account(
+        mut,
+        seeds = [VAULT_SEED_STAKE, peer_review.key().as_ref()],
+        bump
+    )]
+    pub stake_vault: SystemAccount<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
     pub system_program: Program<'info, System>,
 }
 
 impl<'a> ReviewPaper<'a> {
-    pub fn review_paper(&mut self, review_url: String, proposed_reward: u64, bumps: &ReviewPaperBumps) -> Result<()> {
+    pub fn review_paper(
+        &mut self,
+        review_url: String,
+        review_hash: [u8; 32],
+        scores: ReviewScores,
+        proposed_reward: u64,
+        bumps: &ReviewPaperBumps,
+    ) -> Result<()> {
+        require!(
+            self.research_paper.status == PaperStatus::UnderReview,
+            ErrorCodes::PaperNotUnderReview
+        );
         require!(
-            self.access_receipt.buyer == self.reviewer.key(),
-            ErrorCodes::PaperNotPurchased
+            self.review_round.outcome.is_none()
+                && !self.review_round.is_full()
+                && Clock::get()?.unix_timestamp < self.review_round.deadline,
+            ErrorCodes::ReviewRoundClosed
         );
+        check_reader_access(
+            self.reviewer.key(),
+            &self.research_paper,
+            self.access_receipt.as_ref(),
+            self.institution.as_ref(),
+            self.institution_member.as_ref(),
+            self.subscription_plan.as_ref(),
+            self.subscription.as_ref(),
+        )?;
         require!(
-            review_url.len() > 0 && review_url.len() < REVIEW_URL_MAX_LENGTH,
+            !review_url.is_empty() && review_url.len() < REVIEW_URL_MAX_LENGTH,
             ErrorCodes::ReviewUrlEmpty
         );
+        require!(review_hash != [0u8; 32], ErrorCodes::ReviewHashInvalid);
+        scores.validate()?;
         require!(
-            self.research_paper.author != self.reviewer.key(),
+            !self.research_paper.is_author(self.reviewer.key()),
             ErrorCodes::AuthorCantReviewSelf
         );
+        require!(
+            self.reviewer_user_account.reputation >= self.research_paper.min_reputation,
+            ErrorCodes::ReputationTooLow
+        );
+        //rewards can only be claimed against the open bounty other reviews have not reserved yet
+        if proposed_reward > 0 {
+            require!(
+                proposed_reward <= self.research_paper.unreserved_bounty(),
+                ErrorCodes::RewardExceedsBounty
+            );
+            require!(
+                Clock::get()?.unix_timestamp < self.research_paper.bounty_deadline,
+                ErrorCodes::BountyExpired
+            );
+        }
 
-        //storing the review
+        //locking the reviewer's stake, it comes back unless the review is flagged as abusive
+        let stake = self
+            .research_paper
+            .required_review_stake(self.config.review_stake);
+        if stake > 0 {
+            let cpi_program = self.system_program.to_account_info();
+            let cpi_account_options = Transfer {
+                from: self.reviewer.to_account_info(),
+                to: self.stake_vault.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(cpi_program, cpi_account_options);
+            transfer(cpi_ctx, stake)?;
+        }
+
+        //storing the review, with the response deadline and expiry policy fixed at submission
+        let now = Clock::get()?.unix_timestamp;
+        let respond_by = now
+            .checked_add(self.research_paper.review_response_window)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        let paused_offset = self.config.paused_seconds_at(now)?;
         let reviewer = self.reviewer.key();
         let reviewed_paper = self.research_paper.key();
+        let round = self.review_round.key();
         self.peer_review.set_inner(PeerReview {
             reviewer,
             reviewed_paper,
+            round,
             review_url,
+            review_hash,
             status: ReviewStatus::Pending,
+            scores,
             proposed_reward,
-            timestamp: Clock::get()?.unix_timestamp,
+            stake,
+            timestamp: now,
+            decided_at: 0i64,
+            respond_by,
+            paused_offset,
+            expiry_policy: self.research_paper.review_expiry_policy,
+            identity_commitment: None,
+            revealed_reviewer: None,
+            unclaimed_reward: 0u64,
+            counter_offer: None,
+            countered_at: 0i64,
             bump: bumps.peer_review,
         });
+        self.round_voter.set_inner(RoundVoter {
+            round,
+            voter: reviewer,
+            review: self.peer_review.key(),
+            bump: bumps.round_voter,
+        });
         //updating the states
-        self.research_paper
+        self.review_round.reviews = self
+            .review_round
+            .reviews
+            .checked_add(1u16)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        if scores.recommendation.recommends_acceptance() {
+            self.review_round.approvals = self
+                .review_round
+                .approvals
+                .checked_add(1u16)
+                .ok_or(ErrorCodes::MathOverflow)?;
+        }
+        self.research_paper.reviews = self
+            .research_paper
             .reviews
             .checked_add(1u32)
             .ok_or(ErrorCodes::MathOverflow)?;
-        self.reviewer_user_account
+        self.research_paper.open_review(proposed_reward)?;
+        self.reviewer_user_account.reviewed = self
+            .reviewer_user_account
             .reviewed
             .checked_add(1u16)
             .ok_or(ErrorCodes::MathOverflow)?;
+        self.reviewer_user_account.pending_reviews = self
+            .reviewer_user_account
+            .pending_reviews
+            .checked_add(1u16)
+            .ok_or(ErrorCodes::MathOverflow)?;
+
+        emit!(ReviewSubmitted {
+            review: self.peer_review.key(),
+            paper: reviewed_paper,
+            reviewer,
+            round,
+            review_hash,
+            scores,
+            proposed_reward,
+            stake,
+            timestamp: self.peer_review.timestamp,
+        });
 
         Ok(())
     }
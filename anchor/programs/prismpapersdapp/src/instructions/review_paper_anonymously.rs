@@ -0,0 +1,252 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+
+use crate::{
+    access::check_reader_access,
+    constants::{
+        ANCHOR_DISCRIMINATOR, CONFIG_SEED, INSTITUTION_SEED, MEMBER_SEED, PAPER_SEED, PLAN_SEED,
+        RECEIPT_SEED, REVIEW_SEED, REVIEW_URL_MAX_LENGTH, ROUND_SEED, SUBSCRIPTION_SEED,
+        VAULT_SEED_STAKE,
+    },
+    errors::ErrorCodes,
+    events::AnonymousReviewSubmitted,
+    states::{
+        AccessReceipt, Config, Institution, InstitutionMember, PaperStatus, PeerReview,
+        ResearchPaper, ReviewRound, ReviewScores, ReviewStatus, Subscription, SubscriptionPlan,
+    },
+};
+
+//Submits a review under a fresh pseudonym keypair instead of the reviewer's wallet.
+//The review only carries a commitment to the wallet, `reveal_review` links the two once the author has decided.
+//The pseudonym proves it can read the paper like a named reviewer, with a receipt, license seat or
+//subscription of its own. Presenting a User account would give the reviewer away, so anonymous reviews
+//are limited to papers that ask for a stake and no minimum reputation, and their vote only joins the
+//round's tally once revealed.
+#[derive(Accounts)]
+pub struct ReviewPaperAnonymously<'info> {
+    #[account(mut)]
+    pub pseudonym: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PAPER_SEED,
+            research_paper.author.key().as_ref(),
+            research_paper.index.to_le_bytes().as_ref()
+        ],
+        bump=research_paper.bump
+    )]
+    pub research_paper: Account<'info, ResearchPaper>,
+
+    #[account(
+        seeds = [
+            ROUND_SEED,
+            research_paper.key().as_ref(),
+            review_round.round_id.to_le_bytes().as_ref()
+        ],
+        bump = review_round.bump
+    )]
+    pub review_round: Account<'info, ReviewRound>,
+
+    //the pseudonym's access to the paper, see `ReviewPaper`
+    #[account(
+        seeds = [
+            RECEIPT_SEED,
+            access_receipt.buyer.key().as_ref(),
+            research_paper.key().as_ref()
+        ],
+        bump = access_receipt.bump
+    )]
+    pub access_receipt: Option<Account<'info, AccessReceipt>>,
+
+    #[account(
+        seeds = [INSTITUTION_SEED, institution.admin.key().as_ref()],
+        bump = institution.bump
+    )]
+    pub institution: Option<Account<'info, Institution>>,
+
+    #[account(
+        seeds = [
+            MEMBER_SEED,
+            institution_member.institution.key().as_ref(),
+            pseudonym.key().as_ref()
+        ],
+        bump = institution_member.bump
+    )]
+    pub institution_member: Option<Account<'info, InstitutionMember>>,
+
+    #[account(
+        seeds = [
+            PLAN_SEED,
+            subscription_plan.author.key().as_ref(),
+            subscription_plan.plan_id.to_le_bytes().as_ref()
+        ],
+        bump = subscription_plan.bump
+    )]
+    pub subscription_plan: Option<Account<'info, SubscriptionPlan>>,
+
+    #[account(
+        seeds = [
+            SUBSCRIPTION_SEED,
+            pseudonym.key().as_ref(),
+            subscription.plan.key().as_ref()
+        ],
+        bump = subscription.bump
+    )]
+    pub subscription: Option<Account<'info, Subscription>>,
+
+    //the same PDA a named review uses, seeded by the pseudonym in place of the wallet
+    #[account(
+        init,
+        payer = pseudonym,
+        space = ANCHOR_DISCRIMINATOR + PeerReview::INIT_SPACE,
+        seeds = [REVIEW_SEED, pseudonym.key().as_ref(), research_paper.key().as_ref()],
+        bump
+    )]
+    pub peer_review: Account<'info, PeerReview>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED_STAKE, peer_review.key().as_ref()],
+        bump
+    )]
+    pub stake_vault: SystemAccount<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'a> ReviewPaperAnonymously<'a> {
+    pub fn review_paper_anonymously(
+        &mut self,
+        review_url: String,
+        review_hash: [u8; 32],
+        scores: ReviewScores,
+        proposed_reward: u64,
+        identity_commitment: [u8; 32],
+        bumps: &ReviewPaperAnonymouslyBumps,
+    ) -> Result<()> {
+        require!(
+            self.research_paper.status == PaperStatus::UnderReview,
+            ErrorCodes::PaperNotUnderReview
+        );
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            self.review_round.outcome.is_none()
+                && !self.review_round.is_full()
+                && now < self.review_round.deadline,
+            ErrorCodes::ReviewRoundClosed
+        );
+        check_reader_access(
+            self.pseudonym.key(),
+            &self.research_paper,
+            self.access_receipt.as_ref(),
+            self.institution.as_ref(),
+            self.institution_member.as_ref(),
+            self.subscription_plan.as_ref(),
+            self.subscription.as_ref(),
+        )?;
+        require!(
+            !review_url.is_empty() && review_url.len() < REVIEW_URL_MAX_LENGTH,
+            ErrorCodes::ReviewUrlEmpty
+        );
+        require!(review_hash != [0u8; 32], ErrorCodes::ReviewHashInvalid);
+        require!(
+            identity_commitment != [0u8; 32],
+            ErrorCodes::IdentityCommitmentInvalid
+        );
+        scores.validate()?;
+
+        let stake = self
+            .research_paper
+            .required_review_stake(self.config.review_stake);
+        require!(
+            stake > 0 && self.research_paper.min_reputation == 0,
+            ErrorCodes::AnonymousReviewNotAllowed
+        );
+        if proposed_reward > 0 {
+            require!(
+                proposed_reward <= self.research_paper.unreserved_bounty(),
+                ErrorCodes::RewardExceedsBounty
+            );
+            require!(
+                now < self.research_paper.bounty_deadline,
+                ErrorCodes::BountyExpired
+            );
+            //the reward waits in the stake vault until the reviewer reveals, which only works for lamports
+            require!(
+                self.research_paper.payment_mint.is_none(),
+                ErrorCodes::AnonymousTokenReward
+            );
+        }
+
+        //locking the stake
+        let cpi_program = self.system_program.to_account_info();
+        let cpi_account_options = Transfer {
+            from: self.pseudonym.to_account_info(),
+            to: self.stake_vault.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_account_options);
+        transfer(cpi_ctx, stake)?;
+
+        //storing the review, with the response deadline and expiry policy fixed at submission
+        let respond_by = now
+            .checked_add(self.research_paper.review_response_window)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        let paused_offset = self.config.paused_seconds_at(now)?;
+        let pseudonym = self.pseudonym.key();
+        let reviewed_paper = self.research_paper.key();
+        let round = self.review_round.key();
+        self.peer_review.set_inner(PeerReview {
+            reviewer: pseudonym,
+            reviewed_paper,
+            round,
+            review_url,
+            review_hash,
+            status: ReviewStatus::Pending,
+            scores,
+            proposed_reward,
+            stake,
+            timestamp: now,
+            decided_at: 0i64,
+            respond_by,
+            paused_offset,
+            expiry_policy: self.research_paper.review_expiry_policy,
+            identity_commitment: Some(identity_commitment),
+            revealed_reviewer: None,
+            unclaimed_reward: 0u64,
+            counter_offer: None,
+            countered_at: 0i64,
+            bump: bumps.peer_review,
+        });
+
+        //updating the states, the round's tally and the reviewer's own counters wait for the reveal
+        self.research_paper.reviews = self
+            .research_paper
+            .reviews
+            .checked_add(1u32)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        self.research_paper.open_review(proposed_reward)?;
+
+        emit!(AnonymousReviewSubmitted {
+            review: self.peer_review.key(),
+            paper: reviewed_paper,
+            pseudonym,
+            round,
+            review_hash,
+            identity_commitment,
+            scores,
+            proposed_reward,
+            stake,
+            timestamp: now,
+        });
+        Ok(())
+    }
+}
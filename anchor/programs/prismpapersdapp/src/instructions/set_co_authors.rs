@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::PAPER_SEED,
+    errors::ErrorCodes,
+    events::CoAuthorsUpdated,
+    splits::{require_co_author_signatures, track_live_papers, validate_co_authors},
+    states::{CoAuthor, ResearchPaper},
+};
+
+//Replaces a paper's revenue split. Every current and new co-author has to sign.
+//`remaining_accounts` holds, for the co-authors other than the author: the current ones as signers,
+//then the new ones as signers, then the User accounts of the current ones, then those of the new
+//ones, each in list order. Someone on both lists is passed in both.
+#[derive(Accounts)]
+pub struct SetCoAuthors<'info> {
+    pub author: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PAPER_SEED,
+            research_paper.author.key().as_ref(),
+            research_paper.index.to_le_bytes().as_ref()
+        ],
+        bump=research_paper.bump
+    )]
+    pub research_paper: Account<'info, ResearchPaper>,
+}
+
+impl<'a> SetCoAuthors<'a> {
+    pub fn set_co_authors(
+        &mut self,
+        co_authors: Vec<CoAuthor>,
+        remaining_accounts: &'a [AccountInfo<'a>],
+    ) -> Result<()> {
+        let author = self.author.key();
+        require!(
+            author == self.research_paper.author,
+            ErrorCodes::UnauthorizedUpdate
+        );
+        //escrowed purchases are settled with the split they were bought under
+        require!(
+            self.research_paper.escrowed == 0,
+            ErrorCodes::EscrowNotEmpty
+        );
+        validate_co_authors(author, &co_authors)?;
+
+        let current: Vec<CoAuthor> = self.research_paper.other_co_authors().cloned().collect();
+        let incoming: Vec<CoAuthor> = co_authors
+            .iter()
+            .filter(|co_author| co_author.wallet != author)
+            .cloned()
+            .collect();
+        require!(
+            remaining_accounts.len() == (current.len() + incoming.len()) * 2,
+            ErrorCodes::CoAuthorAccountsInvalid
+        );
+        let (current_signers, rest) = remaining_accounts.split_at(current.len());
+        let (incoming_signers, user_infos) = rest.split_at(incoming.len());
+        let (current_users, incoming_users) = user_infos.split_at(current.len());
+
+        require_co_author_signatures(current_signers, &current)?;
+        require_co_author_signatures(incoming_signers, &incoming)?;
+        track_live_papers(current_users, &current, false)?;
+        track_live_papers(incoming_users, &incoming, true)?;
+
+        self.research_paper.co_authors = co_authors;
+
+        emit!(CoAuthorsUpdated {
+            paper: self.research_paper.key(),
+            author,
+            co_authors: self.research_paper.co_authors.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+}
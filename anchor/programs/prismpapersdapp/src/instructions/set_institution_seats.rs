@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::INSTITUTION_SEED, errors::ErrorCodes, events::InstitutionSeatsUpdated,
+    states::Institution,
+};
+
+#[derive(Accounts)]
+pub struct SetInstitutionSeats<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin @ ErrorCodes::UnauthorizedInstitutionAdmin,
+        seeds = [INSTITUTION_SEED, admin.key().as_ref()],
+        bump=institution.bump
+    )]
+    pub institution: Account<'info, Institution>,
+}
+
+impl<'a> SetInstitutionSeats<'a> {
+    pub fn set_institution_seats(&mut self, seats: u32) -> Result<()> {
+        //seats can't drop below the members already holding one
+        require!(
+            seats > 0 && seats >= self.institution.members,
+            ErrorCodes::InstitutionSeatsInvalid
+        );
+        let old_seats = self.institution.seats;
+        self.institution.seats = seats;
+
+        emit!(InstitutionSeatsUpdated {
+            institution: self.institution.key(),
+            admin: self.admin.key(),
+            old_seats,
+            new_seats: seats,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{BPS_DENOMINATOR, PAPER_SEED},
+    errors::ErrorCodes,
+    events::MinReputationUpdated,
+    states::{PaperStatus, ResearchPaper},
+};
+
+#[derive(Accounts)]
+pub struct SetMinReputation<'info> {
+    pub author: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PAPER_SEED,
+            research_paper.author.key().as_ref(),
+            research_paper.index.to_le_bytes().as_ref()
+        ],
+        bump=research_paper.bump
+    )]
+    pub research_paper: Account<'info, ResearchPaper>,
+}
+
+impl<'a> SetMinReputation<'a> {
+    pub fn set_min_reputation(&mut self, min_reputation: u16) -> Result<()> {
+        require!(
+            self.author.key() == self.research_paper.author,
+            ErrorCodes::UnauthorizedUpdate
+        );
+        require!(
+            self.research_paper.status != PaperStatus::Retracted,
+            ErrorCodes::PaperIsRetracted
+        );
+        require!(
+            u64::from(min_reputation) <= BPS_DENOMINATOR,
+            ErrorCodes::MinReputationInvalid
+        );
+
+        //only gates new reviews, submitted ones stay valid
+        self.research_paper.min_reputation = min_reputation;
+
+        emit!(MinReputationUpdated {
+            paper: self.research_paper.key(),
+            author: self.research_paper.author,
+            min_reputation,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+}
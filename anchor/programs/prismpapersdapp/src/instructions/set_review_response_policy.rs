@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{PAPER_SEED, REVIEW_RESPONSE_WINDOW_MAX},
+    errors::ErrorCodes,
+    events::ReviewResponsePolicyUpdated,
+    states::{PaperStatus, ResearchPaper, ReviewExpiryPolicy},
+};
+
+#[derive(Accounts)]
+pub struct SetReviewResponsePolicy<'info> {
+    pub author: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PAPER_SEED,
+            research_paper.author.key().as_ref(),
+            research_paper.index.to_le_bytes().as_ref()
+        ],
+        bump=research_paper.bump
+    )]
+    pub research_paper: Account<'info, ResearchPaper>,
+}
+
+impl<'a> SetReviewResponsePolicy<'a> {
+    pub fn set_review_response_policy(
+        &mut self,
+        response_window: i64,
+        expiry_policy: ReviewExpiryPolicy,
+    ) -> Result<()> {
+        require!(
+            self.author.key() == self.research_paper.author,
+            ErrorCodes::UnauthorizedUpdate
+        );
+        require!(
+            self.research_paper.status != PaperStatus::Retracted,
+            ErrorCodes::PaperIsRetracted
+        );
+        require!(
+            response_window > 0 && response_window <= REVIEW_RESPONSE_WINDOW_MAX,
+            ErrorCodes::ReviewResponseWindowInvalid
+        );
+        //the expiry crank pays auto-accepted rewards from the bounty vault in lamports only
+        require!(
+            expiry_policy != ReviewExpiryPolicy::AutoAccept
+                || self.research_paper.payment_mint.is_none(),
+            ErrorCodes::AutoAcceptTokenPaper
+        );
+
+        //only applies to new reviews, submitted ones keep the terms they were submitted under
+        self.research_paper.review_response_window = response_window;
+        self.research_paper.review_expiry_policy = expiry_policy;
+
+        emit!(ReviewResponsePolicyUpdated {
+            paper: self.research_paper.key(),
+            author: self.research_paper.author,
+            response_window,
+            expiry_policy,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+}
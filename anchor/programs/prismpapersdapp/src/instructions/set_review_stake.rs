@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::PAPER_SEED,
+    errors::ErrorCodes,
+    events::ReviewStakeUpdated,
+    states::{PaperStatus, ResearchPaper},
+};
+
+#[derive(Accounts)]
+pub struct SetReviewStake<'info> {
+    pub author: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PAPER_SEED,
+            research_paper.author.key().as_ref(),
+            research_paper.index.to_le_bytes().as_ref()
+        ],
+        bump=research_paper.bump
+    )]
+    pub research_paper: Account<'info, ResearchPaper>,
+}
+
+impl<'a> SetReviewStake<'a> {
+    //None goes back to the platform-wide stake, Some(0) opens the paper to stake-free reviews
+    pub fn set_review_stake(&mut self, stake: Option<u64>) -> Result<()> {
+        require!(
+            self.author.key() == self.research_paper.author,
+            ErrorCodes::UnauthorizedUpdate
+        );
+        require!(
+            self.research_paper.status != PaperStatus::Retracted,
+            ErrorCodes::PaperIsRetracted
+        );
+
+        if let Some(stake) = stake {
+            require!(
+                stake == 0 || stake >= Rent::get()?.minimum_balance(0),
+                ErrorCodes::ReviewStakeInvalid
+            );
+        }
+
+        //reviews already submitted keep the stake they locked
+        self.research_paper.review_stake = stake;
+
+        emit!(ReviewStakeUpdated {
+            paper: self.research_paper.key(),
+            author: self.research_paper.author,
+            stake,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::PAPER_SEED,
+    errors::ErrorCodes,
+    events::RevisionPolicyUpdated,
+    states::{PaperStatus, ResearchPaper, RevisionAccessPolicy},
+};
+
+#[derive(Accounts)]
+pub struct SetRevisionPolicy<'info> {
+    pub author: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PAPER_SEED,
+            research_paper.author.key().as_ref(),
+            research_paper.index.to_le_bytes().as_ref()
+        ],
+        bump=research_paper.bump
+    )]
+    pub research_paper: Account<'info, ResearchPaper>,
+}
+
+impl<'a> SetRevisionPolicy<'a> {
+    pub fn set_revision_policy(&mut self, policy: RevisionAccessPolicy) -> Result<()> {
+        require!(
+            self.author.key() == self.research_paper.author,
+            ErrorCodes::UnauthorizedUpdate
+        );
+        require!(
+            self.research_paper.status != PaperStatus::Retracted,
+            ErrorCodes::PaperIsRetracted
+        );
+
+        //the policy decides which revision's key every buyer is owed, so it is fixed once someone paid
+        require!(
+            self.research_paper.sales == 0 || self.research_paper.revision_policy == policy,
+            ErrorCodes::RevisionPolicyLocked
+        );
+
+        self.research_paper.revision_policy = policy;
+
+        emit!(RevisionPolicyUpdated {
+            paper: self.research_paper.key(),
+            author: self.research_paper.author,
+            policy,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,295 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    constants::{
+        PAPER_SEED, RECEIPT_SEED, USER_SEED, VAULT_SEED_ADMIN, VAULT_SEED_ESCROW, VAULT_SEED_USER,
+    },
+    errors::ErrorCodes,
+    events::PurchaseSettled,
+    payments::transfer_tokens,
+    splits::{pay_co_authors_sol, pay_co_authors_tokens, split_earning},
+    states::{AccessReceipt, CoAuthor, ReceiptStatus, ResearchPaper, User},
+};
+
+//Permissionless crank that releases an escrowed purchase once its refund window has closed.
+//`remaining_accounts` holds a (User account, user vault) pair for every co-author other than the
+//author, in list order; for token papers the user vault's token account takes the vault's place.
+#[derive(Accounts)]
+pub struct SettlePurchase<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PAPER_SEED,
+            research_paper.author.key().as_ref(),
+            research_paper.index.to_le_bytes().as_ref()
+        ],
+        bump=research_paper.bump
+    )]
+    pub research_paper: Account<'info, ResearchPaper>,
+
+    #[account(
+        mut,
+        seeds = [
+            RECEIPT_SEED,
+            access_receipt.buyer.key().as_ref(),
+            research_paper.key().as_ref()
+        ],
+        bump=access_receipt.bump
+    )]
+    pub access_receipt: Account<'info, AccessReceipt>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, research_paper.author.key().as_ref()],
+        bump=author_user_account.bump
+    )]
+    pub author_user_account: Account<'info, User>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED_ESCROW, research_paper.key().as_ref()],
+        bump
+    )]
+    pub escrow_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED_USER, research_paper.author.key().as_ref()],
+        bump
+    )]
+    pub author_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED_ADMIN],
+        bump
+    )]
+    pub admin_vault: SystemAccount<'info>,
+
+    //token accounts, only required when the paper is priced in an SPL token
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = escrow_vault,
+        associated_token::token_program = token_program
+    )]
+    pub escrow_token_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = payment_mint,
+        associated_token::authority = author_vault,
+        associated_token::token_program = token_program
+    )]
+    pub author_token_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = payment_mint,
+        associated_token::authority = admin_vault,
+        associated_token::token_program = token_program
+    )]
+    pub admin_token_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'a> SettlePurchase<'a> {
+    pub fn settle_purchase(
+        &mut self,
+        remaining_accounts: &'a [AccountInfo<'a>],
+        bumps: &SettlePurchaseBumps,
+    ) -> Result<()> {
+        require!(
+            self.access_receipt.status == ReceiptStatus::Escrowed,
+            ErrorCodes::ReceiptNotEscrowed
+        );
+        let timestamp = Clock::get()?.unix_timestamp;
+        require!(
+            timestamp >= self.access_receipt.refund_deadline,
+            ErrorCodes::RefundWindowOpen
+        );
+
+        //the split was fixed at purchase time
+        let total_amount = self.access_receipt.amount_paid;
+        let platform_fee = self.access_receipt.platform_fee;
+        let author_earning = total_amount
+            .checked_sub(platform_fee)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        let research_paper = self.research_paper.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            VAULT_SEED_ESCROW,
+            research_paper.as_ref(),
+            &[bumps.escrow_vault],
+        ]];
+
+        //the author earning is shared across the co-authors
+        let (author_share, co_author_shares) = split_earning(
+            author_earning,
+            self.research_paper.author,
+            &self.research_paper.co_authors,
+        )?;
+        let co_authors: Vec<CoAuthor> = self.research_paper.other_co_authors().cloned().collect();
+        match self.research_paper.payment_mint {
+            None => {
+                self.release_sol(platform_fee, author_share, signer_seeds)?;
+                pay_co_authors_sol(
+                    &self.escrow_vault.to_account_info(),
+                    &self.system_program.to_account_info(),
+                    remaining_accounts,
+                    &co_authors,
+                    &co_author_shares,
+                    signer_seeds,
+                )?
+            }
+            Some(payment_mint) => {
+                self.release_tokens(payment_mint, platform_fee, author_share, signer_seeds)?;
+                let (Some(mint), Some(escrow_token_vault), Some(token_program)) = (
+                    &self.payment_mint,
+                    &self.escrow_token_vault,
+                    &self.token_program,
+                ) else {
+                    return err!(ErrorCodes::TokenAccountsMissing);
+                };
+                pay_co_authors_tokens(
+                    escrow_token_vault,
+                    &self.escrow_vault.to_account_info(),
+                    mint,
+                    token_program,
+                    remaining_accounts,
+                    &co_authors,
+                    &co_author_shares,
+                    signer_seeds,
+                )?
+            }
+        }
+
+        //updating the states
+        self.access_receipt.status = ReceiptStatus::Settled;
+        self.research_paper.escrowed = self
+            .research_paper
+            .escrowed
+            .checked_sub(total_amount)
+            .ok_or(ErrorCodes::MathOverflow)?;
+
+        emit!(PurchaseSettled {
+            receipt: self.access_receipt.key(),
+            paper: research_paper,
+            author: self.research_paper.author,
+            payment_mint: self.research_paper.payment_mint,
+            platform_fee,
+            author_earning,
+            timestamp,
+        });
+        Ok(())
+    }
+
+    //SOL settlements pay into the author and admin vaults and accrue to the author's earning ledger
+    fn release_sol(
+        &mut self,
+        platform_fee: u64,
+        author_share: u64,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        require!(
+            self.escrow_vault.lamports() >= platform_fee + author_share,
+            ErrorCodes::InsufficientFundsInVault
+        );
+
+        //transferring the author amount to the author vault
+        let cpi_program = self.system_program.to_account_info();
+        let escrow_vault = self.escrow_vault.to_account_info();
+        let author_vault = self.author_vault.to_account_info();
+        let cpi_account_options = Transfer {
+            from: escrow_vault,
+            to: author_vault,
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_account_options, signer_seeds);
+        transfer(cpi_ctx, author_share)?;
+
+        //transferring the platform fee to the admin vault
+        let cpi_program = self.system_program.to_account_info();
+        let escrow_vault = self.escrow_vault.to_account_info();
+        let admin_vault = self.admin_vault.to_account_info();
+        let cpi_account_options = Transfer {
+            from: escrow_vault,
+            to: admin_vault,
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_account_options, signer_seeds);
+        transfer(cpi_ctx, platform_fee)?;
+
+        self.author_user_account.earning = self
+            .author_user_account
+            .earning
+            .checked_add(author_share)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        Ok(())
+    }
+
+    //token settlements pay into token accounts owned by the author and admin vaults,
+    //whose balances are the per-mint earning ledger
+    fn release_tokens(
+        &mut self,
+        payment_mint: Pubkey,
+        platform_fee: u64,
+        author_share: u64,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let (
+            Some(mint),
+            Some(escrow_token_vault),
+            Some(author_token_vault),
+            Some(admin_token_vault),
+            Some(token_program),
+        ) = (
+            &self.payment_mint,
+            &self.escrow_token_vault,
+            &self.author_token_vault,
+            &self.admin_token_vault,
+            &self.token_program,
+        )
+        else {
+            return err!(ErrorCodes::TokenAccountsMissing);
+        };
+        require_keys_eq!(mint.key(), payment_mint, ErrorCodes::PaymentMintMismatch);
+        require!(
+            escrow_token_vault.amount >= platform_fee + author_share,
+            ErrorCodes::InsufficientFundsInVault
+        );
+
+        transfer_tokens(
+            escrow_token_vault,
+            author_token_vault,
+            self.escrow_vault.to_account_info(),
+            mint,
+            token_program,
+            author_share,
+            signer_seeds,
+        )?;
+        transfer_tokens(
+            escrow_token_vault,
+            admin_token_vault,
+            self.escrow_vault.to_account_info(),
+            mint,
+            token_program,
+            platform_fee,
+            signer_seeds,
+        )
+    }
+}
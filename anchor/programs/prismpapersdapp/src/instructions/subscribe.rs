@@ -0,0 +1,323 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    constants::{
+        ANCHOR_DISCRIMINATOR, CONFIG_SEED, PLAN_SEED, SUBSCRIPTION_SEED, USER_SEED,
+        VAULT_SEED_ADMIN, VAULT_SEED_USER,
+    },
+    errors::ErrorCodes,
+    events::Subscribed,
+    fees::split_fee,
+    payments::transfer_tokens,
+    splits::{pay_co_authors_sol, pay_co_authors_tokens, split_earning},
+    states::{CoAuthor, Config, Subscription, SubscriptionPlan, User},
+};
+
+//Subscribes to a plan, or renews it, for one more period.
+//`remaining_accounts` holds a (User account, user vault) pair for every co-author of the plan other
+//than the author, in list order; for token plans the user vault's token account takes the vault's place.
+#[derive(Accounts)]
+pub struct Subscribe<'info> {
+    #[account(mut)]
+    pub subscriber: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PLAN_SEED,
+            subscription_plan.author.key().as_ref(),
+            subscription_plan.plan_id.to_le_bytes().as_ref()
+        ],
+        bump=subscription_plan.bump
+    )]
+    pub subscription_plan: Account<'info, SubscriptionPlan>,
+
+    #[account(
+        init_if_needed,
+        payer = subscriber,
+        space = ANCHOR_DISCRIMINATOR + Subscription::INIT_SPACE,
+        seeds = [
+            SUBSCRIPTION_SEED,
+            subscriber.key().as_ref(),
+            subscription_plan.key().as_ref()
+        ],
+        bump
+    )]
+    pub subscription: Account<'info, Subscription>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, subscription_plan.author.key().as_ref()],
+        bump=author_user_account.bump
+    )]
+    pub author_user_account: Account<'info, User>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED_USER, subscription_plan.author.key().as_ref()],
+        bump
+    )]
+    pub author_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED_ADMIN],
+        bump
+    )]
+    pub admin_vault: SystemAccount<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    //token accounts, only required when the plan is priced in an SPL token
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = subscriber,
+        associated_token::token_program = token_program
+    )]
+    pub subscriber_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = subscriber,
+        associated_token::mint = payment_mint,
+        associated_token::authority = author_vault,
+        associated_token::token_program = token_program
+    )]
+    pub author_token_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = subscriber,
+        associated_token::mint = payment_mint,
+        associated_token::authority = admin_vault,
+        associated_token::token_program = token_program
+    )]
+    pub admin_token_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'a> Subscribe<'a> {
+    pub fn subscribe(
+        &mut self,
+        subscriber_encryption_key: [u8; 32],
+        remaining_accounts: &'a [AccountInfo<'a>],
+        bumps: &SubscribeBumps,
+    ) -> Result<()> {
+        require!(!self.config.paused, ErrorCodes::ProgramPaused);
+        require!(self.subscription_plan.active, ErrorCodes::PlanInactive);
+        require!(
+            self.subscriber.key() != self.subscription_plan.author,
+            ErrorCodes::AuthorCantBuySelf
+        );
+        require!(
+            subscriber_encryption_key != [0u8; 32],
+            ErrorCodes::BuyerEncryptionKeyInvalid
+        );
+
+        //subscriptions are not refundable, so the split is paid out right away
+        let total_amount = self.subscription_plan.price;
+        let (platform_fee, author_earning) = match self.subscription_plan.payment_mint {
+            None => {
+                let split = split_fee(
+                    total_amount,
+                    self.config.purchase_fee_bps,
+                    self.config.min_fee_lamports,
+                )?;
+                self.pay_in_sol(split.0, split.1, remaining_accounts)?;
+                split
+            }
+            Some(payment_mint) => {
+                //the lamport minimum fee does not apply to token amounts
+                let split = split_fee(total_amount, self.config.purchase_fee_bps, 0)?;
+                self.pay_in_tokens(payment_mint, split.0, split.1, remaining_accounts)?;
+                split
+            }
+        };
+
+        //a renewal before expiry extends the running period instead of replacing it
+        let timestamp = Clock::get()?.unix_timestamp;
+        let plan = self.subscription_plan.key();
+        if self.subscription.plan == Pubkey::default() {
+            self.subscription.set_inner(Subscription {
+                subscriber: self.subscriber.key(),
+                plan,
+                expires_at: 0i64,
+                subscriber_encryption_key,
+                timestamp,
+                bump: bumps.subscription,
+            });
+        }
+        let expires_at = self
+            .subscription
+            .expires_at
+            .max(timestamp)
+            .checked_add(self.subscription_plan.duration)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        self.subscription.expires_at = expires_at;
+        //a renewal may rotate the key future content keys are wrapped to
+        self.subscription.subscriber_encryption_key = subscriber_encryption_key;
+
+        //updating the states
+        self.subscription_plan.subscribers = self
+            .subscription_plan
+            .subscribers
+            .checked_add(1u32)
+            .ok_or(ErrorCodes::MathOverflow)?;
+
+        emit!(Subscribed {
+            subscription: self.subscription.key(),
+            plan,
+            subscriber: self.subscriber.key(),
+            author: self.subscription_plan.author,
+            payment_mint: self.subscription_plan.payment_mint,
+            price: total_amount,
+            platform_fee,
+            author_earning,
+            expires_at,
+            timestamp,
+        });
+        Ok(())
+    }
+
+    //SOL subscriptions pay into the author, co-author and admin vaults and accrue to their earning ledgers
+    fn pay_in_sol(
+        &mut self,
+        platform_fee: u64,
+        author_earning: u64,
+        remaining_accounts: &'a [AccountInfo<'a>],
+    ) -> Result<()> {
+        require!(
+            self.subscriber.lamports() >= platform_fee + author_earning,
+            ErrorCodes::InsufficientFundsInWallet
+        );
+        //the author earning is shared across the plan's co-authors
+        let (author_share, co_author_shares) = split_earning(
+            author_earning,
+            self.subscription_plan.author,
+            &self.subscription_plan.co_authors,
+        )?;
+        let co_authors: Vec<CoAuthor> =
+            self.subscription_plan.other_co_authors().cloned().collect();
+        pay_co_authors_sol(
+            &self.subscriber.to_account_info(),
+            &self.system_program.to_account_info(),
+            remaining_accounts,
+            &co_authors,
+            &co_author_shares,
+            &[],
+        )?;
+
+        //transferring the author amount to the author vault
+        let cpi_program = self.system_program.to_account_info();
+        let subscriber = self.subscriber.to_account_info();
+        let author_vault = self.author_vault.to_account_info();
+        let cpi_account_options = Transfer {
+            from: subscriber,
+            to: author_vault,
+        };
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_account_options);
+        transfer(cpi_ctx, author_share)?;
+
+        //transferring the platform fee to the admin vault
+        let cpi_program = self.system_program.to_account_info();
+        let subscriber = self.subscriber.to_account_info();
+        let admin_vault = self.admin_vault.to_account_info();
+        let cpi_account_options = Transfer {
+            from: subscriber,
+            to: admin_vault,
+        };
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_account_options);
+        transfer(cpi_ctx, platform_fee)?;
+
+        self.author_user_account.earning = self
+            .author_user_account
+            .earning
+            .checked_add(author_share)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        Ok(())
+    }
+
+    //token subscriptions pay into token accounts owned by the author, co-author and admin vaults
+    fn pay_in_tokens(
+        &mut self,
+        payment_mint: Pubkey,
+        platform_fee: u64,
+        author_earning: u64,
+        remaining_accounts: &'a [AccountInfo<'a>],
+    ) -> Result<()> {
+        let (
+            Some(mint),
+            Some(subscriber_token_account),
+            Some(author_token_vault),
+            Some(admin_token_vault),
+            Some(token_program),
+        ) = (
+            &self.payment_mint,
+            &self.subscriber_token_account,
+            &self.author_token_vault,
+            &self.admin_token_vault,
+            &self.token_program,
+        )
+        else {
+            return err!(ErrorCodes::TokenAccountsMissing);
+        };
+        require_keys_eq!(mint.key(), payment_mint, ErrorCodes::PaymentMintMismatch);
+        require!(
+            subscriber_token_account.amount >= platform_fee + author_earning,
+            ErrorCodes::InsufficientFundsInWallet
+        );
+
+        let (author_share, co_author_shares) = split_earning(
+            author_earning,
+            self.subscription_plan.author,
+            &self.subscription_plan.co_authors,
+        )?;
+        let co_authors: Vec<CoAuthor> =
+            self.subscription_plan.other_co_authors().cloned().collect();
+        pay_co_authors_tokens(
+            subscriber_token_account,
+            &self.subscriber.to_account_info(),
+            mint,
+            token_program,
+            remaining_accounts,
+            &co_authors,
+            &co_author_shares,
+            &[],
+        )?;
+        transfer_tokens(
+            subscriber_token_account,
+            author_token_vault,
+            self.subscriber.to_account_info(),
+            mint,
+            token_program,
+            author_share,
+            &[],
+        )?;
+        transfer_tokens(
+            subscriber_token_account,
+            admin_token_vault,
+            self.subscriber.to_account_info(),
+            mint,
+            token_program,
+            platform_fee,
+            &[],
+        )
+    }
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{BPS_DENOMINATOR, BUNDLE_MAX_PAPERS, BUNDLE_SEED},
+    errors::ErrorCodes,
+    events::BundleUpdated,
+    states::Bundle,
+};
+
+#[derive(Accounts)]
+pub struct UpdateBundle<'info> {
+    pub author: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            BUNDLE_SEED,
+            author.key().as_ref(),
+            bundle.bundle_id.to_le_bytes().as_ref()
+        ],
+        bump=bundle.bump
+    )]
+    pub bundle: Account<'info, Bundle>,
+}
+
+impl<'a> UpdateBundle<'a> {
+    pub fn update_bundle(
+        &mut self,
+        papers: Vec<Pubkey>,
+        discount_bps: u16,
+        active: bool,
+    ) -> Result<()> {
+        require!(
+            !papers.is_empty() && papers.len() <= BUNDLE_MAX_PAPERS,
+            ErrorCodes::BundlePapersInvalid
+        );
+        require!(
+            u64::from(discount_bps) < BPS_DENOMINATOR,
+            ErrorCodes::BundleDiscountInvalid
+        );
+
+        let bundle = &mut self.bundle;
+        bundle.papers = papers;
+        bundle.discount_bps = discount_bps;
+        bundle.active = active;
+
+        emit!(BundleUpdated {
+            bundle: bundle.key(),
+            author: bundle.author,
+            bundle_id: bundle.bundle_id,
+            papers: bundle.papers.clone(),
+            discount_bps,
+            active,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{BPS_DENOMINATOR, CONFIG_SEED, MAX_ADMINS},
+    errors::ErrorCodes,
+    events::ConfigUpdated,
+    states::Config,
+};
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl<'a> UpdateConfig<'a> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_config(
+        &mut self,
+        admins: Vec<Pubkey>,
+        purchase_fee_bps: u16,
+        review_fee_bps: u16,
+        min_fee_lamports: u64,
+        treasury: Pubkey,
+        refund_window: i64,
+        review_stake: u64,
+        paused: bool,
+    ) -> Result<()> {
+        require!(
+            self.config.is_admin(&self.admin.key()),
+            ErrorCodes::UnauthorizedAdmin
+        );
+        require!(
+            !admins.is_empty() && admins.len() <= MAX_ADMINS,
+            ErrorCodes::InvalidAdminSet
+        );
+        require!(
+            u64::from(purchase_fee_bps) <= BPS_DENOMINATOR
+                && u64::from(review_fee_bps) <= BPS_DENOMINATOR,
+            ErrorCodes::InvalidFee
+        );
+        require!(refund_window >= 0, ErrorCodes::RefundWindowInvalid);
+        //the stake vault is an empty system account, so a smaller stake could never be deposited
+        require!(
+            review_stake == 0 || review_stake >= Rent::get()?.minimum_balance(0),
+            ErrorCodes::ReviewStakeInvalid
+        );
+
+        self.config.admins = admins;
+        self.config.purchase_fee_bps = purchase_fee_bps;
+        self.config.review_fee_bps = review_fee_bps;
+        self.config.min_fee_lamports = min_fee_lamports;
+        self.config.treasury = treasury;
+        self.config.refund_window = refund_window;
+        self.config.review_stake = review_stake;
+        //review response windows stand still while the program is paused
+        let timestamp = Clock::get()?.unix_timestamp;
+        self.config.set_paused(paused, timestamp)?;
+
+        emit!(ConfigUpdated {
+            updated_by: self.admin.key(),
+            admins: self.config.admins.clone(),
+            purchase_fee_bps,
+            review_fee_bps,
+            min_fee_lamports,
+            treasury,
+            refund_window,
+            review_stake,
+            paused: self.config.paused,
+            timestamp,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{PLAN_MAX_PAPERS, PLAN_SEED},
+    errors::ErrorCodes,
+    events::PlanUpdated,
+    states::SubscriptionPlan,
+};
+
+#[derive(Accounts)]
+pub struct UpdatePlan<'info> {
+    pub author: Signer<'info>,
+
+    //changes apply to new subscriptions and renewals, running periods keep their expiry
+    #[account(
+        mut,
+        seeds = [
+            PLAN_SEED,
+            author.key().as_ref(),
+            subscription_plan.plan_id.to_le_bytes().as_ref()
+        ],
+        bump=subscription_plan.bump
+    )]
+    pub subscription_plan: Account<'info, SubscriptionPlan>,
+}
+
+impl<'a> UpdatePlan<'a> {
+    pub fn update_plan(
+        &mut self,
+        price: u64,
+        duration: i64,
+        papers: Vec<Pubkey>,
+        active: bool,
+    ) -> Result<()> {
+        require!(price > 0, ErrorCodes::ResearchPriceInvalid);
+        require!(duration > 0, ErrorCodes::PlanDurationInvalid);
+        require!(
+            papers.len() <= PLAN_MAX_PAPERS,
+            ErrorCodes::PlanPapersTooMany
+        );
+
+        let plan = &mut self.subscription_plan;
+        plan.price = price;
+        plan.duration = duration;
+        plan.papers = papers;
+        plan.active = active;
+
+        emit!(PlanUpdated {
+            plan: plan.key(),
+            author: plan.author,
+            plan_id: plan.plan_id,
+            price,
+            payment_mint: plan.payment_mint,
+            duration,
+            papers: plan.papers.clone(),
+            active,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+}
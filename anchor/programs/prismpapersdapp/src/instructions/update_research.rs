@@ -2,11 +2,12 @@ use anchor_lang::prelude::*;
 
 use crate::{
     constants::{
-        ENCRYPTION_KEY_MAX_LENGTH, PAPER_DESCRIPTION_MAX_LENGTH, PAPER_SEED,
-        PAPER_TITLE_MAX_LENGTH, PAPER_URL_MAX_LENGTH,
+        ANCHOR_DISCRIMINATOR, PAPER_DESCRIPTION_MAX_LENGTH, PAPER_SEED, PAPER_TITLE_MAX_LENGTH,
+        PAPER_URL_MAX_LENGTH, REVISION_SEED,
     },
     errors::ErrorCodes,
-    states::ResearchPaper,
+    events::PaperUpdated,
+    states::{PaperRevision, PaperStatus, ResearchPaper, RevisionAccessPolicy},
 };
 
 #[derive(Accounts)]
@@ -16,48 +17,109 @@ pub struct UpdateResearch<'info> {
 
     #[account(
         mut,
-        seeds = [PAPER_SEED, research_paper.author.key().as_ref()],
+        seeds = [
+            PAPER_SEED,
+            research_paper.author.key().as_ref(),
+            research_paper.index.to_le_bytes().as_ref()
+        ],
         bump=research_paper.bump
     )]
     pub research_paper: Account<'info, ResearchPaper>,
+
+    //every update is kept as a new immutable revision
+    #[account(
+        init,
+        payer = author,
+        space = ANCHOR_DISCRIMINATOR + PaperRevision::INIT_SPACE,
+        seeds = [
+            REVISION_SEED,
+            research_paper.key().as_ref(),
+            (research_paper.revision + 1).to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub paper_revision: Account<'info, PaperRevision>,
+
+    pub system_program: Program<'info, System>,
 }
 
 impl<'a> UpdateResearch<'a> {
+    #[allow(clippy::too_many_arguments)]
     pub fn update_research(
         &mut self,
         title: String,
         description: String,
         price: u64,
         encrypted_url: String,
-        encryption_key: String,
+        content_hash: [u8; 32],
+        ciphertext_hash: Option<[u8; 32]>,
+        bumps: &UpdateResearchBumps,
     ) -> Result<()> {
         require!(
             self.author.key() == self.research_paper.author,
             ErrorCodes::UnauthorizedUpdate
         );
         require!(
-            title.len() > 0 && title.len() < PAPER_TITLE_MAX_LENGTH,
+            self.research_paper.status != PaperStatus::Retracted,
+            ErrorCodes::PaperIsRetracted
+        );
+        require!(
+            !title.is_empty() && title.len() < PAPER_TITLE_MAX_LENGTH,
             ErrorCodes::PaperTitleInvalid
         );
         require!(
-            description.len() > 0 && description.len() < PAPER_DESCRIPTION_MAX_LENGTH,
+            !description.is_empty() && description.len() < PAPER_DESCRIPTION_MAX_LENGTH,
             ErrorCodes::PaperDescriptionInvalid
         );
         require!(
-            encrypted_url.len() > 0 && encrypted_url.len() < PAPER_URL_MAX_LENGTH,
+            !encrypted_url.is_empty() && encrypted_url.len() < PAPER_URL_MAX_LENGTH,
             ErrorCodes::PaperUrlEmptyOrTooLong
         );
         require!(price > 0, ErrorCodes::ResearchPriceInvalid);
+        require!(content_hash != [0u8; 32], ErrorCodes::ContentHashInvalid);
         require!(
-            encryption_key.len() > 0 && encryption_key.len() < ENCRYPTION_KEY_MAX_LENGTH,
-            ErrorCodes::EncryptionKeyEmptyOrTooLong
+            ciphertext_hash != Some([0u8; 32]),
+            ErrorCodes::ContentHashInvalid
         );
 
+        let old_price = self.research_paper.price;
         self.research_paper.title = title;
         self.research_paper.description = description;
         self.research_paper.price = price;
-        self.research_paper.encrypted_url = encrypted_url;
-        self.research_paper.encryption_key = encryption_key;
+        self.research_paper.encrypted_url = encrypted_url.clone();
+        self.research_paper.content_hash = content_hash;
+        self.research_paper.ciphertext_hash = ciphertext_hash;
+        self.research_paper.revision = self
+            .research_paper
+            .revision
+            .checked_add(1u32)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        //every live buyer is now owed the new revision's key
+        if self.research_paper.revision_policy == RevisionAccessPolicy::AllRevisions {
+            self.research_paper.pending_deliveries = self.research_paper.live_receipts;
+        }
+
+        let timestamp = Clock::get()?.unix_timestamp;
+        self.paper_revision.set_inner(PaperRevision {
+            paper: self.research_paper.key(),
+            revision: self.research_paper.revision,
+            content_hash,
+            ciphertext_hash,
+            encrypted_url,
+            price,
+            timestamp,
+            bump: bumps.paper_revision,
+        });
+
+        emit!(PaperUpdated {
+            paper: self.research_paper.key(),
+            author: self.research_paper.author,
+            revision: self.research_paper.revision,
+            content_hash,
+            old_price,
+            new_price: price,
+            timestamp,
+        });
         Ok(())
     }
 }
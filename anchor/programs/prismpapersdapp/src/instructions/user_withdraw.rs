@@ -2,14 +2,32 @@ use anchor_lang::{
     prelude::*,
     system_program::{transfer, Transfer},
 };
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
 
-use crate::{constants::VAULT_SEED_USER, errors::ErrorCodes};
+use crate::{
+    constants::{USER_SEED, VAULT_SEED_USER},
+    errors::ErrorCodes,
+    events::UserWithdrawal,
+    payments::transfer_tokens,
+    states::User,
+};
 
 #[derive(Accounts)]
 pub struct UserWithdraw<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
 
+    //the earnings ledger that backs the vault balance
+    #[account(
+        mut,
+        seeds = [USER_SEED, user.key().as_ref()],
+        bump=user_account.bump
+    )]
+    pub user_account: Account<'info, User>,
+
     #[account(
         mut,
         seeds = [VAULT_SEED_USER, user.key().as_ref()],
@@ -17,11 +35,46 @@ pub struct UserWithdraw<'info> {
     )]
     pub user_vault: SystemAccount<'info>,
 
+    //token accounts, pass them to withdraw SPL token earnings instead of SOL
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = user_vault,
+        associated_token::token_program = token_program
+    )]
+    pub user_token_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = payment_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program
+    )]
+    pub user_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
     pub system_program: Program<'info, System>,
 }
 
 impl<'a> UserWithdraw<'a> {
     pub fn user_withdraw(&mut self, amount: u64, bumps: &UserWithdrawBumps) -> Result<()> {
+        let binding = self.user.key();
+        let signer_seeds: &[&[&[u8]]] =
+            &[&[VAULT_SEED_USER, binding.as_ref(), &[bumps.user_vault]]];
+
+        if self.payment_mint.is_some() {
+            self.withdraw_tokens(amount, signer_seeds)?;
+            return self.emit_withdrawal(amount);
+        }
+
+        require!(
+            self.user_account.earning >= amount,
+            ErrorCodes::InsufficientUserEarnings
+        );
         require!(
             self.user_vault.lamports() >= amount,
             ErrorCodes::InsufficientFundsInVault
@@ -34,12 +87,57 @@ impl<'a> UserWithdraw<'a> {
             from: user_vault,
             to: user,
         };
-        let binding = self.user.key();
-        let signer_seeds: &[&[&[u8]]] =
-            &[&[VAULT_SEED_USER, binding.as_ref(), &[bumps.user_vault]]];
         let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_account_options, signer_seeds);
         transfer(cpi_ctx, amount)?;
 
+        //updating the states
+        self.user_account.earning = self
+            .user_account
+            .earning
+            .checked_sub(amount)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        self.user_account.withdrawn = self
+            .user_account
+            .withdrawn
+            .checked_add(amount)
+            .ok_or(ErrorCodes::MathOverflow)?;
+
+        self.emit_withdrawal(amount)
+    }
+
+    fn emit_withdrawal(&self, amount: u64) -> Result<()> {
+        emit!(UserWithdrawal {
+            user: self.user.key(),
+            payment_mint: self.payment_mint.as_ref().map(|mint| mint.key()),
+            amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
         Ok(())
     }
+
+    //token vaults only ever receive earnings, so their balance is the per-mint earning ledger
+    fn withdraw_tokens(&mut self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let (Some(mint), Some(user_token_vault), Some(user_token_account), Some(token_program)) = (
+            &self.payment_mint,
+            &self.user_token_vault,
+            &self.user_token_account,
+            &self.token_program,
+        ) else {
+            return err!(ErrorCodes::TokenAccountsMissing);
+        };
+        require!(
+            user_token_vault.amount >= amount,
+            ErrorCodes::InsufficientUserEarnings
+        );
+
+        transfer_tokens(
+            user_token_vault,
+            user_token_account,
+            self.user_vault.to_account_info(),
+            mint,
+            token_program,
+            amount,
+            signer_seeds,
+        )
+    }
 }
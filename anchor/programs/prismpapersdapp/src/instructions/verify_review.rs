@@ -1,15 +1,18 @@
-use anchor_lang::{
-    prelude::*,
-    system_program::{transfer, Transfer},
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
 use crate::{
     constants::{
-        GLOBAL_FEE_PERCENTAGE, PAPER_SEED, REVIEW_SEED, USER_SEED, VAULT_SEED_ADMIN,
-        VAULT_SEED_USER,
+        CONFIG_SEED, PAPER_SEED, REVIEW_SEED, USER_SEED, VAULT_SEED_ADMIN, VAULT_SEED_BOUNTY,
+        VAULT_SEED_STAKE, VAULT_SEED_USER,
     },
     errors::ErrorCodes,
-    states::{PeerReview, ResearchPaper, ReviewStatus, User},
+    events::ReviewVerdict,
+    rewards::{pay_reward_in_sol, pay_reward_in_tokens, record_scores, return_stake},
+    states::{Config, PeerReview, ResearchPaper, ReviewStatus, User},
 };
 
 #[derive(Accounts)]
@@ -24,30 +27,50 @@ pub struct VerifyReview<'info> {
     )]
     pub peer_review: Account<'info, PeerReview>,
 
+    //receives the stake back when the review is accepted
     #[account(
         mut,
-        seeds = [PAPER_SEED, peer_review.reviewed_paper.key().as_ref()],
-        bump=research_paper.bump
+        address = peer_review.reviewer
+    )]
+    pub reviewer: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED_STAKE, peer_review.key().as_ref()],
+        bump
+    )]
+    pub stake_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PAPER_SEED,
+            research_paper.author.key().as_ref(),
+            research_paper.index.to_le_bytes().as_ref()
+        ],
+        bump=research_paper.bump,
+        constraint = peer_review.reviewed_paper == research_paper.key() @ ErrorCodes::ReviewPaperMismatch
     )]
     pub research_paper: Account<'info, ResearchPaper>,
 
+    //required for named reviews and rejected for anonymous ones, which are only credited by `reveal_review`
     #[account(
         mut,
-        seeds = [USER_SEED, reviewer_user_account.owner.key().as_ref()],
+        seeds = [USER_SEED, peer_review.reviewer.key().as_ref()],
         bump=reviewer_user_account.bump
     )]
-    pub reviewer_user_account: Account<'info, User>,
+    pub reviewer_user_account: Option<Account<'info, User>>,
 
     #[account(
         mut,
-        seeds = [USER_SEED, author_user_account.owner.key().as_ref()],
+        seeds = [USER_SEED, author.key().as_ref()],
         bump=author_user_account.bump
     )]
     pub author_user_account: Account<'info, User>,
 
     #[account(
         mut,
-        seeds = [VAULT_SEED_USER, reviewer_user_account.owner.key().as_ref()],
+        seeds = [VAULT_SEED_USER, peer_review.reviewer.key().as_ref()],
         bump
     )]
     pub reviewer_vault: SystemAccount<'info>,
@@ -59,6 +82,13 @@ pub struct VerifyReview<'info> {
     )]
     pub author_vault: SystemAccount<'info>,
 
+    #[account(
+        mut,
+        seeds = [VAULT_SEED_BOUNTY, research_paper.key().as_ref()],
+        bump
+    )]
+    pub bounty_vault: SystemAccount<'info>,
+
     #[account(
         mut,
         seeds = [VAULT_SEED_ADMIN],
@@ -66,11 +96,53 @@ pub struct VerifyReview<'info> {
     )]
     pub admin_vault: SystemAccount<'info>,
 
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    //token accounts, only required when the paper is priced in an SPL token
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = bounty_vault,
+        associated_token::token_program = token_program
+    )]
+    pub bounty_token_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = author,
+        associated_token::mint = payment_mint,
+        associated_token::authority = reviewer_vault,
+        associated_token::token_program = token_program
+    )]
+    pub reviewer_token_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = author,
+        associated_token::mint = payment_mint,
+        associated_token::authority = admin_vault,
+        associated_token::token_program = token_program
+    )]
+    pub admin_token_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
     pub system_program: Program<'info, System>,
 }
 
 impl<'a> VerifyReview<'a> {
-    pub fn verify_review(&mut self, accept_proposed_review: bool) -> Result<()> {
+    pub fn verify_review(
+        &mut self,
+        accept_proposed_review: bool,
+        bumps: &VerifyReviewBumps,
+    ) -> Result<()> {
+        require!(!self.config.paused, ErrorCodes::ProgramPaused);
         require!(
             self.peer_review.status == ReviewStatus::Pending,
             ErrorCodes::ReviewNotPending
@@ -79,52 +151,114 @@ impl<'a> VerifyReview<'a> {
             self.author.key() == self.research_paper.author,
             ErrorCodes::UnauthorizedUpdate
         );
+        self.peer_review
+            .check_reviewer_account(self.reviewer_user_account.is_some())?;
+        //past the window the review is left to `expire_review` and the policy it was submitted under
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            now < self.peer_review.response_deadline(&self.config, now)?,
+            ErrorCodes::ReviewResponseWindowPassed
+        );
 
+        let (mut total_amount, mut platform_fee, mut reviewer_earning) = (0u64, 0u64, 0u64);
+        let mut stake_returned = 0u64;
         if accept_proposed_review {
-            let total_amount = self.peer_review.proposed_reward;
-            require!(
-                self.author.lamports() >= total_amount,
-                ErrorCodes::InsufficientFundsInWallet
-            );
-            let platform_fee = total_amount
-                .checked_mul(GLOBAL_FEE_PERCENTAGE)
-                .ok_or(ErrorCodes::MathOverflow)?
-                .checked_div(100)
-                .ok_or(ErrorCodes::MathOverflow)?;
-            let reviewer_earning = total_amount
-                .checked_sub(platform_fee)
-                .ok_or(ErrorCodes::MathOverflow)?;
-            //transferring the reward to the reviewer vault
-            let cpi_program = self.system_program.to_account_info();
-            let author = self.author.to_account_info();
-            let reviewer_vault = self.reviewer_vault.to_account_info();
-            let cpi_account_options = Transfer {
-                from: author,
-                to: reviewer_vault,
-            };
-            let cpi_ctx = CpiContext::new(cpi_program, cpi_account_options);
-            transfer(cpi_ctx, reviewer_earning)?;
-
-            //transferring the platform fee to the admin vault
-            let cpi_program = self.system_program.to_account_info();
-            let author = self.author.to_account_info();
-            let admin_vault = self.admin_vault.to_account_info();
-            let cpi_account_options = Transfer {
-                from: author,
-                to: admin_vault,
+            //the reward is drawn from the paper's bounty escrow, where it was reserved at submission
+            total_amount = self.peer_review.proposed_reward;
+            (platform_fee, reviewer_earning) = match self.research_paper.payment_mint {
+                None => pay_reward_in_sol(
+                    &mut self.peer_review,
+                    self.reviewer_user_account.as_deref_mut(),
+                    self.research_paper.key(),
+                    &self.bounty_vault.to_account_info(),
+                    &self.reviewer_vault.to_account_info(),
+                    &self.stake_vault.to_account_info(),
+                    &self.admin_vault.to_account_info(),
+                    &self.system_program.to_account_info(),
+                    &self.config,
+                    total_amount,
+                    bumps.bounty_vault,
+                )?,
+                Some(payment_mint) => pay_reward_in_tokens(
+                    payment_mint,
+                    &self.payment_mint,
+                    &self.bounty_token_vault,
+                    &self.reviewer_token_vault,
+                    &self.admin_token_vault,
+                    &self.token_program,
+                    self.research_paper.key(),
+                    self.bounty_vault.to_account_info(),
+                    &self.config,
+                    total_amount,
+                    bumps.bounty_vault,
+                )?,
             };
-            let cpi_ctx = CpiContext::new(cpi_program, cpi_account_options);
-            transfer(cpi_ctx, platform_fee)?;
-
+            stake_returned = return_stake(
+                &mut self.peer_review,
+                &self.stake_vault.to_account_info(),
+                &self.reviewer.to_account_info(),
+                &self.system_program.to_account_info(),
+                bumps.stake_vault,
+            )?;
             self.peer_review.status = ReviewStatus::Accepted;
-            self.reviewer_user_account.earning = self
-                .reviewer_user_account
-                .earning
-                .checked_add(reviewer_earning)
-                .ok_or(ErrorCodes::MathOverflow)?;
+            if let Some(user) = &mut self.reviewer_user_account {
+                user.reviews_accepted = user
+                    .reviews_accepted
+                    .checked_add(1u16)
+                    .ok_or(ErrorCodes::MathOverflow)?;
+            }
+
+            //only accepted reviews count towards the paper's score averages
+            record_scores(&mut self.research_paper, &self.peer_review)?;
         } else {
+            //a rejected review keeps its stake locked through the cooldown, so it can still be flagged
             self.peer_review.status = ReviewStatus::Rejected;
+            if let Some(user) = &mut self.reviewer_user_account {
+                user.reviews_rejected = user
+                    .reviews_rejected
+                    .checked_add(1u16)
+                    .ok_or(ErrorCodes::MathOverflow)?;
+                user.rejected_open = user
+                    .rejected_open
+                    .checked_add(1u16)
+                    .ok_or(ErrorCodes::MathOverflow)?;
+            }
+        }
+        self.research_paper
+            .decide_review(self.peer_review.proposed_reward, total_amount)?;
+        self.peer_review.decided_at = now;
+        self.author_user_account.reviews_answered = self
+            .author_user_account
+            .reviews_answered
+            .checked_add(1u16)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        self.author_user_account.refresh_author_reputation();
+        if let Some(user) = &mut self.reviewer_user_account {
+            user.refresh_reputation();
+            user.pending_reviews = user
+                .pending_reviews
+                .checked_sub(1u16)
+                .ok_or(ErrorCodes::MathOverflow)?;
         }
+
+        emit!(ReviewVerdict {
+            review: self.peer_review.key(),
+            paper: self.research_paper.key(),
+            reviewer: self.peer_review.reviewer,
+            author: self.author.key(),
+            status: self.peer_review.status.clone(),
+            payment_mint: self.research_paper.payment_mint,
+            reward: total_amount,
+            platform_fee,
+            reviewer_earning,
+            stake_returned,
+            //0 for anonymous reviews, their reputation moves when they are revealed
+            reviewer_reputation: self
+                .reviewer_user_account
+                .as_ref()
+                .map_or(0, |user| user.reputation),
+            timestamp: self.peer_review.decided_at,
+        });
         Ok(())
     }
 }
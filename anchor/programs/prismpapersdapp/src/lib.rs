@@ -1,29 +1,88 @@
 #![allow(unexpected_cfgs)]
 use anchor_lang::prelude::*;
 
+mod access;
 mod constants;
 mod errors;
+mod events;
+mod fees;
 mod instructions;
+mod payments;
+mod reputation;
+mod rewards;
+mod splits;
 use instructions::*;
 mod states;
+use states::{CoAuthor, ReviewExpiryPolicy, ReviewScores, RevisionAccessPolicy};
 declare_id!("2nvhRn83KBxkkAfLH64meTq8cYB5aRLnZVbsxZdgfPTv");
 
 #[program]
 pub mod prismpapersdapp {
     use super::*;
 
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize_config(
+        ctx: Context<InitializeConfig>,
+        admins: Vec<Pubkey>,
+        purchase_fee_bps: u16,
+        review_fee_bps: u16,
+        min_fee_lamports: u64,
+        treasury: Pubkey,
+        refund_window: i64,
+        review_stake: u64,
+    ) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.initialize_config(
+            admins,
+            purchase_fee_bps,
+            review_fee_bps,
+            min_fee_lamports,
+            treasury,
+            refund_window,
+            review_stake,
+            &bumps,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        admins: Vec<Pubkey>,
+        purchase_fee_bps: u16,
+        review_fee_bps: u16,
+        min_fee_lamports: u64,
+        treasury: Pubkey,
+        refund_window: i64,
+        review_stake: u64,
+        paused: bool,
+    ) -> Result<()> {
+        ctx.accounts.update_config(
+            admins,
+            purchase_fee_bps,
+            review_fee_bps,
+            min_fee_lamports,
+            treasury,
+            refund_window,
+            review_stake,
+            paused,
+        )
+    }
+
     pub fn init_user(ctx: Context<InitUser>, name: String) -> Result<()> {
         let bumps = ctx.bumps;
         ctx.accounts.initiate_user(name, &bumps)
     }
 
-    pub fn init_research(
-        ctx: Context<InitResearch>,
+    #[allow(clippy::too_many_arguments)]
+    pub fn init_research<'info>(
+        ctx: Context<'_, '_, 'info, 'info, InitResearch<'info>>,
         title: String,
         description: String,
         price: u64,
         encrypted_url: String,
-        encryption_key: String,
+        content_hash: [u8; 32],
+        ciphertext_hash: Option<[u8; 32]>,
+        co_authors: Vec<CoAuthor>,
     ) -> Result<()> {
         let bumps = ctx.bumps;
         ctx.accounts.initiate_research(
@@ -31,7 +90,10 @@ pub mod prismpapersdapp {
             description,
             price,
             encrypted_url,
-            encryption_key,
+            content_hash,
+            ciphertext_hash,
+            co_authors,
+            ctx.remaining_accounts,
             &bumps,
         )
     }
@@ -41,28 +103,337 @@ pub mod prismpapersdapp {
         description: String,
         price: u64,
         encrypted_url: String,
-        encryption_key: String,
+        content_hash: [u8; 32],
+        ciphertext_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.update_research(
+            title,
+            description,
+            price,
+            encrypted_url,
+            content_hash,
+            ciphertext_hash,
+            &bumps,
+        )
+    }
+
+    pub fn set_revision_policy(
+        ctx: Context<SetRevisionPolicy>,
+        policy: RevisionAccessPolicy,
+    ) -> Result<()> {
+        ctx.accounts.set_revision_policy(policy)
+    }
+
+    pub fn set_review_stake(ctx: Context<SetReviewStake>, stake: Option<u64>) -> Result<()> {
+        ctx.accounts.set_review_stake(stake)
+    }
+
+    pub fn set_min_reputation(ctx: Context<SetMinReputation>, min_reputation: u16) -> Result<()> {
+        ctx.accounts.set_min_reputation(min_reputation)
+    }
+
+    pub fn set_review_response_policy(
+        ctx: Context<SetReviewResponsePolicy>,
+        response_window: i64,
+        expiry_policy: ReviewExpiryPolicy,
+    ) -> Result<()> {
+        ctx.accounts
+            .set_review_response_policy(response_window, expiry_policy)
+    }
+
+    pub fn publish_paper(ctx: Context<PublishPaper>) -> Result<()> {
+        ctx.accounts.publish_paper()
+    }
+
+    pub fn open_review_round(
+        ctx: Context<OpenReviewRound>,
+        target_reviewers: u16,
+        quorum: u16,
+        approval_bps: u16,
+        deadline: i64,
+    ) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts
+            .open_review_round(target_reviewers, quorum, approval_bps, deadline, &bumps)
+    }
+
+    pub fn close_review_round(ctx: Context<CloseReviewRound>) -> Result<()> {
+        ctx.accounts.close_review_round()
+    }
+
+    pub fn finalize_review_round(ctx: Context<FinalizeReviewRound>) -> Result<()> {
+        ctx.accounts.finalize_review_round()
+    }
+
+    pub fn retract_paper(ctx: Context<RetractPaper>, reason_code: u8) -> Result<()> {
+        ctx.accounts.retract_paper(reason_code)
+    }
+
+    pub fn close_paper<'info>(ctx: Context<'_, '_, 'info, 'info, ClosePaper<'info>>) -> Result<()> {
+        ctx.accounts.close_paper(ctx.remaining_accounts)
+    }
+
+    pub fn set_co_authors<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SetCoAuthors<'info>>,
+        co_authors: Vec<CoAuthor>,
+    ) -> Result<()> {
+        ctx.accounts
+            .set_co_authors(co_authors, ctx.remaining_accounts)
+    }
+
+    pub fn purchase_access(
+        ctx: Context<PurchaseAccess>,
+        buyer_encryption_key: [u8; 32],
+    ) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.purchase_access(buyer_encryption_key, &bumps)
+    }
+
+    pub fn init_institution(ctx: Context<InitInstitution>, name: String, seats: u32) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.init_institution(name, seats, &bumps)
+    }
+
+    pub fn set_institution_seats(ctx: Context<SetInstitutionSeats>, seats: u32) -> Result<()> {
+        ctx.accounts.set_institution_seats(seats)
+    }
+
+    pub fn add_institution_member(
+        ctx: Context<AddInstitutionMember>,
+        member: Pubkey,
+    ) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.add_institution_member(member, &bumps)
+    }
+
+    pub fn remove_institution_member(ctx: Context<RemoveInstitutionMember>) -> Result<()> {
+        ctx.accounts.remove_institution_member()
+    }
+
+    pub fn purchase_license(
+        ctx: Context<PurchaseLicense>,
+        buyer_encryption_key: [u8; 32],
+    ) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.purchase_license(buyer_encryption_key, &bumps)
+    }
+
+    pub fn create_plan(
+        ctx: Context<CreatePlan>,
+        plan_id: u16,
+        price: u64,
+        duration: i64,
+        papers: Vec<Pubkey>,
+        co_authors: Vec<CoAuthor>,
+    ) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts
+            .create_plan(plan_id, price, duration, papers, co_authors, &bumps)
+    }
+
+    pub fn update_plan(
+        ctx: Context<UpdatePlan>,
+        price: u64,
+        duration: i64,
+        papers: Vec<Pubkey>,
+        active: bool,
+    ) -> Result<()> {
+        ctx.accounts.update_plan(price, duration, papers, active)
+    }
+
+    pub fn subscribe<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Subscribe<'info>>,
+        subscriber_encryption_key: [u8; 32],
+    ) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts
+            .subscribe(subscriber_encryption_key, ctx.remaining_accounts, &bumps)
+    }
+
+    pub fn close_subscription(ctx: Context<CloseSubscription>) -> Result<()> {
+        ctx.accounts.close_subscription()
+    }
+
+    pub fn create_bundle(
+        ctx: Context<CreateBundle>,
+        bundle_id: u16,
+        papers: Vec<Pubkey>,
+        discount_bps: u16,
     ) -> Result<()> {
+        let bumps = ctx.bumps;
         ctx.accounts
-            .update_research(title, description, price, encrypted_url, encryption_key)
+            .create_bundle(bundle_id, papers, discount_bps, &bumps)
+    }
+
+    pub fn update_bundle(
+        ctx: Context<UpdateBundle>,
+        papers: Vec<Pubkey>,
+        discount_bps: u16,
+        active: bool,
+    ) -> Result<()> {
+        ctx.accounts.update_bundle(papers, discount_bps, active)
+    }
+
+    pub fn purchase_bundle<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PurchaseBundle<'info>>,
+        buyer_encryption_key: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts
+            .purchase_bundle(buyer_encryption_key, ctx.remaining_accounts)
+    }
+
+    pub fn settle_purchase<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SettlePurchase<'info>>,
+    ) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.settle_purchase(ctx.remaining_accounts, &bumps)
+    }
+
+    pub fn request_refund(ctx: Context<RequestRefund>) -> Result<()> {
+        ctx.accounts.request_refund()
+    }
+
+    pub fn approve_refund(ctx: Context<ResolveRefund>) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.approve_refund(&bumps)
     }
 
-    pub fn purchase_access(ctx: Context<PurchaseAccess>) -> Result<()> {
+    pub fn arbitrate_refund(ctx: Context<ResolveRefund>, refund: bool) -> Result<()> {
         let bumps = ctx.bumps;
-        ctx.accounts.purchase_access(&bumps)
+        ctx.accounts.arbitrate_refund(refund, &bumps)
+    }
+
+    pub fn lapse_refund(ctx: Context<ResolveRefund>) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.lapse_refund(&bumps)
+    }
+
+    pub fn deliver_key(
+        ctx: Context<DeliverKey>,
+        revision: u32,
+        wrapped_key: Vec<u8>,
+    ) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.deliver_key(revision, wrapped_key, &bumps)
+    }
+
+    pub fn deliver_subscription_key(
+        ctx: Context<DeliverSubscriptionKey>,
+        revision: u32,
+        wrapped_key: Vec<u8>,
+    ) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts
+            .deliver_subscription_key(revision, wrapped_key, &bumps)
+    }
+
+    pub fn deliver_keys<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DeliverKeys<'info>>,
+        wrapped_keys: Vec<Vec<u8>>,
+    ) -> Result<()> {
+        ctx.accounts
+            .deliver_keys(wrapped_keys, ctx.remaining_accounts)
     }
     pub fn review_paper(
         ctx: Context<ReviewPaper>,
         review_url: String,
+        review_hash: [u8; 32],
+        scores: ReviewScores,
         proposed_reward: u64,
     ) -> Result<()> {
         let bumps = ctx.bumps;
         ctx.accounts
-            .review_paper(review_url, proposed_reward, &bumps)
+            .review_paper(review_url, review_hash, scores, proposed_reward, &bumps)
+    }
+
+    pub fn review_paper_anonymously(
+        ctx: Context<ReviewPaperAnonymously>,
+        review_url: String,
+        review_hash: [u8; 32],
+        scores: ReviewScores,
+        proposed_reward: u64,
+        identity_commitment: [u8; 32],
+    ) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.review_paper_anonymously(
+            review_url,
+            review_hash,
+            scores,
+            proposed_reward,
+            identity_commitment,
+            &bumps,
+        )
+    }
+
+    pub fn close_receipt(ctx: Context<CloseReceipt>) -> Result<()> {
+        ctx.accounts.close_receipt()
+    }
+
+    pub fn close_license_receipt(ctx: Context<CloseLicenseReceipt>) -> Result<()> {
+        ctx.accounts.close_license_receipt()
+    }
+
+    pub fn close_revision(ctx: Context<CloseRevision>, paper_index: u16) -> Result<()> {
+        ctx.accounts.close_revision(paper_index)
+    }
+
+    pub fn close_key_delivery(ctx: Context<CloseKeyDelivery>, paper_index: u16) -> Result<()> {
+        ctx.accounts.close_key_delivery(paper_index)
+    }
+
+    pub fn fund_bounty(ctx: Context<FundBounty>, amount: u64, deadline: i64) -> Result<()> {
+        ctx.accounts.fund_bounty(amount, deadline)
+    }
+
+    pub fn reclaim_bounty(ctx: Context<ReclaimBounty>) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.reclaim_bounty(&bumps)
     }
 
     pub fn verify_review(ctx: Context<VerifyReview>, accept_proposed_review: bool) -> Result<()> {
-        ctx.accounts.verify_review(accept_proposed_review)
+        let bumps = ctx.bumps;
+        ctx.accounts.verify_review(accept_proposed_review, &bumps)
+    }
+
+    pub fn counter_offer_review(
+        ctx: Context<CounterOfferReview>,
+        counter_offer: u64,
+    ) -> Result<()> {
+        ctx.accounts.counter_offer_review(counter_offer)
+    }
+
+    pub fn respond_to_counter_offer(
+        ctx: Context<RespondToCounterOffer>,
+        accept_counter_offer: bool,
+    ) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts
+            .respond_to_counter_offer(accept_counter_offer, &bumps)
+    }
+
+    pub fn expire_review(ctx: Context<ExpireReview>) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.expire_review(&bumps)
+    }
+
+    pub fn flag_review<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FlagReview<'info>>,
+        slash_to_author: bool,
+    ) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts
+            .flag_review(slash_to_author, ctx.remaining_accounts, &bumps)
+    }
+
+    pub fn reveal_review(ctx: Context<RevealReview>, salt: [u8; 32]) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.reveal_review(salt, &bumps)
+    }
+
+    pub fn close_review(ctx: Context<CloseReview>) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.close_review(&bumps)
     }
 
     pub fn user_withdraw(ctx: Context<UserWithdraw>, amount: u64) -> Result<()> {
@@ -74,4 +445,7 @@ pub mod prismpapersdapp {
         let bumps = ctx.bumps;
         ctx.accounts.admin_withdraw(amount, &bumps)
     }
+    pub fn close_user(ctx: Context<CloseUser>) -> Result<()> {
+        ctx.accounts.close_user(&ctx.bumps)
+    }
 }
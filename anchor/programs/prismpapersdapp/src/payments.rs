@@ -0,0 +1,64 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{allocate, assign, transfer, Allocate, Assign, Transfer},
+};
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+//Moves `amount` of `mint` between two token accounts.
+//Pass the vault PDA seeds as `signer_seeds` when a program vault is the authority, or `&[]` for a wallet signer.
+pub fn transfer_tokens<'info>(
+    from: &InterfaceAccount<'info, TokenAccount>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    authority: AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let cpi_program = token_program.to_account_info();
+    let cpi_account_options = TransferChecked {
+        from: from.to_account_info(),
+        mint: mint.to_account_info(),
+        to: to.to_account_info(),
+        authority,
+    };
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_account_options, signer_seeds);
+    transfer_checked(cpi_ctx, amount, mint.decimals)
+}
+
+//Creates a program-owned PDA of `space` bytes the way Anchor's `init` does, so lamports sent to
+//the address ahead of time can't block it: top it up to rent exemption, then allocate and assign it.
+pub fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let lamports = Rent::get()?.minimum_balance(space);
+    let top_up = lamports.saturating_sub(account.lamports());
+    if top_up > 0 {
+        let cpi_account_options = Transfer {
+            from: payer.clone(),
+            to: account.clone(),
+        };
+        let cpi_ctx = CpiContext::new(system_program.clone(), cpi_account_options);
+        transfer(cpi_ctx, top_up)?;
+    }
+
+    let cpi_account_options = Allocate {
+        account_to_allocate: account.clone(),
+    };
+    let cpi_ctx =
+        CpiContext::new_with_signer(system_program.clone(), cpi_account_options, signer_seeds);
+    allocate(cpi_ctx, space as u64)?;
+
+    let cpi_account_options = Assign {
+        account_to_assign: account.clone(),
+    };
+    let cpi_ctx =
+        CpiContext::new_with_signer(system_program.clone(), cpi_account_options, signer_seeds);
+    assign(cpi_ctx, &crate::ID)
+}
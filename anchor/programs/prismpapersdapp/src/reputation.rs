@@ -0,0 +1,42 @@
+use crate::constants::{BPS_DENOMINATOR, FLAGGED_REVIEW_WEIGHT};
+
+//Reviewer reputation in basis points: the share of a reviewer's decided reviews that were accepted.
+//One accepted and one rejected review are assumed up front, so a newcomer starts at 5000 and a
+//single verdict cannot swing the score to 0 or 10000. A flagged review counts as
+//FLAGGED_REVIEW_WEIGHT rejections on top of the rejection it usually follows.
+pub fn reputation_score(accepted: u16, rejected: u16, flagged: u16) -> u16 {
+    let accepted = u64::from(accepted) + 1;
+    let decided = accepted + u64::from(rejected) + 1 + u64::from(flagged) * FLAGGED_REVIEW_WEIGHT;
+    //accepted <= decided, so the score never exceeds BPS_DENOMINATOR
+    (accepted * BPS_DENOMINATOR / decided) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn newcomers_start_in_the_middle() {
+        assert_eq!(reputation_score(0, 0, 0), 5_000);
+    }
+
+    #[test]
+    fn follows_the_acceptance_rate() {
+        assert_eq!(reputation_score(1, 0, 0), 6_666);
+        assert_eq!(reputation_score(0, 1, 0), 3_333);
+        assert_eq!(reputation_score(8, 0, 0), 9_000);
+        assert_eq!(reputation_score(4, 4, 0), 5_000);
+    }
+
+    #[test]
+    fn flags_weigh_more_than_rejections() {
+        assert!(reputation_score(4, 1, 1) < reputation_score(4, 2, 0));
+        assert_eq!(reputation_score(0, 1, 1), 1_666);
+    }
+
+    #[test]
+    fn stays_within_basis_points() {
+        assert_eq!(reputation_score(u16::MAX, 0, 0), 9_999);
+        assert_eq!(reputation_score(0, u16::MAX, u16::MAX), 0);
+    }
+}
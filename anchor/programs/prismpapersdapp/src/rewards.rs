@@ -0,0 +1,274 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    constants::{VAULT_SEED_BOUNTY, VAULT_SEED_STAKE},
+    errors::ErrorCodes,
+    events::PaperScoresUpdated,
+    fees::split_fee,
+    payments::transfer_tokens,
+    states::{Config, PeerReview, ResearchPaper, User},
+};
+
+//Pays an accepted review's SOL reward out of the bounty and credits it, returning (platform_fee, reviewer_earning).
+//A named reviewer is credited in their user vault and `earning` right away, an anonymous review's earning
+//waits in its stake vault as `unclaimed_reward` until `reveal_review`.
+#[allow(clippy::too_many_arguments)]
+fn pay_review_reward<'info>(
+    peer_review: &mut PeerReview,
+    reviewer_user_account: Option<&mut User>,
+    bounty_vault: &AccountInfo<'info>,
+    reviewer_vault: &AccountInfo<'info>,
+    stake_vault: &AccountInfo<'info>,
+    admin_vault: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    config: &Config,
+    total_amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<(u64, u64)> {
+    let earning_vault = if peer_review.is_anonymous() {
+        stake_vault
+    } else {
+        reviewer_vault
+    };
+    let (platform_fee, reviewer_earning) = pay_reward_sol(
+        bounty_vault,
+        earning_vault,
+        admin_vault,
+        system_program,
+        config,
+        total_amount,
+        signer_seeds,
+    )?;
+
+    match reviewer_user_account {
+        Some(user) => {
+            user.earning = user
+                .earning
+                .checked_add(reviewer_earning)
+                .ok_or(ErrorCodes::MathOverflow)?;
+            user.review_rewards = user
+                .review_rewards
+                .checked_add(reviewer_earning)
+                .ok_or(ErrorCodes::MathOverflow)?;
+        }
+        None => peer_review.unclaimed_reward = reviewer_earning,
+    }
+    Ok((platform_fee, reviewer_earning))
+}
+
+//Pays an accepted review of a lamport-priced paper out of the paper's bounty vault, see `pay_review_reward`.
+//Shared by `verify_review`, `respond_to_counter_offer` and an auto-accepting `expire_review`.
+#[allow(clippy::too_many_arguments)]
+pub fn pay_reward_in_sol<'info>(
+    peer_review: &mut PeerReview,
+    reviewer_user_account: Option<&mut User>,
+    research_paper: Pubkey,
+    bounty_vault: &AccountInfo<'info>,
+    reviewer_vault: &AccountInfo<'info>,
+    stake_vault: &AccountInfo<'info>,
+    admin_vault: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    config: &Config,
+    total_amount: u64,
+    bounty_vault_bump: u8,
+) -> Result<(u64, u64)> {
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        VAULT_SEED_BOUNTY,
+        research_paper.as_ref(),
+        &[bounty_vault_bump],
+    ]];
+    pay_review_reward(
+        peer_review,
+        reviewer_user_account,
+        bounty_vault,
+        reviewer_vault,
+        stake_vault,
+        admin_vault,
+        system_program,
+        config,
+        total_amount,
+        signer_seeds,
+    )
+}
+
+//Pays an accepted review of a token-priced paper out of the bounty's token vault.
+//The token accounts are the instruction's optional ones, all required here and checked against the paper's mint.
+#[allow(clippy::too_many_arguments)]
+pub fn pay_reward_in_tokens<'info>(
+    payment_mint: Pubkey,
+    mint: &Option<InterfaceAccount<'info, Mint>>,
+    bounty_token_vault: &Option<InterfaceAccount<'info, TokenAccount>>,
+    reviewer_token_vault: &Option<InterfaceAccount<'info, TokenAccount>>,
+    admin_token_vault: &Option<InterfaceAccount<'info, TokenAccount>>,
+    token_program: &Option<Interface<'info, TokenInterface>>,
+    research_paper: Pubkey,
+    bounty_vault: AccountInfo<'info>,
+    config: &Config,
+    total_amount: u64,
+    bounty_vault_bump: u8,
+) -> Result<(u64, u64)> {
+    let (
+        Some(mint),
+        Some(bounty_token_vault),
+        Some(reviewer_token_vault),
+        Some(admin_token_vault),
+        Some(token_program),
+    ) = (
+        mint,
+        bounty_token_vault,
+        reviewer_token_vault,
+        admin_token_vault,
+        token_program,
+    )
+    else {
+        return err!(ErrorCodes::TokenAccountsMissing);
+    };
+    require_keys_eq!(mint.key(), payment_mint, ErrorCodes::PaymentMintMismatch);
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        VAULT_SEED_BOUNTY,
+        research_paper.as_ref(),
+        &[bounty_vault_bump],
+    ]];
+    pay_reward_tokens(
+        bounty_token_vault,
+        reviewer_token_vault,
+        admin_token_vault,
+        bounty_vault,
+        mint,
+        token_program,
+        config,
+        total_amount,
+        signer_seeds,
+    )
+}
+
+//Pays `total_amount` of a paper's SOL bounty for an accepted review, returning (platform_fee, reviewer_earning).
+//`reviewer_vault` receives the earning, the fee goes to the admin vault. `signer_seeds` are the bounty vault's.
+fn pay_reward_sol<'info>(
+    bounty_vault: &AccountInfo<'info>,
+    reviewer_vault: &AccountInfo<'info>,
+    admin_vault: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    config: &Config,
+    total_amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<(u64, u64)> {
+    require!(
+        bounty_vault.lamports() >= total_amount,
+        ErrorCodes::InsufficientFundsInVault
+    );
+    let (platform_fee, reviewer_earning) =
+        split_fee(total_amount, config.review_fee_bps, config.min_fee_lamports)?;
+
+    //transferring the reward to the reviewer vault
+    let cpi_account_options = Transfer {
+        from: bounty_vault.clone(),
+        to: reviewer_vault.clone(),
+    };
+    let cpi_ctx =
+        CpiContext::new_with_signer(system_program.clone(), cpi_account_options, signer_seeds);
+    transfer(cpi_ctx, reviewer_earning)?;
+
+    //transferring the platform fee to the admin vault
+    let cpi_account_options = Transfer {
+        from: bounty_vault.clone(),
+        to: admin_vault.clone(),
+    };
+    let cpi_ctx =
+        CpiContext::new_with_signer(system_program.clone(), cpi_account_options, signer_seeds);
+    transfer(cpi_ctx, platform_fee)?;
+
+    Ok((platform_fee, reviewer_earning))
+}
+
+//Token counterpart of `pay_reward_sol`, the lamport minimum fee does not apply to token amounts
+#[allow(clippy::too_many_arguments)]
+fn pay_reward_tokens<'info>(
+    bounty_token_vault: &InterfaceAccount<'info, TokenAccount>,
+    reviewer_token_vault: &InterfaceAccount<'info, TokenAccount>,
+    admin_token_vault: &InterfaceAccount<'info, TokenAccount>,
+    bounty_vault: AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    config: &Config,
+    total_amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<(u64, u64)> {
+    require!(
+        bounty_token_vault.amount >= total_amount,
+        ErrorCodes::InsufficientFundsInVault
+    );
+    let (platform_fee, reviewer_earning) = split_fee(total_amount, config.review_fee_bps, 0)?;
+
+    transfer_tokens(
+        bounty_token_vault,
+        reviewer_token_vault,
+        bounty_vault.clone(),
+        mint,
+        token_program,
+        reviewer_earning,
+        signer_seeds,
+    )?;
+    transfer_tokens(
+        bounty_token_vault,
+        admin_token_vault,
+        bounty_vault,
+        mint,
+        token_program,
+        platform_fee,
+        signer_seeds,
+    )?;
+    Ok((platform_fee, reviewer_earning))
+}
+
+//Hands a review's stake back to the reviewer and returns the amount.
+//An escrowed anonymous reward keeps the stake next to it, so the vault stays rent exempt until the reveal.
+pub fn return_stake<'info>(
+    peer_review: &mut Account<'info, PeerReview>,
+    stake_vault: &AccountInfo<'info>,
+    reviewer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    stake_vault_bump: u8,
+) -> Result<u64> {
+    let stake = peer_review.stake;
+    if stake == 0 || peer_review.unclaimed_reward > 0 {
+        return Ok(0);
+    }
+    let review = peer_review.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[VAULT_SEED_STAKE, review.as_ref(), &[stake_vault_bump]]];
+
+    let cpi_account_options = Transfer {
+        from: stake_vault.clone(),
+        to: reviewer.clone(),
+    };
+    let cpi_ctx =
+        CpiContext::new_with_signer(system_program.clone(), cpi_account_options, signer_seeds);
+    transfer(cpi_ctx, stake)?;
+
+    peer_review.stake = 0;
+    Ok(stake)
+}
+
+//Adds an accepted review's scores to the paper's totals and publishes the new averages
+pub fn record_scores(
+    research_paper: &mut Account<ResearchPaper>,
+    peer_review: &PeerReview,
+) -> Result<()> {
+    research_paper.review_scores.add(&peer_review.scores)?;
+    let review_scores = &research_paper.review_scores;
+    emit!(PaperScoresUpdated {
+        paper: research_paper.key(),
+        scored_reviews: review_scores.scored_reviews,
+        average_novelty: review_scores.average(review_scores.novelty_total),
+        average_rigor: review_scores.average(review_scores.rigor_total),
+        average_clarity: review_scores.average(review_scores.clarity_total),
+        average_confidence: review_scores.average(review_scores.confidence_total),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
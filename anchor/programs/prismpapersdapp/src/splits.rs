@@ -0,0 +1,265 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    constants::{BPS_DENOMINATOR, MAX_CO_AUTHORS, VAULT_SEED_USER},
+    errors::ErrorCodes,
+    payments::transfer_tokens,
+    states::{CoAuthor, User},
+};
+
+//Co-author revenue splits.
+//A paper's `co_authors` lists every author, its own author included, with shares in basis points
+//totalling BPS_DENOMINATOR. An empty list leaves the whole earning to the paper's author.
+pub fn validate_co_authors(author: Pubkey, co_authors: &[CoAuthor]) -> Result<()> {
+    if co_authors.is_empty() {
+        return Ok(());
+    }
+    require!(
+        co_authors.len() <= MAX_CO_AUTHORS
+            && co_authors
+                .iter()
+                .any(|co_author| co_author.wallet == author),
+        ErrorCodes::CoAuthorsInvalid
+    );
+
+    let mut total_bps = 0u64;
+    for (position, co_author) in co_authors.iter().enumerate() {
+        require!(
+            co_author.share_bps > 0
+                && !co_authors[..position]
+                    .iter()
+                    .any(|earlier| earlier.wallet == co_author.wallet),
+            ErrorCodes::CoAuthorsInvalid
+        );
+        total_bps += u64::from(co_author.share_bps);
+    }
+    require!(total_bps == BPS_DENOMINATOR, ErrorCodes::CoAuthorsInvalid);
+    Ok(())
+}
+
+//Splits an author earning into (author share, shares of the other co-authors in list order).
+//Shares round down and the paper's author keeps the remainder.
+pub fn split_earning(
+    amount: u64,
+    author: Pubkey,
+    co_authors: &[CoAuthor],
+) -> Result<(u64, Vec<u64>)> {
+    let mut shares = Vec::with_capacity(co_authors.len());
+    let mut shared = 0u64;
+    for co_author in co_authors
+        .iter()
+        .filter(|co_author| co_author.wallet != author)
+    {
+        let share = u128::from(amount)
+            .checked_mul(u128::from(co_author.share_bps))
+            .ok_or(ErrorCodes::MathOverflow)?
+            .checked_div(u128::from(BPS_DENOMINATOR))
+            .ok_or(ErrorCodes::MathOverflow)?;
+        let share = u64::try_from(share).map_err(|_| ErrorCodes::MathOverflow)?;
+        shared = shared.checked_add(share).ok_or(ErrorCodes::MathOverflow)?;
+        shares.push(share);
+    }
+    let author_share = amount.checked_sub(shared).ok_or(ErrorCodes::MathOverflow)?;
+    Ok((author_share, shares))
+}
+
+//Loads a co-author's User account passed through `remaining_accounts`.
+//User accounts only ever live at their owner's PDA, so the owner field pins it down.
+pub fn load_co_author_user<'info>(
+    user_info: &'info AccountInfo<'info>,
+    wallet: Pubkey,
+) -> Result<Account<'info, User>> {
+    let user_account = Account::<User>::try_from(user_info)?;
+    require_keys_eq!(
+        user_account.owner,
+        wallet,
+        ErrorCodes::CoAuthorAccountsInvalid
+    );
+    Ok(user_account)
+}
+
+//Checks every co-author in `co_authors` signed: listing someone keeps their profile open, so
+//nobody can be added to (or left out of) a paper without consenting.
+//`signers` holds exactly one wallet per co-author, in the same order.
+pub fn require_co_author_signatures(
+    signers: &[AccountInfo],
+    co_authors: &[CoAuthor],
+) -> Result<()> {
+    require!(
+        signers.len() == co_authors.len(),
+        ErrorCodes::CoAuthorAccountsInvalid
+    );
+    for (signer, co_author) in signers.iter().zip(co_authors) {
+        require!(
+            signer.key() == co_author.wallet && signer.is_signer,
+            ErrorCodes::CoAuthorSignatureMissing
+        );
+    }
+    Ok(())
+}
+
+//Counts a paper in (or out of) every co-author's live_papers, so close_user waits for it too.
+//`user_infos` holds exactly one User account per co-author, in the same order.
+pub fn track_live_papers<'info>(
+    user_infos: &'info [AccountInfo<'info>],
+    co_authors: &[CoAuthor],
+    added: bool,
+) -> Result<()> {
+    require!(
+        user_infos.len() == co_authors.len(),
+        ErrorCodes::CoAuthorAccountsInvalid
+    );
+    for (user_info, co_author) in user_infos.iter().zip(co_authors) {
+        let mut user_account = load_co_author_user(user_info, co_author.wallet)?;
+        user_account.live_papers = if added {
+            user_account.live_papers.checked_add(1u16)
+        } else {
+            user_account.live_papers.checked_sub(1u16)
+        }
+        .ok_or(ErrorCodes::MathOverflow)?;
+        user_account.exit(&crate::ID)?;
+    }
+    Ok(())
+}
+
+//Pays the co-authors other than the author their SOL shares and credits their earning ledgers.
+//`accounts` holds one (User account, user vault) pair per co-author, in list order.
+pub fn pay_co_authors_sol<'info>(
+    from: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    accounts: &'info [AccountInfo<'info>],
+    co_authors: &[CoAuthor],
+    shares: &[u64],
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    require!(
+        accounts.len() == co_authors.len() * 2,
+        ErrorCodes::CoAuthorAccountsInvalid
+    );
+    for ((pair, co_author), share) in accounts.chunks(2).zip(co_authors).zip(shares) {
+        let mut user_account = load_co_author_user(&pair[0], co_author.wallet)?;
+        let (user_vault, _) =
+            Pubkey::find_program_address(&[VAULT_SEED_USER, co_author.wallet.as_ref()], &crate::ID);
+        require_keys_eq!(
+            pair[1].key(),
+            user_vault,
+            ErrorCodes::CoAuthorAccountsInvalid
+        );
+
+        let cpi_account_options = Transfer {
+            from: from.clone(),
+            to: pair[1].clone(),
+        };
+        let cpi_ctx =
+            CpiContext::new_with_signer(system_program.clone(), cpi_account_options, signer_seeds);
+        transfer(cpi_ctx, *share)?;
+
+        user_account.earning = user_account
+            .earning
+            .checked_add(*share)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        user_account.exit(&crate::ID)?;
+    }
+    Ok(())
+}
+
+//Pays the co-authors other than the author their token shares.
+//`accounts` holds one (User account, user vault token account) pair per co-author, in list order.
+#[allow(clippy::too_many_arguments)]
+pub fn pay_co_authors_tokens<'info>(
+    from: &InterfaceAccount<'info, TokenAccount>,
+    authority: &AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    accounts: &'info [AccountInfo<'info>],
+    co_authors: &[CoAuthor],
+    shares: &[u64],
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    require!(
+        accounts.len() == co_authors.len() * 2,
+        ErrorCodes::CoAuthorAccountsInvalid
+    );
+    for ((pair, co_author), share) in accounts.chunks(2).zip(co_authors).zip(shares) {
+        load_co_author_user(&pair[0], co_author.wallet)?;
+        let (user_vault, _) =
+            Pubkey::find_program_address(&[VAULT_SEED_USER, co_author.wallet.as_ref()], &crate::ID);
+        let user_token_vault = InterfaceAccount::<TokenAccount>::try_from(&pair[1])?;
+        require!(
+            user_token_vault.owner == user_vault && user_token_vault.mint == mint.key(),
+            ErrorCodes::CoAuthorAccountsInvalid
+        );
+
+        transfer_tokens(
+            from,
+            &user_token_vault,
+            authority.clone(),
+            mint,
+            token_program,
+            *share,
+            signer_seeds,
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn co_author(wallet: Pubkey, share_bps: u16) -> CoAuthor {
+        CoAuthor { wallet, share_bps }
+    }
+
+    #[test]
+    fn accepts_sole_author_and_full_splits() {
+        let (author, other) = (Pubkey::new_unique(), Pubkey::new_unique());
+        assert!(validate_co_authors(author, &[]).is_ok());
+        assert!(validate_co_authors(author, &[co_author(author, 10_000)]).is_ok());
+        assert!(
+            validate_co_authors(author, &[co_author(other, 4_000), co_author(author, 6_000)])
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_splits() {
+        let (author, other) = (Pubkey::new_unique(), Pubkey::new_unique());
+        //the author must be listed
+        assert!(validate_co_authors(author, &[co_author(other, 10_000)]).is_err());
+        //shares must total 100%
+        assert!(
+            validate_co_authors(author, &[co_author(author, 5_000), co_author(other, 4_000)])
+                .is_err()
+        );
+        //no zero shares or duplicates
+        assert!(
+            validate_co_authors(author, &[co_author(author, 10_000), co_author(other, 0)]).is_err()
+        );
+        assert!(validate_co_authors(
+            author,
+            &[co_author(author, 5_000), co_author(author, 5_000)]
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn splits_by_share_and_leaves_remainder_to_author() {
+        let author = Pubkey::new_unique();
+        let (first, second) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let co_authors = [
+            co_author(first, 3_333),
+            co_author(author, 3_334),
+            co_author(second, 3_333),
+        ];
+        assert_eq!(
+            split_earning(100, author, &co_authors).unwrap(),
+            (34, vec![33, 33])
+        );
+        assert_eq!(split_earning(100, author, &[]).unwrap(), (100, vec![]));
+    }
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::BUNDLE_MAX_PAPERS;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Bundle {
+    pub author: Pubkey,
+    pub bundle_id: u16, //author-chosen id, part of the bundle PDA seeds
+    #[max_len(BUNDLE_MAX_PAPERS)]
+    pub papers: Vec<Pubkey>, //sold together, in this order
+    pub discount_bps: u16, //taken off every paper's price when bought as this bundle
+    pub active: bool,
+    pub sales: u32,
+    pub timestamp: i64,
+    pub bump: u8,
+}
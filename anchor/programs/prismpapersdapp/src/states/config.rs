@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::MAX_ADMINS, errors::ErrorCodes};
+
+#[account]
+#[derive(InitSpace)]
+pub struct Config {
+    #[max_len(MAX_ADMINS)]
+    pub admins: Vec<Pubkey>,
+    pub purchase_fee_bps: u16, //platform cut of paper sales
+    pub review_fee_bps: u16,   //platform cut of review rewards
+    pub min_fee_lamports: u64, //floor applied to both fees, 0 disables it
+    pub treasury: Pubkey,
+    pub refund_window: i64, //seconds a purchase stays refundable before it can be settled
+    pub review_stake: u64,  //lamports a reviewer locks per review unless the paper overrides it
+    pub paused: bool,
+    pub paused_at: i64, //when the current pause began, 0 while the program runs
+    pub paused_seconds: i64, //time spent paused before the current pause, review windows stop for it
+    pub bump: u8,
+}
+
+impl Config {
+    pub fn is_admin(&self, key: &Pubkey) -> bool {
+        self.admins.contains(key)
+    }
+
+    //total time the program has spent paused up to `now`, the current pause included
+    pub fn paused_seconds_at(&self, now: i64) -> Result<i64> {
+        if !self.paused {
+            return Ok(self.paused_seconds);
+        }
+        now.checked_sub(self.paused_at)
+            .and_then(|pause| self.paused_seconds.checked_add(pause))
+            .ok_or(error!(ErrorCodes::MathOverflow))
+    }
+
+    //records a pause starting or ending at `now`
+    pub fn set_paused(&mut self, paused: bool, now: i64) -> Result<()> {
+        if paused != self.paused {
+            self.paused_seconds = self.paused_seconds_at(now)?;
+            self.paused_at = if paused { now } else { 0 };
+            self.paused = paused;
+        }
+        Ok(())
+    }
+}
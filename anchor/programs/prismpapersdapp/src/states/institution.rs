@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::INSTITUTION_NAME_MAX_LENGTH;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Institution {
+    pub admin: Pubkey, //librarian wallet that manages seats and buys site licenses
+    #[max_len(INSTITUTION_NAME_MAX_LENGTH)]
+    pub name: String,
+    pub seats: u32,    //upper bound on members, site licenses are priced per seat
+    pub members: u32,  //current InstitutionMember accounts
+    pub licenses: u32, //site licenses bought so far
+    pub timestamp: i64,
+    pub bump: u8,
+}
+
+//Proof that a wallet holds a seat at an institution
+#[account]
+#[derive(InitSpace)]
+pub struct InstitutionMember {
+    pub institution: Pubkey,
+    pub member: Pubkey,
+    pub timestamp: i64,
+    pub bump: u8,
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::ENCRYPTION_KEY_MAX_LENGTH;
+
+//The content key of one paper revision, wrapped by the author to the buyer's encryption key
+#[account]
+#[derive(InitSpace)]
+pub struct KeyDelivery {
+    pub access_receipt: Pubkey, //receipt the key is owed to, or the subscription for subscribers
+    pub buyer: Pubkey,
+    pub paper: Pubkey,
+    pub revision: u32, //paper revision the wrapped key decrypts
+    #[max_len(ENCRYPTION_KEY_MAX_LENGTH)]
+    pub wrapped_key: Vec<u8>,
+    pub timestamp: i64,
+    pub bump: u8,
+}
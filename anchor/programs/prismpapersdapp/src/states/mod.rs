@@ -1,8 +1,26 @@
+pub mod bundle;
+pub mod config;
+pub mod institution;
+pub mod key_delivery;
 pub mod paper;
+pub mod paper_counter;
 pub mod receipt;
 pub mod review;
+pub mod review_round;
+pub mod revision;
+pub mod round_voter;
+pub mod subscription;
 pub mod user;
+pub use bundle::*;
+pub use config::*;
+pub use institution::*;
+pub use key_delivery::*;
 pub use paper::*;
+pub use paper_counter::*;
 pub use receipt::*;
 pub use review::*;
+pub use review_round::*;
+pub use revision::*;
+pub use round_voter::*;
+pub use subscription::*;
 pub use user::*;
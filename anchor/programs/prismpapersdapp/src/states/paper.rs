@@ -1,25 +1,164 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::{
-    ENCRYPTION_KEY_MAX_LENGTH, PAPER_DESCRIPTION_MAX_LENGTH, PAPER_TITLE_MAX_LENGTH,
-    PAPER_URL_MAX_LENGTH,
+use crate::{
+    constants::{
+        MAX_CO_AUTHORS, PAPER_DESCRIPTION_MAX_LENGTH, PAPER_TITLE_MAX_LENGTH, PAPER_URL_MAX_LENGTH,
+    },
+    errors::ErrorCodes,
+    states::{AccessReceipt, ReceiptStatus, ReviewAggregate, ReviewExpiryPolicy, RoundOutcome},
 };
 
 #[account]
 #[derive(InitSpace)]
 pub struct ResearchPaper {
     pub author: Pubkey,
+    pub index: u16, //per-author sequence number, part of the paper PDA seeds
     #[max_len(PAPER_TITLE_MAX_LENGTH)]
     pub title: String,
     #[max_len(PAPER_DESCRIPTION_MAX_LENGTH)]
     pub description: String,
     pub price: u64,
+    pub payment_mint: Option<Pubkey>, //None means the price is in lamports
     pub sales: u32,
     pub reviews: u32,
+    pub open_reviews: u32, //reviews still pending or negotiating, they block closing the paper
+    pub bounty: u64,       //unspent review bounty held in the paper's bounty vault
+    pub bounty_reserved: u64, //part of the bounty set aside for the rewards of undecided reviews
+    pub bounty_deadline: i64, //after this the author can reclaim the unreserved bounty
     #[max_len(PAPER_URL_MAX_LENGTH)]
     pub encrypted_url: String,
-    #[max_len(ENCRYPTION_KEY_MAX_LENGTH)]
-    pub encryption_key: String,
+    pub content_hash: [u8; 32], //SHA-256 of the plaintext file of the current revision
+    pub ciphertext_hash: Option<[u8; 32]>, //optional hash of the encrypted file behind encrypted_url
+    pub pending_deliveries: u32,           //live buyers still waiting for the key they are owed
+    pub live_receipts: u32, //receipts neither revoked nor closed, owed each new key under AllRevisions
+    pub escrowed: u64,      //purchase payments held in the escrow vault until settled or refunded
+    pub status: PaperStatus,
+    pub retraction_reason: u8, //reason code given on retraction, 0 while not retracted
+    pub revision: u32,         //latest revision number, 0 is the content published at creation
+    pub revision_policy: RevisionAccessPolicy,
+    #[max_len(MAX_CO_AUTHORS)]
+    pub co_authors: Vec<CoAuthor>, //revenue shares of every author, empty when the author takes it all
+    pub review_stake: Option<u64>, //per-paper reviewer stake in lamports, None falls back to the config
+    pub min_reputation: u16, //reputation in basis points a reviewer needs, 0 lets anyone review
+    pub review_rounds: u16,  //rounds opened so far, the next round's id
+    pub review_outcome: Option<RoundOutcome>, //outcome of the latest finalized round, None before any
+    pub review_scores: ReviewAggregate,       //score totals of the accepted reviews
+    pub review_response_window: i64,          //seconds the author has to decide on each review
+    pub review_expiry_policy: ReviewExpiryPolicy, //applied to reviews left undecided past the window
     pub timestamp: i64,
     pub bump: u8,
 }
+
+//A enum to represent where a paper is in its editorial lifecycle
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum PaperStatus {
+    Draft,       //created but not yet visible to buyers
+    Published,   //purchasable
+    UnderReview, //purchasable and open for peer reviews
+    Retracted,   //withdrawn from sale, terminal
+}
+
+//One author's share of a paper's earnings
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub struct CoAuthor {
+    pub wallet: Pubkey,
+    pub share_bps: u16,
+}
+
+//A enum to represent which revisions a buyer can read after the paper is updated
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum RevisionAccessPolicy {
+    PurchasedOnly, //buyers keep access to the revision they paid for only
+    AllRevisions,  //buyers get every later revision for free
+}
+
+impl PaperStatus {
+    pub fn is_purchasable(&self) -> bool {
+        matches!(self, PaperStatus::Published | PaperStatus::UnderReview)
+    }
+}
+
+impl ResearchPaper {
+    //the "peer reviewed" badge: the latest finalized round passed
+    pub fn is_peer_reviewed(&self) -> bool {
+        self.review_outcome == Some(RoundOutcome::Passed)
+    }
+
+    //co-authors other than the paper's author, in list order
+    pub fn other_co_authors(&self) -> impl Iterator<Item = &CoAuthor> {
+        self.co_authors
+            .iter()
+            .filter(move |co_author| co_author.wallet != self.author)
+    }
+
+    //whether `wallet` is the paper's author or one of its co-authors
+    pub fn is_author(&self, wallet: Pubkey) -> bool {
+        wallet == self.author
+            || self
+                .co_authors
+                .iter()
+                .any(|co_author| co_author.wallet == wallet)
+    }
+
+    //bounty still free to back new reward claims or to be reclaimed
+    pub fn unreserved_bounty(&self) -> u64 {
+        self.bounty.saturating_sub(self.bounty_reserved)
+    }
+
+    //counts a submitted review as open and sets its proposed reward aside until it is decided
+    pub fn open_review(&mut self, proposed_reward: u64) -> Result<()> {
+        self.open_reviews = self
+            .open_reviews
+            .checked_add(1)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        self.bounty_reserved = self
+            .bounty_reserved
+            .checked_add(proposed_reward)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        Ok(())
+    }
+
+    //closes a decided review and frees its reservation, `paid` of it leaves the bounty as the reviewer's reward
+    pub fn decide_review(&mut self, proposed_reward: u64, paid: u64) -> Result<()> {
+        self.open_reviews = self
+            .open_reviews
+            .checked_sub(1)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        self.bounty_reserved = self
+            .bounty_reserved
+            .checked_sub(proposed_reward)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        self.bounty = self
+            .bounty
+            .checked_sub(paid)
+            .ok_or(ErrorCodes::MathOverflow)?;
+        Ok(())
+    }
+
+    //lamports a reviewer has to lock to review this paper
+    pub fn required_review_stake(&self, default_stake: u64) -> u64 {
+        self.review_stake.unwrap_or(default_stake)
+    }
+
+    //whether a receipt for `purchased_revision` grants access to the current revision
+    pub fn grants_access(&self, purchased_revision: u32) -> bool {
+        match self.revision_policy {
+            RevisionAccessPolicy::PurchasedOnly => purchased_revision == self.revision,
+            RevisionAccessPolicy::AllRevisions => purchased_revision <= self.revision,
+        }
+    }
+
+    //revision whose content key a buyer of `purchased_revision` is owed
+    pub fn owed_key_revision(&self, purchased_revision: u32) -> u32 {
+        match self.revision_policy {
+            RevisionAccessPolicy::PurchasedOnly => purchased_revision,
+            RevisionAccessPolicy::AllRevisions => self.revision,
+        }
+    }
+
+    //whether the receipt still counts in `pending_deliveries`
+    pub fn owes_key(&self, receipt: &AccessReceipt) -> bool {
+        receipt.status != ReceiptStatus::Revoked
+            && receipt.key_revision != Some(self.owed_key_revision(receipt.revision))
+    }
+}
@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+//Per-author paper index source. It is never closed, so a closed and re-created profile
+//keeps counting up and its new papers can't land on the PDAs of earlier ones.
+#[account]
+#[derive(InitSpace)]
+pub struct PaperCounter {
+    pub author: Pubkey,
+    pub next_index: u16, //index the author's next paper is created under
+    pub bump: u8,
+}
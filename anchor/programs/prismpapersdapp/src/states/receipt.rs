@@ -4,7 +4,25 @@ use anchor_lang::prelude::*;
 #[derive(InitSpace)]
 pub struct AccessReceipt {
     pub buyer: Pubkey,
+    pub payer: Pubkey, //wallet that paid, requests and receives refunds: the admin for a site license
     pub purchased_paper: Pubkey,
+    pub revision: u32, //paper revision that was current at purchase time
+    pub seats: u32,    //0 for a personal receipt, seats paid for when the buyer is an Institution
+    pub buyer_encryption_key: [u8; 32], //X25519 public key the author wraps the content key to
+    pub key_revision: Option<u32>, //latest revision whose wrapped key was delivered, None before the first
+    pub amount_paid: u64,          //full price, escrowed until the purchase is settled or refunded
+    pub platform_fee: u64,         //fee share of amount_paid, fixed at purchase time
+    pub refund_deadline: i64, //refunds can be requested until this time, a dispute lapses into a refund after it
+    pub status: ReceiptStatus,
     pub timestamp: i64,
     pub bump: u8,
 }
+
+//A enum to represent where a purchase is in its refund lifecycle
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ReceiptStatus {
+    Escrowed, //payment held in the paper's escrow vault
+    Disputed, //buyer asked for a refund, waiting for the author or an admin until the deadline
+    Settled,  //payment released to the author and the platform
+    Revoked,  //payment refunded to the buyer, access withdrawn
+}
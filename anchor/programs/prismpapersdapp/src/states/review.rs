@@ -1,23 +1,177 @@
+use crate::{
+    constants::{REVIEW_SCORE_MAX, REVIEW_SCORE_MIN, REVIEW_URL_MAX_LENGTH},
+    errors::ErrorCodes,
+    states::Config,
+};
 use anchor_lang::prelude::*;
-use crate::constants::REVIEW_URL_MAX_LENGTH;
 
 #[account]
 #[derive(InitSpace)]
 pub struct PeerReview {
     pub reviewer: Pubkey,
     pub reviewed_paper: Pubkey,
+    pub round: Pubkey, //review round the review was submitted to
     #[max_len(REVIEW_URL_MAX_LENGTH)]
     pub review_url: String,
+    pub review_hash: [u8; 32], //hash of the review document behind review_url
     pub status: ReviewStatus,
+    pub scores: ReviewScores, //the recommendation doubles as the reviewer's vote in the round
     pub proposed_reward: u64,
+    pub stake: u64, //lamports locked in the review's stake vault until returned or slashed
     pub timestamp: i64,
+    pub decided_at: i64, //when the author accepted or rejected the review, 0 while pending
+    //after this `expire_review` resolves the review: the author's deadline while Pending,
+    //reset by a counter-offer to the reviewer's deadline while Negotiating
+    pub respond_by: i64,
+    pub paused_offset: i64, //config pause time when respond_by was set, later pauses push it back
+    pub expiry_policy: ReviewExpiryPolicy, //the paper's policy when the review was submitted
+    //set for anonymous reviews, where `reviewer` is a pseudonym: hash(reviewer wallet, salt)
+    pub identity_commitment: Option<[u8; 32]>,
+    pub revealed_reviewer: Option<Pubkey>, //the wallet behind an anonymous review, once revealed
+    pub unclaimed_reward: u64, //lamports an accepted anonymous review holds in its stake vault until revealed
+    pub counter_offer: Option<u64>, //the author's lower reward offer, settled at if the reviewer agrees
+    pub countered_at: i64,          //when the author counter-offered, 0 if they never did
     pub bump: u8,
 }
 
+impl PeerReview {
+    pub fn is_anonymous(&self) -> bool {
+        self.identity_commitment.is_some()
+    }
+
+    //named reviews are credited to the reviewer's User account, anonymous ones only through
+    //`reveal_review`, so a pseudonym can't collect an escrowed reward through a User of its own
+    pub fn check_reviewer_account(&self, has_user_account: bool) -> Result<()> {
+        if self.is_anonymous() {
+            require!(!has_user_account, ErrorCodes::AnonymousReviewerAccount);
+        } else {
+            require!(has_user_account, ErrorCodes::ReviewerAccountMissing);
+        }
+        Ok(())
+    }
+
+    //respond_by pushed back by the time the program has been paused since it was set
+    pub fn response_deadline(&self, config: &Config, now: i64) -> Result<i64> {
+        config
+            .paused_seconds_at(now)?
+            .checked_sub(self.paused_offset)
+            .and_then(|paused| self.respond_by.checked_add(paused))
+            .ok_or(error!(ErrorCodes::MathOverflow))
+    }
+
+    //whether the review's vote is in its round's tally, anonymous ones only join it once revealed
+    pub fn counts_in_round(&self) -> bool {
+        !self.is_anonymous() || self.revealed_reviewer.is_some()
+    }
+
+    //accepted by the author, or by the auto-accept policy once the response window passed
+    pub fn is_accepted(&self) -> bool {
+        self.status == ReviewStatus::Accepted
+            || (self.status == ReviewStatus::Expired
+                && self.expiry_policy == ReviewExpiryPolicy::AutoAccept)
+    }
+}
+
 //A enum to represent the status of a submitted peer review
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
 pub enum ReviewStatus {
     Pending,
     Accepted,
     Rejected,
+    Flagged,     //abusive, the stake was slashed
+    Expired,     //the author let the response window pass, see expiry_policy
+    Negotiating, //the author counter-offered a lower reward, waiting for the reviewer
+    Withdrawn,   //the reviewer turned the counter-offer down, the stake was returned
+}
+
+//A enum to represent what happens to a review the author did not decide on in time
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ReviewExpiryPolicy {
+    AutoAccept, //paid from the bounty escrow as if accepted
+    Release,    //stake returned unpaid, the author's reputation takes the hit
+}
+
+//The structured part of a review, every score runs from REVIEW_SCORE_MIN to REVIEW_SCORE_MAX
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct ReviewScores {
+    pub novelty: u8,
+    pub rigor: u8,
+    pub clarity: u8,
+    pub recommendation: Recommendation,
+    pub confidence: u8, //how sure the reviewer is of their assessment
+}
+
+//A enum to represent the reviewer's overall recommendation
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum Recommendation {
+    Accept,
+    MinorRevision,
+    MajorRevision,
+    Reject,
+}
+
+//Running totals of the scores of a paper's accepted reviews, averages are total / scored_reviews
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Eq, InitSpace)]
+pub struct ReviewAggregate {
+    pub scored_reviews: u32,
+    pub novelty_total: u32,
+    pub rigor_total: u32,
+    pub clarity_total: u32,
+    pub confidence_total: u32,
+    pub accept: u32,
+    pub minor_revision: u32,
+    pub major_revision: u32,
+    pub reject: u32,
+}
+
+impl ReviewScores {
+    pub fn validate(&self) -> Result<()> {
+        let in_range = |score: u8| (REVIEW_SCORE_MIN..=REVIEW_SCORE_MAX).contains(&score);
+        require!(
+            in_range(self.novelty)
+                && in_range(self.rigor)
+                && in_range(self.clarity)
+                && in_range(self.confidence),
+            ErrorCodes::ReviewScoresInvalid
+        );
+        Ok(())
+    }
+}
+
+impl Recommendation {
+    //accepting as is or after minor changes counts as a vote for the paper in its review round
+    pub fn recommends_acceptance(&self) -> bool {
+        matches!(self, Recommendation::Accept | Recommendation::MinorRevision)
+    }
+}
+
+impl ReviewAggregate {
+    pub fn add(&mut self, scores: &ReviewScores) -> Result<()> {
+        let add = |total: u32, score: u8| {
+            total
+                .checked_add(u32::from(score))
+                .ok_or(ErrorCodes::MathOverflow)
+        };
+        self.scored_reviews = add(self.scored_reviews, 1)?;
+        self.novelty_total = add(self.novelty_total, scores.novelty)?;
+        self.rigor_total = add(self.rigor_total, scores.rigor)?;
+        self.clarity_total = add(self.clarity_total, scores.clarity)?;
+        self.confidence_total = add(self.confidence_total, scores.confidence)?;
+        let recommendation = match scores.recommendation {
+            Recommendation::Accept => &mut self.accept,
+            Recommendation::MinorRevision => &mut self.minor_revision,
+            Recommendation::MajorRevision => &mut self.major_revision,
+            Recommendation::Reject => &mut self.reject,
+        };
+        *recommendation = add(*recommendation, 1)?;
+        Ok(())
+    }
+
+    //average of a score total in hundredths, e.g. 375 for 3.75, 0 before any review is scored
+    pub fn average(&self, total: u32) -> u16 {
+        if self.scored_reviews == 0 {
+            return 0;
+        }
+        (u64::from(total) * 100 / u64::from(self.scored_reviews)) as u16
+    }
 }
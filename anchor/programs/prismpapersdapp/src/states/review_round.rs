@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::BPS_DENOMINATOR;
+
+#[account]
+#[derive(InitSpace)]
+pub struct ReviewRound {
+    pub paper: Pubkey,
+    pub round_id: u16, //per-paper sequence number, part of the round PDA seeds
+    pub target_reviewers: u16, //reviews the round takes before it can be finalized early
+    pub quorum: u16,   //reviews needed for the outcome to count
+    pub approval_bps: u16, //share of reviews recommending acceptance needed to pass
+    pub deadline: i64, //reviews close at this time, the round can be finalized from then on
+    pub reviews: u16,
+    pub approvals: u16,                //reviews recommending acceptance
+    pub outcome: Option<RoundOutcome>, //None while the round is open
+    pub finalized_at: i64,
+    pub timestamp: i64,
+    pub bump: u8,
+}
+
+//A enum to represent the aggregate verdict of a finalized review round
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum RoundOutcome {
+    Passed,   //quorum met and enough reviewers recommended acceptance
+    Failed,   //quorum met but the approval threshold was not
+    NoQuorum, //too few reviews to decide
+}
+
+impl ReviewRound {
+    pub fn is_full(&self) -> bool {
+        self.reviews >= self.target_reviewers
+    }
+
+    //the outcome the current tally would give if the round were finalized now
+    pub fn tally(&self) -> RoundOutcome {
+        if self.reviews < self.quorum {
+            return RoundOutcome::NoQuorum;
+        }
+        let approved = u64::from(self.approvals) * BPS_DENOMINATOR;
+        let required = u64::from(self.reviews) * u64::from(self.approval_bps);
+        if approved >= required {
+            RoundOutcome::Passed
+        } else {
+            RoundOutcome::Failed
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::PAPER_URL_MAX_LENGTH;
+
+//Immutable snapshot of a paper's content, one per revision
+#[account]
+#[derive(InitSpace)]
+pub struct PaperRevision {
+    pub paper: Pubkey,
+    pub revision: u32,
+    pub content_hash: [u8; 32], //hash of the plaintext file of this revision
+    pub ciphertext_hash: Option<[u8; 32]>,
+    #[max_len(PAPER_URL_MAX_LENGTH)]
+    pub encrypted_url: String,
+    pub price: u64,
+    pub timestamp: i64,
+    pub bump: u8,
+}
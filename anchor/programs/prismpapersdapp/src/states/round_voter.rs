@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+//Marks a wallet as having voted in a review round. It outlives the review, so closing a review
+//and submitting again can't add a second vote to the same round.
+#[account]
+#[derive(InitSpace)]
+pub struct RoundVoter {
+    pub round: Pubkey,
+    pub voter: Pubkey, //the reviewer's wallet, for anonymous reviews the one revealed behind the pseudonym
+    pub review: Pubkey,
+    pub bump: u8,
+}
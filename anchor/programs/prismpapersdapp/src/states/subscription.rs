@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{MAX_CO_AUTHORS, PLAN_MAX_PAPERS},
+    states::{CoAuthor, ResearchPaper},
+};
+
+#[account]
+#[derive(InitSpace)]
+pub struct SubscriptionPlan {
+    pub author: Pubkey,
+    pub plan_id: u16,                 //author-chosen id, part of the plan PDA seeds
+    pub price: u64,                   //per period
+    pub payment_mint: Option<Pubkey>, //None means the price is in lamports
+    pub duration: i64,                //seconds of access bought by one payment
+    #[max_len(PLAN_MAX_PAPERS)]
+    pub papers: Vec<Pubkey>, //papers included, empty covers the author's whole catalogue
+    #[max_len(MAX_CO_AUTHORS)]
+    pub co_authors: Vec<CoAuthor>, //revenue split, empty when the author takes it all
+    pub active: bool,                 //inactive plans can't be subscribed to or renewed
+    pub subscribers: u32,             //subscriptions and renewals sold
+    pub timestamp: i64,
+    pub bump: u8,
+}
+
+impl SubscriptionPlan {
+    //whether the plan includes `paper`. Subscriptions pay the plan's own recorded split, so a later
+    //`set_co_authors` on the paper leaves running subscriptions alone; the plan only has to pay every
+    //co-author of the paper something, so none of them is cut out of its subscriptions.
+    pub fn covers(&self, paper: &Account<ResearchPaper>) -> bool {
+        paper.author == self.author
+            && (self.papers.is_empty() || self.papers.contains(&paper.key()))
+            && paper.co_authors.iter().all(|co_author| {
+                self.co_authors
+                    .iter()
+                    .any(|plan_co_author| plan_co_author.wallet == co_author.wallet)
+            })
+    }
+
+    //co-authors other than the plan's author, in list order
+    pub fn other_co_authors(&self) -> impl Iterator<Item = &CoAuthor> {
+        self.co_authors
+            .iter()
+            .filter(move |co_author| co_author.wallet != self.author)
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Subscription {
+    pub subscriber: Pubkey,
+    pub plan: Pubkey,
+    pub expires_at: i64, //access ends at this time unless renewed
+    pub subscriber_encryption_key: [u8; 32], //X25519 public key the author wraps content keys to
+    pub timestamp: i64,
+    pub bump: u8,
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::USER_NAME_MAX_LENGTH;
+use crate::{constants::USER_NAME_MAX_LENGTH, reputation::reputation_score};
 
 #[account]
 #[derive(InitSpace)]
@@ -12,7 +12,35 @@ pub struct User {
     pub purchased: u16,
     pub sold: u16,
     pub reviewed: u16,
-    pub earning: u64,
+    pub live_papers: u16,     //papers that have not been closed yet
+    pub pending_reviews: u16, //submitted reviews still waiting for a verdict
+    pub rejected_open: u16, //rejected reviews not closed or flagged yet, they can still be flagged
+    pub reviews_accepted: u16,
+    pub reviews_rejected: u16,
+    pub reviews_flagged: u16,   //reviews an arbiter flagged as abusive
+    pub reviews_answered: u16, //reviews of this user's papers they accepted, rejected or countered in time
+    pub reviews_expired: u16,  //reviews of this user's papers released unanswered
+    pub author_reputation: u16, //share of reviews of this user's papers answered in time, in basis points
+    pub review_rewards: u64,    //lifetime SOL rewards earned by accepted reviews
+    pub reputation: u16,        //reputation score in basis points, see reputation::reputation_score
+    pub earning: u64,           //withdrawable balance, backed by the user vault
+    pub withdrawn: u64,         //lifetime total pulled out of the user vault
     pub timestamp: i64,
     pub bump: u8,
 }
+
+impl User {
+    //recomputes the cached reputation after a review outcome changed
+    pub fn refresh_reputation(&mut self) {
+        self.reputation = reputation_score(
+            self.reviews_accepted,
+            self.reviews_rejected,
+            self.reviews_flagged,
+        );
+    }
+
+    //recomputes the author-side score after a review of this user's papers was answered or released
+    pub fn refresh_author_reputation(&mut self) {
+        self.author_reputation = reputation_score(self.reviews_answered, self.reviews_expired, 0);
+    }
+}
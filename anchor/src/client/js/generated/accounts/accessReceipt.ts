@@ -17,27 +17,43 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getI64Decoder,
   getI64Encoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   transformEncoder,
   type Account,
   type Address,
+  type Codec,
+  type Decoder,
   type EncodedAccount,
+  type Encoder,
   type FetchAccountConfig,
   type FetchAccountsConfig,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
   type MaybeAccount,
   type MaybeEncodedAccount,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyUint8Array,
 } from 'gill';
+import {
+  getReceiptStatusDecoder,
+  getReceiptStatusEncoder,
+  type ReceiptStatus,
+  type ReceiptStatusArgs,
+} from '../types';
 
 export const ACCESS_RECEIPT_DISCRIMINATOR = new Uint8Array([
   160, 208, 81, 74, 140, 203, 172, 176,
@@ -52,25 +68,52 @@ export function getAccessReceiptDiscriminatorBytes() {
 export type AccessReceipt = {
   discriminator: ReadonlyUint8Array;
   buyer: Address;
+  payer: Address;
   purchasedPaper: Address;
+  revision: number;
+  seats: number;
+  buyerEncryptionKey: Array<number>;
+  keyRevision: Option<number>;
+  amountPaid: bigint;
+  platformFee: bigint;
+  refundDeadline: bigint;
+  status: ReceiptStatus;
   timestamp: bigint;
   bump: number;
 };
 
 export type AccessReceiptArgs = {
   buyer: Address;
+  payer: Address;
   purchasedPaper: Address;
+  revision: number;
+  seats: number;
+  buyerEncryptionKey: Array<number>;
+  keyRevision: OptionOrNullable<number>;
+  amountPaid: number | bigint;
+  platformFee: number | bigint;
+  refundDeadline: number | bigint;
+  status: ReceiptStatusArgs;
   timestamp: number | bigint;
   bump: number;
 };
 
 /** Gets the encoder for {@link AccessReceiptArgs} account data. */
-export function getAccessReceiptEncoder(): FixedSizeEncoder<AccessReceiptArgs> {
+export function getAccessReceiptEncoder(): Encoder<AccessReceiptArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['buyer', getAddressEncoder()],
+      ['payer', getAddressEncoder()],
       ['purchasedPaper', getAddressEncoder()],
+      ['revision', getU32Encoder()],
+      ['seats', getU32Encoder()],
+      ['buyerEncryptionKey', getArrayEncoder(getU8Encoder(), { size: 32 })],
+      ['keyRevision', getOptionEncoder(getU32Encoder())],
+      ['amountPaid', getU64Encoder()],
+      ['platformFee', getU64Encoder()],
+      ['refundDeadline', getI64Encoder()],
+      ['status', getReceiptStatusEncoder()],
       ['timestamp', getI64Encoder()],
       ['bump', getU8Encoder()],
     ]),
@@ -79,18 +122,27 @@ export function getAccessReceiptEncoder(): FixedSizeEncoder<AccessReceiptArgs> {
 }
 
 /** Gets the decoder for {@link AccessReceipt} account data. */
-export function getAccessReceiptDecoder(): FixedSizeDecoder<AccessReceipt> {
+export function getAccessReceiptDecoder(): Decoder<AccessReceipt> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['buyer', getAddressDecoder()],
+    ['payer', getAddressDecoder()],
     ['purchasedPaper', getAddressDecoder()],
+    ['revision', getU32Decoder()],
+    ['seats', getU32Decoder()],
+    ['buyerEncryptionKey', getArrayDecoder(getU8Decoder(), { size: 32 })],
+    ['keyRevision', getOptionDecoder(getU32Decoder())],
+    ['amountPaid', getU64Decoder()],
+    ['platformFee', getU64Decoder()],
+    ['refundDeadline', getI64Decoder()],
+    ['status', getReceiptStatusDecoder()],
     ['timestamp', getI64Decoder()],
     ['bump', getU8Decoder()],
   ]);
 }
 
 /** Gets the codec for {@link AccessReceipt} account data. */
-export function getAccessReceiptCodec(): FixedSizeCodec<
+export function getAccessReceiptCodec(): Codec<
   AccessReceiptArgs,
   AccessReceipt
 > {
@@ -153,7 +205,3 @@ export async function fetchAllMaybeAccessReceipt(
   const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
   return maybeAccounts.map((maybeAccount) => decodeAccessReceipt(maybeAccount));
 }
-
-export function getAccessReceiptSize(): number {
-  return 81;
-}
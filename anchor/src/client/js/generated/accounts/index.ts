@@ -7,6 +7,17 @@
  */
 
 export * from './accessReceipt';
+export * from './bundle';
+export * from './config';
+export * from './institution';
+export * from './institutionMember';
+export * from './keyDelivery';
+export * from './paperCounter';
+export * from './paperRevision';
 export * from './peerReview';
 export * from './researchPaper';
+export * from './reviewRound';
+export * from './roundVoter';
+export * from './subscription';
+export * from './subscriptionPlan';
 export * from './user';
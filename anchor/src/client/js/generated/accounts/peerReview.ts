@@ -19,10 +19,14 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getI64Decoder,
   getI64Encoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU32Decoder,
@@ -44,11 +48,21 @@ import {
   type FetchAccountsConfig,
   type MaybeAccount,
   type MaybeEncodedAccount,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyUint8Array,
 } from 'gill';
 import {
+  getReviewExpiryPolicyDecoder,
+  getReviewExpiryPolicyEncoder,
+  getReviewScoresDecoder,
+  getReviewScoresEncoder,
   getReviewStatusDecoder,
   getReviewStatusEncoder,
+  type ReviewExpiryPolicy,
+  type ReviewExpiryPolicyArgs,
+  type ReviewScores,
+  type ReviewScoresArgs,
   type ReviewStatus,
   type ReviewStatusArgs,
 } from '../types';
@@ -65,20 +79,46 @@ export type PeerReview = {
   discriminator: ReadonlyUint8Array;
   reviewer: Address;
   reviewedPaper: Address;
+  round: Address;
   reviewUrl: string;
+  reviewHash: Array<number>;
   status: ReviewStatus;
+  scores: ReviewScores;
   proposedReward: bigint;
+  stake: bigint;
   timestamp: bigint;
+  decidedAt: bigint;
+  respondBy: bigint;
+  pausedOffset: bigint;
+  expiryPolicy: ReviewExpiryPolicy;
+  identityCommitment: Option<Array<number>>;
+  revealedReviewer: Option<Address>;
+  unclaimedReward: bigint;
+  counterOffer: Option<bigint>;
+  counteredAt: bigint;
   bump: number;
 };
 
 export type PeerReviewArgs = {
   reviewer: Address;
   reviewedPaper: Address;
+  round: Address;
   reviewUrl: string;
+  reviewHash: Array<number>;
   status: ReviewStatusArgs;
+  scores: ReviewScoresArgs;
   proposedReward: number | bigint;
+  stake: number | bigint;
   timestamp: number | bigint;
+  decidedAt: number | bigint;
+  respondBy: number | bigint;
+  pausedOffset: number | bigint;
+  expiryPolicy: ReviewExpiryPolicyArgs;
+  identityCommitment: OptionOrNullable<Array<number>>;
+  revealedReviewer: OptionOrNullable<Address>;
+  unclaimedReward: number | bigint;
+  counterOffer: OptionOrNullable<number | bigint>;
+  counteredAt: number | bigint;
   bump: number;
 };
 
@@ -89,10 +129,26 @@ export function getPeerReviewEncoder(): Encoder<PeerReviewArgs> {
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['reviewer', getAddressEncoder()],
       ['reviewedPaper', getAddressEncoder()],
+      ['round', getAddressEncoder()],
       ['reviewUrl', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      ['reviewHash', getArrayEncoder(getU8Encoder(), { size: 32 })],
       ['status', getReviewStatusEncoder()],
+      ['scores', getReviewScoresEncoder()],
       ['proposedReward', getU64Encoder()],
+      ['stake', getU64Encoder()],
       ['timestamp', getI64Encoder()],
+      ['decidedAt', getI64Encoder()],
+      ['respondBy', getI64Encoder()],
+      ['pausedOffset', getI64Encoder()],
+      ['expiryPolicy', getReviewExpiryPolicyEncoder()],
+      [
+        'identityCommitment',
+        getOptionEncoder(getArrayEncoder(getU8Encoder(), { size: 32 })),
+      ],
+      ['revealedReviewer', getOptionEncoder(getAddressEncoder())],
+      ['unclaimedReward', getU64Encoder()],
+      ['counterOffer', getOptionEncoder(getU64Encoder())],
+      ['counteredAt', getI64Encoder()],
       ['bump', getU8Encoder()],
     ]),
     (value) => ({ ...value, discriminator: PEER_REVIEW_DISCRIMINATOR })
@@ -105,10 +161,26 @@ export function getPeerReviewDecoder(): Decoder<PeerReview> {
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['reviewer', getAddressDecoder()],
     ['reviewedPaper', getAddressDecoder()],
+    ['round', getAddressDecoder()],
     ['reviewUrl', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ['reviewHash', getArrayDecoder(getU8Decoder(), { size: 32 })],
     ['status', getReviewStatusDecoder()],
+    ['scores', getReviewScoresDecoder()],
     ['proposedReward', getU64Decoder()],
+    ['stake', getU64Decoder()],
     ['timestamp', getI64Decoder()],
+    ['decidedAt', getI64Decoder()],
+    ['respondBy', getI64Decoder()],
+    ['pausedOffset', getI64Decoder()],
+    ['expiryPolicy', getReviewExpiryPolicyDecoder()],
+    [
+      'identityCommitment',
+      getOptionDecoder(getArrayDecoder(getU8Decoder(), { size: 32 })),
+    ],
+    ['revealedReviewer', getOptionDecoder(getAddressDecoder())],
+    ['unclaimedReward', getU64Decoder()],
+    ['counterOffer', getOptionDecoder(getU64Decoder())],
+    ['counteredAt', getI64Decoder()],
     ['bump', getU8Decoder()],
   ]);
 }
@@ -19,12 +19,18 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getI64Decoder,
   getI64Encoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU32Decoder,
   getU32Encoder,
   getU64Decoder,
@@ -44,8 +50,36 @@ import {
   type FetchAccountsConfig,
   type MaybeAccount,
   type MaybeEncodedAccount,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyUint8Array,
 } from 'gill';
+import {
+  getCoAuthorDecoder,
+  getCoAuthorEncoder,
+  getPaperStatusDecoder,
+  getPaperStatusEncoder,
+  getReviewAggregateDecoder,
+  getReviewAggregateEncoder,
+  getReviewExpiryPolicyDecoder,
+  getReviewExpiryPolicyEncoder,
+  getRevisionAccessPolicyDecoder,
+  getRevisionAccessPolicyEncoder,
+  getRoundOutcomeDecoder,
+  getRoundOutcomeEncoder,
+  type CoAuthor,
+  type CoAuthorArgs,
+  type PaperStatus,
+  type PaperStatusArgs,
+  type ReviewAggregate,
+  type ReviewAggregateArgs,
+  type ReviewExpiryPolicy,
+  type ReviewExpiryPolicyArgs,
+  type RevisionAccessPolicy,
+  type RevisionAccessPolicyArgs,
+  type RoundOutcome,
+  type RoundOutcomeArgs,
+} from '../types';
 
 export const RESEARCH_PAPER_DISCRIMINATOR = new Uint8Array([
   103, 125, 147, 192, 213, 248, 70, 245,
@@ -60,26 +94,70 @@ export function getResearchPaperDiscriminatorBytes() {
 export type ResearchPaper = {
   discriminator: ReadonlyUint8Array;
   author: Address;
+  index: number;
   title: string;
   description: string;
   price: bigint;
+  paymentMint: Option<Address>;
   sales: number;
   reviews: number;
+  openReviews: number;
+  bounty: bigint;
+  bountyReserved: bigint;
+  bountyDeadline: bigint;
   encryptedUrl: string;
-  encryptionKey: string;
+  contentHash: Array<number>;
+  ciphertextHash: Option<Array<number>>;
+  pendingDeliveries: number;
+  liveReceipts: number;
+  escrowed: bigint;
+  status: PaperStatus;
+  retractionReason: number;
+  revision: number;
+  revisionPolicy: RevisionAccessPolicy;
+  coAuthors: Array<CoAuthor>;
+  reviewStake: Option<bigint>;
+  minReputation: number;
+  reviewRounds: number;
+  reviewOutcome: Option<RoundOutcome>;
+  reviewScores: ReviewAggregate;
+  reviewResponseWindow: bigint;
+  reviewExpiryPolicy: ReviewExpiryPolicy;
   timestamp: bigint;
   bump: number;
 };
 
 export type ResearchPaperArgs = {
   author: Address;
+  index: number;
   title: string;
   description: string;
   price: number | bigint;
+  paymentMint: OptionOrNullable<Address>;
   sales: number;
   reviews: number;
+  openReviews: number;
+  bounty: number | bigint;
+  bountyReserved: number | bigint;
+  bountyDeadline: number | bigint;
   encryptedUrl: string;
-  encryptionKey: string;
+  contentHash: Array<number>;
+  ciphertextHash: OptionOrNullable<Array<number>>;
+  pendingDeliveries: number;
+  liveReceipts: number;
+  escrowed: number | bigint;
+  status: PaperStatusArgs;
+  retractionReason: number;
+  revision: number;
+  revisionPolicy: RevisionAccessPolicyArgs;
+  coAuthors: Array<CoAuthorArgs>;
+  reviewStake: OptionOrNullable<number | bigint>;
+  minReputation: number;
+  reviewRounds: number;
+  reviewOutcome: OptionOrNullable<RoundOutcomeArgs>;
+  reviewScores: ReviewAggregateArgs;
+  reviewResponseWindow: number | bigint;
+  reviewExpiryPolicy: ReviewExpiryPolicyArgs;
   timestamp: number | bigint;
   bump: number;
 };
@@ -90,16 +168,38 @@ export function getResearchPaperEncoder(): Encoder<ResearchPaperArgs> {
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['author', getAddressEncoder()],
+      ['index', getU16Encoder()],
       ['title', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ['description', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ['price', getU64Encoder()],
+      ['paymentMint', getOptionEncoder(getAddressEncoder())],
       ['sales', getU32Encoder()],
       ['reviews', getU32Encoder()],
+      ['openReviews', getU32Encoder()],
+      ['bounty', getU64Encoder()],
+      ['bountyReserved', getU64Encoder()],
+      ['bountyDeadline', getI64Encoder()],
       ['encryptedUrl', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      ['contentHash', getArrayEncoder(getU8Encoder(), { size: 32 })],
       [
-        'encryptionKey',
-        addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder()),
+        'ciphertextHash',
+        getOptionEncoder(getArrayEncoder(getU8Encoder(), { size: 32 })),
       ],
+      ['pendingDeliveries', getU32Encoder()],
+      ['liveReceipts', getU32Encoder()],
+      ['escrowed', getU64Encoder()],
+      ['status', getPaperStatusEncoder()],
+      ['retractionReason', getU8Encoder()],
+      ['revision', getU32Encoder()],
+      ['revisionPolicy', getRevisionAccessPolicyEncoder()],
+      ['coAuthors', getArrayEncoder(getCoAuthorEncoder())],
+      ['reviewStake', getOptionEncoder(getU64Encoder())],
+      ['minReputation', getU16Encoder()],
+      ['reviewRounds', getU16Encoder()],
+      ['reviewOutcome', getOptionEncoder(getRoundOutcomeEncoder())],
+      ['reviewScores', getReviewAggregateEncoder()],
+      ['reviewResponseWindow', getI64Encoder()],
+      ['reviewExpiryPolicy', getReviewExpiryPolicyEncoder()],
       ['timestamp', getI64Encoder()],
       ['bump', getU8Encoder()],
     ]),
@@ -112,13 +212,38 @@ export function getResearchPaperDecoder(): Decoder<ResearchPaper> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['author', getAddressDecoder()],
+    ['index', getU16Decoder()],
     ['title', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ['description', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ['price', getU64Decoder()],
+    ['paymentMint', getOptionDecoder(getAddressDecoder())],
     ['sales', getU32Decoder()],
     ['reviews', getU32Decoder()],
+    ['openReviews', getU32Decoder()],
+    ['bounty', getU64Decoder()],
+    ['bountyReserved', getU64Decoder()],
+    ['bountyDeadline', getI64Decoder()],
     ['encryptedUrl', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
-    ['encryptionKey', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ['contentHash', getArrayDecoder(getU8Decoder(), { size: 32 })],
+    [
+      'ciphertextHash',
+      getOptionDecoder(getArrayDecoder(getU8Decoder(), { size: 32 })),
+    ],
+    ['pendingDeliveries', getU32Decoder()],
+    ['liveReceipts', getU32Decoder()],
+    ['escrowed', getU64Decoder()],
+    ['status', getPaperStatusDecoder()],
+    ['retractionReason', getU8Decoder()],
+    ['revision', getU32Decoder()],
+    ['revisionPolicy', getRevisionAccessPolicyDecoder()],
+    ['coAuthors', getArrayDecoder(getCoAuthorDecoder())],
+    ['reviewStake', getOptionDecoder(getU64Decoder())],
+    ['minReputation', getU16Decoder()],
+    ['reviewRounds', getU16Decoder()],
+    ['reviewOutcome', getOptionDecoder(getRoundOutcomeDecoder())],
+    ['reviewScores', getReviewAggregateDecoder()],
+    ['reviewResponseWindow', getI64Decoder()],
+    ['reviewExpiryPolicy', getReviewExpiryPolicyDecoder()],
     ['timestamp', getI64Decoder()],
     ['bump', getU8Decoder()],
   ]);
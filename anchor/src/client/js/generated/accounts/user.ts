@@ -65,7 +65,19 @@ export type User = {
   purchased: number;
   sold: number;
   reviewed: number;
+  livePapers: number;
+  pendingReviews: number;
+  rejectedOpen: number;
+  reviewsAccepted: number;
+  reviewsRejected: number;
+  reviewsFlagged: number;
+  reviewsAnswered: number;
+  reviewsExpired: number;
+  authorReputation: number;
+  reviewRewards: bigint;
+  reputation: number;
   earning: bigint;
+  withdrawn: bigint;
   timestamp: bigint;
   bump: number;
 };
@@ -77,7 +89,19 @@ export type UserArgs = {
   purchased: number;
   sold: number;
   reviewed: number;
+  livePapers: number;
+  pendingReviews: number;
+  rejectedOpen: number;
+  reviewsAccepted: number;
+  reviewsRejected: number;
+  reviewsFlagged: number;
+  reviewsAnswered: number;
+  reviewsExpired: number;
+  authorReputation: number;
+  reviewRewards: number | bigint;
+  reputation: number;
   earning: number | bigint;
+  withdrawn: number | bigint;
   timestamp: number | bigint;
   bump: number;
 };
@@ -93,7 +117,19 @@ export function getUserEncoder(): Encoder<UserArgs> {
       ['purchased', getU16Encoder()],
       ['sold', getU16Encoder()],
       ['reviewed', getU16Encoder()],
+      ['livePapers', getU16Encoder()],
+      ['pendingReviews', getU16Encoder()],
+      ['rejectedOpen', getU16Encoder()],
+      ['reviewsAccepted', getU16Encoder()],
+      ['reviewsRejected', getU16Encoder()],
+      ['reviewsFlagged', getU16Encoder()],
+      ['reviewsAnswered', getU16Encoder()],
+      ['reviewsExpired', getU16Encoder()],
+      ['authorReputation', getU16Encoder()],
+      ['reviewRewards', getU64Encoder()],
+      ['reputation', getU16Encoder()],
       ['earning', getU64Encoder()],
+      ['withdrawn', getU64Encoder()],
       ['timestamp', getI64Encoder()],
       ['bump', getU8Encoder()],
     ]),
@@ -111,7 +147,19 @@ export function getUserDecoder(): Decoder<User> {
     ['purchased', getU16Decoder()],
     ['sold', getU16Decoder()],
     ['reviewed', getU16Decoder()],
+    ['livePapers', getU16Decoder()],
+    ['pendingReviews', getU16Decoder()],
+    ['rejectedOpen', getU16Decoder()],
+    ['reviewsAccepted', getU16Decoder()],
+    ['reviewsRejected', getU16Decoder()],
+    ['reviewsFlagged', getU16Decoder()],
+    ['reviewsAnswered', getU16Decoder()],
+    ['reviewsExpired', getU16Decoder()],
+    ['authorReputation', getU16Decoder()],
+    ['reviewRewards', getU64Decoder()],
+    ['reputation', getU16Decoder()],
     ['earning', getU64Decoder()],
+    ['withdrawn', getU64Decoder()],
     ['timestamp', getI64Decoder()],
     ['bump', getU8Decoder()],
   ]);
@@ -22,7 +22,7 @@ export const PRISMPAPERSDAPP_ERROR__PAPER_TITLE_INVALID = 0x1771; // 6001
 export const PRISMPAPERSDAPP_ERROR__PAPER_DESCRIPTION_INVALID = 0x1772; // 6002
 /** PaperUrlEmptyOrTooLong: Research Paper URL/CID cannot be empty */
 export const PRISMPAPERSDAPP_ERROR__PAPER_URL_EMPTY_OR_TOO_LONG = 0x1773; // 6003
-/** EncryptionKeyEmptyOrTooLong: Protocol Encryption Key cannot be empty */
+/** EncryptionKeyEmptyOrTooLong: Wrapped content key cannot be empty or too long */
 export const PRISMPAPERSDAPP_ERROR__ENCRYPTION_KEY_EMPTY_OR_TOO_LONG = 0x1774; // 6004
 /** ReviewUrlEmpty: Review Link/CID cannot be empty */
 export const PRISMPAPERSDAPP_ERROR__REVIEW_URL_EMPTY = 0x1775; // 6005
@@ -52,27 +52,309 @@ export const PRISMPAPERSDAPP_ERROR__ALREADY_REVIEWED = 0x1780; // 6016
 export const PRISMPAPERSDAPP_ERROR__REVIEW_NOT_PENDING = 0x1781; // 6017
 /** PaperNotPurchased: You must purchase the paper before reviewing it */
 export const PRISMPAPERSDAPP_ERROR__PAPER_NOT_PURCHASED = 0x1782; // 6018
+/** ReviewPaperMismatch: The review does not belong to this research paper */
+export const PRISMPAPERSDAPP_ERROR__REVIEW_PAPER_MISMATCH = 0x1783; // 6019
+/** ProgramPaused: The program is paused by the platform admins */
+export const PRISMPAPERSDAPP_ERROR__PROGRAM_PAUSED = 0x1784; // 6020
+/** InvalidFee: Fee basis points cannot exceed 10000 */
+export const PRISMPAPERSDAPP_ERROR__INVALID_FEE = 0x1785; // 6021
+/** InvalidAdminSet: Admin set cannot be empty or exceed the maximum number of admins */
+export const PRISMPAPERSDAPP_ERROR__INVALID_ADMIN_SET = 0x1786; // 6022
+/** BountyAmountInvalid: Bounty amount must be greater than zero */
+export const PRISMPAPERSDAPP_ERROR__BOUNTY_AMOUNT_INVALID = 0x1787; // 6023
+/** BountyDeadlineInvalid: Bounty deadline must be in the future and cannot be moved earlier */
+export const PRISMPAPERSDAPP_ERROR__BOUNTY_DEADLINE_INVALID = 0x1788; // 6024
+/** RewardExceedsBounty: The proposed reward exceeds the paper's remaining bounty */
+export const PRISMPAPERSDAPP_ERROR__REWARD_EXCEEDS_BOUNTY = 0x1789; // 6025
+/** BountyExpired: The review bounty for this paper has expired */
+export const PRISMPAPERSDAPP_ERROR__BOUNTY_EXPIRED = 0x178a; // 6026
+/** BountyStillActive: The bounty cannot be reclaimed before its deadline */
+export const PRISMPAPERSDAPP_ERROR__BOUNTY_STILL_ACTIVE = 0x178b; // 6027
+/** TokenAccountsMissing: Token accounts are required for papers priced in an SPL token */
+export const PRISMPAPERSDAPP_ERROR__TOKEN_ACCOUNTS_MISSING = 0x178c; // 6028
+/** PaymentMintMismatch: The payment mint does not match the paper's accepted mint */
+export const PRISMPAPERSDAPP_ERROR__PAYMENT_MINT_MISMATCH = 0x178d; // 6029
+/** BuyerEncryptionKeyInvalid: Buyer encryption public key cannot be empty */
+export const PRISMPAPERSDAPP_ERROR__BUYER_ENCRYPTION_KEY_INVALID = 0x178e; // 6030
+/** ReceiptPaperMismatch: The access receipt does not belong to this research paper */
+export const PRISMPAPERSDAPP_ERROR__RECEIPT_PAPER_MISMATCH = 0x178f; // 6031
+/** KeyAlreadyDelivered: The content key has already been delivered for this receipt */
+export const PRISMPAPERSDAPP_ERROR__KEY_ALREADY_DELIVERED = 0x1790; // 6032
+/** InvalidDeliveryAccounts: Key deliveries must be passed as (receipt, key delivery) account pairs */
+export const PRISMPAPERSDAPP_ERROR__INVALID_DELIVERY_ACCOUNTS = 0x1791; // 6033
+/** InvalidPaperStatus: This action is not allowed in the paper's current status */
+export const PRISMPAPERSDAPP_ERROR__INVALID_PAPER_STATUS = 0x1792; // 6034
+/** PaperNotForSale: This paper is not published for sale */
+export const PRISMPAPERSDAPP_ERROR__PAPER_NOT_FOR_SALE = 0x1793; // 6035
+/** PaperNotUnderReview: This paper is not open for peer review */
+export const PRISMPAPERSDAPP_ERROR__PAPER_NOT_UNDER_REVIEW = 0x1794; // 6036
+/** PaperIsRetracted: This paper has been retracted */
+export const PRISMPAPERSDAPP_ERROR__PAPER_IS_RETRACTED = 0x1795; // 6037
+/** UnauthorizedRetraction: Only the author or a platform admin can retract this paper */
+export const PRISMPAPERSDAPP_ERROR__UNAUTHORIZED_RETRACTION = 0x1796; // 6038
+/** RetractionReasonInvalid: Retraction reason code cannot be zero */
+export const PRISMPAPERSDAPP_ERROR__RETRACTION_REASON_INVALID = 0x1797; // 6039
+/** PaperNotClosable: Only unsold or retracted papers can be closed */
+export const PRISMPAPERSDAPP_ERROR__PAPER_NOT_CLOSABLE = 0x1798; // 6040
+/** BountyNotEmpty: The paper's bounty escrow must be empty first */
+export const PRISMPAPERSDAPP_ERROR__BOUNTY_NOT_EMPTY = 0x1799; // 6041
+/** DeliveriesOutstanding: All purchased keys must be delivered first */
+export const PRISMPAPERSDAPP_ERROR__DELIVERIES_OUTSTANDING = 0x179a; // 6042
+/** ReviewNotClosable: Only rejected or flagged reviews can be closed */
+export const PRISMPAPERSDAPP_ERROR__REVIEW_NOT_CLOSABLE = 0x179b; // 6043
+/** ReviewCooldownActive: Rejected reviews can only be closed after the cooling period */
+export const PRISMPAPERSDAPP_ERROR__REVIEW_COOLDOWN_ACTIVE = 0x179c; // 6044
+/** KeyNotDelivered: The content key for this receipt has not been delivered yet */
+export const PRISMPAPERSDAPP_ERROR__KEY_NOT_DELIVERED = 0x179d; // 6045
+/** UserVaultNotEmpty: The user's earnings must be withdrawn first */
+export const PRISMPAPERSDAPP_ERROR__USER_VAULT_NOT_EMPTY = 0x179e; // 6046
+/** UserHasOpenItems: The user still has open papers, pending reviews or rejected reviews to close */
+export const PRISMPAPERSDAPP_ERROR__USER_HAS_OPEN_ITEMS = 0x179f; // 6047
+/** ContentHashInvalid: Content hash cannot be all zeroes */
+export const PRISMPAPERSDAPP_ERROR__CONTENT_HASH_INVALID = 0x17a0; // 6048
+/** RevisionNotCovered: This receipt does not grant access to the paper's current revision */
+export const PRISMPAPERSDAPP_ERROR__REVISION_NOT_COVERED = 0x17a1; // 6049
+/** ReviewHashInvalid: Review hash cannot be all zeroes */
+export const PRISMPAPERSDAPP_ERROR__REVIEW_HASH_INVALID = 0x17a2; // 6050
+/** RefundWindowInvalid: Refund window cannot be negative */
+export const PRISMPAPERSDAPP_ERROR__REFUND_WINDOW_INVALID = 0x17a3; // 6051
+/** RefundWindowClosed: The refund window for this purchase has closed */
+export const PRISMPAPERSDAPP_ERROR__REFUND_WINDOW_CLOSED = 0x17a4; // 6052
+/** RefundWindowOpen: The refund window for this purchase is still open */
+export const PRISMPAPERSDAPP_ERROR__REFUND_WINDOW_OPEN = 0x17a5; // 6053
+/** ReceiptNotEscrowed: This purchase is not held in escrow */
+export const PRISMPAPERSDAPP_ERROR__RECEIPT_NOT_ESCROWED = 0x17a6; // 6054
+/** ReceiptNotDisputed: No refund has been requested for this purchase */
+export const PRISMPAPERSDAPP_ERROR__RECEIPT_NOT_DISPUTED = 0x17a7; // 6055
+/** UnauthorizedRefund: Only the author or a platform admin can resolve this refund */
+export const PRISMPAPERSDAPP_ERROR__UNAUTHORIZED_REFUND = 0x17a8; // 6056
+/** ReceiptRevoked: This access receipt has been revoked */
+export const PRISMPAPERSDAPP_ERROR__RECEIPT_REVOKED = 0x17a9; // 6057
+/** EscrowNotEmpty: Escrowed purchase payments must be settled or refunded first */
+export const PRISMPAPERSDAPP_ERROR__ESCROW_NOT_EMPTY = 0x17aa; // 6058
+/** ReceiptNotFinalized: This purchase has not been settled or refunded yet */
+export const PRISMPAPERSDAPP_ERROR__RECEIPT_NOT_FINALIZED = 0x17ab; // 6059
+/** InstitutionNameInvalid: Institution name must be between 1 and 100 characters */
+export const PRISMPAPERSDAPP_ERROR__INSTITUTION_NAME_INVALID = 0x17ac; // 6060
+/** InstitutionSeatsInvalid: An institution needs at least one seat, and no fewer than its members */
+export const PRISMPAPERSDAPP_ERROR__INSTITUTION_SEATS_INVALID = 0x17ad; // 6061
+/** InstitutionSeatsFull: Every seat at this institution is taken */
+export const PRISMPAPERSDAPP_ERROR__INSTITUTION_SEATS_FULL = 0x17ae; // 6062
+/** UnauthorizedInstitutionAdmin: Only the institution admin can do this */
+export const PRISMPAPERSDAPP_ERROR__UNAUTHORIZED_INSTITUTION_ADMIN = 0x17af; // 6063
+/** InstitutionMembershipMissing: Institutional licenses need the institution and a membership proof */
+export const PRISMPAPERSDAPP_ERROR__INSTITUTION_MEMBERSHIP_MISSING = 0x17b0; // 6064
+/** InstitutionMembershipInvalid: This membership does not belong to the licensed institution */
+export const PRISMPAPERSDAPP_ERROR__INSTITUTION_MEMBERSHIP_INVALID = 0x17b1; // 6065
+/** LicenseSeatsExceeded: The institution has more members than this license paid for */
+export const PRISMPAPERSDAPP_ERROR__LICENSE_SEATS_EXCEEDED = 0x17b2; // 6066
+/** PlanDurationInvalid: Subscription duration must be positive */
+export const PRISMPAPERSDAPP_ERROR__PLAN_DURATION_INVALID = 0x17b3; // 6067
+/** PlanPapersTooMany: Subscription plan lists too many papers */
+export const PRISMPAPERSDAPP_ERROR__PLAN_PAPERS_TOO_MANY = 0x17b4; // 6068
+/** PlanInactive: This subscription plan is not active */
+export const PRISMPAPERSDAPP_ERROR__PLAN_INACTIVE = 0x17b5; // 6069
+/** SubscriptionExpired: This subscription has expired */
+export const PRISMPAPERSDAPP_ERROR__SUBSCRIPTION_EXPIRED = 0x17b6; // 6070
+/** SubscriptionNotCovering: This subscription does not cover the paper */
+export const PRISMPAPERSDAPP_ERROR__SUBSCRIPTION_NOT_COVERING = 0x17b7; // 6071
+/** SubscriptionStillActive: This subscription has not expired yet */
+export const PRISMPAPERSDAPP_ERROR__SUBSCRIPTION_STILL_ACTIVE = 0x17b8; // 6072
+/** BundlePapersInvalid: A bundle needs between 1 and 10 papers */
+export const PRISMPAPERSDAPP_ERROR__BUNDLE_PAPERS_INVALID = 0x17b9; // 6073
+/** InvalidBundleAccounts: Bundle accounts must come as (paper, receipt, author user, author vault) groups plus co-author pairs */
+export const PRISMPAPERSDAPP_ERROR__INVALID_BUNDLE_ACCOUNTS = 0x17ba; // 6074
+/** BundleMismatch: The papers passed do not match the bundle */
+export const PRISMPAPERSDAPP_ERROR__BUNDLE_MISMATCH = 0x17bb; // 6075
+/** BundleInactive: This bundle is not for sale */
+export const PRISMPAPERSDAPP_ERROR__BUNDLE_INACTIVE = 0x17bc; // 6076
+/** BundleDiscountInvalid: Bundle discount must be below 100% */
+export const PRISMPAPERSDAPP_ERROR__BUNDLE_DISCOUNT_INVALID = 0x17bd; // 6077
+/** BundleTokenPaper: Papers priced in an SPL token must be bought individually */
+export const PRISMPAPERSDAPP_ERROR__BUNDLE_TOKEN_PAPER = 0x17be; // 6078
+/** CoAuthorsInvalid: Co-authors must include the author once each, up to 5, with positive shares totalling 10000 bps */
+export const PRISMPAPERSDAPP_ERROR__CO_AUTHORS_INVALID = 0x17bf; // 6079
+/** CoAuthorAccountsInvalid: Co-author accounts are missing or do not match the paper's co-authors */
+export const PRISMPAPERSDAPP_ERROR__CO_AUTHOR_ACCOUNTS_INVALID = 0x17c0; // 6080
+/** CoAuthorSignatureMissing: Every co-author must sign to join or change the revenue split */
+export const PRISMPAPERSDAPP_ERROR__CO_AUTHOR_SIGNATURE_MISSING = 0x17c1; // 6081
+/** ReviewNotFlaggable: Only pending reviews, or rejected ones still in their cooldown, can be flagged */
+export const PRISMPAPERSDAPP_ERROR__REVIEW_NOT_FLAGGABLE = 0x17c2; // 6082
+/** ReviewStakeInvalid: Review stake must be 0 or at least the rent-exempt minimum of an empty account */
+export const PRISMPAPERSDAPP_ERROR__REVIEW_STAKE_INVALID = 0x17c3; // 6083
+/** MinReputationInvalid: Minimum reputation cannot exceed 10000 bps */
+export const PRISMPAPERSDAPP_ERROR__MIN_REPUTATION_INVALID = 0x17c4; // 6084
+/** ReputationTooLow: Reviewer reputation is below the paper's minimum */
+export const PRISMPAPERSDAPP_ERROR__REPUTATION_TOO_LOW = 0x17c5; // 6085
+/** ReviewRoundInvalid: Round needs 1 <= quorum <= target reviewers, an approval threshold of at most 10000 bps and a future deadline */
+export const PRISMPAPERSDAPP_ERROR__REVIEW_ROUND_INVALID = 0x17c6; // 6086
+/** ReviewRoundFinalized: This review round has already been finalized */
+export const PRISMPAPERSDAPP_ERROR__REVIEW_ROUND_FINALIZED = 0x17c7; // 6087
+/** ReviewRoundHasReviews: A round that has reviews can only be finalized */
+export const PRISMPAPERSDAPP_ERROR__REVIEW_ROUND_HAS_REVIEWS = 0x17c8; // 6088
+/** ReviewRoundNotOver: The review round cannot be finalized before its deadline unless it is full */
+export const PRISMPAPERSDAPP_ERROR__REVIEW_ROUND_NOT_OVER = 0x17c9; // 6089
+/** ReviewRoundClosed: The review round is closed to new reviews */
+export const PRISMPAPERSDAPP_ERROR__REVIEW_ROUND_CLOSED = 0x17ca; // 6090
+/** ReviewRoundMismatch: Review round does not match the paper or the review */
+export const PRISMPAPERSDAPP_ERROR__REVIEW_ROUND_MISMATCH = 0x17cb; // 6091
+/** ReviewScoresInvalid: Review scores and confidence must be between 1 and 5 */
+export const PRISMPAPERSDAPP_ERROR__REVIEW_SCORES_INVALID = 0x17cc; // 6092
+/** AnonymousReviewNotAllowed: Anonymous reviews need a non-zero review stake and cannot be submitted to papers with a minimum reputation */
+export const PRISMPAPERSDAPP_ERROR__ANONYMOUS_REVIEW_NOT_ALLOWED = 0x17cd; // 6093
+/** AnonymousTokenReward: Anonymous rewards can only be escrowed in lamports */
+export const PRISMPAPERSDAPP_ERROR__ANONYMOUS_TOKEN_REWARD = 0x17ce; // 6094
+/** IdentityCommitmentInvalid: Identity commitment cannot be empty */
+export const PRISMPAPERSDAPP_ERROR__IDENTITY_COMMITMENT_INVALID = 0x17cf; // 6095
+/** ReviewerAccountMissing: The reviewer's user account is required for a named review */
+export const PRISMPAPERSDAPP_ERROR__REVIEWER_ACCOUNT_MISSING = 0x17d0; // 6096
+/** ReviewNotRevealable: Only an anonymous review that the author has decided on can be revealed, and only once */
+export const PRISMPAPERSDAPP_ERROR__REVIEW_NOT_REVEALABLE = 0x17d1; // 6097
+/** IdentityCommitmentMismatch: The wallet and salt do not match the review's identity commitment */
+export const PRISMPAPERSDAPP_ERROR__IDENTITY_COMMITMENT_MISMATCH = 0x17d2; // 6098
+/** ReviewResponseWindowInvalid: Review response window must be between 1 second and 90 days */
+export const PRISMPAPERSDAPP_ERROR__REVIEW_RESPONSE_WINDOW_INVALID = 0x17d3; // 6099
+/** AutoAcceptTokenPaper: Auto-accept can only pay rewards in lamports */
+export const PRISMPAPERSDAPP_ERROR__AUTO_ACCEPT_TOKEN_PAPER = 0x17d4; // 6100
+/** ReviewResponseWindowPassed: The author's response window for this review has passed */
+export const PRISMPAPERSDAPP_ERROR__REVIEW_RESPONSE_WINDOW_PASSED = 0x17d5; // 6101
+/** ReviewResponseWindowOpen: The author can still respond to this review */
+export const PRISMPAPERSDAPP_ERROR__REVIEW_RESPONSE_WINDOW_OPEN = 0x17d6; // 6102
+/** CounterOfferInvalid: A counter-offer must be lower than the proposed reward */
+export const PRISMPAPERSDAPP_ERROR__COUNTER_OFFER_INVALID = 0x17d7; // 6103
+/** ReviewNotNegotiating: This review has no counter-offer to respond to */
+export const PRISMPAPERSDAPP_ERROR__REVIEW_NOT_NEGOTIATING = 0x17d8; // 6104
+/** ReviewsOutstanding: Every pending or negotiating review must be decided first */
+export const PRISMPAPERSDAPP_ERROR__REVIEWS_OUTSTANDING = 0x17d9; // 6105
+/** KeyRevisionMismatch: The key must be for the revision the buyer is owed */
+export const PRISMPAPERSDAPP_ERROR__KEY_REVISION_MISMATCH = 0x17da; // 6106
+/** RevisionPolicyLocked: The revision policy cannot change once the paper has buyers */
+export const PRISMPAPERSDAPP_ERROR__REVISION_POLICY_LOCKED = 0x17db; // 6107
+/** AnonymousReviewerAccount: An anonymous review is credited to a User account only when it is revealed */
+export const PRISMPAPERSDAPP_ERROR__ANONYMOUS_REVIEWER_ACCOUNT = 0x17dc; // 6108
+/** BuyerAccountMissing: The buyer's user account is required to refund a personal purchase */
+export const PRISMPAPERSDAPP_ERROR__BUYER_ACCOUNT_MISSING = 0x17dd; // 6109
+/** DisputeWindowOpen: The author or an admin can still resolve this dispute */
+export const PRISMPAPERSDAPP_ERROR__DISPUTE_WINDOW_OPEN = 0x17de; // 6110
+/** PaperNotClosed: The paper must be closed first */
+export const PRISMPAPERSDAPP_ERROR__PAPER_NOT_CLOSED = 0x17df; // 6111
+/** KeyDeliveryPaperMismatch: The key delivery does not belong to this research paper */
+export const PRISMPAPERSDAPP_ERROR__KEY_DELIVERY_PAPER_MISMATCH = 0x17e0; // 6112
 
 export type PrismpapersdappError =
   | typeof PRISMPAPERSDAPP_ERROR__ALREADY_PURCHASED
   | typeof PRISMPAPERSDAPP_ERROR__ALREADY_REVIEWED
+  | typeof PRISMPAPERSDAPP_ERROR__ANONYMOUS_REVIEWER_ACCOUNT
+  | typeof PRISMPAPERSDAPP_ERROR__ANONYMOUS_REVIEW_NOT_ALLOWED
+  | typeof PRISMPAPERSDAPP_ERROR__ANONYMOUS_TOKEN_REWARD
   | typeof PRISMPAPERSDAPP_ERROR__AUTHOR_CANT_BUY_SELF
   | typeof PRISMPAPERSDAPP_ERROR__AUTHOR_CANT_REVIEW_SELF
+  | typeof PRISMPAPERSDAPP_ERROR__AUTO_ACCEPT_TOKEN_PAPER
+  | typeof PRISMPAPERSDAPP_ERROR__BOUNTY_AMOUNT_INVALID
+  | typeof PRISMPAPERSDAPP_ERROR__BOUNTY_DEADLINE_INVALID
+  | typeof PRISMPAPERSDAPP_ERROR__BOUNTY_EXPIRED
+  | typeof PRISMPAPERSDAPP_ERROR__BOUNTY_NOT_EMPTY
+  | typeof PRISMPAPERSDAPP_ERROR__BOUNTY_STILL_ACTIVE
+  | typeof PRISMPAPERSDAPP_ERROR__BUNDLE_DISCOUNT_INVALID
+  | typeof PRISMPAPERSDAPP_ERROR__BUNDLE_INACTIVE
+  | typeof PRISMPAPERSDAPP_ERROR__BUNDLE_MISMATCH
+  | typeof PRISMPAPERSDAPP_ERROR__BUNDLE_PAPERS_INVALID
+  | typeof PRISMPAPERSDAPP_ERROR__BUNDLE_TOKEN_PAPER
+  | typeof PRISMPAPERSDAPP_ERROR__BUYER_ACCOUNT_MISSING
+  | typeof PRISMPAPERSDAPP_ERROR__BUYER_ENCRYPTION_KEY_INVALID
+  | typeof PRISMPAPERSDAPP_ERROR__CONTENT_HASH_INVALID
+  | typeof PRISMPAPERSDAPP_ERROR__COUNTER_OFFER_INVALID
+  | typeof PRISMPAPERSDAPP_ERROR__CO_AUTHORS_INVALID
+  | typeof PRISMPAPERSDAPP_ERROR__CO_AUTHOR_ACCOUNTS_INVALID
+  | typeof PRISMPAPERSDAPP_ERROR__CO_AUTHOR_SIGNATURE_MISSING
+  | typeof PRISMPAPERSDAPP_ERROR__DELIVERIES_OUTSTANDING
+  | typeof PRISMPAPERSDAPP_ERROR__DISPUTE_WINDOW_OPEN
   | typeof PRISMPAPERSDAPP_ERROR__ENCRYPTION_KEY_EMPTY_OR_TOO_LONG
+  | typeof PRISMPAPERSDAPP_ERROR__ESCROW_NOT_EMPTY
+  | typeof PRISMPAPERSDAPP_ERROR__IDENTITY_COMMITMENT_INVALID
+  | typeof PRISMPAPERSDAPP_ERROR__IDENTITY_COMMITMENT_MISMATCH
+  | typeof PRISMPAPERSDAPP_ERROR__INSTITUTION_MEMBERSHIP_INVALID
+  | typeof PRISMPAPERSDAPP_ERROR__INSTITUTION_MEMBERSHIP_MISSING
+  | typeof PRISMPAPERSDAPP_ERROR__INSTITUTION_NAME_INVALID
+  | typeof PRISMPAPERSDAPP_ERROR__INSTITUTION_SEATS_FULL
+  | typeof PRISMPAPERSDAPP_ERROR__INSTITUTION_SEATS_INVALID
   | typeof PRISMPAPERSDAPP_ERROR__INSUFFICIENT_FUNDS_IN_VAULT
   | typeof PRISMPAPERSDAPP_ERROR__INSUFFICIENT_FUNDS_IN_WALLET
   | typeof PRISMPAPERSDAPP_ERROR__INSUFFICIENT_USER_EARNINGS
+  | typeof PRISMPAPERSDAPP_ERROR__INVALID_ADMIN_SET
+  | typeof PRISMPAPERSDAPP_ERROR__INVALID_BUNDLE_ACCOUNTS
+  | typeof PRISMPAPERSDAPP_ERROR__INVALID_DELIVERY_ACCOUNTS
+  | typeof PRISMPAPERSDAPP_ERROR__INVALID_FEE
+  | typeof PRISMPAPERSDAPP_ERROR__INVALID_PAPER_STATUS
+  | typeof PRISMPAPERSDAPP_ERROR__KEY_ALREADY_DELIVERED
+  | typeof PRISMPAPERSDAPP_ERROR__KEY_DELIVERY_PAPER_MISMATCH
+  | typeof PRISMPAPERSDAPP_ERROR__KEY_NOT_DELIVERED
+  | typeof PRISMPAPERSDAPP_ERROR__KEY_REVISION_MISMATCH
+  | typeof PRISMPAPERSDAPP_ERROR__LICENSE_SEATS_EXCEEDED
   | typeof PRISMPAPERSDAPP_ERROR__MATH_OVERFLOW
+  | typeof PRISMPAPERSDAPP_ERROR__MIN_REPUTATION_INVALID
   | typeof PRISMPAPERSDAPP_ERROR__PAPER_DESCRIPTION_INVALID
+  | typeof PRISMPAPERSDAPP_ERROR__PAPER_IS_RETRACTED
+  | typeof PRISMPAPERSDAPP_ERROR__PAPER_NOT_CLOSABLE
+  | typeof PRISMPAPERSDAPP_ERROR__PAPER_NOT_CLOSED
+  | typeof PRISMPAPERSDAPP_ERROR__PAPER_NOT_FOR_SALE
   | typeof PRISMPAPERSDAPP_ERROR__PAPER_NOT_PURCHASED
+  | typeof PRISMPAPERSDAPP_ERROR__PAPER_NOT_UNDER_REVIEW
   | typeof PRISMPAPERSDAPP_ERROR__PAPER_TITLE_INVALID
   | typeof PRISMPAPERSDAPP_ERROR__PAPER_URL_EMPTY_OR_TOO_LONG
+  | typeof PRISMPAPERSDAPP_ERROR__PAYMENT_MINT_MISMATCH
+  | typeof PRISMPAPERSDAPP_ERROR__PLAN_DURATION_INVALID
+  | typeof PRISMPAPERSDAPP_ERROR__PLAN_INACTIVE
+  | typeof PRISMPAPERSDAPP_ERROR__PLAN_PAPERS_TOO_MANY
+  | typeof PRISMPAPERSDAPP_ERROR__PROGRAM_PAUSED
+  | typeof PRISMPAPERSDAPP_ERROR__RECEIPT_NOT_DISPUTED
+  | typeof PRISMPAPERSDAPP_ERROR__RECEIPT_NOT_ESCROWED
+  | typeof PRISMPAPERSDAPP_ERROR__RECEIPT_NOT_FINALIZED
+  | typeof PRISMPAPERSDAPP_ERROR__RECEIPT_PAPER_MISMATCH
+  | typeof PRISMPAPERSDAPP_ERROR__RECEIPT_REVOKED
+  | typeof PRISMPAPERSDAPP_ERROR__REFUND_WINDOW_CLOSED
+  | typeof PRISMPAPERSDAPP_ERROR__REFUND_WINDOW_INVALID
+  | typeof PRISMPAPERSDAPP_ERROR__REFUND_WINDOW_OPEN
+  | typeof PRISMPAPERSDAPP_ERROR__REPUTATION_TOO_LOW
   | typeof PRISMPAPERSDAPP_ERROR__RESEARCH_PRICE_INVALID
+  | typeof PRISMPAPERSDAPP_ERROR__RETRACTION_REASON_INVALID
+  | typeof PRISMPAPERSDAPP_ERROR__REVIEWER_ACCOUNT_MISSING
+  | typeof PRISMPAPERSDAPP_ERROR__REVIEWS_OUTSTANDING
+  | typeof PRISMPAPERSDAPP_ERROR__REVIEW_COOLDOWN_ACTIVE
+  | typeof PRISMPAPERSDAPP_ERROR__REVIEW_HASH_INVALID
+  | typeof PRISMPAPERSDAPP_ERROR__REVIEW_NOT_CLOSABLE
+  | typeof PRISMPAPERSDAPP_ERROR__REVIEW_NOT_FLAGGABLE
+  | typeof PRISMPAPERSDAPP_ERROR__REVIEW_NOT_NEGOTIATING
   | typeof PRISMPAPERSDAPP_ERROR__REVIEW_NOT_PENDING
+  | typeof PRISMPAPERSDAPP_ERROR__REVIEW_NOT_REVEALABLE
+  | typeof PRISMPAPERSDAPP_ERROR__REVIEW_PAPER_MISMATCH
+  | typeof PRISMPAPERSDAPP_ERROR__REVIEW_RESPONSE_WINDOW_INVALID
+  | typeof PRISMPAPERSDAPP_ERROR__REVIEW_RESPONSE_WINDOW_OPEN
+  | typeof PRISMPAPERSDAPP_ERROR__REVIEW_RESPONSE_WINDOW_PASSED
+  | typeof PRISMPAPERSDAPP_ERROR__REVIEW_ROUND_CLOSED
+  | typeof PRISMPAPERSDAPP_ERROR__REVIEW_ROUND_FINALIZED
+  | typeof PRISMPAPERSDAPP_ERROR__REVIEW_ROUND_HAS_REVIEWS
+  | typeof PRISMPAPERSDAPP_ERROR__REVIEW_ROUND_INVALID
+  | typeof PRISMPAPERSDAPP_ERROR__REVIEW_ROUND_MISMATCH
+  | typeof PRISMPAPERSDAPP_ERROR__REVIEW_ROUND_NOT_OVER
+  | typeof PRISMPAPERSDAPP_ERROR__REVIEW_SCORES_INVALID
+  | typeof PRISMPAPERSDAPP_ERROR__REVIEW_STAKE_INVALID
   | typeof PRISMPAPERSDAPP_ERROR__REVIEW_URL_EMPTY
+  | typeof PRISMPAPERSDAPP_ERROR__REVISION_NOT_COVERED
+  | typeof PRISMPAPERSDAPP_ERROR__REVISION_POLICY_LOCKED
+  | typeof PRISMPAPERSDAPP_ERROR__REWARD_EXCEEDS_BOUNTY
+  | typeof PRISMPAPERSDAPP_ERROR__SUBSCRIPTION_EXPIRED
+  | typeof PRISMPAPERSDAPP_ERROR__SUBSCRIPTION_NOT_COVERING
+  | typeof PRISMPAPERSDAPP_ERROR__SUBSCRIPTION_STILL_ACTIVE
+  | typeof PRISMPAPERSDAPP_ERROR__TOKEN_ACCOUNTS_MISSING
   | typeof PRISMPAPERSDAPP_ERROR__UNAUTHORIZED_ADMIN
+  | typeof PRISMPAPERSDAPP_ERROR__UNAUTHORIZED_INSTITUTION_ADMIN
+  | typeof PRISMPAPERSDAPP_ERROR__UNAUTHORIZED_REFUND
+  | typeof PRISMPAPERSDAPP_ERROR__UNAUTHORIZED_RETRACTION
   | typeof PRISMPAPERSDAPP_ERROR__UNAUTHORIZED_UPDATE
-  | typeof PRISMPAPERSDAPP_ERROR__USER_NAME_INVALID;
+  | typeof PRISMPAPERSDAPP_ERROR__USER_HAS_OPEN_ITEMS
+  | typeof PRISMPAPERSDAPP_ERROR__USER_NAME_INVALID
+  | typeof PRISMPAPERSDAPP_ERROR__USER_VAULT_NOT_EMPTY;
 
 let prismpapersdappErrorMessages:
   | Record<PrismpapersdappError, string>
@@ -81,23 +363,117 @@ if (process.env.NODE_ENV !== 'production') {
   prismpapersdappErrorMessages = {
     [PRISMPAPERSDAPP_ERROR__ALREADY_PURCHASED]: `You have already purchased this paper`,
     [PRISMPAPERSDAPP_ERROR__ALREADY_REVIEWED]: `You have already submitted a review for this paper`,
+    [PRISMPAPERSDAPP_ERROR__ANONYMOUS_REVIEWER_ACCOUNT]: `An anonymous review is credited to a User account only when it is revealed`,
+    [PRISMPAPERSDAPP_ERROR__ANONYMOUS_REVIEW_NOT_ALLOWED]: `Anonymous reviews need a non-zero review stake and cannot be submitted to papers with a minimum reputation`,
+    [PRISMPAPERSDAPP_ERROR__ANONYMOUS_TOKEN_REWARD]: `Anonymous rewards can only be escrowed in lamports`,
     [PRISMPAPERSDAPP_ERROR__AUTHOR_CANT_BUY_SELF]: `You cannot buy your own research paper`,
     [PRISMPAPERSDAPP_ERROR__AUTHOR_CANT_REVIEW_SELF]: `You cannot review your own research paper`,
-    [PRISMPAPERSDAPP_ERROR__ENCRYPTION_KEY_EMPTY_OR_TOO_LONG]: `Protocol Encryption Key cannot be empty`,
+    [PRISMPAPERSDAPP_ERROR__AUTO_ACCEPT_TOKEN_PAPER]: `Auto-accept can only pay rewards in lamports`,
+    [PRISMPAPERSDAPP_ERROR__BOUNTY_AMOUNT_INVALID]: `Bounty amount must be greater than zero`,
+    [PRISMPAPERSDAPP_ERROR__BOUNTY_DEADLINE_INVALID]: `Bounty deadline must be in the future and cannot be moved earlier`,
+    [PRISMPAPERSDAPP_ERROR__BOUNTY_EXPIRED]: `The review bounty for this paper has expired`,
+    [PRISMPAPERSDAPP_ERROR__BOUNTY_NOT_EMPTY]: `The paper's bounty escrow must be empty first`,
+    [PRISMPAPERSDAPP_ERROR__BOUNTY_STILL_ACTIVE]: `The bounty cannot be reclaimed before its deadline`,
+    [PRISMPAPERSDAPP_ERROR__BUNDLE_DISCOUNT_INVALID]: `Bundle discount must be below 100%`,
+    [PRISMPAPERSDAPP_ERROR__BUNDLE_INACTIVE]: `This bundle is not for sale`,
+    [PRISMPAPERSDAPP_ERROR__BUNDLE_MISMATCH]: `The papers passed do not match the bundle`,
+    [PRISMPAPERSDAPP_ERROR__BUNDLE_PAPERS_INVALID]: `A bundle needs between 1 and 10 papers`,
+    [PRISMPAPERSDAPP_ERROR__BUNDLE_TOKEN_PAPER]: `Papers priced in an SPL token must be bought individually`,
+    [PRISMPAPERSDAPP_ERROR__BUYER_ACCOUNT_MISSING]: `The buyer's user account is required to refund a personal purchase`,
+    [PRISMPAPERSDAPP_ERROR__BUYER_ENCRYPTION_KEY_INVALID]: `Buyer encryption public key cannot be empty`,
+    [PRISMPAPERSDAPP_ERROR__CONTENT_HASH_INVALID]: `Content hash cannot be all zeroes`,
+    [PRISMPAPERSDAPP_ERROR__COUNTER_OFFER_INVALID]: `A counter-offer must be lower than the proposed reward`,
+    [PRISMPAPERSDAPP_ERROR__CO_AUTHORS_INVALID]: `Co-authors must include the author once each, up to 5, with positive shares totalling 10000 bps`,
+    [PRISMPAPERSDAPP_ERROR__CO_AUTHOR_ACCOUNTS_INVALID]: `Co-author accounts are missing or do not match the paper's co-authors`,
+    [PRISMPAPERSDAPP_ERROR__CO_AUTHOR_SIGNATURE_MISSING]: `Every co-author must sign to join or change the revenue split`,
+    [PRISMPAPERSDAPP_ERROR__DELIVERIES_OUTSTANDING]: `All purchased keys must be delivered first`,
+    [PRISMPAPERSDAPP_ERROR__DISPUTE_WINDOW_OPEN]: `The author or an admin can still resolve this dispute`,
+    [PRISMPAPERSDAPP_ERROR__ENCRYPTION_KEY_EMPTY_OR_TOO_LONG]: `Wrapped content key cannot be empty or too long`,
+    [PRISMPAPERSDAPP_ERROR__ESCROW_NOT_EMPTY]: `Escrowed purchase payments must be settled or refunded first`,
+    [PRISMPAPERSDAPP_ERROR__IDENTITY_COMMITMENT_INVALID]: `Identity commitment cannot be empty`,
+    [PRISMPAPERSDAPP_ERROR__IDENTITY_COMMITMENT_MISMATCH]: `The wallet and salt do not match the review's identity commitment`,
+    [PRISMPAPERSDAPP_ERROR__INSTITUTION_MEMBERSHIP_INVALID]: `This membership does not belong to the licensed institution`,
+    [PRISMPAPERSDAPP_ERROR__INSTITUTION_MEMBERSHIP_MISSING]: `Institutional licenses need the institution and a membership proof`,
+    [PRISMPAPERSDAPP_ERROR__INSTITUTION_NAME_INVALID]: `Institution name must be between 1 and 100 characters`,
+    [PRISMPAPERSDAPP_ERROR__INSTITUTION_SEATS_FULL]: `Every seat at this institution is taken`,
+    [PRISMPAPERSDAPP_ERROR__INSTITUTION_SEATS_INVALID]: `An institution needs at least one seat, and no fewer than its members`,
     [PRISMPAPERSDAPP_ERROR__INSUFFICIENT_FUNDS_IN_VAULT]: `The vault does not have enough SOL to fulfill this request`,
     [PRISMPAPERSDAPP_ERROR__INSUFFICIENT_FUNDS_IN_WALLET]: `The buyer/reviewer/author does not have enough SOL in their wallet`,
     [PRISMPAPERSDAPP_ERROR__INSUFFICIENT_USER_EARNINGS]: `User does not have enough accrued earnings for this withdrawal`,
+    [PRISMPAPERSDAPP_ERROR__INVALID_ADMIN_SET]: `Admin set cannot be empty or exceed the maximum number of admins`,
+    [PRISMPAPERSDAPP_ERROR__INVALID_BUNDLE_ACCOUNTS]: `Bundle accounts must come as (paper, receipt, author user, author vault) groups plus co-author pairs`,
+    [PRISMPAPERSDAPP_ERROR__INVALID_DELIVERY_ACCOUNTS]: `Key deliveries must be passed as (receipt, key delivery) account pairs`,
+    [PRISMPAPERSDAPP_ERROR__INVALID_FEE]: `Fee basis points cannot exceed 10000`,
+    [PRISMPAPERSDAPP_ERROR__INVALID_PAPER_STATUS]: `This action is not allowed in the paper's current status`,
+    [PRISMPAPERSDAPP_ERROR__KEY_ALREADY_DELIVERED]: `The content key has already been delivered for this receipt`,
+    [PRISMPAPERSDAPP_ERROR__KEY_DELIVERY_PAPER_MISMATCH]: `The key delivery does not belong to this research paper`,
+    [PRISMPAPERSDAPP_ERROR__KEY_NOT_DELIVERED]: `The content key for this receipt has not been delivered yet`,
+    [PRISMPAPERSDAPP_ERROR__KEY_REVISION_MISMATCH]: `The key must be for the revision the buyer is owed`,
+    [PRISMPAPERSDAPP_ERROR__LICENSE_SEATS_EXCEEDED]: `The institution has more members than this license paid for`,
     [PRISMPAPERSDAPP_ERROR__MATH_OVERFLOW]: `Mathematical Operation Overflow`,
+    [PRISMPAPERSDAPP_ERROR__MIN_REPUTATION_INVALID]: `Minimum reputation cannot exceed 10000 bps`,
     [PRISMPAPERSDAPP_ERROR__PAPER_DESCRIPTION_INVALID]: `Research Paper Description cannot be empty or too long`,
+    [PRISMPAPERSDAPP_ERROR__PAPER_IS_RETRACTED]: `This paper has been retracted`,
+    [PRISMPAPERSDAPP_ERROR__PAPER_NOT_CLOSABLE]: `Only unsold or retracted papers can be closed`,
+    [PRISMPAPERSDAPP_ERROR__PAPER_NOT_CLOSED]: `The paper must be closed first`,
+    [PRISMPAPERSDAPP_ERROR__PAPER_NOT_FOR_SALE]: `This paper is not published for sale`,
     [PRISMPAPERSDAPP_ERROR__PAPER_NOT_PURCHASED]: `You must purchase the paper before reviewing it`,
+    [PRISMPAPERSDAPP_ERROR__PAPER_NOT_UNDER_REVIEW]: `This paper is not open for peer review`,
     [PRISMPAPERSDAPP_ERROR__PAPER_TITLE_INVALID]: `Research Paper Title cannot be empty or too long`,
     [PRISMPAPERSDAPP_ERROR__PAPER_URL_EMPTY_OR_TOO_LONG]: `Research Paper URL/CID cannot be empty`,
+    [PRISMPAPERSDAPP_ERROR__PAYMENT_MINT_MISMATCH]: `The payment mint does not match the paper's accepted mint`,
+    [PRISMPAPERSDAPP_ERROR__PLAN_DURATION_INVALID]: `Subscription duration must be positive`,
+    [PRISMPAPERSDAPP_ERROR__PLAN_INACTIVE]: `This subscription plan is not active`,
+    [PRISMPAPERSDAPP_ERROR__PLAN_PAPERS_TOO_MANY]: `Subscription plan lists too many papers`,
+    [PRISMPAPERSDAPP_ERROR__PROGRAM_PAUSED]: `The program is paused by the platform admins`,
+    [PRISMPAPERSDAPP_ERROR__RECEIPT_NOT_DISPUTED]: `No refund has been requested for this purchase`,
+    [PRISMPAPERSDAPP_ERROR__RECEIPT_NOT_ESCROWED]: `This purchase is not held in escrow`,
+    [PRISMPAPERSDAPP_ERROR__RECEIPT_NOT_FINALIZED]: `This purchase has not been settled or refunded yet`,
+    [PRISMPAPERSDAPP_ERROR__RECEIPT_PAPER_MISMATCH]: `The access receipt does not belong to this research paper`,
+    [PRISMPAPERSDAPP_ERROR__RECEIPT_REVOKED]: `This access receipt has been revoked`,
+    [PRISMPAPERSDAPP_ERROR__REFUND_WINDOW_CLOSED]: `The refund window for this purchase has closed`,
+    [PRISMPAPERSDAPP_ERROR__REFUND_WINDOW_INVALID]: `Refund window cannot be negative`,
+    [PRISMPAPERSDAPP_ERROR__REFUND_WINDOW_OPEN]: `The refund window for this purchase is still open`,
+    [PRISMPAPERSDAPP_ERROR__REPUTATION_TOO_LOW]: `Reviewer reputation is below the paper's minimum`,
     [PRISMPAPERSDAPP_ERROR__RESEARCH_PRICE_INVALID]: `Price must be greater than zero`,
+    [PRISMPAPERSDAPP_ERROR__RETRACTION_REASON_INVALID]: `Retraction reason code cannot be zero`,
+    [PRISMPAPERSDAPP_ERROR__REVIEWER_ACCOUNT_MISSING]: `The reviewer's user account is required for a named review`,
+    [PRISMPAPERSDAPP_ERROR__REVIEWS_OUTSTANDING]: `Every pending or negotiating review must be decided first`,
+    [PRISMPAPERSDAPP_ERROR__REVIEW_COOLDOWN_ACTIVE]: `Rejected reviews can only be closed after the cooling period`,
+    [PRISMPAPERSDAPP_ERROR__REVIEW_HASH_INVALID]: `Review hash cannot be all zeroes`,
+    [PRISMPAPERSDAPP_ERROR__REVIEW_NOT_CLOSABLE]: `Only rejected or flagged reviews can be closed`,
+    [PRISMPAPERSDAPP_ERROR__REVIEW_NOT_FLAGGABLE]: `Only pending reviews, or rejected ones still in their cooldown, can be flagged`,
+    [PRISMPAPERSDAPP_ERROR__REVIEW_NOT_NEGOTIATING]: `This review has no counter-offer to respond to`,
     [PRISMPAPERSDAPP_ERROR__REVIEW_NOT_PENDING]: `This review has already been processed (Accepted/Rejected)`,
+    [PRISMPAPERSDAPP_ERROR__REVIEW_NOT_REVEALABLE]: `Only an anonymous review that the author has decided on can be revealed, and only once`,
+    [PRISMPAPERSDAPP_ERROR__REVIEW_PAPER_MISMATCH]: `The review does not belong to this research paper`,
+    [PRISMPAPERSDAPP_ERROR__REVIEW_RESPONSE_WINDOW_INVALID]: `Review response window must be between 1 second and 90 days`,
+    [PRISMPAPERSDAPP_ERROR__REVIEW_RESPONSE_WINDOW_OPEN]: `The author can still respond to this review`,
+    [PRISMPAPERSDAPP_ERROR__REVIEW_RESPONSE_WINDOW_PASSED]: `The author's response window for this review has passed`,
+    [PRISMPAPERSDAPP_ERROR__REVIEW_ROUND_CLOSED]: `The review round is closed to new reviews`,
+    [PRISMPAPERSDAPP_ERROR__REVIEW_ROUND_FINALIZED]: `This review round has already been finalized`,
+    [PRISMPAPERSDAPP_ERROR__REVIEW_ROUND_HAS_REVIEWS]: `A round that has reviews can only be finalized`,
+    [PRISMPAPERSDAPP_ERROR__REVIEW_ROUND_INVALID]: `Round needs 1 <= quorum <= target reviewers, an approval threshold of at most 10000 bps and a future deadline`,
+    [PRISMPAPERSDAPP_ERROR__REVIEW_ROUND_MISMATCH]: `Review round does not match the paper or the review`,
+    [PRISMPAPERSDAPP_ERROR__REVIEW_ROUND_NOT_OVER]: `The review round cannot be finalized before its deadline unless it is full`,
+    [PRISMPAPERSDAPP_ERROR__REVIEW_SCORES_INVALID]: `Review scores and confidence must be between 1 and 5`,
+    [PRISMPAPERSDAPP_ERROR__REVIEW_STAKE_INVALID]: `Review stake must be 0 or at least the rent-exempt minimum of an empty account`,
     [PRISMPAPERSDAPP_ERROR__REVIEW_URL_EMPTY]: `Review Link/CID cannot be empty`,
+    [PRISMPAPERSDAPP_ERROR__REVISION_NOT_COVERED]: `This receipt does not grant access to the paper's current revision`,
+    [PRISMPAPERSDAPP_ERROR__REVISION_POLICY_LOCKED]: `The revision policy cannot change once the paper has buyers`,
+    [PRISMPAPERSDAPP_ERROR__REWARD_EXCEEDS_BOUNTY]: `The proposed reward exceeds the paper's remaining bounty`,
+    [PRISMPAPERSDAPP_ERROR__SUBSCRIPTION_EXPIRED]: `This subscription has expired`,
+    [PRISMPAPERSDAPP_ERROR__SUBSCRIPTION_NOT_COVERING]: `This subscription does not cover the paper`,
+    [PRISMPAPERSDAPP_ERROR__SUBSCRIPTION_STILL_ACTIVE]: `This subscription has not expired yet`,
+    [PRISMPAPERSDAPP_ERROR__TOKEN_ACCOUNTS_MISSING]: `Token accounts are required for papers priced in an SPL token`,
     [PRISMPAPERSDAPP_ERROR__UNAUTHORIZED_ADMIN]: `You are not authorized to perform this action (Admin Only)`,
+    [PRISMPAPERSDAPP_ERROR__UNAUTHORIZED_INSTITUTION_ADMIN]: `Only the institution admin can do this`,
+    [PRISMPAPERSDAPP_ERROR__UNAUTHORIZED_REFUND]: `Only the author or a platform admin can resolve this refund`,
+    [PRISMPAPERSDAPP_ERROR__UNAUTHORIZED_RETRACTION]: `Only the author or a platform admin can retract this paper`,
     [PRISMPAPERSDAPP_ERROR__UNAUTHORIZED_UPDATE]: `Only the original author can update this paper`,
+    [PRISMPAPERSDAPP_ERROR__USER_HAS_OPEN_ITEMS]: `The user still has open papers, pending reviews or rejected reviews to close`,
     [PRISMPAPERSDAPP_ERROR__USER_NAME_INVALID]: `User name cannot be empty or too long`,
+    [PRISMPAPERSDAPP_ERROR__USER_VAULT_NOT_EMPTY]: `The user's earnings must be withdrawn first`,
   };
 }
 
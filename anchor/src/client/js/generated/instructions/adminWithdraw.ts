@@ -10,6 +10,7 @@ import {
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
+  getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getProgramDerivedAddress,
@@ -34,7 +35,11 @@ import {
   type WritableSignerAccount,
 } from 'gill';
 import { PRISMPAPERSDAPP_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const ADMIN_WITHDRAW_DISCRIMINATOR = new Uint8Array([
   160, 166, 147, 222, 46, 220, 75, 224,
@@ -49,7 +54,16 @@ export function getAdminWithdrawDiscriminatorBytes() {
 export type AdminWithdrawInstruction<
   TProgram extends string = typeof PRISMPAPERSDAPP_PROGRAM_ADDRESS,
   TAccountAdmin extends string | AccountMeta<string> = string,
+  TAccountConfig extends string | AccountMeta<string> = string,
   TAccountAdminVault extends string | AccountMeta<string> = string,
+  TAccountTreasury extends string | AccountMeta<string> = string,
+  TAccountPaymentMint extends string | AccountMeta<string> = string,
+  TAccountAdminTokenVault extends string | AccountMeta<string> = string,
+  TAccountTreasuryTokenAccount extends string | AccountMeta<string> = string,
+  TAccountTokenProgram extends string | AccountMeta<string> = string,
+  TAccountAssociatedTokenProgram extends
+    | string
+    | AccountMeta<string> = 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL',
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
@@ -62,9 +76,30 @@ export type AdminWithdrawInstruction<
         ? WritableSignerAccount<TAccountAdmin> &
             AccountSignerMeta<TAccountAdmin>
         : TAccountAdmin,
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
       TAccountAdminVault extends string
         ? WritableAccount<TAccountAdminVault>
         : TAccountAdminVault,
+      TAccountTreasury extends string
+        ? WritableAccount<TAccountTreasury>
+        : TAccountTreasury,
+      TAccountPaymentMint extends string
+        ? ReadonlyAccount<TAccountPaymentMint>
+        : TAccountPaymentMint,
+      TAccountAdminTokenVault extends string
+        ? WritableAccount<TAccountAdminTokenVault>
+        : TAccountAdminTokenVault,
+      TAccountTreasuryTokenAccount extends string
+        ? WritableAccount<TAccountTreasuryTokenAccount>
+        : TAccountTreasuryTokenAccount,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      TAccountAssociatedTokenProgram extends string
+        ? ReadonlyAccount<TAccountAssociatedTokenProgram>
+        : TAccountAssociatedTokenProgram,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -108,24 +143,52 @@ export function getAdminWithdrawInstructionDataCodec(): FixedSizeCodec<
 
 export type AdminWithdrawAsyncInput<
   TAccountAdmin extends string = string,
+  TAccountConfig extends string = string,
   TAccountAdminVault extends string = string,
+  TAccountTreasury extends string = string,
+  TAccountPaymentMint extends string = string,
+  TAccountAdminTokenVault extends string = string,
+  TAccountTreasuryTokenAccount extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountAssociatedTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   admin: TransactionSigner<TAccountAdmin>;
+  config?: Address<TAccountConfig>;
   adminVault?: Address<TAccountAdminVault>;
+  treasury: Address<TAccountTreasury>;
+  paymentMint?: Address<TAccountPaymentMint>;
+  adminTokenVault?: Address<TAccountAdminTokenVault>;
+  treasuryTokenAccount?: Address<TAccountTreasuryTokenAccount>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
   amount: AdminWithdrawInstructionDataArgs['amount'];
 };
 
 export async function getAdminWithdrawInstructionAsync<
   TAccountAdmin extends string,
+  TAccountConfig extends string,
   TAccountAdminVault extends string,
+  TAccountTreasury extends string,
+  TAccountPaymentMint extends string,
+  TAccountAdminTokenVault extends string,
+  TAccountTreasuryTokenAccount extends string,
+  TAccountTokenProgram extends string,
+  TAccountAssociatedTokenProgram extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof PRISMPAPERSDAPP_PROGRAM_ADDRESS,
 >(
   input: AdminWithdrawAsyncInput<
     TAccountAdmin,
+    TAccountConfig,
     TAccountAdminVault,
+    TAccountTreasury,
+    TAccountPaymentMint,
+    TAccountAdminTokenVault,
+    TAccountTreasuryTokenAccount,
+    TAccountTokenProgram,
+    TAccountAssociatedTokenProgram,
     TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
@@ -133,7 +196,14 @@ export async function getAdminWithdrawInstructionAsync<
   AdminWithdrawInstruction<
     TProgramAddress,
     TAccountAdmin,
+    TAccountConfig,
     TAccountAdminVault,
+    TAccountTreasury,
+    TAccountPaymentMint,
+    TAccountAdminTokenVault,
+    TAccountTreasuryTokenAccount,
+    TAccountTokenProgram,
+    TAccountAssociatedTokenProgram,
     TAccountSystemProgram
   >
 > {
@@ -144,7 +214,20 @@ export async function getAdminWithdrawInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     admin: { value: input.admin ?? null, isWritable: true },
+    config: { value: input.config ?? null, isWritable: false },
     adminVault: { value: input.adminVault ?? null, isWritable: true },
+    treasury: { value: input.treasury ?? null, isWritable: true },
+    paymentMint: { value: input.paymentMint ?? null, isWritable: false },
+    adminTokenVault: { value: input.adminTokenVault ?? null, isWritable: true },
+    treasuryTokenAccount: {
+      value: input.treasuryTokenAccount ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    associatedTokenProgram: {
+      value: input.associatedTokenProgram ?? null,
+      isWritable: false,
+    },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -156,6 +239,14 @@ export async function getAdminWithdrawInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(new Uint8Array([99, 111, 110, 102, 105, 103])),
+      ],
+    });
+  }
   if (!accounts.adminVault.value) {
     accounts.adminVault.value = await getProgramDerivedAddress({
       programAddress,
@@ -166,6 +257,32 @@ export async function getAdminWithdrawInstructionAsync<
       ],
     });
   }
+  if (!accounts.adminTokenVault.value) {
+    accounts.adminTokenVault.value = await getProgramDerivedAddress({
+      programAddress:
+        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>,
+      seeds: [
+        getAddressEncoder().encode(expectAddress(accounts.adminVault.value)),
+        getAddressEncoder().encode(expectAddress(accounts.tokenProgram.value)),
+        getAddressEncoder().encode(expectAddress(accounts.paymentMint.value)),
+      ],
+    });
+  }
+  if (!accounts.treasuryTokenAccount.value) {
+    accounts.treasuryTokenAccount.value = await getProgramDerivedAddress({
+      programAddress:
+        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>,
+      seeds: [
+        getAddressEncoder().encode(expectAddress(accounts.treasury.value)),
+        getAddressEncoder().encode(expectAddress(accounts.tokenProgram.value)),
+        getAddressEncoder().encode(expectAddress(accounts.paymentMint.value)),
+      ],
+    });
+  }
+  if (!accounts.associatedTokenProgram.value) {
+    accounts.associatedTokenProgram.value =
+      'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>;
+  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -175,7 +292,14 @@ export async function getAdminWithdrawInstructionAsync<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.config),
       getAccountMeta(accounts.adminVault),
+      getAccountMeta(accounts.treasury),
+      getAccountMeta(accounts.paymentMint),
+      getAccountMeta(accounts.adminTokenVault),
+      getAccountMeta(accounts.treasuryTokenAccount),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.systemProgram),
     ],
     data: getAdminWithdrawInstructionDataEncoder().encode(
@@ -185,38 +309,80 @@ export async function getAdminWithdrawInstructionAsync<
   } as AdminWithdrawInstruction<
     TProgramAddress,
     TAccountAdmin,
+    TAccountConfig,
     TAccountAdminVault,
+    TAccountTreasury,
+    TAccountPaymentMint,
+    TAccountAdminTokenVault,
+    TAccountTreasuryTokenAccount,
+    TAccountTokenProgram,
+    TAccountAssociatedTokenProgram,
     TAccountSystemProgram
   >);
 }
 
 export type AdminWithdrawInput<
   TAccountAdmin extends string = string,
+  TAccountConfig extends string = string,
   TAccountAdminVault extends string = string,
+  TAccountTreasury extends string = string,
+  TAccountPaymentMint extends string = string,
+  TAccountAdminTokenVault extends string = string,
+  TAccountTreasuryTokenAccount extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountAssociatedTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   admin: TransactionSigner<TAccountAdmin>;
+  config: Address<TAccountConfig>;
   adminVault: Address<TAccountAdminVault>;
+  treasury: Address<TAccountTreasury>;
+  paymentMint?: Address<TAccountPaymentMint>;
+  adminTokenVault?: Address<TAccountAdminTokenVault>;
+  treasuryTokenAccount?: Address<TAccountTreasuryTokenAccount>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
   amount: AdminWithdrawInstructionDataArgs['amount'];
 };
 
 export function getAdminWithdrawInstruction<
   TAccountAdmin extends string,
+  TAccountConfig extends string,
   TAccountAdminVault extends string,
+  TAccountTreasury extends string,
+  TAccountPaymentMint extends string,
+  TAccountAdminTokenVault extends string,
+  TAccountTreasuryTokenAccount extends string,
+  TAccountTokenProgram extends string,
+  TAccountAssociatedTokenProgram extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof PRISMPAPERSDAPP_PROGRAM_ADDRESS,
 >(
   input: AdminWithdrawInput<
     TAccountAdmin,
+    TAccountConfig,
     TAccountAdminVault,
+    TAccountTreasury,
+    TAccountPaymentMint,
+    TAccountAdminTokenVault,
+    TAccountTreasuryTokenAccount,
+    TAccountTokenProgram,
+    TAccountAssociatedTokenProgram,
     TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): AdminWithdrawInstruction<
   TProgramAddress,
   TAccountAdmin,
+  TAccountConfig,
   TAccountAdminVault,
+  TAccountTreasury,
+  TAccountPaymentMint,
+  TAccountAdminTokenVault,
+  TAccountTreasuryTokenAccount,
+  TAccountTokenProgram,
+  TAccountAssociatedTokenProgram,
   TAccountSystemProgram
 > {
   // Program address.
@@ -226,7 +392,20 @@ export function getAdminWithdrawInstruction<
   // Original accounts.
   const originalAccounts = {
     admin: { value: input.admin ?? null, isWritable: true },
+    config: { value: input.config ?? null, isWritable: false },
     adminVault: { value: input.adminVault ?? null, isWritable: true },
+    treasury: { value: input.treasury ?? null, isWritable: true },
+    paymentMint: { value: input.paymentMint ?? null, isWritable: false },
+    adminTokenVault: { value: input.adminTokenVault ?? null, isWritable: true },
+    treasuryTokenAccount: {
+      value: input.treasuryTokenAccount ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    associatedTokenProgram: {
+      value: input.associatedTokenProgram ?? null,
+      isWritable: false,
+    },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -238,6 +417,10 @@ export function getAdminWithdrawInstruction<
   const args = { ...input };
 
   // Resolve default values.
+  if (!accounts.associatedTokenProgram.value) {
+    accounts.associatedTokenProgram.value =
+      'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>;
+  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -247,7 +430,14 @@ export function getAdminWithdrawInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.config),
       getAccountMeta(accounts.adminVault),
+      getAccountMeta(accounts.treasury),
+      getAccountMeta(accounts.paymentMint),
+      getAccountMeta(accounts.adminTokenVault),
+      getAccountMeta(accounts.treasuryTokenAccount),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.systemProgram),
     ],
     data: getAdminWithdrawInstructionDataEncoder().encode(
@@ -257,7 +447,14 @@ export function getAdminWithdrawInstruction<
   } as AdminWithdrawInstruction<
     TProgramAddress,
     TAccountAdmin,
+    TAccountConfig,
     TAccountAdminVault,
+    TAccountTreasury,
+    TAccountPaymentMint,
+    TAccountAdminTokenVault,
+    TAccountTreasuryTokenAccount,
+    TAccountTokenProgram,
+    TAccountAssociatedTokenProgram,
     TAccountSystemProgram
   >);
 }
@@ -269,8 +466,15 @@ export type ParsedAdminWithdrawInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     admin: TAccountMetas[0];
-    adminVault: TAccountMetas[1];
-    systemProgram: TAccountMetas[2];
+    config: TAccountMetas[1];
+    adminVault: TAccountMetas[2];
+    treasury: TAccountMetas[3];
+    paymentMint?: TAccountMetas[4] | undefined;
+    adminTokenVault?: TAccountMetas[5] | undefined;
+    treasuryTokenAccount?: TAccountMetas[6] | undefined;
+    tokenProgram?: TAccountMetas[7] | undefined;
+    associatedTokenProgram?: TAccountMetas[8] | undefined;
+    systemProgram: TAccountMetas[9];
   };
   data: AdminWithdrawInstructionData;
 };
@@ -283,7 +487,7 @@ export function parseAdminWithdrawInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedAdminWithdrawInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 3) {
+  if (instruction.accounts.length < 10) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -293,11 +497,24 @@ export function parseAdminWithdrawInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === PRISMPAPERSDAPP_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       admin: getNextAccount(),
+      config: getNextAccount(),
       adminVault: getNextAccount(),
+      treasury: getNextAccount(),
+      paymentMint: getNextOptionalAccount(),
+      adminTokenVault: getNextOptionalAccount(),
+      treasuryTokenAccount: getNextOptionalAccount(),
+      tokenProgram: getNextOptionalAccount(),
+      associatedTokenProgram: getNextOptionalAccount(),
       systemProgram: getNextAccount(),
     },
     data: getAdminWithdrawInstructionDataDecoder().decode(instruction.data),
@@ -6,11 +6,58 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './addInstitutionMember';
 export * from './adminWithdraw';
+export * from './approveRefund';
+export * from './arbitrateRefund';
+export * from './closeKeyDelivery';
+export * from './closeLicenseReceipt';
+export * from './closePaper';
+export * from './closeReceipt';
+export * from './closeReview';
+export * from './closeReviewRound';
+export * from './closeRevision';
+export * from './closeSubscription';
+export * from './closeUser';
+export * from './counterOfferReview';
+export * from './createBundle';
+export * from './createPlan';
+export * from './deliverKey';
+export * from './deliverKeys';
+export * from './deliverSubscriptionKey';
+export * from './expireReview';
+export * from './finalizeReviewRound';
+export * from './flagReview';
+export * from './fundBounty';
+export * from './initInstitution';
 export * from './initResearch';
 export * from './initUser';
+export * from './initializeConfig';
+export * from './lapseRefund';
+export * from './openReviewRound';
+export * from './publishPaper';
 export * from './purchaseAccess';
+export * from './purchaseBundle';
+export * from './purchaseLicense';
+export * from './reclaimBounty';
+export * from './removeInstitutionMember';
+export * from './requestRefund';
+export * from './respondToCounterOffer';
+export * from './retractPaper';
+export * from './revealReview';
 export * from './reviewPaper';
+export * from './reviewPaperAnonymously';
+export * from './setCoAuthors';
+export * from './setInstitutionSeats';
+export * from './setMinReputation';
+export * from './setReviewResponsePolicy';
+export * from './setReviewStake';
+export * from './setRevisionPolicy';
+export * from './settlePurchase';
+export * from './subscribe';
+export * from './updateBundle';
+export * from './updateConfig';
+export * from './updatePlan';
 export * from './updateResearch';
 export * from './userWithdraw';
 export * from './verifyReview';
@@ -13,8 +13,12 @@ import {
   fixDecoderSize,
   fixEncoderSize,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
@@ -22,6 +26,8 @@ import {
   getU32Encoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   getUtf8Decoder,
   getUtf8Encoder,
   transformEncoder,
@@ -34,6 +40,8 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
@@ -46,6 +54,12 @@ import {
   getAccountMetaFactory,
   type ResolvedAccount,
 } from '../shared';
+import {
+  getCoAuthorDecoder,
+  getCoAuthorEncoder,
+  type CoAuthor,
+  type CoAuthorArgs,
+} from '../types';
 
 export const INIT_RESEARCH_DISCRIMINATOR = new Uint8Array([
   244, 75, 131, 90, 164, 217, 220, 42,
@@ -60,8 +74,11 @@ export function getInitResearchDiscriminatorBytes() {
 export type InitResearchInstruction<
   TProgram extends string = typeof PRISMPAPERSDAPP_PROGRAM_ADDRESS,
   TAccountAuthor extends string | AccountMeta<string> = string,
-  TAccountResearchPaper extends string | AccountMeta<string> = string,
   TAccountUserAccount extends string | AccountMeta<string> = string,
+  TAccountPaperCounter extends string | AccountMeta<string> = string,
+  TAccountResearchPaper extends string | AccountMeta<string> = string,
+  TAccountPaperRevision extends string | AccountMeta<string> = string,
+  TAccountPaymentMint extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
@@ -74,12 +91,21 @@ export type InitResearchInstruction<
         ? WritableSignerAccount<TAccountAuthor> &
             AccountSignerMeta<TAccountAuthor>
         : TAccountAuthor,
-      TAccountResearchPaper extends string
-        ? WritableAccount<TAccountResearchPaper>
-        : TAccountResearchPaper,
       TAccountUserAccount extends string
         ? WritableAccount<TAccountUserAccount>
         : TAccountUserAccount,
+      TAccountPaperCounter extends string
+        ? WritableAccount<TAccountPaperCounter>
+        : TAccountPaperCounter,
+      TAccountResearchPaper extends string
+        ? WritableAccount<TAccountResearchPaper>
+        : TAccountResearchPaper,
+      TAccountPaperRevision extends string
+        ? WritableAccount<TAccountPaperRevision>
+        : TAccountPaperRevision,
+      TAccountPaymentMint extends string
+        ? ReadonlyAccount<TAccountPaymentMint>
+        : TAccountPaymentMint,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -93,7 +119,9 @@ export type InitResearchInstructionData = {
   description: string;
   price: bigint;
   encryptedUrl: string;
-  encryptionKey: string;
+  contentHash: Array<number>;
+  ciphertextHash: Option<Array<number>>;
+  coAuthors: Array<CoAuthor>;
 };
 
 export type InitResearchInstructionDataArgs = {
@@ -101,7 +129,9 @@ export type InitResearchInstructionDataArgs = {
   description: string;
   price: number | bigint;
   encryptedUrl: string;
-  encryptionKey: string;
+  contentHash: Array<number>;
+  ciphertextHash: OptionOrNullable<Array<number>>;
+  coAuthors: Array<CoAuthorArgs>;
 };
 
 export function getInitResearchInstructionDataEncoder(): Encoder<InitResearchInstructionDataArgs> {
@@ -112,10 +142,12 @@ export function getInitResearchInstructionDataEncoder(): Encoder<InitResearchIns
       ['description', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ['price', getU64Encoder()],
       ['encryptedUrl', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      ['contentHash', getArrayEncoder(getU8Encoder(), { size: 32 })],
       [
-        'encryptionKey',
-        addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder()),
+        'ciphertextHash',
+        getOptionEncoder(getArrayEncoder(getU8Encoder(), { size: 32 })),
       ],
+      ['coAuthors', getArrayEncoder(getCoAuthorEncoder())],
     ]),
     (value) => ({ ...value, discriminator: INIT_RESEARCH_DISCRIMINATOR })
   );
@@ -128,7 +160,12 @@ export function getInitResearchInstructionDataDecoder(): Decoder<InitResearchIns
     ['description', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ['price', getU64Decoder()],
     ['encryptedUrl', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
-    ['encryptionKey', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ['contentHash', getArrayDecoder(getU8Decoder(), { size: 32 })],
+    [
+      'ciphertextHash',
+      getOptionDecoder(getArrayDecoder(getU8Decoder(), { size: 32 })),
+    ],
+    ['coAuthors', getArrayDecoder(getCoAuthorDecoder())],
   ]);
 }
 
@@ -144,32 +181,46 @@ export function getInitResearchInstructionDataCodec(): Codec<
 
 export type InitResearchAsyncInput<
   TAccountAuthor extends string = string,
-  TAccountResearchPaper extends string = string,
   TAccountUserAccount extends string = string,
+  TAccountPaperCounter extends string = string,
+  TAccountResearchPaper extends string = string,
+  TAccountPaperRevision extends string = string,
+  TAccountPaymentMint extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   author: TransactionSigner<TAccountAuthor>;
-  researchPaper?: Address<TAccountResearchPaper>;
-  userAccount: Address<TAccountUserAccount>;
+  userAccount?: Address<TAccountUserAccount>;
+  paperCounter?: Address<TAccountPaperCounter>;
+  researchPaper: Address<TAccountResearchPaper>;
+  paperRevision?: Address<TAccountPaperRevision>;
+  paymentMint?: Address<TAccountPaymentMint>;
   systemProgram?: Address<TAccountSystemProgram>;
   title: InitResearchInstructionDataArgs['title'];
   description: InitResearchInstructionDataArgs['description'];
   price: InitResearchInstructionDataArgs['price'];
   encryptedUrl: InitResearchInstructionDataArgs['encryptedUrl'];
-  encryptionKey: InitResearchInstructionDataArgs['encryptionKey'];
+  contentHash: InitResearchInstructionDataArgs['contentHash'];
+  ciphertextHash: InitResearchInstructionDataArgs['ciphertextHash'];
+  coAuthors: InitResearchInstructionDataArgs['coAuthors'];
 };
 
 export async function getInitResearchInstructionAsync<
   TAccountAuthor extends string,
-  TAccountResearchPaper extends string,
   TAccountUserAccount extends string,
+  TAccountPaperCounter extends string,
+  TAccountResearchPaper extends string,
+  TAccountPaperRevision extends string,
+  TAccountPaymentMint extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof PRISMPAPERSDAPP_PROGRAM_ADDRESS,
 >(
   input: InitResearchAsyncInput<
     TAccountAuthor,
-    TAccountResearchPaper,
     TAccountUserAccount,
+    TAccountPaperCounter,
+    TAccountResearchPaper,
+    TAccountPaperRevision,
+    TAccountPaymentMint,
     TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
@@ -177,8 +228,11 @@ export async function getInitResearchInstructionAsync<
   InitResearchInstruction<
     TProgramAddress,
     TAccountAuthor,
-    TAccountResearchPaper,
     TAccountUserAccount,
+    TAccountPaperCounter,
+    TAccountResearchPaper,
+    TAccountPaperRevision,
+    TAccountPaymentMint,
     TAccountSystemProgram
   >
 > {
@@ -189,8 +243,11 @@ export async function getInitResearchInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     author: { value: input.author ?? null, isWritable: true },
-    researchPaper: { value: input.researchPaper ?? null, isWritable: true },
     userAccount: { value: input.userAccount ?? null, isWritable: true },
+    paperCounter: { value: input.paperCounter ?? null, isWritable: true },
+    researchPaper: { value: input.researchPaper ?? null, isWritable: true },
+    paperRevision: { value: input.paperRevision ?? null, isWritable: true },
+    paymentMint: { value: input.paymentMint ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -202,15 +259,40 @@ export async function getInitResearchInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.researchPaper.value) {
-    accounts.researchPaper.value = await getProgramDerivedAddress({
+  if (!accounts.userAccount.value) {
+    accounts.userAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(new Uint8Array([117, 115, 101, 114])),
+        getAddressEncoder().encode(expectAddress(accounts.author.value)),
+      ],
+    });
+  }
+  if (!accounts.paperCounter.value) {
+    accounts.paperCounter.value = await getProgramDerivedAddress({
       programAddress,
       seeds: [
-        getBytesEncoder().encode(new Uint8Array([112, 97, 112, 101, 114])),
+        getBytesEncoder().encode(
+          new Uint8Array([
+            112, 97, 112, 101, 114, 95, 99, 111, 117, 110, 116, 101, 114,
+          ])
+        ),
         getAddressEncoder().encode(expectAddress(accounts.author.value)),
       ],
     });
   }
+  if (!accounts.paperRevision.value) {
+    accounts.paperRevision.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([114, 101, 118, 105, 115, 105, 111, 110])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.researchPaper.value)),
+        getBytesEncoder().encode(new Uint8Array([0, 0, 0, 0])),
+      ],
+    });
+  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -220,8 +302,11 @@ export async function getInitResearchInstructionAsync<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.author),
-      getAccountMeta(accounts.researchPaper),
       getAccountMeta(accounts.userAccount),
+      getAccountMeta(accounts.paperCounter),
+      getAccountMeta(accounts.researchPaper),
+      getAccountMeta(accounts.paperRevision),
+      getAccountMeta(accounts.paymentMint),
       getAccountMeta(accounts.systemProgram),
     ],
     data: getInitResearchInstructionDataEncoder().encode(
@@ -231,48 +316,68 @@ export async function getInitResearchInstructionAsync<
   } as InitResearchInstruction<
     TProgramAddress,
     TAccountAuthor,
-    TAccountResearchPaper,
     TAccountUserAccount,
+    TAccountPaperCounter,
+    TAccountResearchPaper,
+    TAccountPaperRevision,
+    TAccountPaymentMint,
     TAccountSystemProgram
   >);
 }
 
 export type InitResearchInput<
   TAccountAuthor extends string = string,
-  TAccountResearchPaper extends string = string,
   TAccountUserAccount extends string = string,
+  TAccountPaperCounter extends string = string,
+  TAccountResearchPaper extends string = string,
+  TAccountPaperRevision extends string = string,
+  TAccountPaymentMint extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   author: TransactionSigner<TAccountAuthor>;
-  researchPaper: Address<TAccountResearchPaper>;
   userAccount: Address<TAccountUserAccount>;
+  paperCounter: Address<TAccountPaperCounter>;
+  researchPaper: Address<TAccountResearchPaper>;
+  paperRevision: Address<TAccountPaperRevision>;
+  paymentMint?: Address<TAccountPaymentMint>;
   systemProgram?: Address<TAccountSystemProgram>;
   title: InitResearchInstructionDataArgs['title'];
   description: InitResearchInstructionDataArgs['description'];
   price: InitResearchInstructionDataArgs['price'];
   encryptedUrl: InitResearchInstructionDataArgs['encryptedUrl'];
-  encryptionKey: InitResearchInstructionDataArgs['encryptionKey'];
+  contentHash: InitResearchInstructionDataArgs['contentHash'];
+  ciphertextHash: InitResearchInstructionDataArgs['ciphertextHash'];
+  coAuthors: InitResearchInstructionDataArgs['coAuthors'];
 };
 
 export function getInitResearchInstruction<
   TAccountAuthor extends string,
-  TAccountResearchPaper extends string,
   TAccountUserAccount extends string,
+  TAccountPaperCounter extends string,
+  TAccountResearchPaper extends string,
+  TAccountPaperRevision extends string,
+  TAccountPaymentMint extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof PRISMPAPERSDAPP_PROGRAM_ADDRESS,
 >(
   input: InitResearchInput<
     TAccountAuthor,
-    TAccountResearchPaper,
     TAccountUserAccount,
+    TAccountPaperCounter,
+    TAccountResearchPaper,
+    TAccountPaperRevision,
+    TAccountPaymentMint,
     TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): InitResearchInstruction<
   TProgramAddress,
   TAccountAuthor,
-  TAccountResearchPaper,
   TAccountUserAccount,
+  TAccountPaperCounter,
+  TAccountResearchPaper,
+  TAccountPaperRevision,
+  TAccountPaymentMint,
   TAccountSystemProgram
 > {
   // Program address.
@@ -282,8 +387,11 @@ export function getInitResearchInstruction<
   // Original accounts.
   const originalAccounts = {
     author: { value: input.author ?? null, isWritable: true },
-    researchPaper: { value: input.researchPaper ?? null, isWritable: true },
     userAccount: { value: input.userAccount ?? null, isWritable: true },
+    paperCounter: { value: input.paperCounter ?? null, isWritable: true },
+    researchPaper: { value: input.researchPaper ?? null, isWritable: true },
+    paperRevision: { value: input.paperRevision ?? null, isWritable: true },
+    paymentMint: { value: input.paymentMint ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -304,8 +412,11 @@ export function getInitResearchInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.author),
-      getAccountMeta(accounts.researchPaper),
       getAccountMeta(accounts.userAccount),
+      getAccountMeta(accounts.paperCounter),
+      getAccountMeta(accounts.researchPaper),
+      getAccountMeta(accounts.paperRevision),
+      getAccountMeta(accounts.paymentMint),
       getAccountMeta(accounts.systemProgram),
     ],
     data: getInitResearchInstructionDataEncoder().encode(
@@ -315,8 +426,11 @@ export function getInitResearchInstruction<
   } as InitResearchInstruction<
     TProgramAddress,
     TAccountAuthor,
-    TAccountResearchPaper,
     TAccountUserAccount,
+    TAccountPaperCounter,
+    TAccountResearchPaper,
+    TAccountPaperRevision,
+    TAccountPaymentMint,
     TAccountSystemProgram
   >);
 }
@@ -328,9 +442,12 @@ export type ParsedInitResearchInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     author: TAccountMetas[0];
-    researchPaper: TAccountMetas[1];
-    userAccount: TAccountMetas[2];
-    systemProgram: TAccountMetas[3];
+    userAccount: TAccountMetas[1];
+    paperCounter: TAccountMetas[2];
+    researchPaper: TAccountMetas[3];
+    paperRevision: TAccountMetas[4];
+    paymentMint?: TAccountMetas[5] | undefined;
+    systemProgram: TAccountMetas[6];
   };
   data: InitResearchInstructionData;
 };
@@ -343,7 +460,7 @@ export function parseInitResearchInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedInitResearchInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 4) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -353,12 +470,21 @@ export function parseInitResearchInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === PRISMPAPERSDAPP_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       author: getNextAccount(),
-      researchPaper: getNextAccount(),
       userAccount: getNextAccount(),
+      paperCounter: getNextAccount(),
+      researchPaper: getNextAccount(),
+      paperRevision: getNextAccount(),
+      paymentMint: getNextOptionalAccount(),
       systemProgram: getNextAccount(),
     },
     data: getInitResearchInstructionDataDecoder().decode(instruction.data),
@@ -11,11 +11,15 @@ import {
   fixDecoderSize,
   fixEncoderSize,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
@@ -56,9 +60,16 @@ export type PurchaseAccessInstruction<
   TAccountBuyerUserAccount extends string | AccountMeta<string> = string,
   TAccountBuyerVault extends string | AccountMeta<string> = string,
   TAccountAuthorUserAccount extends string | AccountMeta<string> = string,
-  TAccountAuthorVault extends string | AccountMeta<string> = string,
-  TAccountAdminVault extends string | AccountMeta<string> = string,
+  TAccountEscrowVault extends string | AccountMeta<string> = string,
+  TAccountConfig extends string | AccountMeta<string> = string,
   TAccountAccessReceipt extends string | AccountMeta<string> = string,
+  TAccountPaymentMint extends string | AccountMeta<string> = string,
+  TAccountBuyerTokenAccount extends string | AccountMeta<string> = string,
+  TAccountEscrowTokenVault extends string | AccountMeta<string> = string,
+  TAccountTokenProgram extends string | AccountMeta<string> = string,
+  TAccountAssociatedTokenProgram extends
+    | string
+    | AccountMeta<string> = 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL',
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
@@ -83,15 +94,30 @@ export type PurchaseAccessInstruction<
       TAccountAuthorUserAccount extends string
         ? WritableAccount<TAccountAuthorUserAccount>
         : TAccountAuthorUserAccount,
-      TAccountAuthorVault extends string
-        ? WritableAccount<TAccountAuthorVault>
-        : TAccountAuthorVault,
-      TAccountAdminVault extends string
-        ? WritableAccount<TAccountAdminVault>
-        : TAccountAdminVault,
+      TAccountEscrowVault extends string
+        ? WritableAccount<TAccountEscrowVault>
+        : TAccountEscrowVault,
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
       TAccountAccessReceipt extends string
         ? WritableAccount<TAccountAccessReceipt>
         : TAccountAccessReceipt,
+      TAccountPaymentMint extends string
+        ? ReadonlyAccount<TAccountPaymentMint>
+        : TAccountPaymentMint,
+      TAccountBuyerTokenAccount extends string
+        ? WritableAccount<TAccountBuyerTokenAccount>
+        : TAccountBuyerTokenAccount,
+      TAccountEscrowTokenVault extends string
+        ? WritableAccount<TAccountEscrowTokenVault>
+        : TAccountEscrowTokenVault,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      TAccountAssociatedTokenProgram extends string
+        ? ReadonlyAccount<TAccountAssociatedTokenProgram>
+        : TAccountAssociatedTokenProgram,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -101,13 +127,19 @@ export type PurchaseAccessInstruction<
 
 export type PurchaseAccessInstructionData = {
   discriminator: ReadonlyUint8Array;
+  buyerEncryptionKey: Array<number>;
 };
 
-export type PurchaseAccessInstructionDataArgs = {};
+export type PurchaseAccessInstructionDataArgs = {
+  buyerEncryptionKey: Array<number>;
+};
 
 export function getPurchaseAccessInstructionDataEncoder(): FixedSizeEncoder<PurchaseAccessInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([['discriminator', fixEncoderSize(getBytesEncoder(), 8)]]),
+    getStructEncoder([
+      ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+      ['buyerEncryptionKey', getArrayEncoder(getU8Encoder(), { size: 32 })],
+    ]),
     (value) => ({ ...value, discriminator: PURCHASE_ACCESS_DISCRIMINATOR })
   );
 }
@@ -115,6 +147,7 @@ export function getPurchaseAccessInstructionDataEncoder(): FixedSizeEncoder<Purc
 export function getPurchaseAccessInstructionDataDecoder(): FixedSizeDecoder<PurchaseAccessInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+    ['buyerEncryptionKey', getArrayDecoder(getU8Decoder(), { size: 32 })],
   ]);
 }
 
@@ -134,20 +167,31 @@ export type PurchaseAccessAsyncInput<
   TAccountBuyerUserAccount extends string = string,
   TAccountBuyerVault extends string = string,
   TAccountAuthorUserAccount extends string = string,
-  TAccountAuthorVault extends string = string,
-  TAccountAdminVault extends string = string,
+  TAccountEscrowVault extends string = string,
+  TAccountConfig extends string = string,
   TAccountAccessReceipt extends string = string,
+  TAccountPaymentMint extends string = string,
+  TAccountBuyerTokenAccount extends string = string,
+  TAccountEscrowTokenVault extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountAssociatedTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   buyer: TransactionSigner<TAccountBuyer>;
   researchPaper: Address<TAccountResearchPaper>;
-  buyerUserAccount: Address<TAccountBuyerUserAccount>;
+  buyerUserAccount?: Address<TAccountBuyerUserAccount>;
   buyerVault?: Address<TAccountBuyerVault>;
   authorUserAccount: Address<TAccountAuthorUserAccount>;
-  authorVault: Address<TAccountAuthorVault>;
-  adminVault?: Address<TAccountAdminVault>;
+  escrowVault?: Address<TAccountEscrowVault>;
+  config?: Address<TAccountConfig>;
   accessReceipt?: Address<TAccountAccessReceipt>;
+  paymentMint?: Address<TAccountPaymentMint>;
+  buyerTokenAccount?: Address<TAccountBuyerTokenAccount>;
+  escrowTokenVault?: Address<TAccountEscrowTokenVault>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  buyerEncryptionKey: PurchaseAccessInstructionDataArgs['buyerEncryptionKey'];
 };
 
 export async function getPurchaseAccessInstructionAsync<
@@ -156,9 +200,14 @@ export async function getPurchaseAccessInstructionAsync<
   TAccountBuyerUserAccount extends string,
   TAccountBuyerVault extends string,
   TAccountAuthorUserAccount extends string,
-  TAccountAuthorVault extends string,
-  TAccountAdminVault extends string,
+  TAccountEscrowVault extends string,
+  TAccountConfig extends string,
   TAccountAccessReceipt extends string,
+  TAccountPaymentMint extends string,
+  TAccountBuyerTokenAccount extends string,
+  TAccountEscrowTokenVault extends string,
+  TAccountTokenProgram extends string,
+  TAccountAssociatedTokenProgram extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof PRISMPAPERSDAPP_PROGRAM_ADDRESS,
 >(
@@ -168,9 +217,14 @@ export async function getPurchaseAccessInstructionAsync<
     TAccountBuyerUserAccount,
     TAccountBuyerVault,
     TAccountAuthorUserAccount,
-    TAccountAuthorVault,
-    TAccountAdminVault,
+    TAccountEscrowVault,
+    TAccountConfig,
     TAccountAccessReceipt,
+    TAccountPaymentMint,
+    TAccountBuyerTokenAccount,
+    TAccountEscrowTokenVault,
+    TAccountTokenProgram,
+    TAccountAssociatedTokenProgram,
     TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
@@ -182,9 +236,14 @@ export async function getPurchaseAccessInstructionAsync<
     TAccountBuyerUserAccount,
     TAccountBuyerVault,
     TAccountAuthorUserAccount,
-    TAccountAuthorVault,
-    TAccountAdminVault,
+    TAccountEscrowVault,
+    TAccountConfig,
     TAccountAccessReceipt,
+    TAccountPaymentMint,
+    TAccountBuyerTokenAccount,
+    TAccountEscrowTokenVault,
+    TAccountTokenProgram,
+    TAccountAssociatedTokenProgram,
     TAccountSystemProgram
   >
 > {
@@ -205,9 +264,23 @@ export async function getPurchaseAccessInstructionAsync<
       value: input.authorUserAccount ?? null,
       isWritable: true,
     },
-    authorVault: { value: input.authorVault ?? null, isWritable: true },
-    adminVault: { value: input.adminVault ?? null, isWritable: true },
+    escrowVault: { value: input.escrowVault ?? null, isWritable: true },
+    config: { value: input.config ?? null, isWritable: false },
     accessReceipt: { value: input.accessReceipt ?? null, isWritable: true },
+    paymentMint: { value: input.paymentMint ?? null, isWritable: false },
+    buyerTokenAccount: {
+      value: input.buyerTokenAccount ?? null,
+      isWritable: true,
+    },
+    escrowTokenVault: {
+      value: input.escrowTokenVault ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    associatedTokenProgram: {
+      value: input.associatedTokenProgram ?? null,
+      isWritable: false,
+    },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -215,7 +288,19 @@ export async function getPurchaseAccessInstructionAsync<
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
+  if (!accounts.buyerUserAccount.value) {
+    accounts.buyerUserAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(new Uint8Array([117, 115, 101, 114])),
+        getAddressEncoder().encode(expectAddress(accounts.buyer.value)),
+      ],
+    });
+  }
   if (!accounts.buyerVault.value) {
     accounts.buyerVault.value = await getProgramDerivedAddress({
       programAddress,
@@ -227,13 +312,24 @@ export async function getPurchaseAccessInstructionAsync<
       ],
     });
   }
-  if (!accounts.adminVault.value) {
-    accounts.adminVault.value = await getProgramDerivedAddress({
+  if (!accounts.escrowVault.value) {
+    accounts.escrowVault.value = await getProgramDerivedAddress({
       programAddress,
       seeds: [
         getBytesEncoder().encode(
-          new Uint8Array([118, 97, 117, 108, 116, 95, 97, 100, 109, 105, 110])
+          new Uint8Array([
+            118, 97, 117, 108, 116, 95, 101, 115, 99, 114, 111, 119,
+          ])
         ),
+        getAddressEncoder().encode(expectAddress(accounts.researchPaper.value)),
+      ],
+    });
+  }
+  if (!accounts.config.value) {
+    accounts.config.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(new Uint8Array([99, 111, 110, 102, 105, 103])),
       ],
     });
   }
@@ -249,6 +345,32 @@ export async function getPurchaseAccessInstructionAsync<
       ],
     });
   }
+  if (!accounts.buyerTokenAccount.value) {
+    accounts.buyerTokenAccount.value = await getProgramDerivedAddress({
+      programAddress:
+        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>,
+      seeds: [
+        getAddressEncoder().encode(expectAddress(accounts.buyer.value)),
+        getAddressEncoder().encode(expectAddress(accounts.tokenProgram.value)),
+        getAddressEncoder().encode(expectAddress(accounts.paymentMint.value)),
+      ],
+    });
+  }
+  if (!accounts.escrowTokenVault.value) {
+    accounts.escrowTokenVault.value = await getProgramDerivedAddress({
+      programAddress:
+        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>,
+      seeds: [
+        getAddressEncoder().encode(expectAddress(accounts.escrowVault.value)),
+        getAddressEncoder().encode(expectAddress(accounts.tokenProgram.value)),
+        getAddressEncoder().encode(expectAddress(accounts.paymentMint.value)),
+      ],
+    });
+  }
+  if (!accounts.associatedTokenProgram.value) {
+    accounts.associatedTokenProgram.value =
+      'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>;
+  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -262,12 +384,19 @@ export async function getPurchaseAccessInstructionAsync<
       getAccountMeta(accounts.buyerUserAccount),
       getAccountMeta(accounts.buyerVault),
       getAccountMeta(accounts.authorUserAccount),
-      getAccountMeta(accounts.authorVault),
-      getAccountMeta(accounts.adminVault),
+      getAccountMeta(accounts.escrowVault),
+      getAccountMeta(accounts.config),
       getAccountMeta(accounts.accessReceipt),
+      getAccountMeta(accounts.paymentMint),
+      getAccountMeta(accounts.buyerTokenAccount),
+      getAccountMeta(accounts.escrowTokenVault),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.systemProgram),
     ],
-    data: getPurchaseAccessInstructionDataEncoder().encode({}),
+    data: getPurchaseAccessInstructionDataEncoder().encode(
+      args as PurchaseAccessInstructionDataArgs
+    ),
     programAddress,
   } as PurchaseAccessInstruction<
     TProgramAddress,
@@ -276,9 +405,14 @@ export async function getPurchaseAccessInstructionAsync<
     TAccountBuyerUserAccount,
     TAccountBuyerVault,
     TAccountAuthorUserAccount,
-    TAccountAuthorVault,
-    TAccountAdminVault,
+    TAccountEscrowVault,
+    TAccountConfig,
     TAccountAccessReceipt,
+    TAccountPaymentMint,
+    TAccountBuyerTokenAccount,
+    TAccountEscrowTokenVault,
+    TAccountTokenProgram,
+    TAccountAssociatedTokenProgram,
     TAccountSystemProgram
   >);
 }
@@ -289,9 +423,14 @@ export type PurchaseAccessInput<
   TAccountBuyerUserAccount extends string = string,
   TAccountBuyerVault extends string = string,
   TAccountAuthorUserAccount extends string = string,
-  TAccountAuthorVault extends string = string,
-  TAccountAdminVault extends string = string,
+  TAccountEscrowVault extends string = string,
+  TAccountConfig extends string = string,
   TAccountAccessReceipt extends string = string,
+  TAccountPaymentMint extends string = string,
+  TAccountBuyerTokenAccount extends string = string,
+  TAccountEscrowTokenVault extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountAssociatedTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   buyer: TransactionSigner<TAccountBuyer>;
@@ -299,10 +438,16 @@ export type PurchaseAccessInput<
   buyerUserAccount: Address<TAccountBuyerUserAccount>;
   buyerVault: Address<TAccountBuyerVault>;
   authorUserAccount: Address<TAccountAuthorUserAccount>;
-  authorVault: Address<TAccountAuthorVault>;
-  adminVault: Address<TAccountAdminVault>;
+  escrowVault: Address<TAccountEscrowVault>;
+  config: Address<TAccountConfig>;
   accessReceipt: Address<TAccountAccessReceipt>;
+  paymentMint?: Address<TAccountPaymentMint>;
+  buyerTokenAccount?: Address<TAccountBuyerTokenAccount>;
+  escrowTokenVault?: Address<TAccountEscrowTokenVault>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  buyerEncryptionKey: PurchaseAccessInstructionDataArgs['buyerEncryptionKey'];
 };
 
 export function getPurchaseAccessInstruction<
@@ -311,9 +456,14 @@ export function getPurchaseAccessInstruction<
   TAccountBuyerUserAccount extends string,
   TAccountBuyerVault extends string,
   TAccountAuthorUserAccount extends string,
-  TAccountAuthorVault extends string,
-  TAccountAdminVault extends string,
+  TAccountEscrowVault extends string,
+  TAccountConfig extends string,
   TAccountAccessReceipt extends string,
+  TAccountPaymentMint extends string,
+  TAccountBuyerTokenAccount extends string,
+  TAccountEscrowTokenVault extends string,
+  TAccountTokenProgram extends string,
+  TAccountAssociatedTokenProgram extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof PRISMPAPERSDAPP_PROGRAM_ADDRESS,
 >(
@@ -323,9 +473,14 @@ export function getPurchaseAccessInstruction<
     TAccountBuyerUserAccount,
     TAccountBuyerVault,
     TAccountAuthorUserAccount,
-    TAccountAuthorVault,
-    TAccountAdminVault,
+    TAccountEscrowVault,
+    TAccountConfig,
     TAccountAccessReceipt,
+    TAccountPaymentMint,
+    TAccountBuyerTokenAccount,
+    TAccountEscrowTokenVault,
+    TAccountTokenProgram,
+    TAccountAssociatedTokenProgram,
     TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
@@ -336,9 +491,14 @@ export function getPurchaseAccessInstruction<
   TAccountBuyerUserAccount,
   TAccountBuyerVault,
   TAccountAuthorUserAccount,
-  TAccountAuthorVault,
-  TAccountAdminVault,
+  TAccountEscrowVault,
+  TAccountConfig,
   TAccountAccessReceipt,
+  TAccountPaymentMint,
+  TAccountBuyerTokenAccount,
+  TAccountEscrowTokenVault,
+  TAccountTokenProgram,
+  TAccountAssociatedTokenProgram,
   TAccountSystemProgram
 > {
   // Program address.
@@ -358,9 +518,23 @@ export function getPurchaseAccessInstruction<
       value: input.authorUserAccount ?? null,
       isWritable: true,
     },
-    authorVault: { value: input.authorVault ?? null, isWritable: true },
-    adminVault: { value: input.adminVault ?? null, isWritable: true },
+    escrowVault: { value: input.escrowVault ?? null, isWritable: true },
+    config: { value: input.config ?? null, isWritable: false },
     accessReceipt: { value: input.accessReceipt ?? null, isWritable: true },
+    paymentMint: { value: input.paymentMint ?? null, isWritable: false },
+    buyerTokenAccount: {
+      value: input.buyerTokenAccount ?? null,
+      isWritable: true,
+    },
+    escrowTokenVault: {
+      value: input.escrowTokenVault ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    associatedTokenProgram: {
+      value: input.associatedTokenProgram ?? null,
+      isWritable: false,
+    },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -368,7 +542,14 @@ export function getPurchaseAccessInstruction<
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
+  if (!accounts.associatedTokenProgram.value) {
+    accounts.associatedTokenProgram.value =
+      'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>;
+  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -382,12 +563,19 @@ export function getPurchaseAccessInstruction<
       getAccountMeta(accounts.buyerUserAccount),
       getAccountMeta(accounts.buyerVault),
       getAccountMeta(accounts.authorUserAccount),
-      getAccountMeta(accounts.authorVault),
-      getAccountMeta(accounts.adminVault),
+      getAccountMeta(accounts.escrowVault),
+      getAccountMeta(accounts.config),
       getAccountMeta(accounts.accessReceipt),
+      getAccountMeta(accounts.paymentMint),
+      getAccountMeta(accounts.buyerTokenAccount),
+      getAccountMeta(accounts.escrowTokenVault),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.systemProgram),
     ],
-    data: getPurchaseAccessInstructionDataEncoder().encode({}),
+    data: getPurchaseAccessInstructionDataEncoder().encode(
+      args as PurchaseAccessInstructionDataArgs
+    ),
     programAddress,
   } as PurchaseAccessInstruction<
     TProgramAddress,
@@ -396,9 +584,14 @@ export function getPurchaseAccessInstruction<
     TAccountBuyerUserAccount,
     TAccountBuyerVault,
     TAccountAuthorUserAccount,
-    TAccountAuthorVault,
-    TAccountAdminVault,
+    TAccountEscrowVault,
+    TAccountConfig,
     TAccountAccessReceipt,
+    TAccountPaymentMint,
+    TAccountBuyerTokenAccount,
+    TAccountEscrowTokenVault,
+    TAccountTokenProgram,
+    TAccountAssociatedTokenProgram,
     TAccountSystemProgram
   >);
 }
@@ -414,10 +607,15 @@ export type ParsedPurchaseAccessInstruction<
     buyerUserAccount: TAccountMetas[2];
     buyerVault: TAccountMetas[3];
     authorUserAccount: TAccountMetas[4];
-    authorVault: TAccountMetas[5];
-    adminVault: TAccountMetas[6];
+    escrowVault: TAccountMetas[5];
+    config: TAccountMetas[6];
     accessReceipt: TAccountMetas[7];
-    systemProgram: TAccountMetas[8];
+    paymentMint?: TAccountMetas[8] | undefined;
+    buyerTokenAccount?: TAccountMetas[9] | undefined;
+    escrowTokenVault?: TAccountMetas[10] | undefined;
+    tokenProgram?: TAccountMetas[11] | undefined;
+    associatedTokenProgram?: TAccountMetas[12] | undefined;
+    systemProgram: TAccountMetas[13];
   };
   data: PurchaseAccessInstructionData;
 };
@@ -430,7 +628,7 @@ export function parsePurchaseAccessInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedPurchaseAccessInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 9) {
+  if (instruction.accounts.length < 14) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -440,6 +638,12 @@ export function parsePurchaseAccessInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === PRISMPAPERSDAPP_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -448,9 +652,14 @@ export function parsePurchaseAccessInstruction<
       buyerUserAccount: getNextAccount(),
       buyerVault: getNextAccount(),
       authorUserAccount: getNextAccount(),
-      authorVault: getNextAccount(),
-      adminVault: getNextAccount(),
+      escrowVault: getNextAccount(),
+      config: getNextAccount(),
       accessReceipt: getNextAccount(),
+      paymentMint: getNextOptionalAccount(),
+      buyerTokenAccount: getNextOptionalAccount(),
+      escrowTokenVault: getNextOptionalAccount(),
+      tokenProgram: getNextOptionalAccount(),
+      associatedTokenProgram: getNextOptionalAccount(),
       systemProgram: getNextAccount(),
     },
     data: getPurchaseAccessInstructionDataDecoder().decode(instruction.data),
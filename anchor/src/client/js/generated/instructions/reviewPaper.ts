@@ -13,6 +13,8 @@ import {
   fixDecoderSize,
   fixEncoderSize,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getProgramDerivedAddress,
@@ -22,6 +24,8 @@ import {
   getU32Encoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   getUtf8Decoder,
   getUtf8Encoder,
   transformEncoder,
@@ -46,6 +50,12 @@ import {
   getAccountMetaFactory,
   type ResolvedAccount,
 } from '../shared';
+import {
+  getReviewScoresDecoder,
+  getReviewScoresEncoder,
+  type ReviewScores,
+  type ReviewScoresArgs,
+} from '../types';
 
 export const REVIEW_PAPER_DISCRIMINATOR = new Uint8Array([
   143, 66, 152, 52, 94, 165, 129, 123,
@@ -61,9 +71,17 @@ export type ReviewPaperInstruction<
   TProgram extends string = typeof PRISMPAPERSDAPP_PROGRAM_ADDRESS,
   TAccountReviewer extends string | AccountMeta<string> = string,
   TAccountResearchPaper extends string | AccountMeta<string> = string,
+  TAccountReviewRound extends string | AccountMeta<string> = string,
   TAccountAccessReceipt extends string | AccountMeta<string> = string,
+  TAccountInstitution extends string | AccountMeta<string> = string,
+  TAccountInstitutionMember extends string | AccountMeta<string> = string,
+  TAccountSubscriptionPlan extends string | AccountMeta<string> = string,
+  TAccountSubscription extends string | AccountMeta<string> = string,
   TAccountReviewerUserAccount extends string | AccountMeta<string> = string,
   TAccountPeerReview extends string | AccountMeta<string> = string,
+  TAccountRoundVoter extends string | AccountMeta<string> = string,
+  TAccountStakeVault extends string | AccountMeta<string> = string,
+  TAccountConfig extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
@@ -79,15 +97,39 @@ export type ReviewPaperInstruction<
       TAccountResearchPaper extends string
         ? WritableAccount<TAccountResearchPaper>
         : TAccountResearchPaper,
+      TAccountReviewRound extends string
+        ? WritableAccount<TAccountReviewRound>
+        : TAccountReviewRound,
       TAccountAccessReceipt extends string
         ? ReadonlyAccount<TAccountAccessReceipt>
         : TAccountAccessReceipt,
+      TAccountInstitution extends string
+        ? ReadonlyAccount<TAccountInstitution>
+        : TAccountInstitution,
+      TAccountInstitutionMember extends string
+        ? ReadonlyAccount<TAccountInstitutionMember>
+        : TAccountInstitutionMember,
+      TAccountSubscriptionPlan extends string
+        ? ReadonlyAccount<TAccountSubscriptionPlan>
+        : TAccountSubscriptionPlan,
+      TAccountSubscription extends string
+        ? ReadonlyAccount<TAccountSubscription>
+        : TAccountSubscription,
       TAccountReviewerUserAccount extends string
         ? WritableAccount<TAccountReviewerUserAccount>
         : TAccountReviewerUserAccount,
       TAccountPeerReview extends string
         ? WritableAccount<TAccountPeerReview>
         : TAccountPeerReview,
+      TAccountRoundVoter extends string
+        ? WritableAccount<TAccountRoundVoter>
+        : TAccountRoundVoter,
+      TAccountStakeVault extends string
+        ? WritableAccount<TAccountStakeVault>
+        : TAccountStakeVault,
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -98,11 +140,15 @@ export type ReviewPaperInstruction<
 export type ReviewPaperInstructionData = {
   discriminator: ReadonlyUint8Array;
   reviewUrl: string;
+  reviewHash: Array<number>;
+  scores: ReviewScores;
   proposedReward: bigint;
 };
 
 export type ReviewPaperInstructionDataArgs = {
   reviewUrl: string;
+  reviewHash: Array<number>;
+  scores: ReviewScoresArgs;
   proposedReward: number | bigint;
 };
 
@@ -111,6 +157,8 @@ export function getReviewPaperInstructionDataEncoder(): Encoder<ReviewPaperInstr
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['reviewUrl', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      ['reviewHash', getArrayEncoder(getU8Encoder(), { size: 32 })],
+      ['scores', getReviewScoresEncoder()],
       ['proposedReward', getU64Encoder()],
     ]),
     (value) => ({ ...value, discriminator: REVIEW_PAPER_DISCRIMINATOR })
@@ -121,6 +169,8 @@ export function getReviewPaperInstructionDataDecoder(): Decoder<ReviewPaperInstr
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['reviewUrl', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ['reviewHash', getArrayDecoder(getU8Decoder(), { size: 32 })],
+    ['scores', getReviewScoresDecoder()],
     ['proposedReward', getU64Decoder()],
   ]);
 }
@@ -138,36 +188,70 @@ export function getReviewPaperInstructionDataCodec(): Codec<
 export type ReviewPaperAsyncInput<
   TAccountReviewer extends string = string,
   TAccountResearchPaper extends string = string,
+  TAccountReviewRound extends string = string,
   TAccountAccessReceipt extends string = string,
+  TAccountInstitution extends string = string,
+  TAccountInstitutionMember extends string = string,
+  TAccountSubscriptionPlan extends string = string,
+  TAccountSubscription extends string = string,
   TAccountReviewerUserAccount extends string = string,
   TAccountPeerReview extends string = string,
+  TAccountRoundVoter extends string = string,
+  TAccountStakeVault extends string = string,
+  TAccountConfig extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   reviewer: TransactionSigner<TAccountReviewer>;
   researchPaper: Address<TAccountResearchPaper>;
+  reviewRound: Address<TAccountReviewRound>;
   accessReceipt?: Address<TAccountAccessReceipt>;
-  reviewerUserAccount: Address<TAccountReviewerUserAccount>;
+  institution?: Address<TAccountInstitution>;
+  institutionMember?: Address<TAccountInstitutionMember>;
+  subscriptionPlan?: Address<TAccountSubscriptionPlan>;
+  subscription?: Address<TAccountSubscription>;
+  reviewerUserAccount?: Address<TAccountReviewerUserAccount>;
   peerReview?: Address<TAccountPeerReview>;
+  roundVoter?: Address<TAccountRoundVoter>;
+  stakeVault?: Address<TAccountStakeVault>;
+  config?: Address<TAccountConfig>;
   systemProgram?: Address<TAccountSystemProgram>;
   reviewUrl: ReviewPaperInstructionDataArgs['reviewUrl'];
+  reviewHash: ReviewPaperInstructionDataArgs['reviewHash'];
+  scores: ReviewPaperInstructionDataArgs['scores'];
   proposedReward: ReviewPaperInstructionDataArgs['proposedReward'];
 };
 
 export async function getReviewPaperInstructionAsync<
   TAccountReviewer extends string,
   TAccountResearchPaper extends string,
+  TAccountReviewRound extends string,
   TAccountAccessReceipt extends string,
+  TAccountInstitution extends string,
+  TAccountInstitutionMember extends string,
+  TAccountSubscriptionPlan extends string,
+  TAccountSubscription extends string,
   TAccountReviewerUserAccount extends string,
   TAccountPeerReview extends string,
+  TAccountRoundVoter extends string,
+  TAccountStakeVault extends string,
+  TAccountConfig extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof PRISMPAPERSDAPP_PROGRAM_ADDRESS,
 >(
   input: ReviewPaperAsyncInput<
     TAccountReviewer,
     TAccountResearchPaper,
+    TAccountReviewRound,
     TAccountAccessReceipt,
+    TAccountInstitution,
+    TAccountInstitutionMember,
+    TAccountSubscriptionPlan,
+    TAccountSubscription,
     TAccountReviewerUserAccount,
     TAccountPeerReview,
+    TAccountRoundVoter,
+    TAccountStakeVault,
+    TAccountConfig,
     TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
@@ -176,9 +260,17 @@ export async function getReviewPaperInstructionAsync<
     TProgramAddress,
     TAccountReviewer,
     TAccountResearchPaper,
+    TAccountReviewRound,
     TAccountAccessReceipt,
+    TAccountInstitution,
+    TAccountInstitutionMember,
+    TAccountSubscriptionPlan,
+    TAccountSubscription,
     TAccountReviewerUserAccount,
     TAccountPeerReview,
+    TAccountRoundVoter,
+    TAccountStakeVault,
+    TAccountConfig,
     TAccountSystemProgram
   >
 > {
@@ -190,12 +282,26 @@ export async function getReviewPaperInstructionAsync<
   const originalAccounts = {
     reviewer: { value: input.reviewer ?? null, isWritable: true },
     researchPaper: { value: input.researchPaper ?? null, isWritable: true },
+    reviewRound: { value: input.reviewRound ?? null, isWritable: true },
     accessReceipt: { value: input.accessReceipt ?? null, isWritable: false },
+    institution: { value: input.institution ?? null, isWritable: false },
+    institutionMember: {
+      value: input.institutionMember ?? null,
+      isWritable: false,
+    },
+    subscriptionPlan: {
+      value: input.subscriptionPlan ?? null,
+      isWritable: false,
+    },
+    subscription: { value: input.subscription ?? null, isWritable: false },
     reviewerUserAccount: {
       value: input.reviewerUserAccount ?? null,
       isWritable: true,
     },
     peerReview: { value: input.peerReview ?? null, isWritable: true },
+    roundVoter: { value: input.roundVoter ?? null, isWritable: true },
+    stakeVault: { value: input.stakeVault ?? null, isWritable: true },
+    config: { value: input.config ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -207,15 +313,12 @@ export async function getReviewPaperInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.accessReceipt.value) {
-    accounts.accessReceipt.value = await getProgramDerivedAddress({
+  if (!accounts.reviewerUserAccount.value) {
+    accounts.reviewerUserAccount.value = await getProgramDerivedAddress({
       programAddress,
       seeds: [
-        getBytesEncoder().encode(
-          new Uint8Array([114, 101, 99, 101, 105, 112, 116])
-        ),
+        getBytesEncoder().encode(new Uint8Array([117, 115, 101, 114])),
         getAddressEncoder().encode(expectAddress(accounts.reviewer.value)),
-        getAddressEncoder().encode(expectAddress(accounts.researchPaper.value)),
       ],
     });
   }
@@ -231,6 +334,35 @@ export async function getReviewPaperInstructionAsync<
       ],
     });
   }
+  if (!accounts.roundVoter.value) {
+    accounts.roundVoter.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(new Uint8Array([118, 111, 116, 101, 114])),
+        getAddressEncoder().encode(expectAddress(accounts.reviewRound.value)),
+        getAddressEncoder().encode(expectAddress(accounts.reviewer.value)),
+      ],
+    });
+  }
+  if (!accounts.stakeVault.value) {
+    accounts.stakeVault.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([118, 97, 117, 108, 116, 95, 115, 116, 97, 107, 101])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.peerReview.value)),
+      ],
+    });
+  }
+  if (!accounts.config.value) {
+    accounts.config.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(new Uint8Array([99, 111, 110, 102, 105, 103])),
+      ],
+    });
+  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -241,9 +373,17 @@ export async function getReviewPaperInstructionAsync<
     accounts: [
       getAccountMeta(accounts.reviewer),
       getAccountMeta(accounts.researchPaper),
+      getAccountMeta(accounts.reviewRound),
       getAccountMeta(accounts.accessReceipt),
+      getAccountMeta(accounts.institution),
+      getAccountMeta(accounts.institutionMember),
+      getAccountMeta(accounts.subscriptionPlan),
+      getAccountMeta(accounts.subscription),
       getAccountMeta(accounts.reviewerUserAccount),
       getAccountMeta(accounts.peerReview),
+      getAccountMeta(accounts.roundVoter),
+      getAccountMeta(accounts.stakeVault),
+      getAccountMeta(accounts.config),
       getAccountMeta(accounts.systemProgram),
     ],
     data: getReviewPaperInstructionDataEncoder().encode(
@@ -254,9 +394,17 @@ export async function getReviewPaperInstructionAsync<
     TProgramAddress,
     TAccountReviewer,
     TAccountResearchPaper,
+    TAccountReviewRound,
     TAccountAccessReceipt,
+    TAccountInstitution,
+    TAccountInstitutionMember,
+    TAccountSubscriptionPlan,
+    TAccountSubscription,
     TAccountReviewerUserAccount,
     TAccountPeerReview,
+    TAccountRoundVoter,
+    TAccountStakeVault,
+    TAccountConfig,
     TAccountSystemProgram
   >);
 }
@@ -264,36 +412,70 @@ export async function getReviewPaperInstructionAsync<
 export type ReviewPaperInput<
   TAccountReviewer extends string = string,
   TAccountResearchPaper extends string = string,
+  TAccountReviewRound extends string = string,
   TAccountAccessReceipt extends string = string,
+  TAccountInstitution extends string = string,
+  TAccountInstitutionMember extends string = string,
+  TAccountSubscriptionPlan extends string = string,
+  TAccountSubscription extends string = string,
   TAccountReviewerUserAccount extends string = string,
   TAccountPeerReview extends string = string,
+  TAccountRoundVoter extends string = string,
+  TAccountStakeVault extends string = string,
+  TAccountConfig extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   reviewer: TransactionSigner<TAccountReviewer>;
   researchPaper: Address<TAccountResearchPaper>;
-  accessReceipt: Address<TAccountAccessReceipt>;
+  reviewRound: Address<TAccountReviewRound>;
+  accessReceipt?: Address<TAccountAccessReceipt>;
+  institution?: Address<TAccountInstitution>;
+  institutionMember?: Address<TAccountInstitutionMember>;
+  subscriptionPlan?: Address<TAccountSubscriptionPlan>;
+  subscription?: Address<TAccountSubscription>;
   reviewerUserAccount: Address<TAccountReviewerUserAccount>;
   peerReview: Address<TAccountPeerReview>;
+  roundVoter: Address<TAccountRoundVoter>;
+  stakeVault: Address<TAccountStakeVault>;
+  config: Address<TAccountConfig>;
   systemProgram?: Address<TAccountSystemProgram>;
   reviewUrl: ReviewPaperInstructionDataArgs['reviewUrl'];
+  reviewHash: ReviewPaperInstructionDataArgs['reviewHash'];
+  scores: ReviewPaperInstructionDataArgs['scores'];
   proposedReward: ReviewPaperInstructionDataArgs['proposedReward'];
 };
 
 export function getReviewPaperInstruction<
   TAccountReviewer extends string,
   TAccountResearchPaper extends string,
+  TAccountReviewRound extends string,
   TAccountAccessReceipt extends string,
+  TAccountInstitution extends string,
+  TAccountInstitutionMember extends string,
+  TAccountSubscriptionPlan extends string,
+  TAccountSubscription extends string,
   TAccountReviewerUserAccount extends string,
   TAccountPeerReview extends string,
+  TAccountRoundVoter extends string,
+  TAccountStakeVault extends string,
+  TAccountConfig extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof PRISMPAPERSDAPP_PROGRAM_ADDRESS,
 >(
   input: ReviewPaperInput<
     TAccountReviewer,
     TAccountResearchPaper,
+    TAccountReviewRound,
     TAccountAccessReceipt,
+    TAccountInstitution,
+    TAccountInstitutionMember,
+    TAccountSubscriptionPlan,
+    TAccountSubscription,
     TAccountReviewerUserAccount,
     TAccountPeerReview,
+    TAccountRoundVoter,
+    TAccountStakeVault,
+    TAccountConfig,
     TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
@@ -301,9 +483,17 @@ export function getReviewPaperInstruction<
   TProgramAddress,
   TAccountReviewer,
   TAccountResearchPaper,
+  TAccountReviewRound,
   TAccountAccessReceipt,
+  TAccountInstitution,
+  TAccountInstitutionMember,
+  TAccountSubscriptionPlan,
+  TAccountSubscription,
   TAccountReviewerUserAccount,
   TAccountPeerReview,
+  TAccountRoundVoter,
+  TAccountStakeVault,
+  TAccountConfig,
   TAccountSystemProgram
 > {
   // Program address.
@@ -314,12 +504,26 @@ export function getReviewPaperInstruction<
   const originalAccounts = {
     reviewer: { value: input.reviewer ?? null, isWritable: true },
     researchPaper: { value: input.researchPaper ?? null, isWritable: true },
+    reviewRound: { value: input.reviewRound ?? null, isWritable: true },
     accessReceipt: { value: input.accessReceipt ?? null, isWritable: false },
+    institution: { value: input.institution ?? null, isWritable: false },
+    institutionMember: {
+      value: input.institutionMember ?? null,
+      isWritable: false,
+    },
+    subscriptionPlan: {
+      value: input.subscriptionPlan ?? null,
+      isWritable: false,
+    },
+    subscription: { value: input.subscription ?? null, isWritable: false },
     reviewerUserAccount: {
       value: input.reviewerUserAccount ?? null,
       isWritable: true,
     },
     peerReview: { value: input.peerReview ?? null, isWritable: true },
+    roundVoter: { value: input.roundVoter ?? null, isWritable: true },
+    stakeVault: { value: input.stakeVault ?? null, isWritable: true },
+    config: { value: input.config ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -341,9 +545,17 @@ export function getReviewPaperInstruction<
     accounts: [
       getAccountMeta(accounts.reviewer),
       getAccountMeta(accounts.researchPaper),
+      getAccountMeta(accounts.reviewRound),
       getAccountMeta(accounts.accessReceipt),
+      getAccountMeta(accounts.institution),
+      getAccountMeta(accounts.institutionMember),
+      getAccountMeta(accounts.subscriptionPlan),
+      getAccountMeta(accounts.subscription),
       getAccountMeta(accounts.reviewerUserAccount),
       getAccountMeta(accounts.peerReview),
+      getAccountMeta(accounts.roundVoter),
+      getAccountMeta(accounts.stakeVault),
+      getAccountMeta(accounts.config),
       getAccountMeta(accounts.systemProgram),
     ],
     data: getReviewPaperInstructionDataEncoder().encode(
@@ -354,9 +566,17 @@ export function getReviewPaperInstruction<
     TProgramAddress,
     TAccountReviewer,
     TAccountResearchPaper,
+    TAccountReviewRound,
     TAccountAccessReceipt,
+    TAccountInstitution,
+    TAccountInstitutionMember,
+    TAccountSubscriptionPlan,
+    TAccountSubscription,
     TAccountReviewerUserAccount,
     TAccountPeerReview,
+    TAccountRoundVoter,
+    TAccountStakeVault,
+    TAccountConfig,
     TAccountSystemProgram
   >);
 }
@@ -369,10 +589,18 @@ export type ParsedReviewPaperInstruction<
   accounts: {
     reviewer: TAccountMetas[0];
     researchPaper: TAccountMetas[1];
-    accessReceipt: TAccountMetas[2];
-    reviewerUserAccount: TAccountMetas[3];
-    peerReview: TAccountMetas[4];
-    systemProgram: TAccountMetas[5];
+    reviewRound: TAccountMetas[2];
+    accessReceipt?: TAccountMetas[3] | undefined;
+    institution?: TAccountMetas[4] | undefined;
+    institutionMember?: TAccountMetas[5] | undefined;
+    subscriptionPlan?: TAccountMetas[6] | undefined;
+    subscription?: TAccountMetas[7] | undefined;
+    reviewerUserAccount: TAccountMetas[8];
+    peerReview: TAccountMetas[9];
+    roundVoter: TAccountMetas[10];
+    stakeVault: TAccountMetas[11];
+    config: TAccountMetas[12];
+    systemProgram: TAccountMetas[13];
   };
   data: ReviewPaperInstructionData;
 };
@@ -385,7 +613,7 @@ export function parseReviewPaperInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedReviewPaperInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 14) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -395,14 +623,28 @@ export function parseReviewPaperInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === PRISMPAPERSDAPP_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       reviewer: getNextAccount(),
       researchPaper: getNextAccount(),
-      accessReceipt: getNextAccount(),
+      reviewRound: getNextAccount(),
+      accessReceipt: getNextOptionalAccount(),
+      institution: getNextOptionalAccount(),
+      institutionMember: getNextOptionalAccount(),
+      subscriptionPlan: getNextOptionalAccount(),
+      subscription: getNextOptionalAccount(),
       reviewerUserAccount: getNextAccount(),
       peerReview: getNextAccount(),
+      roundVoter: getNextAccount(),
+      stakeVault: getNextAccount(),
+      config: getNextAccount(),
       systemProgram: getNextAccount(),
     },
     data: getReviewPaperInstructionDataDecoder().decode(instruction.data),
@@ -12,14 +12,20 @@ import {
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
+  getArrayDecoder,
+  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU32Decoder,
   getU32Encoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   getUtf8Decoder,
   getUtf8Encoder,
   transformEncoder,
@@ -32,6 +38,9 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
+  type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
@@ -54,6 +63,10 @@ export type UpdateResearchInstruction<
   TProgram extends string = typeof PRISMPAPERSDAPP_PROGRAM_ADDRESS,
   TAccountAuthor extends string | AccountMeta<string> = string,
   TAccountResearchPaper extends string | AccountMeta<string> = string,
+  TAccountPaperRevision extends string | AccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | AccountMeta<string> = '11111111111111111111111111111111',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -66,6 +79,12 @@ export type UpdateResearchInstruction<
       TAccountResearchPaper extends string
         ? WritableAccount<TAccountResearchPaper>
         : TAccountResearchPaper,
+      TAccountPaperRevision extends string
+        ? WritableAccount<TAccountPaperRevision>
+        : TAccountPaperRevision,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -76,7 +95,8 @@ export type UpdateResearchInstructionData = {
   description: string;
   price: bigint;
   encryptedUrl: string;
-  encryptionKey: string;
+  contentHash: Array<number>;
+  ciphertextHash: Option<Array<number>>;
 };
 
 export type UpdateResearchInstructionDataArgs = {
@@ -84,7 +104,8 @@ export type UpdateResearchInstructionDataArgs = {
   description: string;
   price: number | bigint;
   encryptedUrl: string;
-  encryptionKey: string;
+  contentHash: Array<number>;
+  ciphertextHash: OptionOrNullable<Array<number>>;
 };
 
 export function getUpdateResearchInstructionDataEncoder(): Encoder<UpdateResearchInstructionDataArgs> {
@@ -95,9 +116,10 @@ export function getUpdateResearchInstructionDataEncoder(): Encoder<UpdateResearc
       ['description', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ['price', getU64Encoder()],
       ['encryptedUrl', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      ['contentHash', getArrayEncoder(getU8Encoder(), { size: 32 })],
       [
-        'encryptionKey',
-        addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder()),
+        'ciphertextHash',
+        getOptionEncoder(getArrayEncoder(getU8Encoder(), { size: 32 })),
       ],
     ]),
     (value) => ({ ...value, discriminator: UPDATE_RESEARCH_DISCRIMINATOR })
@@ -111,7 +133,11 @@ export function getUpdateResearchInstructionDataDecoder(): Decoder<UpdateResearc
     ['description', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ['price', getU64Decoder()],
     ['encryptedUrl', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
-    ['encryptionKey', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ['contentHash', getArrayDecoder(getU8Decoder(), { size: 32 })],
+    [
+      'ciphertextHash',
+      getOptionDecoder(getArrayDecoder(getU8Decoder(), { size: 32 })),
+    ],
   ]);
 }
 
@@ -128,27 +154,41 @@ export function getUpdateResearchInstructionDataCodec(): Codec<
 export type UpdateResearchInput<
   TAccountAuthor extends string = string,
   TAccountResearchPaper extends string = string,
+  TAccountPaperRevision extends string = string,
+  TAccountSystemProgram extends string = string,
 > = {
   author: TransactionSigner<TAccountAuthor>;
   researchPaper: Address<TAccountResearchPaper>;
+  paperRevision: Address<TAccountPaperRevision>;
+  systemProgram?: Address<TAccountSystemProgram>;
   title: UpdateResearchInstructionDataArgs['title'];
   description: UpdateResearchInstructionDataArgs['description'];
   price: UpdateResearchInstructionDataArgs['price'];
   encryptedUrl: UpdateResearchInstructionDataArgs['encryptedUrl'];
-  encryptionKey: UpdateResearchInstructionDataArgs['encryptionKey'];
+  contentHash: UpdateResearchInstructionDataArgs['contentHash'];
+  ciphertextHash: UpdateResearchInstructionDataArgs['ciphertextHash'];
 };
 
 export function getUpdateResearchInstruction<
   TAccountAuthor extends string,
   TAccountResearchPaper extends string,
+  TAccountPaperRevision extends string,
+  TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof PRISMPAPERSDAPP_PROGRAM_ADDRESS,
 >(
-  input: UpdateResearchInput<TAccountAuthor, TAccountResearchPaper>,
+  input: UpdateResearchInput<
+    TAccountAuthor,
+    TAccountResearchPaper,
+    TAccountPaperRevision,
+    TAccountSystemProgram
+  >,
   config?: { programAddress?: TProgramAddress }
 ): UpdateResearchInstruction<
   TProgramAddress,
   TAccountAuthor,
-  TAccountResearchPaper
+  TAccountResearchPaper,
+  TAccountPaperRevision,
+  TAccountSystemProgram
 > {
   // Program address.
   const programAddress =
@@ -158,6 +198,8 @@ export function getUpdateResearchInstruction<
   const originalAccounts = {
     author: { value: input.author ?? null, isWritable: true },
     researchPaper: { value: input.researchPaper ?? null, isWritable: true },
+    paperRevision: { value: input.paperRevision ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -167,11 +209,19 @@ export function getUpdateResearchInstruction<
   // Original args.
   const args = { ...input };
 
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.author),
       getAccountMeta(accounts.researchPaper),
+      getAccountMeta(accounts.paperRevision),
+      getAccountMeta(accounts.systemProgram),
     ],
     data: getUpdateResearchInstructionDataEncoder().encode(
       args as UpdateResearchInstructionDataArgs
@@ -180,7 +230,9 @@ export function getUpdateResearchInstruction<
   } as UpdateResearchInstruction<
     TProgramAddress,
     TAccountAuthor,
-    TAccountResearchPaper
+    TAccountResearchPaper,
+    TAccountPaperRevision,
+    TAccountSystemProgram
   >);
 }
 
@@ -192,6 +244,8 @@ export type ParsedUpdateResearchInstruction<
   accounts: {
     author: TAccountMetas[0];
     researchPaper: TAccountMetas[1];
+    paperRevision: TAccountMetas[2];
+    systemProgram: TAccountMetas[3];
   };
   data: UpdateResearchInstructionData;
 };
@@ -204,7 +258,7 @@ export function parseUpdateResearchInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedUpdateResearchInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 2) {
+  if (instruction.accounts.length < 4) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -216,7 +270,12 @@ export function parseUpdateResearchInstruction<
   };
   return {
     programAddress: instruction.programAddress,
-    accounts: { author: getNextAccount(), researchPaper: getNextAccount() },
+    accounts: {
+      author: getNextAccount(),
+      researchPaper: getNextAccount(),
+      paperRevision: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
     data: getUpdateResearchInstructionDataDecoder().decode(instruction.data),
   };
 }
@@ -54,7 +54,15 @@ export function getUserWithdrawDiscriminatorBytes() {
 export type UserWithdrawInstruction<
   TProgram extends string = typeof PRISMPAPERSDAPP_PROGRAM_ADDRESS,
   TAccountUser extends string | AccountMeta<string> = string,
+  TAccountUserAccount extends string | AccountMeta<string> = string,
   TAccountUserVault extends string | AccountMeta<string> = string,
+  TAccountPaymentMint extends string | AccountMeta<string> = string,
+  TAccountUserTokenVault extends string | AccountMeta<string> = string,
+  TAccountUserTokenAccount extends string | AccountMeta<string> = string,
+  TAccountTokenProgram extends string | AccountMeta<string> = string,
+  TAccountAssociatedTokenProgram extends
+    | string
+    | AccountMeta<string> = 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL',
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
@@ -66,9 +74,27 @@ export type UserWithdrawInstruction<
       TAccountUser extends string
         ? WritableSignerAccount<TAccountUser> & AccountSignerMeta<TAccountUser>
         : TAccountUser,
+      TAccountUserAccount extends string
+        ? WritableAccount<TAccountUserAccount>
+        : TAccountUserAccount,
       TAccountUserVault extends string
         ? WritableAccount<TAccountUserVault>
         : TAccountUserVault,
+      TAccountPaymentMint extends string
+        ? ReadonlyAccount<TAccountPaymentMint>
+        : TAccountPaymentMint,
+      TAccountUserTokenVault extends string
+        ? WritableAccount<TAccountUserTokenVault>
+        : TAccountUserTokenVault,
+      TAccountUserTokenAccount extends string
+        ? WritableAccount<TAccountUserTokenAccount>
+        : TAccountUserTokenAccount,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      TAccountAssociatedTokenProgram extends string
+        ? ReadonlyAccount<TAccountAssociatedTokenProgram>
+        : TAccountAssociatedTokenProgram,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -112,24 +138,48 @@ export function getUserWithdrawInstructionDataCodec(): FixedSizeCodec<
 
 export type UserWithdrawAsyncInput<
   TAccountUser extends string = string,
+  TAccountUserAccount extends string = string,
   TAccountUserVault extends string = string,
+  TAccountPaymentMint extends string = string,
+  TAccountUserTokenVault extends string = string,
+  TAccountUserTokenAccount extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountAssociatedTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   user: TransactionSigner<TAccountUser>;
+  userAccount?: Address<TAccountUserAccount>;
   userVault?: Address<TAccountUserVault>;
+  paymentMint?: Address<TAccountPaymentMint>;
+  userTokenVault?: Address<TAccountUserTokenVault>;
+  userTokenAccount?: Address<TAccountUserTokenAccount>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
   amount: UserWithdrawInstructionDataArgs['amount'];
 };
 
 export async function getUserWithdrawInstructionAsync<
   TAccountUser extends string,
+  TAccountUserAccount extends string,
   TAccountUserVault extends string,
+  TAccountPaymentMint extends string,
+  TAccountUserTokenVault extends string,
+  TAccountUserTokenAccount extends string,
+  TAccountTokenProgram extends string,
+  TAccountAssociatedTokenProgram extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof PRISMPAPERSDAPP_PROGRAM_ADDRESS,
 >(
   input: UserWithdrawAsyncInput<
     TAccountUser,
+    TAccountUserAccount,
     TAccountUserVault,
+    TAccountPaymentMint,
+    TAccountUserTokenVault,
+    TAccountUserTokenAccount,
+    TAccountTokenProgram,
+    TAccountAssociatedTokenProgram,
     TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
@@ -137,7 +187,13 @@ export async function getUserWithdrawInstructionAsync<
   UserWithdrawInstruction<
     TProgramAddress,
     TAccountUser,
+    TAccountUserAccount,
     TAccountUserVault,
+    TAccountPaymentMint,
+    TAccountUserTokenVault,
+    TAccountUserTokenAccount,
+    TAccountTokenProgram,
+    TAccountAssociatedTokenProgram,
     TAccountSystemProgram
   >
 > {
@@ -148,7 +204,19 @@ export async function getUserWithdrawInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     user: { value: input.user ?? null, isWritable: true },
+    userAccount: { value: input.userAccount ?? null, isWritable: true },
     userVault: { value: input.userVault ?? null, isWritable: true },
+    paymentMint: { value: input.paymentMint ?? null, isWritable: false },
+    userTokenVault: { value: input.userTokenVault ?? null, isWritable: true },
+    userTokenAccount: {
+      value: input.userTokenAccount ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    associatedTokenProgram: {
+      value: input.associatedTokenProgram ?? null,
+      isWritable: false,
+    },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -160,6 +228,15 @@ export async function getUserWithdrawInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
+  if (!accounts.userAccount.value) {
+    accounts.userAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(new Uint8Array([117, 115, 101, 114])),
+        getAddressEncoder().encode(expectAddress(accounts.user.value)),
+      ],
+    });
+  }
   if (!accounts.userVault.value) {
     accounts.userVault.value = await getProgramDerivedAddress({
       programAddress,
@@ -171,6 +248,32 @@ export async function getUserWithdrawInstructionAsync<
       ],
     });
   }
+  if (!accounts.userTokenVault.value) {
+    accounts.userTokenVault.value = await getProgramDerivedAddress({
+      programAddress:
+        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>,
+      seeds: [
+        getAddressEncoder().encode(expectAddress(accounts.userVault.value)),
+        getAddressEncoder().encode(expectAddress(accounts.tokenProgram.value)),
+        getAddressEncoder().encode(expectAddress(accounts.paymentMint.value)),
+      ],
+    });
+  }
+  if (!accounts.userTokenAccount.value) {
+    accounts.userTokenAccount.value = await getProgramDerivedAddress({
+      programAddress:
+        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>,
+      seeds: [
+        getAddressEncoder().encode(expectAddress(accounts.user.value)),
+        getAddressEncoder().encode(expectAddress(accounts.tokenProgram.value)),
+        getAddressEncoder().encode(expectAddress(accounts.paymentMint.value)),
+      ],
+    });
+  }
+  if (!accounts.associatedTokenProgram.value) {
+    accounts.associatedTokenProgram.value =
+      'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>;
+  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -180,7 +283,13 @@ export async function getUserWithdrawInstructionAsync<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.user),
+      getAccountMeta(accounts.userAccount),
       getAccountMeta(accounts.userVault),
+      getAccountMeta(accounts.paymentMint),
+      getAccountMeta(accounts.userTokenVault),
+      getAccountMeta(accounts.userTokenAccount),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.systemProgram),
     ],
     data: getUserWithdrawInstructionDataEncoder().encode(
@@ -190,38 +299,74 @@ export async function getUserWithdrawInstructionAsync<
   } as UserWithdrawInstruction<
     TProgramAddress,
     TAccountUser,
+    TAccountUserAccount,
     TAccountUserVault,
+    TAccountPaymentMint,
+    TAccountUserTokenVault,
+    TAccountUserTokenAccount,
+    TAccountTokenProgram,
+    TAccountAssociatedTokenProgram,
     TAccountSystemProgram
   >);
 }
 
 export type UserWithdrawInput<
   TAccountUser extends string = string,
+  TAccountUserAccount extends string = string,
   TAccountUserVault extends string = string,
+  TAccountPaymentMint extends string = string,
+  TAccountUserTokenVault extends string = string,
+  TAccountUserTokenAccount extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountAssociatedTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   user: TransactionSigner<TAccountUser>;
+  userAccount: Address<TAccountUserAccount>;
   userVault: Address<TAccountUserVault>;
+  paymentMint?: Address<TAccountPaymentMint>;
+  userTokenVault?: Address<TAccountUserTokenVault>;
+  userTokenAccount?: Address<TAccountUserTokenAccount>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
   amount: UserWithdrawInstructionDataArgs['amount'];
 };
 
 export function getUserWithdrawInstruction<
   TAccountUser extends string,
+  TAccountUserAccount extends string,
   TAccountUserVault extends string,
+  TAccountPaymentMint extends string,
+  TAccountUserTokenVault extends string,
+  TAccountUserTokenAccount extends string,
+  TAccountTokenProgram extends string,
+  TAccountAssociatedTokenProgram extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof PRISMPAPERSDAPP_PROGRAM_ADDRESS,
 >(
   input: UserWithdrawInput<
     TAccountUser,
+    TAccountUserAccount,
     TAccountUserVault,
+    TAccountPaymentMint,
+    TAccountUserTokenVault,
+    TAccountUserTokenAccount,
+    TAccountTokenProgram,
+    TAccountAssociatedTokenProgram,
     TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): UserWithdrawInstruction<
   TProgramAddress,
   TAccountUser,
+  TAccountUserAccount,
   TAccountUserVault,
+  TAccountPaymentMint,
+  TAccountUserTokenVault,
+  TAccountUserTokenAccount,
+  TAccountTokenProgram,
+  TAccountAssociatedTokenProgram,
   TAccountSystemProgram
 > {
   // Program address.
@@ -231,7 +376,19 @@ export function getUserWithdrawInstruction<
   // Original accounts.
   const originalAccounts = {
     user: { value: input.user ?? null, isWritable: true },
+    userAccount: { value: input.userAccount ?? null, isWritable: true },
     userVault: { value: input.userVault ?? null, isWritable: true },
+    paymentMint: { value: input.paymentMint ?? null, isWritable: false },
+    userTokenVault: { value: input.userTokenVault ?? null, isWritable: true },
+    userTokenAccount: {
+      value: input.userTokenAccount ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    associatedTokenProgram: {
+      value: input.associatedTokenProgram ?? null,
+      isWritable: false,
+    },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -243,6 +400,10 @@ export function getUserWithdrawInstruction<
   const args = { ...input };
 
   // Resolve default values.
+  if (!accounts.associatedTokenProgram.value) {
+    accounts.associatedTokenProgram.value =
+      'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>;
+  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -252,7 +413,13 @@ export function getUserWithdrawInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.user),
+      getAccountMeta(accounts.userAccount),
       getAccountMeta(accounts.userVault),
+      getAccountMeta(accounts.paymentMint),
+      getAccountMeta(accounts.userTokenVault),
+      getAccountMeta(accounts.userTokenAccount),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.systemProgram),
     ],
     data: getUserWithdrawInstructionDataEncoder().encode(
@@ -262,7 +429,13 @@ export function getUserWithdrawInstruction<
   } as UserWithdrawInstruction<
     TProgramAddress,
     TAccountUser,
+    TAccountUserAccount,
     TAccountUserVault,
+    TAccountPaymentMint,
+    TAccountUserTokenVault,
+    TAccountUserTokenAccount,
+    TAccountTokenProgram,
+    TAccountAssociatedTokenProgram,
     TAccountSystemProgram
   >);
 }
@@ -274,8 +447,14 @@ export type ParsedUserWithdrawInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     user: TAccountMetas[0];
-    userVault: TAccountMetas[1];
-    systemProgram: TAccountMetas[2];
+    userAccount: TAccountMetas[1];
+    userVault: TAccountMetas[2];
+    paymentMint?: TAccountMetas[3] | undefined;
+    userTokenVault?: TAccountMetas[4] | undefined;
+    userTokenAccount?: TAccountMetas[5] | undefined;
+    tokenProgram?: TAccountMetas[6] | undefined;
+    associatedTokenProgram?: TAccountMetas[7] | undefined;
+    systemProgram: TAccountMetas[8];
   };
   data: UserWithdrawInstructionData;
 };
@@ -288,7 +467,7 @@ export function parseUserWithdrawInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedUserWithdrawInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 3) {
+  if (instruction.accounts.length < 9) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -298,11 +477,23 @@ export function parseUserWithdrawInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === PRISMPAPERSDAPP_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       user: getNextAccount(),
+      userAccount: getNextAccount(),
       userVault: getNextAccount(),
+      paymentMint: getNextOptionalAccount(),
+      userTokenVault: getNextOptionalAccount(),
+      userTokenAccount: getNextOptionalAccount(),
+      tokenProgram: getNextOptionalAccount(),
+      associatedTokenProgram: getNextOptionalAccount(),
       systemProgram: getNextAccount(),
     },
     data: getUserWithdrawInstructionDataDecoder().decode(instruction.data),
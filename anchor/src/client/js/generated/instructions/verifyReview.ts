@@ -55,12 +55,24 @@ export type VerifyReviewInstruction<
   TProgram extends string = typeof PRISMPAPERSDAPP_PROGRAM_ADDRESS,
   TAccountAuthor extends string | AccountMeta<string> = string,
   TAccountPeerReview extends string | AccountMeta<string> = string,
+  TAccountReviewer extends string | AccountMeta<string> = string,
+  TAccountStakeVault extends string | AccountMeta<string> = string,
   TAccountResearchPaper extends string | AccountMeta<string> = string,
   TAccountReviewerUserAccount extends string | AccountMeta<string> = string,
   TAccountAuthorUserAccount extends string | AccountMeta<string> = string,
   TAccountReviewerVault extends string | AccountMeta<string> = string,
   TAccountAuthorVault extends string | AccountMeta<string> = string,
+  TAccountBountyVault extends string | AccountMeta<string> = string,
   TAccountAdminVault extends string | AccountMeta<string> = string,
+  TAccountConfig extends string | AccountMeta<string> = string,
+  TAccountPaymentMint extends string | AccountMeta<string> = string,
+  TAccountBountyTokenVault extends string | AccountMeta<string> = string,
+  TAccountReviewerTokenVault extends string | AccountMeta<string> = string,
+  TAccountAdminTokenVault extends string | AccountMeta<string> = string,
+  TAccountTokenProgram extends string | AccountMeta<string> = string,
+  TAccountAssociatedTokenProgram extends
+    | string
+    | AccountMeta<string> = 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL',
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
@@ -76,6 +88,12 @@ export type VerifyReviewInstruction<
       TAccountPeerReview extends string
         ? WritableAccount<TAccountPeerReview>
         : TAccountPeerReview,
+      TAccountReviewer extends string
+        ? WritableAccount<TAccountReviewer>
+        : TAccountReviewer,
+      TAccountStakeVault extends string
+        ? WritableAccount<TAccountStakeVault>
+        : TAccountStakeVault,
       TAccountResearchPaper extends string
         ? WritableAccount<TAccountResearchPaper>
         : TAccountResearchPaper,
@@ -91,9 +109,33 @@ export type VerifyReviewInstruction<
       TAccountAuthorVault extends string
         ? WritableAccount<TAccountAuthorVault>
         : TAccountAuthorVault,
+      TAccountBountyVault extends string
+        ? WritableAccount<TAccountBountyVault>
+        : TAccountBountyVault,
       TAccountAdminVault extends string
         ? WritableAccount<TAccountAdminVault>
         : TAccountAdminVault,
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountPaymentMint extends string
+        ? ReadonlyAccount<TAccountPaymentMint>
+        : TAccountPaymentMint,
+      TAccountBountyTokenVault extends string
+        ? WritableAccount<TAccountBountyTokenVault>
+        : TAccountBountyTokenVault,
+      TAccountReviewerTokenVault extends string
+        ? WritableAccount<TAccountReviewerTokenVault>
+        : TAccountReviewerTokenVault,
+      TAccountAdminTokenVault extends string
+        ? WritableAccount<TAccountAdminTokenVault>
+        : TAccountAdminTokenVault,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      TAccountAssociatedTokenProgram extends string
+        ? ReadonlyAccount<TAccountAssociatedTokenProgram>
+        : TAccountAssociatedTokenProgram,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -138,22 +180,42 @@ export function getVerifyReviewInstructionDataCodec(): FixedSizeCodec<
 export type VerifyReviewAsyncInput<
   TAccountAuthor extends string = string,
   TAccountPeerReview extends string = string,
+  TAccountReviewer extends string = string,
+  TAccountStakeVault extends string = string,
   TAccountResearchPaper extends string = string,
   TAccountReviewerUserAccount extends string = string,
   TAccountAuthorUserAccount extends string = string,
   TAccountReviewerVault extends string = string,
   TAccountAuthorVault extends string = string,
+  TAccountBountyVault extends string = string,
   TAccountAdminVault extends string = string,
+  TAccountConfig extends string = string,
+  TAccountPaymentMint extends string = string,
+  TAccountBountyTokenVault extends string = string,
+  TAccountReviewerTokenVault extends string = string,
+  TAccountAdminTokenVault extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountAssociatedTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   author: TransactionSigner<TAccountAuthor>;
   peerReview: Address<TAccountPeerReview>;
+  reviewer: Address<TAccountReviewer>;
+  stakeVault?: Address<TAccountStakeVault>;
   researchPaper: Address<TAccountResearchPaper>;
-  reviewerUserAccount: Address<TAccountReviewerUserAccount>;
-  authorUserAccount: Address<TAccountAuthorUserAccount>;
+  reviewerUserAccount?: Address<TAccountReviewerUserAccount>;
+  authorUserAccount?: Address<TAccountAuthorUserAccount>;
   reviewerVault: Address<TAccountReviewerVault>;
   authorVault?: Address<TAccountAuthorVault>;
+  bountyVault?: Address<TAccountBountyVault>;
   adminVault?: Address<TAccountAdminVault>;
+  config?: Address<TAccountConfig>;
+  paymentMint?: Address<TAccountPaymentMint>;
+  bountyTokenVault?: Address<TAccountBountyTokenVault>;
+  reviewerTokenVault?: Address<TAccountReviewerTokenVault>;
+  adminTokenVault?: Address<TAccountAdminTokenVault>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
   acceptProposedReview: VerifyReviewInstructionDataArgs['acceptProposedReview'];
 };
@@ -161,24 +223,44 @@ export type VerifyReviewAsyncInput<
 export async function getVerifyReviewInstructionAsync<
   TAccountAuthor extends string,
   TAccountPeerReview extends string,
+  TAccountReviewer extends string,
+  TAccountStakeVault extends string,
   TAccountResearchPaper extends string,
   TAccountReviewerUserAccount extends string,
   TAccountAuthorUserAccount extends string,
   TAccountReviewerVault extends string,
   TAccountAuthorVault extends string,
+  TAccountBountyVault extends string,
   TAccountAdminVault extends string,
+  TAccountConfig extends string,
+  TAccountPaymentMint extends string,
+  TAccountBountyTokenVault extends string,
+  TAccountReviewerTokenVault extends string,
+  TAccountAdminTokenVault extends string,
+  TAccountTokenProgram extends string,
+  TAccountAssociatedTokenProgram extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof PRISMPAPERSDAPP_PROGRAM_ADDRESS,
 >(
   input: VerifyReviewAsyncInput<
     TAccountAuthor,
     TAccountPeerReview,
+    TAccountReviewer,
+    TAccountStakeVault,
     TAccountResearchPaper,
     TAccountReviewerUserAccount,
     TAccountAuthorUserAccount,
     TAccountReviewerVault,
     TAccountAuthorVault,
+    TAccountBountyVault,
     TAccountAdminVault,
+    TAccountConfig,
+    TAccountPaymentMint,
+    TAccountBountyTokenVault,
+    TAccountReviewerTokenVault,
+    TAccountAdminTokenVault,
+    TAccountTokenProgram,
+    TAccountAssociatedTokenProgram,
     TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
@@ -187,12 +269,22 @@ export async function getVerifyReviewInstructionAsync<
     TProgramAddress,
     TAccountAuthor,
     TAccountPeerReview,
+    TAccountReviewer,
+    TAccountStakeVault,
     TAccountResearchPaper,
     TAccountReviewerUserAccount,
     TAccountAuthorUserAccount,
     TAccountReviewerVault,
     TAccountAuthorVault,
+    TAccountBountyVault,
     TAccountAdminVault,
+    TAccountConfig,
+    TAccountPaymentMint,
+    TAccountBountyTokenVault,
+    TAccountReviewerTokenVault,
+    TAccountAdminTokenVault,
+    TAccountTokenProgram,
+    TAccountAssociatedTokenProgram,
     TAccountSystemProgram
   >
 > {
@@ -204,6 +296,8 @@ export async function getVerifyReviewInstructionAsync<
   const originalAccounts = {
     author: { value: input.author ?? null, isWritable: true },
     peerReview: { value: input.peerReview ?? null, isWritable: true },
+    reviewer: { value: input.reviewer ?? null, isWritable: true },
+    stakeVault: { value: input.stakeVault ?? null, isWritable: true },
     researchPaper: { value: input.researchPaper ?? null, isWritable: true },
     reviewerUserAccount: {
       value: input.reviewerUserAccount ?? null,
@@ -215,7 +309,24 @@ export async function getVerifyReviewInstructionAsync<
     },
     reviewerVault: { value: input.reviewerVault ?? null, isWritable: true },
     authorVault: { value: input.authorVault ?? null, isWritable: true },
+    bountyVault: { value: input.bountyVault ?? null, isWritable: true },
     adminVault: { value: input.adminVault ?? null, isWritable: true },
+    config: { value: input.config ?? null, isWritable: false },
+    paymentMint: { value: input.paymentMint ?? null, isWritable: false },
+    bountyTokenVault: {
+      value: input.bountyTokenVault ?? null,
+      isWritable: true,
+    },
+    reviewerTokenVault: {
+      value: input.reviewerTokenVault ?? null,
+      isWritable: true,
+    },
+    adminTokenVault: { value: input.adminTokenVault ?? null, isWritable: true },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    associatedTokenProgram: {
+      value: input.associatedTokenProgram ?? null,
+      isWritable: false,
+    },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -227,6 +338,26 @@ export async function getVerifyReviewInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
+  if (!accounts.stakeVault.value) {
+    accounts.stakeVault.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([118, 97, 117, 108, 116, 95, 115, 116, 97, 107, 101])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.peerReview.value)),
+      ],
+    });
+  }
+  if (!accounts.authorUserAccount.value) {
+    accounts.authorUserAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(new Uint8Array([117, 115, 101, 114])),
+        getAddressEncoder().encode(expectAddress(accounts.author.value)),
+      ],
+    });
+  }
   if (!accounts.authorVault.value) {
     accounts.authorVault.value = await getProgramDerivedAddress({
       programAddress,
@@ -238,6 +369,19 @@ export async function getVerifyReviewInstructionAsync<
       ],
     });
   }
+  if (!accounts.bountyVault.value) {
+    accounts.bountyVault.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            118, 97, 117, 108, 116, 95, 98, 111, 117, 110, 116, 121,
+          ])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.researchPaper.value)),
+      ],
+    });
+  }
   if (!accounts.adminVault.value) {
     accounts.adminVault.value = await getProgramDerivedAddress({
       programAddress,
@@ -248,6 +392,51 @@ export async function getVerifyReviewInstructionAsync<
       ],
     });
   }
+  if (!accounts.config.value) {
+    accounts.config.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(new Uint8Array([99, 111, 110, 102, 105, 103])),
+      ],
+    });
+  }
+  if (!accounts.bountyTokenVault.value) {
+    accounts.bountyTokenVault.value = await getProgramDerivedAddress({
+      programAddress:
+        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>,
+      seeds: [
+        getAddressEncoder().encode(expectAddress(accounts.bountyVault.value)),
+        getAddressEncoder().encode(expectAddress(accounts.tokenProgram.value)),
+        getAddressEncoder().encode(expectAddress(accounts.paymentMint.value)),
+      ],
+    });
+  }
+  if (!accounts.reviewerTokenVault.value) {
+    accounts.reviewerTokenVault.value = await getProgramDerivedAddress({
+      programAddress:
+        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>,
+      seeds: [
+        getAddressEncoder().encode(expectAddress(accounts.reviewerVault.value)),
+        getAddressEncoder().encode(expectAddress(accounts.tokenProgram.value)),
+        getAddressEncoder().encode(expectAddress(accounts.paymentMint.value)),
+      ],
+    });
+  }
+  if (!accounts.adminTokenVault.value) {
+    accounts.adminTokenVault.value = await getProgramDerivedAddress({
+      programAddress:
+        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>,
+      seeds: [
+        getAddressEncoder().encode(expectAddress(accounts.adminVault.value)),
+        getAddressEncoder().encode(expectAddress(accounts.tokenProgram.value)),
+        getAddressEncoder().encode(expectAddress(accounts.paymentMint.value)),
+      ],
+    });
+  }
+  if (!accounts.associatedTokenProgram.value) {
+    accounts.associatedTokenProgram.value =
+      'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>;
+  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -258,12 +447,22 @@ export async function getVerifyReviewInstructionAsync<
     accounts: [
       getAccountMeta(accounts.author),
       getAccountMeta(accounts.peerReview),
+      getAccountMeta(accounts.reviewer),
+      getAccountMeta(accounts.stakeVault),
       getAccountMeta(accounts.researchPaper),
       getAccountMeta(accounts.reviewerUserAccount),
       getAccountMeta(accounts.authorUserAccount),
       getAccountMeta(accounts.reviewerVault),
       getAccountMeta(accounts.authorVault),
+      getAccountMeta(accounts.bountyVault),
       getAccountMeta(accounts.adminVault),
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.paymentMint),
+      getAccountMeta(accounts.bountyTokenVault),
+      getAccountMeta(accounts.reviewerTokenVault),
+      getAccountMeta(accounts.adminTokenVault),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.systemProgram),
     ],
     data: getVerifyReviewInstructionDataEncoder().encode(
@@ -274,12 +473,22 @@ export async function getVerifyReviewInstructionAsync<
     TProgramAddress,
     TAccountAuthor,
     TAccountPeerReview,
+    TAccountReviewer,
+    TAccountStakeVault,
     TAccountResearchPaper,
     TAccountReviewerUserAccount,
     TAccountAuthorUserAccount,
     TAccountReviewerVault,
     TAccountAuthorVault,
+    TAccountBountyVault,
     TAccountAdminVault,
+    TAccountConfig,
+    TAccountPaymentMint,
+    TAccountBountyTokenVault,
+    TAccountReviewerTokenVault,
+    TAccountAdminTokenVault,
+    TAccountTokenProgram,
+    TAccountAssociatedTokenProgram,
     TAccountSystemProgram
   >);
 }
@@ -287,22 +496,42 @@ export async function getVerifyReviewInstructionAsync<
 export type VerifyReviewInput<
   TAccountAuthor extends string = string,
   TAccountPeerReview extends string = string,
+  TAccountReviewer extends string = string,
+  TAccountStakeVault extends string = string,
   TAccountResearchPaper extends string = string,
   TAccountReviewerUserAccount extends string = string,
   TAccountAuthorUserAccount extends string = string,
   TAccountReviewerVault extends string = string,
   TAccountAuthorVault extends string = string,
+  TAccountBountyVault extends string = string,
   TAccountAdminVault extends string = string,
+  TAccountConfig extends string = string,
+  TAccountPaymentMint extends string = string,
+  TAccountBountyTokenVault extends string = string,
+  TAccountReviewerTokenVault extends string = string,
+  TAccountAdminTokenVault extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountAssociatedTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   author: TransactionSigner<TAccountAuthor>;
   peerReview: Address<TAccountPeerReview>;
+  reviewer: Address<TAccountReviewer>;
+  stakeVault: Address<TAccountStakeVault>;
   researchPaper: Address<TAccountResearchPaper>;
-  reviewerUserAccount: Address<TAccountReviewerUserAccount>;
+  reviewerUserAccount?: Address<TAccountReviewerUserAccount>;
   authorUserAccount: Address<TAccountAuthorUserAccount>;
   reviewerVault: Address<TAccountReviewerVault>;
   authorVault: Address<TAccountAuthorVault>;
+  bountyVault: Address<TAccountBountyVault>;
   adminVault: Address<TAccountAdminVault>;
+  config: Address<TAccountConfig>;
+  paymentMint?: Address<TAccountPaymentMint>;
+  bountyTokenVault?: Address<TAccountBountyTokenVault>;
+  reviewerTokenVault?: Address<TAccountReviewerTokenVault>;
+  adminTokenVault?: Address<TAccountAdminTokenVault>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
   acceptProposedReview: VerifyReviewInstructionDataArgs['acceptProposedReview'];
 };
@@ -310,24 +539,44 @@ export type VerifyReviewInput<
 export function getVerifyReviewInstruction<
   TAccountAuthor extends string,
   TAccountPeerReview extends string,
+  TAccountReviewer extends string,
+  TAccountStakeVault extends string,
   TAccountResearchPaper extends string,
   TAccountReviewerUserAccount extends string,
   TAccountAuthorUserAccount extends string,
   TAccountReviewerVault extends string,
   TAccountAuthorVault extends string,
+  TAccountBountyVault extends string,
   TAccountAdminVault extends string,
+  TAccountConfig extends string,
+  TAccountPaymentMint extends string,
+  TAccountBountyTokenVault extends string,
+  TAccountReviewerTokenVault extends string,
+  TAccountAdminTokenVault extends string,
+  TAccountTokenProgram extends string,
+  TAccountAssociatedTokenProgram extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof PRISMPAPERSDAPP_PROGRAM_ADDRESS,
 >(
   input: VerifyReviewInput<
     TAccountAuthor,
     TAccountPeerReview,
+    TAccountReviewer,
+    TAccountStakeVault,
     TAccountResearchPaper,
     TAccountReviewerUserAccount,
     TAccountAuthorUserAccount,
     TAccountReviewerVault,
     TAccountAuthorVault,
+    TAccountBountyVault,
     TAccountAdminVault,
+    TAccountConfig,
+    TAccountPaymentMint,
+    TAccountBountyTokenVault,
+    TAccountReviewerTokenVault,
+    TAccountAdminTokenVault,
+    TAccountTokenProgram,
+    TAccountAssociatedTokenProgram,
     TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
@@ -335,12 +584,22 @@ export function getVerifyReviewInstruction<
   TProgramAddress,
   TAccountAuthor,
   TAccountPeerReview,
+  TAccountReviewer,
+  TAccountStakeVault,
   TAccountResearchPaper,
   TAccountReviewerUserAccount,
   TAccountAuthorUserAccount,
   TAccountReviewerVault,
   TAccountAuthorVault,
+  TAccountBountyVault,
   TAccountAdminVault,
+  TAccountConfig,
+  TAccountPaymentMint,
+  TAccountBountyTokenVault,
+  TAccountReviewerTokenVault,
+  TAccountAdminTokenVault,
+  TAccountTokenProgram,
+  TAccountAssociatedTokenProgram,
   TAccountSystemProgram
 > {
   // Program address.
@@ -351,6 +610,8 @@ export function getVerifyReviewInstruction<
   const originalAccounts = {
     author: { value: input.author ?? null, isWritable: true },
     peerReview: { value: input.peerReview ?? null, isWritable: true },
+    reviewer: { value: input.reviewer ?? null, isWritable: true },
+    stakeVault: { value: input.stakeVault ?? null, isWritable: true },
     researchPaper: { value: input.researchPaper ?? null, isWritable: true },
     reviewerUserAccount: {
       value: input.reviewerUserAccount ?? null,
@@ -362,7 +623,24 @@ export function getVerifyReviewInstruction<
     },
     reviewerVault: { value: input.reviewerVault ?? null, isWritable: true },
     authorVault: { value: input.authorVault ?? null, isWritable: true },
+    bountyVault: { value: input.bountyVault ?? null, isWritable: true },
     adminVault: { value: input.adminVault ?? null, isWritable: true },
+    config: { value: input.config ?? null, isWritable: false },
+    paymentMint: { value: input.paymentMint ?? null, isWritable: false },
+    bountyTokenVault: {
+      value: input.bountyTokenVault ?? null,
+      isWritable: true,
+    },
+    reviewerTokenVault: {
+      value: input.reviewerTokenVault ?? null,
+      isWritable: true,
+    },
+    adminTokenVault: { value: input.adminTokenVault ?? null, isWritable: true },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    associatedTokenProgram: {
+      value: input.associatedTokenProgram ?? null,
+      isWritable: false,
+    },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -374,6 +652,10 @@ export function getVerifyReviewInstruction<
   const args = { ...input };
 
   // Resolve default values.
+  if (!accounts.associatedTokenProgram.value) {
+    accounts.associatedTokenProgram.value =
+      'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>;
+  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -384,12 +666,22 @@ export function getVerifyReviewInstruction<
     accounts: [
       getAccountMeta(accounts.author),
       getAccountMeta(accounts.peerReview),
+      getAccountMeta(accounts.reviewer),
+      getAccountMeta(accounts.stakeVault),
       getAccountMeta(accounts.researchPaper),
       getAccountMeta(accounts.reviewerUserAccount),
       getAccountMeta(accounts.authorUserAccount),
       getAccountMeta(accounts.reviewerVault),
       getAccountMeta(accounts.authorVault),
+      getAccountMeta(accounts.bountyVault),
       getAccountMeta(accounts.adminVault),
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.paymentMint),
+      getAccountMeta(accounts.bountyTokenVault),
+      getAccountMeta(accounts.reviewerTokenVault),
+      getAccountMeta(accounts.adminTokenVault),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.systemProgram),
     ],
     data: getVerifyReviewInstructionDataEncoder().encode(
@@ -400,12 +692,22 @@ export function getVerifyReviewInstruction<
     TProgramAddress,
     TAccountAuthor,
     TAccountPeerReview,
+    TAccountReviewer,
+    TAccountStakeVault,
     TAccountResearchPaper,
     TAccountReviewerUserAccount,
     TAccountAuthorUserAccount,
     TAccountReviewerVault,
     TAccountAuthorVault,
+    TAccountBountyVault,
     TAccountAdminVault,
+    TAccountConfig,
+    TAccountPaymentMint,
+    TAccountBountyTokenVault,
+    TAccountReviewerTokenVault,
+    TAccountAdminTokenVault,
+    TAccountTokenProgram,
+    TAccountAssociatedTokenProgram,
     TAccountSystemProgram
   >);
 }
@@ -418,13 +720,23 @@ export type ParsedVerifyReviewInstruction<
   accounts: {
     author: TAccountMetas[0];
     peerReview: TAccountMetas[1];
-    researchPaper: TAccountMetas[2];
-    reviewerUserAccount: TAccountMetas[3];
-    authorUserAccount: TAccountMetas[4];
-    reviewerVault: TAccountMetas[5];
-    authorVault: TAccountMetas[6];
-    adminVault: TAccountMetas[7];
-    systemProgram: TAccountMetas[8];
+    reviewer: TAccountMetas[2];
+    stakeVault: TAccountMetas[3];
+    researchPaper: TAccountMetas[4];
+    reviewerUserAccount?: TAccountMetas[5] | undefined;
+    authorUserAccount: TAccountMetas[6];
+    reviewerVault: TAccountMetas[7];
+    authorVault: TAccountMetas[8];
+    bountyVault: TAccountMetas[9];
+    adminVault: TAccountMetas[10];
+    config: TAccountMetas[11];
+    paymentMint?: TAccountMetas[12] | undefined;
+    bountyTokenVault?: TAccountMetas[13] | undefined;
+    reviewerTokenVault?: TAccountMetas[14] | undefined;
+    adminTokenVault?: TAccountMetas[15] | undefined;
+    tokenProgram?: TAccountMetas[16] | undefined;
+    associatedTokenProgram?: TAccountMetas[17] | undefined;
+    systemProgram: TAccountMetas[18];
   };
   data: VerifyReviewInstructionData;
 };
@@ -437,7 +749,7 @@ export function parseVerifyReviewInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedVerifyReviewInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 9) {
+  if (instruction.accounts.length < 19) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -447,17 +759,33 @@ export function parseVerifyReviewInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === PRISMPAPERSDAPP_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       author: getNextAccount(),
       peerReview: getNextAccount(),
+      reviewer: getNextAccount(),
+      stakeVault: getNextAccount(),
       researchPaper: getNextAccount(),
-      reviewerUserAccount: getNextAccount(),
+      reviewerUserAccount: getNextOptionalAccount(),
       authorUserAccount: getNextAccount(),
       reviewerVault: getNextAccount(),
       authorVault: getNextAccount(),
+      bountyVault: getNextAccount(),
       adminVault: getNextAccount(),
+      config: getNextAccount(),
+      paymentMint: getNextOptionalAccount(),
+      bountyTokenVault: getNextOptionalAccount(),
+      reviewerTokenVault: getNextOptionalAccount(),
+      adminTokenVault: getNextOptionalAccount(),
+      tokenProgram: getNextOptionalAccount(),
+      associatedTokenProgram: getNextOptionalAccount(),
       systemProgram: getNextAccount(),
     },
     data: getVerifyReviewInstructionDataDecoder().decode(instruction.data),
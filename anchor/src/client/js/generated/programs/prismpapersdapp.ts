@@ -14,11 +14,58 @@ import {
   type ReadonlyUint8Array,
 } from 'gill';
 import {
+  type ParsedAddInstitutionMemberInstruction,
   type ParsedAdminWithdrawInstruction,
+  type ParsedApproveRefundInstruction,
+  type ParsedArbitrateRefundInstruction,
+  type ParsedCloseKeyDeliveryInstruction,
+  type ParsedCloseLicenseReceiptInstruction,
+  type ParsedClosePaperInstruction,
+  type ParsedCloseReceiptInstruction,
+  type ParsedCloseReviewInstruction,
+  type ParsedCloseReviewRoundInstruction,
+  type ParsedCloseRevisionInstruction,
+  type ParsedCloseSubscriptionInstruction,
+  type ParsedCloseUserInstruction,
+  type ParsedCounterOfferReviewInstruction,
+  type ParsedCreateBundleInstruction,
+  type ParsedCreatePlanInstruction,
+  type ParsedDeliverKeyInstruction,
+  type ParsedDeliverKeysInstruction,
+  type ParsedDeliverSubscriptionKeyInstruction,
+  type ParsedExpireReviewInstruction,
+  type ParsedFinalizeReviewRoundInstruction,
+  type ParsedFlagReviewInstruction,
+  type ParsedFundBountyInstruction,
+  type ParsedInitInstitutionInstruction,
   type ParsedInitResearchInstruction,
   type ParsedInitUserInstruction,
+  type ParsedInitializeConfigInstruction,
+  type ParsedLapseRefundInstruction,
+  type ParsedOpenReviewRoundInstruction,
+  type ParsedPublishPaperInstruction,
   type ParsedPurchaseAccessInstruction,
+  type ParsedPurchaseBundleInstruction,
+  type ParsedPurchaseLicenseInstruction,
+  type ParsedReclaimBountyInstruction,
+  type ParsedRemoveInstitutionMemberInstruction,
+  type ParsedRequestRefundInstruction,
+  type ParsedRespondToCounterOfferInstruction,
+  type ParsedRetractPaperInstruction,
+  type ParsedRevealReviewInstruction,
   type ParsedReviewPaperInstruction,
+  type ParsedReviewPaperAnonymouslyInstruction,
+  type ParsedSetCoAuthorsInstruction,
+  type ParsedSetInstitutionSeatsInstruction,
+  type ParsedSetMinReputationInstruction,
+  type ParsedSetReviewResponsePolicyInstruction,
+  type ParsedSetReviewStakeInstruction,
+  type ParsedSetRevisionPolicyInstruction,
+  type ParsedSettlePurchaseInstruction,
+  type ParsedSubscribeInstruction,
+  type ParsedUpdateBundleInstruction,
+  type ParsedUpdateConfigInstruction,
+  type ParsedUpdatePlanInstruction,
   type ParsedUpdateResearchInstruction,
   type ParsedUserWithdrawInstruction,
   type ParsedVerifyReviewInstruction,
@@ -29,8 +76,19 @@ export const PRISMPAPERSDAPP_PROGRAM_ADDRESS =
 
 export enum PrismpapersdappAccount {
   AccessReceipt,
+  Bundle,
+  Config,
+  Institution,
+  InstitutionMember,
+  KeyDelivery,
+  PaperCounter,
+  PaperRevision,
   PeerReview,
   ResearchPaper,
+  ReviewRound,
+  RoundVoter,
+  Subscription,
+  SubscriptionPlan,
   User,
 }
 
@@ -49,6 +107,83 @@ export function identifyPrismpapersdappAccount(
   ) {
     return PrismpapersdappAccount.AccessReceipt;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([15, 82, 167, 230, 37, 214, 82, 80])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappAccount.Bundle;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([155, 12, 170, 224, 30, 250, 204, 130])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappAccount.Config;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([178, 67, 44, 135, 26, 236, 199, 188])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappAccount.Institution;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([155, 32, 67, 199, 248, 249, 16, 224])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappAccount.InstitutionMember;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([11, 135, 100, 33, 141, 201, 119, 138])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappAccount.KeyDelivery;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([202, 55, 201, 51, 194, 88, 93, 169])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappAccount.PaperCounter;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([87, 107, 244, 228, 201, 59, 243, 179])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappAccount.PaperRevision;
+  }
   if (
     containsBytes(
       data,
@@ -58,93 +193,569 @@ export function identifyPrismpapersdappAccount(
       0
     )
   ) {
-    return PrismpapersdappAccount.PeerReview;
+    return PrismpapersdappAccount.PeerReview;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([103, 125, 147, 192, 213, 248, 70, 245])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappAccount.ResearchPaper;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([90, 236, 225, 30, 1, 135, 67, 114])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappAccount.ReviewRound;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([168, 171, 129, 38, 58, 79, 136, 179])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappAccount.RoundVoter;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([64, 7, 26, 135, 102, 132, 98, 33])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappAccount.Subscription;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([157, 153, 188, 46, 234, 53, 172, 124])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappAccount.SubscriptionPlan;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([159, 117, 95, 227, 239, 151, 58, 236])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappAccount.User;
+  }
+  throw new Error(
+    'The provided account could not be identified as a prismpapersdapp account.'
+  );
+}
+
+export enum PrismpapersdappInstruction {
+  AddInstitutionMember,
+  AdminWithdraw,
+  ApproveRefund,
+  ArbitrateRefund,
+  CloseKeyDelivery,
+  CloseLicenseReceipt,
+  ClosePaper,
+  CloseReceipt,
+  CloseReview,
+  CloseReviewRound,
+  CloseRevision,
+  CloseSubscription,
+  CloseUser,
+  CounterOfferReview,
+  CreateBundle,
+  CreatePlan,
+  DeliverKey,
+  DeliverKeys,
+  DeliverSubscriptionKey,
+  ExpireReview,
+  FinalizeReviewRound,
+  FlagReview,
+  FundBounty,
+  InitInstitution,
+  InitResearch,
+  InitUser,
+  InitializeConfig,
+  LapseRefund,
+  OpenReviewRound,
+  PublishPaper,
+  PurchaseAccess,
+  PurchaseBundle,
+  PurchaseLicense,
+  ReclaimBounty,
+  RemoveInstitutionMember,
+  RequestRefund,
+  RespondToCounterOffer,
+  RetractPaper,
+  RevealReview,
+  ReviewPaper,
+  ReviewPaperAnonymously,
+  SetCoAuthors,
+  SetInstitutionSeats,
+  SetMinReputation,
+  SetReviewResponsePolicy,
+  SetReviewStake,
+  SetRevisionPolicy,
+  SettlePurchase,
+  Subscribe,
+  UpdateBundle,
+  UpdateConfig,
+  UpdatePlan,
+  UpdateResearch,
+  UserWithdraw,
+  VerifyReview,
+}
+
+export function identifyPrismpapersdappInstruction(
+  instruction: { data: ReadonlyUint8Array } | ReadonlyUint8Array
+): PrismpapersdappInstruction {
+  const data = 'data' in instruction ? instruction.data : instruction;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([79, 98, 118, 227, 249, 64, 206, 215])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.AddInstitutionMember;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([160, 166, 147, 222, 46, 220, 75, 224])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.AdminWithdraw;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([133, 74, 53, 175, 88, 246, 218, 27])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.ApproveRefund;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([4, 224, 236, 160, 245, 77, 252, 172])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.ArbitrateRefund;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([180, 136, 150, 52, 237, 126, 209, 64])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.CloseKeyDelivery;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([117, 247, 216, 129, 203, 254, 99, 170])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.CloseLicenseReceipt;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([99, 67, 102, 217, 26, 149, 198, 34])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.ClosePaper;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([126, 254, 244, 203, 124, 164, 134, 89])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.CloseReceipt;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([40, 68, 167, 50, 223, 182, 187, 66])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.CloseReview;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([191, 131, 206, 190, 243, 125, 92, 215])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.CloseReviewRound;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([174, 55, 73, 77, 149, 159, 9, 203])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.CloseRevision;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([33, 214, 169, 135, 35, 127, 78, 7])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.CloseSubscription;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([86, 219, 138, 140, 236, 24, 118, 200])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.CloseUser;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([144, 254, 40, 193, 76, 102, 115, 236])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.CounterOfferReview;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([108, 43, 176, 128, 45, 94, 197, 95])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.CreateBundle;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([77, 43, 141, 254, 212, 118, 41, 186])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.CreatePlan;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([55, 42, 247, 113, 89, 21, 64, 216])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.DeliverKey;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([175, 91, 51, 241, 200, 134, 1, 223])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.DeliverKeys;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([156, 215, 43, 76, 74, 92, 221, 207])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.DeliverSubscriptionKey;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([99, 163, 211, 46, 73, 192, 241, 105])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.ExpireReview;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([84, 10, 72, 199, 213, 146, 83, 221])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.FinalizeReviewRound;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([1, 131, 157, 240, 47, 2, 213, 79])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.FlagReview;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([36, 148, 139, 239, 172, 37, 58, 255])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.FundBounty;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([30, 36, 45, 134, 204, 23, 230, 212])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.InitInstitution;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([244, 75, 131, 90, 164, 217, 220, 42])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.InitResearch;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([14, 51, 68, 159, 237, 78, 158, 102])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.InitUser;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([103, 125, 147, 192, 213, 248, 70, 245])
+        new Uint8Array([208, 127, 21, 1, 194, 190, 196, 70])
       ),
       0
     )
   ) {
-    return PrismpapersdappAccount.ResearchPaper;
+    return PrismpapersdappInstruction.InitializeConfig;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([159, 117, 95, 227, 239, 151, 58, 236])
+        new Uint8Array([118, 200, 249, 131, 3, 248, 130, 243])
       ),
       0
     )
   ) {
-    return PrismpapersdappAccount.User;
+    return PrismpapersdappInstruction.LapseRefund;
   }
-  throw new Error(
-    'The provided account could not be identified as a prismpapersdapp account.'
-  );
-}
-
-export enum PrismpapersdappInstruction {
-  AdminWithdraw,
-  InitResearch,
-  InitUser,
-  PurchaseAccess,
-  ReviewPaper,
-  UpdateResearch,
-  UserWithdraw,
-  VerifyReview,
-}
-
-export function identifyPrismpapersdappInstruction(
-  instruction: { data: ReadonlyUint8Array } | ReadonlyUint8Array
-): PrismpapersdappInstruction {
-  const data = 'data' in instruction ? instruction.data : instruction;
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([160, 166, 147, 222, 46, 220, 75, 224])
+        new Uint8Array([57, 8, 131, 6, 108, 159, 86, 218])
       ),
       0
     )
   ) {
-    return PrismpapersdappInstruction.AdminWithdraw;
+    return PrismpapersdappInstruction.OpenReviewRound;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([244, 75, 131, 90, 164, 217, 220, 42])
+        new Uint8Array([132, 116, 126, 224, 32, 207, 19, 18])
       ),
       0
     )
   ) {
-    return PrismpapersdappInstruction.InitResearch;
+    return PrismpapersdappInstruction.PublishPaper;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([14, 51, 68, 159, 237, 78, 158, 102])
+        new Uint8Array([191, 249, 111, 210, 163, 248, 87, 242])
       ),
       0
     )
   ) {
-    return PrismpapersdappInstruction.InitUser;
+    return PrismpapersdappInstruction.PurchaseAccess;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([191, 249, 111, 210, 163, 248, 87, 242])
+        new Uint8Array([76, 60, 192, 10, 119, 47, 5, 32])
       ),
       0
     )
   ) {
-    return PrismpapersdappInstruction.PurchaseAccess;
+    return PrismpapersdappInstruction.PurchaseBundle;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([42, 213, 241, 95, 60, 182, 79, 151])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.PurchaseLicense;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([168, 233, 180, 135, 93, 48, 135, 145])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.ReclaimBounty;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([190, 78, 41, 159, 114, 137, 53, 244])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.RemoveInstitutionMember;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([155, 77, 126, 53, 47, 81, 144, 82])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.RequestRefund;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([72, 48, 111, 224, 210, 26, 88, 199])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.RespondToCounterOffer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([99, 118, 109, 157, 177, 233, 224, 110])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.RetractPaper;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([58, 28, 112, 202, 235, 241, 64, 45])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.RevealReview;
   }
   if (
     containsBytes(
@@ -157,6 +768,138 @@ export function identifyPrismpapersdappInstruction(
   ) {
     return PrismpapersdappInstruction.ReviewPaper;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([24, 204, 20, 212, 217, 218, 201, 14])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.ReviewPaperAnonymously;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([245, 236, 148, 112, 61, 229, 74, 112])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.SetCoAuthors;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([115, 126, 194, 235, 15, 102, 243, 115])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.SetInstitutionSeats;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([87, 111, 134, 105, 132, 179, 225, 86])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.SetMinReputation;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([246, 37, 144, 232, 255, 253, 147, 64])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.SetReviewResponsePolicy;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([252, 205, 31, 238, 226, 180, 220, 242])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.SetReviewStake;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([86, 57, 212, 187, 239, 8, 136, 252])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.SetRevisionPolicy;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([96, 123, 151, 42, 186, 39, 84, 111])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.SettlePurchase;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([254, 28, 191, 138, 156, 179, 183, 53])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.Subscribe;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([243, 102, 200, 60, 41, 167, 95, 146])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.UpdateBundle;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([29, 158, 252, 191, 10, 83, 219, 99])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.UpdateConfig;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([119, 112, 58, 60, 76, 205, 1, 100])
+      ),
+      0
+    )
+  ) {
+    return PrismpapersdappInstruction.UpdatePlan;
+  }
   if (
     containsBytes(
       data,
@@ -198,21 +941,162 @@ export function identifyPrismpapersdappInstruction(
 export type ParsedPrismpapersdappInstruction<
   TProgram extends string = '2nvhRn83KBxkkAfLH64meTq8cYB5aRLnZVbsxZdgfPTv',
 > =
+  | ({
+      instructionType: PrismpapersdappInstruction.AddInstitutionMember;
+    } & ParsedAddInstitutionMemberInstruction<TProgram>)
   | ({
       instructionType: PrismpapersdappInstruction.AdminWithdraw;
     } & ParsedAdminWithdrawInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.ApproveRefund;
+    } & ParsedApproveRefundInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.ArbitrateRefund;
+    } & ParsedArbitrateRefundInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.CloseKeyDelivery;
+    } & ParsedCloseKeyDeliveryInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.CloseLicenseReceipt;
+    } & ParsedCloseLicenseReceiptInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.ClosePaper;
+    } & ParsedClosePaperInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.CloseReceipt;
+    } & ParsedCloseReceiptInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.CloseReview;
+    } & ParsedCloseReviewInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.CloseReviewRound;
+    } & ParsedCloseReviewRoundInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.CloseRevision;
+    } & ParsedCloseRevisionInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.CloseSubscription;
+    } & ParsedCloseSubscriptionInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.CloseUser;
+    } & ParsedCloseUserInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.CounterOfferReview;
+    } & ParsedCounterOfferReviewInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.CreateBundle;
+    } & ParsedCreateBundleInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.CreatePlan;
+    } & ParsedCreatePlanInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.DeliverKey;
+    } & ParsedDeliverKeyInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.DeliverKeys;
+    } & ParsedDeliverKeysInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.DeliverSubscriptionKey;
+    } & ParsedDeliverSubscriptionKeyInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.ExpireReview;
+    } & ParsedExpireReviewInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.FinalizeReviewRound;
+    } & ParsedFinalizeReviewRoundInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.FlagReview;
+    } & ParsedFlagReviewInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.FundBounty;
+    } & ParsedFundBountyInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.InitInstitution;
+    } & ParsedInitInstitutionInstruction<TProgram>)
   | ({
       instructionType: PrismpapersdappInstruction.InitResearch;
     } & ParsedInitResearchInstruction<TProgram>)
   | ({
       instructionType: PrismpapersdappInstruction.InitUser;
     } & ParsedInitUserInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.InitializeConfig;
+    } & ParsedInitializeConfigInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.LapseRefund;
+    } & ParsedLapseRefundInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.OpenReviewRound;
+    } & ParsedOpenReviewRoundInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.PublishPaper;
+    } & ParsedPublishPaperInstruction<TProgram>)
   | ({
       instructionType: PrismpapersdappInstruction.PurchaseAccess;
     } & ParsedPurchaseAccessInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.PurchaseBundle;
+    } & ParsedPurchaseBundleInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.PurchaseLicense;
+    } & ParsedPurchaseLicenseInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.ReclaimBounty;
+    } & ParsedReclaimBountyInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.RemoveInstitutionMember;
+    } & ParsedRemoveInstitutionMemberInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.RequestRefund;
+    } & ParsedRequestRefundInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.RespondToCounterOffer;
+    } & ParsedRespondToCounterOfferInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.RetractPaper;
+    } & ParsedRetractPaperInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.RevealReview;
+    } & ParsedRevealReviewInstruction<TProgram>)
   | ({
       instructionType: PrismpapersdappInstruction.ReviewPaper;
     } & ParsedReviewPaperInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.ReviewPaperAnonymously;
+    } & ParsedReviewPaperAnonymouslyInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.SetCoAuthors;
+    } & ParsedSetCoAuthorsInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.SetInstitutionSeats;
+    } & ParsedSetInstitutionSeatsInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.SetMinReputation;
+    } & ParsedSetMinReputationInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.SetReviewResponsePolicy;
+    } & ParsedSetReviewResponsePolicyInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.SetReviewStake;
+    } & ParsedSetReviewStakeInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.SetRevisionPolicy;
+    } & ParsedSetRevisionPolicyInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.SettlePurchase;
+    } & ParsedSettlePurchaseInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.Subscribe;
+    } & ParsedSubscribeInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.UpdateBundle;
+    } & ParsedUpdateBundleInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.UpdateConfig;
+    } & ParsedUpdateConfigInstruction<TProgram>)
+  | ({
+      instructionType: PrismpapersdappInstruction.UpdatePlan;
+    } & ParsedUpdatePlanInstruction<TProgram>)
   | ({
       instructionType: PrismpapersdappInstruction.UpdateResearch;
     } & ParsedUpdateResearchInstruction<TProgram>)
@@ -6,4 +6,62 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './accessPurchased';
+export * from './adminWithdrawal';
+export * from './anonymousReviewSubmitted';
+export * from './bountyFunded';
+export * from './bountyReclaimed';
+export * from './bundleCreated';
+export * from './bundlePurchased';
+export * from './bundleUpdated';
+export * from './coAuthor';
+export * from './coAuthorsUpdated';
+export * from './configUpdated';
+export * from './counterOfferResolved';
+export * from './institutionCreated';
+export * from './institutionMemberAdded';
+export * from './institutionMemberRemoved';
+export * from './institutionSeatsUpdated';
+export * from './keyDelivered';
+export * from './licensePurchased';
+export * from './minReputationUpdated';
+export * from './paperClosed';
+export * from './paperCreated';
+export * from './paperPublished';
+export * from './paperRecordClosed';
+export * from './paperRetracted';
+export * from './paperScoresUpdated';
+export * from './paperStatus';
+export * from './paperUpdated';
+export * from './planCreated';
+export * from './planUpdated';
+export * from './purchaseSettled';
+export * from './receiptClosed';
+export * from './receiptStatus';
+export * from './recommendation';
+export * from './refundRequested';
+export * from './refundResolved';
+export * from './reviewAggregate';
+export * from './reviewClosed';
+export * from './reviewCounterOffered';
+export * from './reviewExpired';
+export * from './reviewExpiryPolicy';
+export * from './reviewFlagged';
+export * from './reviewResponsePolicyUpdated';
+export * from './reviewRevealed';
+export * from './reviewRoundClosed';
+export * from './reviewRoundFinalized';
+export * from './reviewRoundOpened';
+export * from './reviewScores';
+export * from './reviewStakeUpdated';
 export * from './reviewStatus';
+export * from './reviewSubmitted';
+export * from './reviewVerdict';
+export * from './revisionAccessPolicy';
+export * from './revisionPolicyUpdated';
+export * from './roundOutcome';
+export * from './subscribed';
+export * from './subscriptionClosed';
+export * from './userClosed';
+export * from './userCreated';
+export * from './userWithdrawal';
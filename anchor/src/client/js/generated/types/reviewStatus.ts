@@ -19,6 +19,10 @@ export enum ReviewStatus {
   Pending,
   Accepted,
   Rejected,
+  Flagged,
+  Expired,
+  Negotiating,
+  Withdrawn,
 }
 
 export type ReviewStatusArgs = ReviewStatus;
@@ -1,4 +1,5 @@
 import { describe, it, expect, beforeAll } from 'vitest'
+import { createHash } from 'node:crypto'
 import {
   Blockhash,
   createSolanaClient,
@@ -11,7 +12,20 @@ import {
   Address,
   getAddressEncoder,
   getProgramDerivedAddress,
+  getU16Encoder,
+  getU32Encoder,
+  ReadonlyUint8Array,
+  some,
+  AccountRole,
 } from 'gill'
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ADDRESS,
+  TOKEN_PROGRAM_ADDRESS,
+  buildCreateTokenTransaction,
+  buildMintTokensTransaction,
+  getAssociatedTokenAccountAddress,
+  getTransferSolInstruction,
+} from 'gill/programs'
 // Import generated helpers from your SDK
 import {
   getInitUserInstruction,
@@ -20,7 +34,72 @@ import {
   getReviewPaperInstruction,
   getVerifyReviewInstruction,
   getUserWithdrawInstruction,
-  getAdminWithdrawInstruction
+  getAdminWithdrawInstruction,
+  getPublishPaperInstruction,
+  getOpenReviewRoundInstruction,
+  getRetractPaperInstruction,
+  getUpdateResearchInstruction,
+  getSetRevisionPolicyInstruction,
+  fetchPaperRevision,
+  fetchAccessReceipt,
+  RevisionAccessPolicy,
+  getClosePaperInstruction,
+  getCloseRevisionInstruction,
+  getCloseKeyDeliveryInstruction,
+  getCloseLicenseReceiptInstruction,
+  getCloseReviewInstruction,
+  getCloseUserInstruction,
+  PaperStatus,
+  getDeliverKeyInstruction,
+  getDeliverSubscriptionKeyInstruction,
+  getDeliverKeysInstruction,
+  fetchKeyDelivery,
+  fetchPaperCounter,
+  getFundBountyInstruction,
+  getSettlePurchaseInstruction,
+  getRequestRefundInstruction,
+  getApproveRefundInstruction,
+  getArbitrateRefundInstruction,
+  getLapseRefundInstruction,
+  ReceiptStatus,
+  getInitInstitutionInstruction,
+  getAddInstitutionMemberInstruction,
+  getRemoveInstitutionMemberInstruction,
+  getPurchaseLicenseInstruction,
+  fetchInstitution,
+  getCreatePlanInstruction,
+  getUpdatePlanInstruction,
+  getSubscribeInstruction,
+  getCloseSubscriptionInstruction,
+  fetchSubscription,
+  fetchSubscriptionPlan,
+  getCreateBundleInstruction,
+  getPurchaseBundleInstruction,
+  fetchBundle,
+  getSetCoAuthorsInstruction,
+  getSetReviewStakeInstruction,
+  getFlagReviewInstruction,
+  getReviewPaperAnonymouslyInstruction,
+  getRevealReviewInstruction,
+  getSetReviewResponsePolicyInstruction,
+  getExpireReviewInstruction,
+  ReviewExpiryPolicy,
+  getCounterOfferReviewInstruction,
+  getRespondToCounterOfferInstruction,
+  getSetMinReputationInstruction,
+  getCloseReviewRoundInstruction,
+  getFinalizeReviewRoundInstruction,
+  fetchReviewRound,
+  fetchRoundVoter,
+  RoundOutcome,
+  Recommendation,
+  fetchPeerReview,
+  ReviewStatus,
+  getReclaimBountyInstruction,
+  getInitializeConfigInstruction,
+  getUpdateConfigInstruction,
+  fetchUser,
+  fetchResearchPaper,
 } from '../src'
 // @ts-ignore
 import { loadKeypairSignerFromFile } from 'gill/node'
@@ -35,10 +114,38 @@ const { rpc, sendAndConfirmTransaction } = createSolanaClient({ urlOrMoniker: pr
 // SEEDS (Must match Rust constants exactly)
 const USER_SEED = new TextEncoder().encode("user");
 const PAPER_SEED = new TextEncoder().encode("paper");
+const PAPER_COUNTER_SEED = new TextEncoder().encode("paper_counter");
 const RECEIPT_SEED = new TextEncoder().encode("receipt");
+const KEY_DELIVERY_SEED = new TextEncoder().encode("key_delivery");
+const REVISION_SEED = new TextEncoder().encode("revision");
 const REVIEW_SEED = new TextEncoder().encode("review");
+const ROUND_SEED = new TextEncoder().encode("round");
+const VOTER_SEED = new TextEncoder().encode("voter");
 const VAULT_USER_SEED = new TextEncoder().encode("vault_user");
 const VAULT_ADMIN_SEED = new TextEncoder().encode("vault_admin");
+const VAULT_BOUNTY_SEED = new TextEncoder().encode("vault_bounty");
+const VAULT_ESCROW_SEED = new TextEncoder().encode("vault_escrow");
+const VAULT_STAKE_SEED = new TextEncoder().encode("vault_stake");
+const INSTITUTION_SEED = new TextEncoder().encode("institution");
+const MEMBER_SEED = new TextEncoder().encode("member");
+const PLAN_SEED = new TextEncoder().encode("plan");
+const SUBSCRIPTION_SEED = new TextEncoder().encode("subscription");
+const BUNDLE_SEED = new TextEncoder().encode("bundle");
+const CONFIG_SEED = new TextEncoder().encode("config");
+
+// Stand-in for the buyer's X25519 public key that the author wraps the content key to
+const BUYER_ENCRYPTION_KEY = new Uint8Array(32).fill(7);
+
+// Stand-in for the SHA-256 of the plaintext PDF
+const CONTENT_HASH = new Uint8Array(32).fill(1);
+const CIPHERTEXT_HASH = new Uint8Array(32).fill(3);
+const REVIEW_HASH = new Uint8Array(32).fill(4);
+const REVIEW_SCORES = { novelty: 4, rigor: 4, clarity: 3, recommendation: Recommendation.Accept, confidence: 4 };
+
+const BPF_LOADER_UPGRADEABLE = address("BPFLoaderUpgradeab1e11111111111111111111111");
+const PURCHASE_FEE_BPS = 500; // 5%
+const REVIEW_FEE_BPS = 250; // 2.5%
+const PRICE = 1_000_000_000n; // 1 SOL
 
 // -----------------------------------------------------------------------------
 // TESTS
@@ -71,6 +178,50 @@ describe('prismpapersdapp', () => {
     console.log(`Buyer: ${buyer.address}`);
   })
 
+  // ===========================================================================
+  // 0. GLOBAL CONFIG
+  // ===========================================================================
+
+  it('Setup: Upgrade authority initializes the global Config', async () => {
+    const config = await pda([CONFIG_SEED]);
+    // The provider wallet deploys the program, so it is the upgrade authority
+    const programData = await pda([getAddressEncoder().encode(PROGRAM_ID)], BPF_LOADER_UPGRADEABLE);
+
+    const ix = getInitializeConfigInstruction({
+      admins: [admin.address],
+      purchaseFeeBps: PURCHASE_FEE_BPS,
+      reviewFeeBps: REVIEW_FEE_BPS,
+      minFeeLamports: 0n,
+      treasury: admin.address,
+      refundWindow: 0n, // purchases settle immediately in these suites, see the refunds suite
+      reviewStake: 0n,
+      authority: admin,
+      config,
+      program: PROGRAM_ID,
+      programData,
+    });
+
+    const sx = await sendAndConfirm({ ix, payer: admin });
+    expect(sx).toBeDefined();
+  });
+
+  it('Sad Path: Non-admin cannot update the Config', async () => {
+    const ix = getUpdateConfigInstruction({
+      admins: [author.address],
+      purchaseFeeBps: 0,
+      reviewFeeBps: 0,
+      minFeeLamports: 0n,
+      treasury: author.address,
+      refundWindow: 0n,
+      reviewStake: 0n,
+      paused: false,
+      admin: author,
+      config: await pda([CONFIG_SEED]),
+    });
+
+    await expect(sendAndConfirm({ ix, payer: author })).rejects.toThrow();
+  });
+
   // ===========================================================================
   // 1. INIT USER
   // ===========================================================================
@@ -150,7 +301,7 @@ describe('prismpapersdapp', () => {
   it('Happy Path: Should publish a Research Paper', async () => {
     const [researchPaper] = await getProgramDerivedAddress({
       programAddress: PROGRAM_ID,
-      seeds: [PAPER_SEED, getAddressEncoder().encode(author.address)],
+      seeds: [PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)],
     });
 
     // We need the user account to update 'published' stats
@@ -164,16 +315,81 @@ describe('prismpapersdapp', () => {
       description: "A deep dive.",
       price: 1000000000n, // 1 SOL
       encryptedUrl: "arweave_cid",
-      encryptionKey: "lit_key",
+      contentHash: CONTENT_HASH,
+      ciphertextHash: null,
+      coAuthors: [],
+      author: author,
+      researchPaper: researchPaper,
+      paperRevision: await revisionPda(researchPaper, 0),
+      userAccount: userAccount,
+      paperCounter: await pda([PAPER_COUNTER_SEED, getAddressEncoder().encode(author.address)])
+    });
+
+    const sx = await sendAndConfirm({ ix, payer: author });
+    expect(sx).toBeDefined();
+  });
+
+  it('Happy Path: Should publish a second Research Paper under the next index', async () => {
+    // The author has published one paper, so the next one lives at index 1
+    const [researchPaper] = await getProgramDerivedAddress({
+      programAddress: PROGRAM_ID,
+      seeds: [PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(1)],
+    });
+    const [userAccount] = await getProgramDerivedAddress({
+      programAddress: PROGRAM_ID,
+      seeds: [USER_SEED, getAddressEncoder().encode(author.address)],
+    });
+
+    const ix = getInitResearchInstruction({
+      title: "Quantum Mechanics II",
+      description: "A deeper dive.",
+      price: 2000000000n, // 2 SOL
+      encryptedUrl: "arweave_cid_2",
+      contentHash: CONTENT_HASH,
+      ciphertextHash: null,
+      coAuthors: [],
       author: author,
       researchPaper: researchPaper,
-      userAccount: userAccount
+      paperRevision: await revisionPda(researchPaper, 0),
+      userAccount: userAccount,
+      paperCounter: await pda([PAPER_COUNTER_SEED, getAddressEncoder().encode(author.address)])
     });
 
     const sx = await sendAndConfirm({ ix, payer: author });
     expect(sx).toBeDefined();
   });
 
+  it('Happy Path: Author publishes the first paper out of Draft', async () => {
+    const researchPaper = await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)]);
+
+    const ix = getPublishPaperInstruction({ author, researchPaper });
+    await sendAndConfirm({ ix, payer: author });
+
+    const { data: paper } = await fetchResearchPaper(rpc, researchPaper);
+    expect(paper.status).toBe(PaperStatus.Published);
+  });
+
+  it('Sad Path: Should fail without a content hash', async () => {
+    const researchPaper = await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(2)]);
+
+    const ix = getInitResearchInstruction({
+      title: "Unhashed",
+      description: "No commitment.",
+      price: 1000000000n,
+      encryptedUrl: "arweave_cid_3",
+      contentHash: new Uint8Array(32),
+      ciphertextHash: null,
+      coAuthors: [],
+      author,
+      researchPaper,
+      paperRevision: await revisionPda(researchPaper, 0),
+      userAccount: await pda([USER_SEED, getAddressEncoder().encode(author.address)]),
+      paperCounter: await pda([PAPER_COUNTER_SEED, getAddressEncoder().encode(author.address)]),
+    });
+
+    await expect(sendAndConfirm({ ix, payer: author })).rejects.toThrow();
+  });
+
   it('Sad Path: Should fail if Price is 0', async () => {
     // Using a different author/paper so we don't collide with existing PDA
     const poorAuthor = await generateKeyPairSigner();
@@ -186,7 +402,7 @@ describe('prismpapersdapp', () => {
 
     const [researchPaper] = await getProgramDerivedAddress({
       programAddress: PROGRAM_ID,
-      seeds: [PAPER_SEED, getAddressEncoder().encode(poorAuthor.address)],
+      seeds: [PAPER_SEED, getAddressEncoder().encode(poorAuthor.address), getU16Encoder().encode(0)],
     });
 
     const ix = getInitResearchInstruction({
@@ -194,10 +410,14 @@ describe('prismpapersdapp', () => {
       description: "Desc",
       price: 0n, // ERROR
       encryptedUrl: "url",
-      encryptionKey: "key",
+      contentHash: CONTENT_HASH,
+      ciphertextHash: null,
+      coAuthors: [],
       author: poorAuthor,
       researchPaper: researchPaper,
-      userAccount: uAcc
+      paperRevision: await revisionPda(researchPaper, 0),
+      userAccount: uAcc,
+      paperCounter: await pda([PAPER_COUNTER_SEED, getAddressEncoder().encode(poorAuthor.address)])
     });
 
     await expect(sendAndConfirm({ ix, payer: poorAuthor })).rejects.toThrow();
@@ -211,7 +431,7 @@ describe('prismpapersdapp', () => {
     // PDAs Setup
     const [researchPaper] = await getProgramDerivedAddress({
       programAddress: PROGRAM_ID,
-      seeds: [PAPER_SEED, getAddressEncoder().encode(author.address)],
+      seeds: [PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)],
     });
 
     const [receipt] = await getProgramDerivedAddress({
@@ -244,24 +464,70 @@ describe('prismpapersdapp', () => {
     });
 
     const ix = getPurchaseAccessInstruction({
+      buyerEncryptionKey: BUYER_ENCRYPTION_KEY,
       buyer: buyer,
       researchPaper: researchPaper,
       accessReceipt: receipt,
       buyerUserAccount,
       buyerVault,
       authorUserAccount,
-      authorVault,
-      adminVault
+      escrowVault: await pda([VAULT_ESCROW_SEED, getAddressEncoder().encode(researchPaper)]),
+      config: await pda([CONFIG_SEED]),
     });
 
     const sx = await sendAndConfirm({ ix, payer: buyer });
     expect(sx).toBeDefined();
+
+    // The whole price waits in the paper's escrow until the purchase is settled
+    const { data: accessReceipt } = await fetchAccessReceipt(rpc, receipt);
+    expect(accessReceipt.status).toBe(ReceiptStatus.Escrowed);
+  });
+
+  it('Happy Path: Anyone settles the purchase once the refund window closes', async () => {
+    const researchPaper = await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)]);
+    const receipt = await pda([RECEIPT_SEED, getAddressEncoder().encode(buyer.address), getAddressEncoder().encode(researchPaper)]);
+
+    const ix = getSettlePurchaseInstruction({
+      payer: admin,
+      researchPaper,
+      accessReceipt: receipt,
+      authorUserAccount: await pda([USER_SEED, getAddressEncoder().encode(author.address)]),
+      escrowVault: await pda([VAULT_ESCROW_SEED, getAddressEncoder().encode(researchPaper)]),
+      authorVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(author.address)]),
+      adminVault: await pda([VAULT_ADMIN_SEED]),
+    });
+    await sendAndConfirm({ ix, payer: admin });
+
+    const { data: accessReceipt } = await fetchAccessReceipt(rpc, receipt);
+    expect(accessReceipt.status).toBe(ReceiptStatus.Settled);
+
+    const { data: paper } = await fetchResearchPaper(rpc, researchPaper);
+    expect(paper.escrowed).toBe(0n);
+  });
+
+  it('Sad Path: Buyer cannot purchase a Draft paper', async () => {
+    // The second paper was never published
+    const researchPaper = await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(1)]);
+
+    const ix = getPurchaseAccessInstruction({
+      buyerEncryptionKey: BUYER_ENCRYPTION_KEY,
+      buyer,
+      researchPaper,
+      accessReceipt: await pda([RECEIPT_SEED, getAddressEncoder().encode(buyer.address), getAddressEncoder().encode(researchPaper)]),
+      buyerUserAccount: await pda([USER_SEED, getAddressEncoder().encode(buyer.address)]),
+      buyerVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(buyer.address)]),
+      authorUserAccount: await pda([USER_SEED, getAddressEncoder().encode(author.address)]),
+      escrowVault: await pda([VAULT_ESCROW_SEED, getAddressEncoder().encode(researchPaper)]),
+      config: await pda([CONFIG_SEED]),
+    });
+
+    await expect(sendAndConfirm({ ix, payer: buyer })).rejects.toThrow();
   });
 
   it('Sad Path: Author buys own paper', async () => {
     const [researchPaper] = await getProgramDerivedAddress({
       programAddress: PROGRAM_ID,
-      seeds: [PAPER_SEED, getAddressEncoder().encode(author.address)],
+      seeds: [PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)],
     });
     const [receipt] = await getProgramDerivedAddress({
       programAddress: PROGRAM_ID,
@@ -274,27 +540,230 @@ describe('prismpapersdapp', () => {
     const [adminVault] = await getProgramDerivedAddress({ programAddress: PROGRAM_ID, seeds: [VAULT_ADMIN_SEED] });
 
     const ix = getPurchaseAccessInstruction({
+      buyerEncryptionKey: BUYER_ENCRYPTION_KEY,
       buyer: author,
       researchPaper: researchPaper,
       accessReceipt: receipt,
       buyerUserAccount: authorAccount,
       buyerVault: authorVault,
       authorUserAccount: authorAccount,
-      authorVault: authorVault,
-      adminVault: adminVault
+      escrowVault: await pda([VAULT_ESCROW_SEED, getAddressEncoder().encode(researchPaper)]),
+      config: await pda([CONFIG_SEED]),
+    });
+
+    await expect(sendAndConfirm({ ix, payer: author })).rejects.toThrow();
+  });
+
+  it('Happy Path: Author delivers the wrapped content key to the buyer', async () => {
+    const researchPaper = await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)]);
+    const receipt = await pda([RECEIPT_SEED, getAddressEncoder().encode(buyer.address), getAddressEncoder().encode(researchPaper)]);
+    const keyDelivery = await keyDeliveryPda(receipt, 0);
+    const wrappedKey = new Uint8Array(48).fill(42); // sealed box of the content key
+
+    const ix = getDeliverKeyInstruction({
+      revision: 0,
+      wrappedKey,
+      author,
+      researchPaper,
+      accessReceipt: receipt,
+      keyDelivery,
+    });
+    await sendAndConfirm({ ix, payer: author });
+
+    const { data } = await fetchKeyDelivery(rpc, keyDelivery);
+    expect(data.buyer).toBe(buyer.address);
+    expect(data.revision).toBe(0);
+    expect(Uint8Array.from(data.wrappedKey)).toEqual(wrappedKey);
+
+    const { data: paper } = await fetchResearchPaper(rpc, researchPaper);
+    expect(paper.pendingDeliveries).toBe(0);
+  });
+
+  it('Sad Path: Author cannot deliver the key twice', async () => {
+    const researchPaper = await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)]);
+    const receipt = await pda([RECEIPT_SEED, getAddressEncoder().encode(buyer.address), getAddressEncoder().encode(researchPaper)]);
+    const keyDelivery = await keyDeliveryPda(receipt, 0);
+
+    // The bulk path must refuse an already fulfilled receipt as well
+    const bulkIx = getDeliverKeysInstruction({
+      wrappedKeys: [new Uint8Array(48).fill(1)],
+      author,
+      researchPaper,
+    });
+    // (receipt, key delivery) pairs go in remaining accounts
+    const ix = {
+      ...bulkIx,
+      accounts: [
+        ...bulkIx.accounts,
+        { address: receipt, role: AccountRole.WRITABLE },
+        { address: keyDelivery, role: AccountRole.WRITABLE },
+      ],
+    };
+
+    await expect(sendAndConfirm({ ix, payer: author })).rejects.toThrow();
+  });
+
+  it('Happy Path: Author updates the paper into a new revision', async () => {
+    const researchPaper = await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)]);
+    const paperRevision = await revisionPda(researchPaper, 1);
+    const newContentHash = new Uint8Array(32).fill(2);
+
+    const ix = getUpdateResearchInstruction({
+      title: "Quantum Mechanics",
+      description: "A deep dive, with errata.",
+      price: 1000000000n,
+      encryptedUrl: "arweave_cid_v1",
+      contentHash: newContentHash,
+      ciphertextHash: some(CIPHERTEXT_HASH),
+      author,
+      researchPaper,
+      paperRevision,
+    });
+    await sendAndConfirm({ ix, payer: author });
+
+    const { data: paper } = await fetchResearchPaper(rpc, researchPaper);
+    expect(paper.revision).toBe(1);
+
+    const { data: revision } = await fetchPaperRevision(rpc, paperRevision);
+    expect(revision.revision).toBe(1);
+    expect(revision.encryptedUrl).toBe("arweave_cid_v1");
+    expect(new Uint8Array(revision.contentHash)).toEqual(newContentHash);
+    expect(new Uint8Array(paper.contentHash)).toEqual(newContentHash);
+    expect(paper.ciphertextHash).toEqual(some(CIPHERTEXT_HASH));
+
+    // The buyer paid for revision 0, which stays on record
+    const receipt = await pda([RECEIPT_SEED, getAddressEncoder().encode(buyer.address), getAddressEncoder().encode(researchPaper)]);
+    const { data: accessReceipt } = await fetchAccessReceipt(rpc, receipt);
+    expect(accessReceipt.revision).toBe(0);
+    expect(accessReceipt.keyRevision).toEqual(some(0));
+
+    // Under AllRevisions the buyer is now owed the new revision's key
+    expect(paper.pendingDeliveries).toBe(1);
+  });
+
+  it('Sad Path: The key of an older revision cannot be delivered once a newer one is owed', async () => {
+    const researchPaper = await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)]);
+    const receipt = await pda([RECEIPT_SEED, getAddressEncoder().encode(buyer.address), getAddressEncoder().encode(researchPaper)]);
+
+    const ix = getDeliverKeyInstruction({
+      revision: 0,
+      wrappedKey: new Uint8Array(48).fill(42),
+      author,
+      researchPaper,
+      accessReceipt: receipt,
+      keyDelivery: await keyDeliveryPda(receipt, 0),
+    });
+
+    await expect(sendAndConfirm({ ix, payer: author })).rejects.toThrow();
+  });
+
+  it('Happy Path: An AllRevisions buyer gets the key of the new revision, even on a pre-funded delivery', async () => {
+    const researchPaper = await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)]);
+    const receipt = await pda([RECEIPT_SEED, getAddressEncoder().encode(buyer.address), getAddressEncoder().encode(researchPaper)]);
+    const keyDelivery = await keyDeliveryPda(receipt, 1);
+
+    // Lamports sent to the delivery address ahead of time must not block the bulk delivery
+    await sendAndConfirm({
+      ix: getTransferSolInstruction({ source: buyer, destination: keyDelivery, amount: 1_000_000n }),
+      payer: buyer,
+    });
+
+    const bulkIx = getDeliverKeysInstruction({
+      wrappedKeys: [new Uint8Array(48).fill(43)],
+      author,
+      researchPaper,
+    });
+    const ix = {
+      ...bulkIx,
+      accounts: [
+        ...bulkIx.accounts,
+        { address: receipt, role: AccountRole.WRITABLE },
+        { address: keyDelivery, role: AccountRole.WRITABLE },
+      ],
+    };
+    await sendAndConfirm({ ix, payer: author });
+
+    const { data } = await fetchKeyDelivery(rpc, keyDelivery);
+    expect(data.revision).toBe(1);
+
+    const { data: accessReceipt } = await fetchAccessReceipt(rpc, receipt);
+    expect(accessReceipt.keyRevision).toEqual(some(1));
+
+    const { data: paper } = await fetchResearchPaper(rpc, researchPaper);
+    expect(paper.pendingDeliveries).toBe(0);
+  });
+
+  it('Sad Path: The revision policy is locked once the paper has buyers', async () => {
+    const ix = getSetRevisionPolicyInstruction({
+      policy: RevisionAccessPolicy.PurchasedOnly,
+      author,
+      researchPaper: await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)]),
     });
 
     await expect(sendAndConfirm({ ix, payer: author })).rejects.toThrow();
   });
 
+  it('Sad Path: Only the author can set the revision access policy', async () => {
+    const ix = getSetRevisionPolicyInstruction({
+      policy: RevisionAccessPolicy.PurchasedOnly,
+      author: buyer,
+      researchPaper: await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)]),
+    });
+
+    await expect(sendAndConfirm({ ix, payer: buyer })).rejects.toThrow();
+  });
+
   // ===========================================================================
   // 4. REVIEW PAPER
   // ===========================================================================
 
+  it('Happy Path: Author funds a review bounty', async () => {
+    const researchPaper = await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)]);
+    const bountyVault = await pda([VAULT_BOUNTY_SEED, getAddressEncoder().encode(researchPaper)]);
+
+    const ix = getFundBountyInstruction({
+      amount: 1000000000n, // 1 SOL escrowed for reviewers
+      deadline: BigInt(Math.floor(Date.now() / 1000) + 3600),
+      author,
+      researchPaper,
+      bountyVault,
+    });
+
+    const sx = await sendAndConfirm({ ix, payer: author });
+    expect(sx).toBeDefined();
+  });
+
+  it('Sad Path: Author cannot reclaim the bounty before its deadline', async () => {
+    const researchPaper = await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)]);
+    const bountyVault = await pda([VAULT_BOUNTY_SEED, getAddressEncoder().encode(researchPaper)]);
+
+    const ix = getReclaimBountyInstruction({ author, researchPaper, bountyVault });
+
+    await expect(sendAndConfirm({ ix, payer: author })).rejects.toThrow();
+  });
+
+  it('Happy Path: Author opens a review round', async () => {
+    const researchPaper = await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)]);
+
+    const ix = getOpenReviewRoundInstruction({
+      targetReviewers: 10,
+      quorum: 1,
+      approvalBps: 5_000,
+      deadline: BigInt(Math.floor(Date.now() / 1000) + 3600),
+      author,
+      researchPaper,
+      reviewRound: await roundPda(researchPaper, 0),
+    });
+    await sendAndConfirm({ ix, payer: author });
+
+    const { data: paper } = await fetchResearchPaper(rpc, researchPaper);
+    expect(paper.status).toBe(PaperStatus.UnderReview);
+  });
+
   it('Happy Path: Buyer reviews paper', async () => {
     const [researchPaper] = await getProgramDerivedAddress({
       programAddress: PROGRAM_ID,
-      seeds: [PAPER_SEED, getAddressEncoder().encode(author.address)],
+      seeds: [PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)],
     });
     const [review] = await getProgramDerivedAddress({
       programAddress: PROGRAM_ID,
@@ -311,12 +780,18 @@ describe('prismpapersdapp', () => {
 
     const ix = getReviewPaperInstruction({
       reviewUrl: "ipfs://valid",
+      reviewHash: REVIEW_HASH,
+      scores: REVIEW_SCORES,
       proposedReward: 500000n,
       reviewer: buyer,
       researchPaper: researchPaper,
+      reviewRound: await roundPda(researchPaper, 0),
+      roundVoter: await voterPda(await roundPda(researchPaper, 0), buyer.address),
       accessReceipt: receipt, // Proof of purchase
       reviewerUserAccount: reviewerAccount,
-      peerReview: review
+      peerReview: review,
+      stakeVault: await pda([VAULT_STAKE_SEED, getAddressEncoder().encode(review)]),
+      config: await pda([CONFIG_SEED]),
     });
 
     const sx = await sendAndConfirm({ ix, payer: buyer });
@@ -330,7 +805,7 @@ describe('prismpapersdapp', () => {
 
     const [researchPaper] = await getProgramDerivedAddress({
       programAddress: PROGRAM_ID,
-      seeds: [PAPER_SEED, getAddressEncoder().encode(author.address)],
+      seeds: [PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)],
     });
     // This receipt DOES NOT EXIST on chain
     const [fakeReceipt] = await getProgramDerivedAddress({
@@ -356,7 +831,7 @@ describe('prismpapersdapp', () => {
   it('Happy Path: Author accepts review', async () => {
     const [researchPaper] = await getProgramDerivedAddress({
       programAddress: PROGRAM_ID,
-      seeds: [PAPER_SEED, getAddressEncoder().encode(author.address)],
+      seeds: [PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)],
     });
     const [review] = await getProgramDerivedAddress({
       programAddress: PROGRAM_ID,
@@ -390,17 +865,208 @@ describe('prismpapersdapp', () => {
       author: author,
       researchPaper,
       peerReview: review,
+      reviewer: buyer.address,
+      stakeVault: await pda([VAULT_STAKE_SEED, getAddressEncoder().encode(review)]),
+      bountyVault: await pda([VAULT_BOUNTY_SEED, getAddressEncoder().encode(researchPaper)]),
       authorUserAccount,
       authorVault,
       reviewerUserAccount,
       reviewerVault,
-      adminVault
+      adminVault,
+      config: await pda([CONFIG_SEED]),
     });
 
     const sx = await sendAndConfirm({ ix, payer: author });
     expect(sx).toBeDefined();
   });
 
+  // ===========================================================================
+  // 5b. RETRACTION
+  // ===========================================================================
+
+  it('Happy Path: Author retracts a paper with a reason code', async () => {
+    const researchPaper = await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(1)]);
+
+    const ix = getRetractPaperInstruction({
+      reasonCode: 3,
+      authority: author,
+      researchPaper,
+      config: await pda([CONFIG_SEED]),
+    });
+    await sendAndConfirm({ ix, payer: author });
+
+    const { data: paper } = await fetchResearchPaper(rpc, researchPaper);
+    expect(paper.status).toBe(PaperStatus.Retracted);
+    expect(paper.retractionReason).toBe(3);
+  });
+
+  it('Sad Path: A stranger cannot retract a paper', async () => {
+    const stranger = await generateKeyPairSigner();
+    await requestAirdrop(stranger.address);
+    const researchPaper = await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)]);
+
+    const ix = getRetractPaperInstruction({
+      reasonCode: 1,
+      authority: stranger,
+      researchPaper,
+      config: await pda([CONFIG_SEED]),
+    });
+
+    await expect(sendAndConfirm({ ix, payer: stranger })).rejects.toThrow();
+  });
+
+  // ===========================================================================
+  // 5c. CLOSING ACCOUNTS
+  // ===========================================================================
+
+  it('Sad Path: Author cannot close a paper that has buyers', async () => {
+    const ix = getClosePaperInstruction({
+      author,
+      researchPaper: await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)]),
+      authorUserAccount: await pda([USER_SEED, getAddressEncoder().encode(author.address)]),
+    });
+
+    await expect(sendAndConfirm({ ix, payer: author })).rejects.toThrow();
+  });
+
+  it('Happy Path: Author closes the retracted paper and reclaims its rent', async () => {
+    const researchPaper = await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(1)]);
+    const authorUserAccount = await pda([USER_SEED, getAddressEncoder().encode(author.address)]);
+
+    const ix = getClosePaperInstruction({ author, researchPaper, authorUserAccount });
+    await sendAndConfirm({ ix, payer: author });
+
+    const { value } = await rpc.getAccountInfo(researchPaper).send();
+    expect(value).toBeNull();
+
+    const { data: user } = await fetchUser(rpc, authorUserAccount);
+    expect(user.livePapers).toBe(1);
+  });
+
+  it('Sad Path: Author cannot close the revisions or key deliveries of a live paper', async () => {
+    const researchPaper = await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)]);
+    const receipt = await pda([RECEIPT_SEED, getAddressEncoder().encode(buyer.address), getAddressEncoder().encode(researchPaper)]);
+
+    const revisionIx = getCloseRevisionInstruction({
+      paperIndex: 0,
+      author,
+      researchPaper,
+      paperRevision: await revisionPda(researchPaper, 0),
+    });
+    await expect(sendAndConfirm({ ix: revisionIx, payer: author })).rejects.toThrow();
+
+    const deliveryIx = getCloseKeyDeliveryInstruction({
+      paperIndex: 0,
+      author,
+      researchPaper,
+      keyDelivery: await keyDeliveryPda(receipt, 0),
+    });
+    await expect(sendAndConfirm({ ix: deliveryIx, payer: author })).rejects.toThrow();
+  });
+
+  it('Happy Path: Author reclaims the revision snapshot of the closed paper', async () => {
+    const researchPaper = await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(1)]);
+    const paperRevision = await revisionPda(researchPaper, 0);
+
+    const ix = getCloseRevisionInstruction({ paperIndex: 1, author, researchPaper, paperRevision });
+    await sendAndConfirm({ ix, payer: author });
+
+    const { value } = await rpc.getAccountInfo(paperRevision).send();
+    expect(value).toBeNull();
+  });
+
+  it('Sad Path: Reviewer cannot close an accepted review', async () => {
+    const researchPaper = await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)]);
+    const peerReview = await pda([REVIEW_SEED, getAddressEncoder().encode(reviewer.address), getAddressEncoder().encode(researchPaper)]);
+
+    const ix = getCloseReviewInstruction({
+      reviewer,
+      peerReview,
+      stakeVault: await pda([VAULT_STAKE_SEED, getAddressEncoder().encode(peerReview)]),
+    });
+
+    await expect(sendAndConfirm({ ix, payer: reviewer })).rejects.toThrow();
+  });
+
+  it('Sad Path: Author cannot close their profile while the vault holds earnings', async () => {
+    const ix = getCloseUserInstruction({
+      owner: author,
+      userAccount: await pda([USER_SEED, getAddressEncoder().encode(author.address)]),
+      userVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(author.address)]),
+    });
+
+    await expect(sendAndConfirm({ ix, payer: author })).rejects.toThrow();
+  });
+
+  it('Happy Path: Closing a profile sweeps stray vault lamports back to the owner', async () => {
+    const owner = await generateKeyPairSigner();
+    await requestAirdrop(owner.address);
+    const userAccount = await pda([USER_SEED, getAddressEncoder().encode(owner.address)]);
+    const userVault = await pda([VAULT_USER_SEED, getAddressEncoder().encode(owner.address)]);
+    await sendAndConfirm({ ix: getInitUserInstruction({ name: "Leaving", owner, userAccount, userVault }), payer: owner });
+
+    // Anyone can send lamports to a vault without them counting as earnings
+    await sendAndConfirm({
+      ix: getTransferSolInstruction({ source: author, destination: userVault, amount: 5_000_000n }),
+      payer: author,
+    });
+
+    await sendAndConfirm({ ix: getCloseUserInstruction({ owner, userAccount, userVault }), payer: owner });
+
+    const { value: vaultBalance } = await rpc.getBalance(userVault).send();
+    expect(vaultBalance).toBe(0n);
+    const { value } = await rpc.getAccountInfo(userAccount).send();
+    expect(value).toBeNull();
+  });
+
+  it('Happy Path: A re-created profile publishes under fresh paper indexes', async () => {
+    const owner = await generateKeyPairSigner();
+    await requestAirdrop(owner.address);
+    const userAccount = await pda([USER_SEED, getAddressEncoder().encode(owner.address)]);
+    const userVault = await pda([VAULT_USER_SEED, getAddressEncoder().encode(owner.address)]);
+    const paperCounter = await pda([PAPER_COUNTER_SEED, getAddressEncoder().encode(owner.address)]);
+
+    async function initResearchIx(index: number) {
+      const researchPaper = await pda([PAPER_SEED, getAddressEncoder().encode(owner.address), getU16Encoder().encode(index)]);
+      return getInitResearchInstruction({
+        title: "Short-lived",
+        description: "Published, closed and published again.",
+        price: PRICE,
+        encryptedUrl: "arweave_cid",
+        contentHash: CONTENT_HASH,
+        ciphertextHash: null,
+        coAuthors: [],
+        author: owner,
+        researchPaper,
+        paperRevision: await revisionPda(researchPaper, 0),
+        userAccount,
+        paperCounter,
+      });
+    }
+
+    await sendAndConfirm({ ix: getInitUserInstruction({ name: "Returning", owner, userAccount, userVault }), payer: owner });
+    await sendAndConfirm({ ix: await initResearchIx(0), payer: owner });
+    await sendAndConfirm({
+      ix: getClosePaperInstruction({
+        author: owner,
+        researchPaper: await pda([PAPER_SEED, getAddressEncoder().encode(owner.address), getU16Encoder().encode(0)]),
+        authorUserAccount: userAccount,
+      }),
+      payer: owner,
+    });
+    await sendAndConfirm({ ix: getCloseUserInstruction({ owner, userAccount, userVault }), payer: owner });
+
+    // The new profile starts from zero, the paper counter does not
+    await sendAndConfirm({ ix: getInitUserInstruction({ name: "Returning", owner, userAccount, userVault }), payer: owner });
+    await expect(sendAndConfirm({ ix: await initResearchIx(0), payer: owner })).rejects.toThrow();
+    await sendAndConfirm({ ix: await initResearchIx(1), payer: owner });
+
+    const { data: counter } = await fetchPaperCounter(rpc, paperCounter);
+    expect(counter.nextIndex).toBe(2);
+    const { data: user } = await fetchUser(rpc, userAccount);
+    expect(user.published).toBe(1);
+  });
+
   // ===========================================================================
   // 6. WITHDRAWS
   // ===========================================================================
@@ -415,16 +1081,42 @@ describe('prismpapersdapp', () => {
     // Log the value (it returns a bigint)
     console.log("User Vault Balance:", balanceResponse.value);
 
+    const [userAccount] = await getProgramDerivedAddress({
+      programAddress: PROGRAM_ID,
+      seeds: [USER_SEED, getAddressEncoder().encode(author.address)],
+    });
+
     // Author should have earned money from the purchase earlier
     const ix = getUserWithdrawInstruction({
       amount: 1000n, // withdraw a tiny bit
       user: author,
+      userAccount: userAccount,
       userVault: userVault
     });
     const sx = await sendAndConfirm({ ix, payer: author });
     expect(sx).toBeDefined();
   });
 
+  it('Sad Path: User withdraws more than their accrued earnings', async () => {
+    const [userVault] = await getProgramDerivedAddress({
+      programAddress: PROGRAM_ID,
+      seeds: [VAULT_USER_SEED, getAddressEncoder().encode(author.address)],
+    });
+    const [userAccount] = await getProgramDerivedAddress({
+      programAddress: PROGRAM_ID,
+      seeds: [USER_SEED, getAddressEncoder().encode(author.address)],
+    });
+
+    // The author sold one 1 SOL paper, so 5 SOL is well above the earning ledger
+    const ix = getUserWithdrawInstruction({
+      amount: 5000000000n,
+      user: author,
+      userAccount: userAccount,
+      userVault: userVault
+    });
+    await expect(sendAndConfirm({ ix, payer: author })).rejects.toThrow();
+  });
+
   it('Happy Path: Admin Withdraws', async () => {
     const [adminVault] = await getProgramDerivedAddress({
       programAddress: PROGRAM_ID,
@@ -434,10 +1126,13 @@ describe('prismpapersdapp', () => {
 
     // Log the value (it returns a bigint)
     console.log("Admin Vault Balance:", balanceResponse.value);
+    // Fees are paid out to the treasury configured during setup
     const ix = getAdminWithdrawInstruction({
       amount: 1000n,
       admin: admin,
-      adminVault: adminVault
+      config: await pda([CONFIG_SEED]),
+      adminVault: adminVault,
+      treasury: admin.address
     });
 
     const sx = await sendAndConfirm({ ix, payer: admin });
@@ -447,29 +1142,3119 @@ describe('prismpapersdapp', () => {
 })
 
 // -----------------------------------------------------------------------------
-// HELPERS
+// STATS REGRESSION
 // -----------------------------------------------------------------------------
 
-let latestBlockhash: Awaited<ReturnType<typeof getLatestBlockhash>> | undefined
-async function getLatestBlockhash(): Promise<Readonly<{ blockhash: Blockhash; lastValidBlockHeight: bigint }>> {
-  if (latestBlockhash) {
-    return latestBlockhash
-  }
-  return await rpc.getLatestBlockhash().send().then(({ value }) => value)
-}
+describe('prismpapersdapp stats', () => {
+  const PROPOSED_REWARD = 500000n;
 
-async function sendAndConfirm({ ix, payer }: { ix: Instruction; payer: KeyPairSigner }) {
-  const tx = createTransaction({
-    feePayer: payer,
-    instructions: [ix],
-    version: 'legacy',
-    latestBlockhash: await getLatestBlockhash(),
+  let author: KeyPairSigner
+  let buyer: KeyPairSigner
+
+  // PDAs
+  let authorAccount: Address
+  let authorVault: Address
+  let buyerAccount: Address
+  let buyerVault: Address
+  let adminVault: Address
+  let researchPaper: Address
+  let receipt: Address
+  let review: Address
+
+  beforeAll(async () => {
+    author = await generateKeyPairSigner()
+    buyer = await generateKeyPairSigner()
+    await requestAirdrop(author.address)
+    await requestAirdrop(buyer.address)
+
+    authorAccount = await pda([USER_SEED, getAddressEncoder().encode(author.address)])
+    authorVault = await pda([VAULT_USER_SEED, getAddressEncoder().encode(author.address)])
+    buyerAccount = await pda([USER_SEED, getAddressEncoder().encode(buyer.address)])
+    buyerVault = await pda([VAULT_USER_SEED, getAddressEncoder().encode(buyer.address)])
+    adminVault = await pda([VAULT_ADMIN_SEED])
+    researchPaper = await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)])
+    receipt = await pda([RECEIPT_SEED, getAddressEncoder().encode(buyer.address), getAddressEncoder().encode(researchPaper)])
+    review = await pda([REVIEW_SEED, getAddressEncoder().encode(buyer.address), getAddressEncoder().encode(researchPaper)])
   })
-  const signedTransaction = await signTransactionMessageWithSigners(tx)
-  return await sendAndConfirmTransaction(signedTransaction)
-}
 
-async function requestAirdrop(address: Address) {
-  await rpc.requestAirdrop(address, 10000000000n as any).send();
-  await new Promise(r => setTimeout(r, 1000));
-}
\ No newline at end of file
+  it('init_user starts every counter at zero', async () => {
+    await sendAndConfirm({
+      ix: getInitUserInstruction({ name: "Stats Author", owner: author, userAccount: authorAccount, userVault: authorVault }),
+      payer: author,
+    });
+    await sendAndConfirm({
+      ix: getInitUserInstruction({ name: "Stats Buyer", owner: buyer, userAccount: buyerAccount, userVault: buyerVault }),
+      payer: buyer,
+    });
+
+    for (const account of [authorAccount, buyerAccount]) {
+      const { data } = await fetchUser(rpc, account);
+      expect(data.published).toBe(0);
+      expect(data.purchased).toBe(0);
+      expect(data.sold).toBe(0);
+      expect(data.reviewed).toBe(0);
+      expect(data.livePapers).toBe(0);
+      expect(data.pendingReviews).toBe(0);
+      expect(data.earning).toBe(0n);
+      expect(data.withdrawn).toBe(0n);
+    }
+  });
+
+  it('init_research increments published', async () => {
+    await sendAndConfirm({
+      ix: getInitResearchInstruction({
+        title: "Stats Paper",
+        description: "Counting things.",
+        price: PRICE,
+        encryptedUrl: "arweave_cid",
+        contentHash: CONTENT_HASH,
+        ciphertextHash: null,
+        coAuthors: [],
+        author,
+        researchPaper,
+        paperRevision: await revisionPda(researchPaper, 0),
+        userAccount: authorAccount,
+        paperCounter: await pda([PAPER_COUNTER_SEED, getAddressEncoder().encode(author.address)]),
+      }),
+      payer: author,
+    });
+
+    const { data: user } = await fetchUser(rpc, authorAccount);
+    expect(user.published).toBe(1);
+    expect(user.livePapers).toBe(1);
+
+    const { data: paper } = await fetchResearchPaper(rpc, researchPaper);
+    expect(paper.index).toBe(0);
+    expect(paper.sales).toBe(0);
+    expect(paper.reviews).toBe(0);
+
+    await sendAndConfirm({ ix: getPublishPaperInstruction({ author, researchPaper }), payer: author });
+  });
+
+  it('purchase_access increments purchased, sales and sold', async () => {
+    await sendAndConfirm({
+      ix: getPurchaseAccessInstruction({
+        buyerEncryptionKey: BUYER_ENCRYPTION_KEY,
+        buyer,
+        researchPaper,
+        accessReceipt: receipt,
+        buyerUserAccount: buyerAccount,
+        buyerVault,
+        authorUserAccount: authorAccount,
+        escrowVault: await pda([VAULT_ESCROW_SEED, getAddressEncoder().encode(researchPaper)]),
+        config: await pda([CONFIG_SEED]),
+      }),
+      payer: buyer,
+    });
+
+    const { data: buyerUser } = await fetchUser(rpc, buyerAccount);
+    expect(buyerUser.purchased).toBe(1);
+    expect(buyerUser.sold).toBe(0);
+
+    const { data: authorUser } = await fetchUser(rpc, authorAccount);
+    expect(authorUser.sold).toBe(1);
+    expect(authorUser.purchased).toBe(0);
+    expect(authorUser.earning).toBe(0n);
+
+    const { data: paper } = await fetchResearchPaper(rpc, researchPaper);
+    expect(paper.sales).toBe(1);
+    expect(paper.escrowed).toBe(PRICE);
+  });
+
+  it('settle_purchase credits the author earning', async () => {
+    await sendAndConfirm({
+      ix: getSettlePurchaseInstruction({
+        payer: buyer,
+        researchPaper,
+        accessReceipt: receipt,
+        authorUserAccount: authorAccount,
+        escrowVault: await pda([VAULT_ESCROW_SEED, getAddressEncoder().encode(researchPaper)]),
+        authorVault,
+        adminVault,
+      }),
+      payer: buyer,
+    });
+
+    const { data: authorUser } = await fetchUser(rpc, authorAccount);
+    expect(authorUser.earning).toBe(PRICE - (PRICE * BigInt(PURCHASE_FEE_BPS)) / 10000n);
+
+    const { data: paper } = await fetchResearchPaper(rpc, researchPaper);
+    expect(paper.escrowed).toBe(0n);
+  });
+
+  it('fund_bounty escrows the review bounty on the paper', async () => {
+    await sendAndConfirm({
+      ix: getFundBountyInstruction({
+        amount: PROPOSED_REWARD * 2n,
+        deadline: BigInt(Math.floor(Date.now() / 1000) + 3600),
+        author,
+        researchPaper,
+        bountyVault: await pda([VAULT_BOUNTY_SEED, getAddressEncoder().encode(researchPaper)]),
+      }),
+      payer: author,
+    });
+
+    const { data: paper } = await fetchResearchPaper(rpc, researchPaper);
+    expect(paper.bounty).toBe(PROPOSED_REWARD * 2n);
+  });
+
+  it('review_paper increments reviews and reviewed', async () => {
+    await sendAndConfirm({ ix: await openRoundIx(author, researchPaper), payer: author });
+
+    await sendAndConfirm({
+      ix: getReviewPaperInstruction({
+        reviewUrl: "ipfs://stats",
+        reviewHash: REVIEW_HASH,
+        scores: REVIEW_SCORES,
+        proposedReward: PROPOSED_REWARD,
+        reviewer: buyer,
+        researchPaper,
+        reviewRound: await roundPda(researchPaper, 0),
+        roundVoter: await voterPda(await roundPda(researchPaper, 0), buyer.address),
+        accessReceipt: receipt,
+        reviewerUserAccount: buyerAccount,
+        peerReview: review,
+        stakeVault: await pda([VAULT_STAKE_SEED, getAddressEncoder().encode(review)]),
+        config: await pda([CONFIG_SEED]),
+      }),
+      payer: buyer,
+    });
+
+    const { data: reviewer } = await fetchUser(rpc, buyerAccount);
+    expect(reviewer.reviewed).toBe(1);
+    expect(reviewer.pendingReviews).toBe(1);
+
+    const { data: paper } = await fetchResearchPaper(rpc, researchPaper);
+    expect(paper.reviews).toBe(1);
+  });
+
+  it('verify_review credits the reviewer earning', async () => {
+    await sendAndConfirm({
+      ix: getVerifyReviewInstruction({
+        acceptProposedReview: true,
+        author,
+        researchPaper,
+        peerReview: review,
+        reviewer: buyer.address,
+        stakeVault: await pda([VAULT_STAKE_SEED, getAddressEncoder().encode(review)]),
+        bountyVault: await pda([VAULT_BOUNTY_SEED, getAddressEncoder().encode(researchPaper)]),
+        authorUserAccount: authorAccount,
+        authorVault,
+        reviewerUserAccount: buyerAccount,
+        reviewerVault: buyerVault,
+        adminVault,
+        config: await pda([CONFIG_SEED]),
+      }),
+      payer: author,
+    });
+
+    const { data: reviewer } = await fetchUser(rpc, buyerAccount);
+    expect(reviewer.earning).toBe(PROPOSED_REWARD - (PROPOSED_REWARD * BigInt(REVIEW_FEE_BPS)) / 10000n);
+    expect(reviewer.reviewRewards).toBe(reviewer.earning);
+    expect(reviewer.reviewsAccepted).toBe(1);
+    expect(reviewer.reputation).toBe(6_666);
+    expect(reviewer.reviewed).toBe(1);
+    expect(reviewer.pendingReviews).toBe(0);
+
+    const { data: paper } = await fetchResearchPaper(rpc, researchPaper);
+    expect(paper.bounty).toBe(PROPOSED_REWARD);
+    // The accepted review's scores feed the paper's averages
+    expect(paper.reviewScores.scoredReviews).toBe(1);
+    expect(paper.reviewScores.noveltyTotal).toBe(REVIEW_SCORES.novelty);
+    expect(paper.reviewScores.accept).toBe(1);
+  });
+
+  it('user_withdraw moves earning into withdrawn', async () => {
+    const { data: before } = await fetchUser(rpc, authorAccount);
+    const amount = 1000n;
+
+    await sendAndConfirm({
+      ix: getUserWithdrawInstruction({ amount, user: author, userAccount: authorAccount, userVault: authorVault }),
+      payer: author,
+    });
+
+    const { data: after } = await fetchUser(rpc, authorAccount);
+    expect(after.earning).toBe(before.earning - amount);
+    expect(after.withdrawn).toBe(before.withdrawn + amount);
+    expect(after.published).toBe(before.published);
+    expect(after.sold).toBe(before.sold);
+  });
+})
+
+
+// -----------------------------------------------------------------------------
+// SPL TOKEN PAYMENTS
+// -----------------------------------------------------------------------------
+
+describe('prismpapersdapp token payments', () => {
+  const TOKEN_PRICE = 10_000_000n; // 10 USDC at 6 decimals
+
+  let author: KeyPairSigner
+  let buyer: KeyPairSigner
+  let mint: KeyPairSigner
+
+  let authorAccount: Address
+  let authorVault: Address
+  let buyerAccount: Address
+  let buyerVault: Address
+  let adminVault: Address
+  let escrowVault: Address
+  let researchPaper: Address
+
+  beforeAll(async () => {
+    author = await generateKeyPairSigner()
+    buyer = await generateKeyPairSigner()
+    mint = await generateKeyPairSigner()
+    await requestAirdrop(author.address)
+    await requestAirdrop(buyer.address)
+
+    authorAccount = await pda([USER_SEED, getAddressEncoder().encode(author.address)])
+    authorVault = await pda([VAULT_USER_SEED, getAddressEncoder().encode(author.address)])
+    buyerAccount = await pda([USER_SEED, getAddressEncoder().encode(buyer.address)])
+    buyerVault = await pda([VAULT_USER_SEED, getAddressEncoder().encode(buyer.address)])
+    adminVault = await pda([VAULT_ADMIN_SEED])
+    researchPaper = await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)])
+    escrowVault = await pda([VAULT_ESCROW_SEED, getAddressEncoder().encode(researchPaper)])
+
+    // A stand-in for USDC: 6 decimals, buyer holds enough for one purchase
+    await sendTransaction(
+      await buildCreateTokenTransaction({
+        feePayer: buyer,
+        latestBlockhash: await getLatestBlockhash(),
+        mint,
+        decimals: 6,
+        tokenProgram: TOKEN_PROGRAM_ADDRESS,
+      }),
+    )
+    await sendTransaction(
+      await buildMintTokensTransaction({
+        feePayer: buyer,
+        latestBlockhash: await getLatestBlockhash(),
+        mint: mint.address,
+        mintAuthority: buyer,
+        amount: TOKEN_PRICE,
+        destination: buyer.address,
+        tokenProgram: TOKEN_PROGRAM_ADDRESS,
+      }),
+    )
+
+    await sendAndConfirm({
+      ix: getInitUserInstruction({ name: "Token Author", owner: author, userAccount: authorAccount, userVault: authorVault }),
+      payer: author,
+    })
+    await sendAndConfirm({
+      ix: getInitUserInstruction({ name: "Token Buyer", owner: buyer, userAccount: buyerAccount, userVault: buyerVault }),
+      payer: buyer,
+    })
+  })
+
+  it('Happy Path: Author publishes a paper priced in an SPL token', async () => {
+    await sendAndConfirm({
+      ix: getInitResearchInstruction({
+        title: "Stablecoin Economics",
+        description: "Priced in USDC.",
+        price: TOKEN_PRICE,
+        encryptedUrl: "arweave_cid",
+        contentHash: CONTENT_HASH,
+        ciphertextHash: null,
+        coAuthors: [],
+        author,
+        researchPaper,
+        paperRevision: await revisionPda(researchPaper, 0),
+        userAccount: authorAccount,
+        paperCounter: await pda([PAPER_COUNTER_SEED, getAddressEncoder().encode(author.address)]),
+        paymentMint: mint.address,
+      }),
+      payer: author,
+    })
+
+    const { data: paper } = await fetchResearchPaper(rpc, researchPaper)
+    expect(paper.paymentMint).toEqual(some(mint.address))
+
+    await sendAndConfirm({ ix: getPublishPaperInstruction({ author, researchPaper }), payer: author })
+  })
+
+  it('Sad Path: Token paper cannot be bought without token accounts', async () => {
+    const receipt = await pda([RECEIPT_SEED, getAddressEncoder().encode(buyer.address), getAddressEncoder().encode(researchPaper)])
+
+    const ix = getPurchaseAccessInstruction({
+      buyerEncryptionKey: BUYER_ENCRYPTION_KEY,
+      buyer,
+      researchPaper,
+      accessReceipt: receipt,
+      buyerUserAccount: buyerAccount,
+      buyerVault,
+      authorUserAccount: authorAccount,
+      escrowVault: await pda([VAULT_ESCROW_SEED, getAddressEncoder().encode(researchPaper)]),
+      config: await pda([CONFIG_SEED]),
+    })
+
+    await expect(sendAndConfirm({ ix, payer: buyer })).rejects.toThrow()
+  })
+
+  it('Happy Path: Buyer pays for a paper in the SPL token', async () => {
+    const receipt = await pda([RECEIPT_SEED, getAddressEncoder().encode(buyer.address), getAddressEncoder().encode(researchPaper)])
+    const escrowTokenVault = await getAssociatedTokenAccountAddress(mint.address, escrowVault, TOKEN_PROGRAM_ADDRESS)
+
+    await sendAndConfirm({
+      ix: getPurchaseAccessInstruction({
+        buyerEncryptionKey: BUYER_ENCRYPTION_KEY,
+        buyer,
+        researchPaper,
+        accessReceipt: receipt,
+        buyerUserAccount: buyerAccount,
+        buyerVault,
+        authorUserAccount: authorAccount,
+        escrowVault: await pda([VAULT_ESCROW_SEED, getAddressEncoder().encode(researchPaper)]),
+        config: await pda([CONFIG_SEED]),
+        paymentMint: mint.address,
+        buyerTokenAccount: await getAssociatedTokenAccountAddress(mint.address, buyer.address, TOKEN_PROGRAM_ADDRESS),
+        escrowTokenVault,
+        tokenProgram: TOKEN_PROGRAM_ADDRESS,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ADDRESS,
+      }),
+      payer: buyer,
+    })
+
+    const { value: escrowBalance } = await rpc.getTokenAccountBalance(escrowTokenVault).send()
+    expect(BigInt(escrowBalance.amount)).toBe(TOKEN_PRICE)
+
+    const { data: authorUser } = await fetchUser(rpc, authorAccount)
+    expect(authorUser.sold).toBe(1)
+  })
+
+  it('Happy Path: Settlement splits the escrowed tokens between author and platform', async () => {
+    const receipt = await pda([RECEIPT_SEED, getAddressEncoder().encode(buyer.address), getAddressEncoder().encode(researchPaper)])
+    const authorTokenVault = await getAssociatedTokenAccountAddress(mint.address, authorVault, TOKEN_PROGRAM_ADDRESS)
+    const adminTokenVault = await getAssociatedTokenAccountAddress(mint.address, adminVault, TOKEN_PROGRAM_ADDRESS)
+
+    await sendAndConfirm({
+      ix: getSettlePurchaseInstruction({
+        payer: author,
+        researchPaper,
+        accessReceipt: receipt,
+        authorUserAccount: authorAccount,
+        escrowVault,
+        authorVault,
+        adminVault,
+        paymentMint: mint.address,
+        escrowTokenVault: await getAssociatedTokenAccountAddress(mint.address, escrowVault, TOKEN_PROGRAM_ADDRESS),
+        authorTokenVault,
+        adminTokenVault,
+        tokenProgram: TOKEN_PROGRAM_ADDRESS,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ADDRESS,
+      }),
+      payer: author,
+    })
+
+    const fee = (TOKEN_PRICE * BigInt(PURCHASE_FEE_BPS)) / 10000n
+    const { value: authorBalance } = await rpc.getTokenAccountBalance(authorTokenVault).send()
+    const { value: adminBalance } = await rpc.getTokenAccountBalance(adminTokenVault).send()
+    expect(BigInt(authorBalance.amount)).toBe(TOKEN_PRICE - fee)
+    expect(BigInt(adminBalance.amount)).toBe(fee)
+
+    // Token sales never touch the lamport earning ledger
+    const { data: authorUser } = await fetchUser(rpc, authorAccount)
+    expect(authorUser.earning).toBe(0n)
+  })
+
+  it('Happy Path: Author withdraws token earnings', async () => {
+    const userTokenVault = await getAssociatedTokenAccountAddress(mint.address, authorVault, TOKEN_PROGRAM_ADDRESS)
+    const userTokenAccount = await getAssociatedTokenAccountAddress(mint.address, author.address, TOKEN_PROGRAM_ADDRESS)
+
+    await sendAndConfirm({
+      ix: getUserWithdrawInstruction({
+        amount: 1_000_000n,
+        user: author,
+        userAccount: authorAccount,
+        userVault: authorVault,
+        paymentMint: mint.address,
+        userTokenVault,
+        userTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ADDRESS,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ADDRESS,
+      }),
+      payer: author,
+    })
+
+    const { value } = await rpc.getTokenAccountBalance(userTokenAccount).send()
+    expect(BigInt(value.amount)).toBe(1_000_000n)
+  })
+})
+
+
+describe('prismpapersdapp refunds', () => {
+  let admin: KeyPairSigner;
+  let author: KeyPairSigner;
+  let buyer: KeyPairSigner;
+  let disputer: KeyPairSigner;
+  let lapser: KeyPairSigner;
+
+  let authorAccount: Address;
+  let researchPaper: Address;
+  let escrowVault: Address;
+
+  async function setRefundWindow(refundWindow: bigint) {
+    await sendAndConfirm({
+      ix: getUpdateConfigInstruction({
+        admins: [admin.address],
+        purchaseFeeBps: PURCHASE_FEE_BPS,
+        reviewFeeBps: REVIEW_FEE_BPS,
+        minFeeLamports: 0n,
+        treasury: admin.address,
+        refundWindow,
+        reviewStake: 0n,
+        paused: false,
+        admin,
+        config: await pda([CONFIG_SEED]),
+      }),
+      payer: admin,
+    });
+  }
+
+  beforeAll(async () => {
+    admin = await loadKeypairSignerFromFile(process.env.ANCHOR_WALLET!);
+    author = await generateKeyPairSigner();
+    buyer = await generateKeyPairSigner();
+    disputer = await generateKeyPairSigner();
+    lapser = await generateKeyPairSigner();
+    await requestAirdrop(author.address);
+    await requestAirdrop(buyer.address);
+    await requestAirdrop(disputer.address);
+    await requestAirdrop(lapser.address);
+
+    authorAccount = await pda([USER_SEED, getAddressEncoder().encode(author.address)]);
+    researchPaper = await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)]);
+    escrowVault = await pda([VAULT_ESCROW_SEED, getAddressEncoder().encode(researchPaper)]);
+
+    // Open a day-long refund window for the purchases in this suite
+    await setRefundWindow(86_400n);
+
+    for (const [signer, name] of [[author, "Refund Author"], [buyer, "Refund Buyer"], [disputer, "Refund Disputer"], [lapser, "Refund Lapser"]] as const) {
+      await sendAndConfirm({
+        ix: getInitUserInstruction({
+          name,
+          owner: signer,
+          userAccount: await pda([USER_SEED, getAddressEncoder().encode(signer.address)]),
+          userVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(signer.address)]),
+        }),
+        payer: signer,
+      });
+    }
+
+    await sendAndConfirm({
+      ix: getInitResearchInstruction({
+        title: "Refundable Research",
+        description: "Bought with a refund window.",
+        price: PRICE,
+        encryptedUrl: "arweave_cid",
+        contentHash: CONTENT_HASH,
+        ciphertextHash: null,
+        coAuthors: [],
+        author,
+        researchPaper,
+        paperRevision: await revisionPda(researchPaper, 0),
+        userAccount: authorAccount,
+        paperCounter: await pda([PAPER_COUNTER_SEED, getAddressEncoder().encode(author.address)]),
+      }),
+      payer: author,
+    });
+    await sendAndConfirm({ ix: getPublishPaperInstruction({ author, researchPaper }), payer: author });
+
+    for (const signer of [buyer, disputer]) {
+      await sendAndConfirm({ ix: await purchaseIx(signer), payer: signer });
+    }
+  });
+
+  async function receiptPda(signer: KeyPairSigner): Promise<Address> {
+    return pda([RECEIPT_SEED, getAddressEncoder().encode(signer.address), getAddressEncoder().encode(researchPaper)]);
+  }
+
+  async function purchaseIx(signer: KeyPairSigner) {
+    return getPurchaseAccessInstruction({
+      buyerEncryptionKey: BUYER_ENCRYPTION_KEY,
+      buyer: signer,
+      researchPaper,
+      accessReceipt: await receiptPda(signer),
+      buyerUserAccount: await pda([USER_SEED, getAddressEncoder().encode(signer.address)]),
+      buyerVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(signer.address)]),
+      authorUserAccount: authorAccount,
+      escrowVault,
+      config: await pda([CONFIG_SEED]),
+    });
+  }
+
+  async function requestIx(signer: KeyPairSigner) {
+    return getRequestRefundInstruction({
+      payer: signer,
+      researchPaper,
+      accessReceipt: await receiptPda(signer),
+      config: await pda([CONFIG_SEED]),
+    });
+  }
+
+  async function settleIx(signer: KeyPairSigner) {
+    return getSettlePurchaseInstruction({
+      payer: author,
+      researchPaper,
+      accessReceipt: await receiptPda(signer),
+      authorUserAccount: authorAccount,
+      escrowVault,
+      authorVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(author.address)]),
+      adminVault: await pda([VAULT_ADMIN_SEED]),
+    });
+  }
+
+  async function resolveAccounts(authority: KeyPairSigner, signer: KeyPairSigner) {
+    return {
+      authority,
+      researchPaper,
+      accessReceipt: await receiptPda(signer),
+      buyer: signer.address,
+      authorUserAccount: authorAccount,
+      buyerUserAccount: await pda([USER_SEED, getAddressEncoder().encode(signer.address)]),
+      escrowVault,
+      config: await pda([CONFIG_SEED]),
+    };
+  }
+
+  it('Sad Path: A purchase cannot be settled while its refund window is open', async () => {
+    await expect(sendAndConfirm({ ix: await settleIx(buyer), payer: author })).rejects.toThrow();
+  });
+
+  it('Happy Path: Buyer requests a refund inside the window', async () => {
+    await sendAndConfirm({
+      ix: await requestIx(buyer),
+      payer: buyer,
+    });
+
+    const { data: receipt } = await fetchAccessReceipt(rpc, await receiptPda(buyer));
+    expect(receipt.status).toBe(ReceiptStatus.Disputed);
+  });
+
+  it('Sad Path: A stranger cannot arbitrate a dispute', async () => {
+    const ix = getArbitrateRefundInstruction({ refund: true, ...(await resolveAccounts(disputer, buyer)) });
+
+    await expect(sendAndConfirm({ ix, payer: disputer })).rejects.toThrow();
+  });
+
+  it('Happy Path: Author approves the refund and the escrow goes back to the buyer', async () => {
+    const before = await rpc.getBalance(buyer.address).send();
+
+    await sendAndConfirm({
+      ix: getApproveRefundInstruction(await resolveAccounts(author, buyer)),
+      payer: author,
+    });
+
+    const after = await rpc.getBalance(buyer.address).send();
+    expect(after.value - before.value).toBe(PRICE);
+
+    const { data: receipt } = await fetchAccessReceipt(rpc, await receiptPda(buyer));
+    expect(receipt.status).toBe(ReceiptStatus.Revoked);
+
+    const { data: paper } = await fetchResearchPaper(rpc, researchPaper);
+    expect(paper.sales).toBe(1);
+    expect(paper.escrowed).toBe(PRICE);
+
+    // The refunded purchase no longer counts on the buyer's profile
+    const { data: buyerUser } = await fetchUser(rpc, await pda([USER_SEED, getAddressEncoder().encode(buyer.address)]));
+    expect(buyerUser.purchased).toBe(0);
+  });
+
+  it('Happy Path: Admin denies a dispute and the purchase settles right away', async () => {
+    await sendAndConfirm({
+      ix: await requestIx(disputer),
+      payer: disputer,
+    });
+    await sendAndConfirm({
+      ix: getArbitrateRefundInstruction({ refund: false, ...(await resolveAccounts(admin, disputer)) }),
+      payer: admin,
+    });
+
+    const { data: denied } = await fetchAccessReceipt(rpc, await receiptPda(disputer));
+    expect(denied.status).toBe(ReceiptStatus.Escrowed);
+
+    await sendAndConfirm({ ix: await settleIx(disputer), payer: author });
+
+    const { data: settled } = await fetchAccessReceipt(rpc, await receiptPda(disputer));
+    expect(settled.status).toBe(ReceiptStatus.Settled);
+
+    const { data: paper } = await fetchResearchPaper(rpc, researchPaper);
+    expect(paper.escrowed).toBe(0n);
+  });
+
+  it('Happy Path: A dispute nobody resolves in time lapses into a refund anyone can pay out', async () => {
+    // A short refund window also gives the author and the admins that long to resolve the dispute
+    await setRefundWindow(2n);
+    await sendAndConfirm({ ix: await purchaseIx(lapser), payer: lapser });
+    await sendAndConfirm({ ix: await requestIx(lapser), payer: lapser });
+
+    // Before the deadline only the author or an admin can resolve it
+    const lapseIx = getLapseRefundInstruction(await resolveAccounts(disputer, lapser));
+    await expect(sendAndConfirm({ ix: lapseIx, payer: disputer })).rejects.toThrow();
+
+    await new Promise(r => setTimeout(r, 3_000));
+    const before = await rpc.getBalance(lapser.address).send();
+    await sendAndConfirm({ ix: lapseIx, payer: disputer });
+    const after = await rpc.getBalance(lapser.address).send();
+    expect(after.value - before.value).toBe(PRICE);
+
+    const { data: receipt } = await fetchAccessReceipt(rpc, await receiptPda(lapser));
+    expect(receipt.status).toBe(ReceiptStatus.Revoked);
+
+    const { data: lapserUser } = await fetchUser(rpc, await pda([USER_SEED, getAddressEncoder().encode(lapser.address)]));
+    expect(lapserUser.purchased).toBe(0);
+
+    await setRefundWindow(86_400n);
+  });
+});
+
+describe('prismpapersdapp institutions', () => {
+  let admin: KeyPairSigner;
+  let librarian: KeyPairSigner;
+  let author: KeyPairSigner;
+  let member: KeyPairSigner;
+  let outsider: KeyPairSigner;
+
+  let institution: Address;
+  let authorAccount: Address;
+  let researchPaper: Address;
+  let license: Address;
+
+  beforeAll(async () => {
+    admin = await loadKeypairSignerFromFile(process.env.ANCHOR_WALLET!);
+    librarian = await generateKeyPairSigner();
+    author = await generateKeyPairSigner();
+    member = await generateKeyPairSigner();
+    outsider = await generateKeyPairSigner();
+    for (const signer of [librarian, author, member, outsider]) {
+      await requestAirdrop(signer.address);
+    }
+
+    institution = await pda([INSTITUTION_SEED, getAddressEncoder().encode(librarian.address)]);
+    authorAccount = await pda([USER_SEED, getAddressEncoder().encode(author.address)]);
+    researchPaper = await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)]);
+    license = await pda([RECEIPT_SEED, getAddressEncoder().encode(institution), getAddressEncoder().encode(researchPaper)]);
+
+    // Licenses get the same day-long refund window as personal purchases
+    await sendAndConfirm({
+      ix: getUpdateConfigInstruction({
+        admins: [admin.address],
+        purchaseFeeBps: PURCHASE_FEE_BPS,
+        reviewFeeBps: REVIEW_FEE_BPS,
+        minFeeLamports: 0n,
+        treasury: admin.address,
+        refundWindow: 86_400n,
+        reviewStake: 0n,
+        paused: false,
+        admin,
+        config: await pda([CONFIG_SEED]),
+      }),
+      payer: admin,
+    });
+
+    for (const [signer, name] of [[author, "Licensed Author"], [member, "Faculty Member"], [outsider, "Visiting Scholar"]] as const) {
+      await sendAndConfirm({
+        ix: getInitUserInstruction({
+          name,
+          owner: signer,
+          userAccount: await pda([USER_SEED, getAddressEncoder().encode(signer.address)]),
+          userVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(signer.address)]),
+        }),
+        payer: signer,
+      });
+    }
+
+    await sendAndConfirm({
+      ix: getInitResearchInstruction({
+        title: "Campus-wide Research",
+        description: "Licensed to a whole university.",
+        price: PRICE,
+        encryptedUrl: "arweave_cid",
+        contentHash: CONTENT_HASH,
+        ciphertextHash: null,
+        coAuthors: [],
+        author,
+        researchPaper,
+        paperRevision: await revisionPda(researchPaper, 0),
+        userAccount: authorAccount,
+        paperCounter: await pda([PAPER_COUNTER_SEED, getAddressEncoder().encode(author.address)]),
+      }),
+      payer: author,
+    });
+    await sendAndConfirm({ ix: getPublishPaperInstruction({ author, researchPaper }), payer: author });
+  });
+
+  async function memberPda(wallet: Address): Promise<Address> {
+    return pda([MEMBER_SEED, getAddressEncoder().encode(institution), getAddressEncoder().encode(wallet)]);
+  }
+
+  async function reviewIx(reviewer: KeyPairSigner) {
+    const peerReview = await pda([REVIEW_SEED, getAddressEncoder().encode(reviewer.address), getAddressEncoder().encode(researchPaper)]);
+    return getReviewPaperInstruction({
+      reviewUrl: "ipfs://site_license_review",
+      reviewHash: REVIEW_HASH,
+      scores: REVIEW_SCORES,
+      proposedReward: 0n,
+      reviewer,
+      researchPaper,
+      reviewRound: await roundPda(researchPaper, 0),
+      roundVoter: await voterPda(await roundPda(researchPaper, 0), reviewer.address),
+      accessReceipt: license,
+      institution,
+      institutionMember: await memberPda(reviewer.address),
+      reviewerUserAccount: await pda([USER_SEED, getAddressEncoder().encode(reviewer.address)]),
+      peerReview,
+      stakeVault: await pda([VAULT_STAKE_SEED, getAddressEncoder().encode(peerReview)]),
+      config: await pda([CONFIG_SEED]),
+    });
+  }
+
+  it('Happy Path: Librarian registers an institution with two seats', async () => {
+    await sendAndConfirm({
+      ix: getInitInstitutionInstruction({ name: "Prism University", seats: 2, admin: librarian, institution }),
+      payer: librarian,
+    });
+
+    const { data } = await fetchInstitution(rpc, institution);
+    expect(data.seats).toBe(2);
+    expect(data.members).toBe(0);
+  });
+
+  it('Happy Path: Librarian gives a faculty member a seat', async () => {
+    await sendAndConfirm({
+      ix: getAddInstitutionMemberInstruction({
+        member: member.address,
+        admin: librarian,
+        institution,
+        institutionMember: await memberPda(member.address),
+      }),
+      payer: librarian,
+    });
+
+    const { data } = await fetchInstitution(rpc, institution);
+    expect(data.members).toBe(1);
+  });
+
+  it('Sad Path: Only the librarian can hand out seats', async () => {
+    const ix = getAddInstitutionMemberInstruction({
+      member: outsider.address,
+      admin: outsider,
+      institution,
+      institutionMember: await memberPda(outsider.address),
+    });
+
+    await expect(sendAndConfirm({ ix, payer: outsider })).rejects.toThrow();
+  });
+
+  it('Sad Path: Members cannot exceed the seat count', async () => {
+    const extra = [await generateKeyPairSigner(), await generateKeyPairSigner()];
+    await sendAndConfirm({
+      ix: getAddInstitutionMemberInstruction({
+        member: extra[0].address,
+        admin: librarian,
+        institution,
+        institutionMember: await memberPda(extra[0].address),
+      }),
+      payer: librarian,
+    });
+
+    const ix = getAddInstitutionMemberInstruction({
+      member: extra[1].address,
+      admin: librarian,
+      institution,
+      institutionMember: await memberPda(extra[1].address),
+    });
+    await expect(sendAndConfirm({ ix, payer: librarian })).rejects.toThrow();
+
+    // Free the seat again for the tests below
+    await sendAndConfirm({
+      ix: getRemoveInstitutionMemberInstruction({ admin: librarian, institution, institutionMember: await memberPda(extra[0].address) }),
+      payer: librarian,
+    });
+  });
+
+  it('Happy Path: Librarian buys a site license priced per seat', async () => {
+    await sendAndConfirm({
+      ix: getPurchaseLicenseInstruction({
+        buyerEncryptionKey: BUYER_ENCRYPTION_KEY,
+        admin: librarian,
+        institution,
+        researchPaper,
+        authorUserAccount: authorAccount,
+        escrowVault: await pda([VAULT_ESCROW_SEED, getAddressEncoder().encode(researchPaper)]),
+        config: await pda([CONFIG_SEED]),
+        accessReceipt: license,
+      }),
+      payer: librarian,
+    });
+
+    const { data: receipt } = await fetchAccessReceipt(rpc, license);
+    expect(receipt.buyer).toBe(institution);
+    expect(receipt.seats).toBe(2);
+    expect(receipt.amountPaid).toBe(PRICE * 2n);
+    expect(receipt.payer).toBe(librarian.address);
+    expect(receipt.refundDeadline).toBeGreaterThan(receipt.timestamp);
+
+    const { data: institutionData } = await fetchInstitution(rpc, institution);
+    expect(institutionData.licenses).toBe(1);
+  });
+
+  it('Sad Path: A wallet without a seat cannot review under the license', async () => {
+    await sendAndConfirm({ ix: await openRoundIx(author, researchPaper), payer: author });
+
+    await expect(sendAndConfirm({ ix: await reviewIx(outsider), payer: outsider })).rejects.toThrow();
+  });
+
+  it('Happy Path: A member reviews the paper under the site license', async () => {
+    await sendAndConfirm({ ix: await reviewIx(member), payer: member });
+
+    const { data: paper } = await fetchResearchPaper(rpc, researchPaper);
+    expect(paper.reviews).toBe(1);
+  });
+
+  it('Sad Path: A member cannot dispute the license on the librarian\'s behalf', async () => {
+    const ix = getRequestRefundInstruction({ payer: member, researchPaper, accessReceipt: license, config: await pda([CONFIG_SEED]) });
+
+    await expect(sendAndConfirm({ ix, payer: member })).rejects.toThrow();
+  });
+
+  it('Happy Path: The librarian disputes the license and the refund goes back to them', async () => {
+    await sendAndConfirm({
+      ix: getRequestRefundInstruction({ payer: librarian, researchPaper, accessReceipt: license, config: await pda([CONFIG_SEED]) }),
+      payer: librarian,
+    });
+
+    const { value: balanceBefore } = await rpc.getBalance(librarian.address).send();
+    await sendAndConfirm({
+      ix: getApproveRefundInstruction({
+        authority: author,
+        researchPaper,
+        accessReceipt: license,
+        buyer: librarian.address,
+        authorUserAccount: authorAccount,
+        escrowVault: await pda([VAULT_ESCROW_SEED, getAddressEncoder().encode(researchPaper)]),
+        config: await pda([CONFIG_SEED]),
+      }),
+      payer: author,
+    });
+    const { value: balanceAfter } = await rpc.getBalance(librarian.address).send();
+    expect(balanceAfter).toBe(balanceBefore + PRICE * 2n);
+
+    const { data: receipt } = await fetchAccessReceipt(rpc, license);
+    expect(receipt.status).toBe(ReceiptStatus.Revoked);
+  });
+
+  it('Sad Path: Only the librarian can close the license', async () => {
+    const ix = getCloseLicenseReceiptInstruction({
+      admin: member,
+      institution,
+      accessReceipt: license,
+      researchPaper,
+    });
+
+    await expect(sendAndConfirm({ ix, payer: member })).rejects.toThrow();
+  });
+
+  it('Happy Path: The librarian closes the refunded license and reclaims its rent', async () => {
+    await sendAndConfirm({
+      ix: getCloseLicenseReceiptInstruction({ admin: librarian, institution, accessReceipt: license, researchPaper }),
+      payer: librarian,
+    });
+
+    const { value } = await rpc.getAccountInfo(license).send();
+    expect(value).toBeNull();
+  });
+});
+
+describe('prismpapersdapp subscriptions', () => {
+  const DURATION = 3_600n;
+
+  let author: KeyPairSigner;
+  let subscriber: KeyPairSigner;
+
+  let authorAccount: Address;
+  let includedPaper: Address;
+  let excludedPaper: Address;
+  let plan: Address;
+  let subscription: Address;
+
+  beforeAll(async () => {
+    author = await generateKeyPairSigner();
+    subscriber = await generateKeyPairSigner();
+    await requestAirdrop(author.address);
+    await requestAirdrop(subscriber.address);
+
+    authorAccount = await pda([USER_SEED, getAddressEncoder().encode(author.address)]);
+    includedPaper = await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)]);
+    excludedPaper = await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(1)]);
+    plan = await pda([PLAN_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)]);
+    subscription = await pda([SUBSCRIPTION_SEED, getAddressEncoder().encode(subscriber.address), getAddressEncoder().encode(plan)]);
+
+    for (const [signer, name] of [[author, "Lab Author"], [subscriber, "Lab Subscriber"]] as const) {
+      await sendAndConfirm({
+        ix: getInitUserInstruction({
+          name,
+          owner: signer,
+          userAccount: await pda([USER_SEED, getAddressEncoder().encode(signer.address)]),
+          userVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(signer.address)]),
+        }),
+        payer: signer,
+      });
+    }
+
+    // Two papers open for review, only the first one is part of the plan
+    for (const researchPaper of [includedPaper, excludedPaper]) {
+      await sendAndConfirm({
+        ix: getInitResearchInstruction({
+          title: "Lab Catalogue Paper",
+          description: "Part of a lab catalogue.",
+          price: PRICE,
+          encryptedUrl: "arweave_cid",
+          contentHash: CONTENT_HASH,
+          ciphertextHash: null,
+          coAuthors: [],
+          author,
+          researchPaper,
+          paperRevision: await revisionPda(researchPaper, 0),
+          userAccount: authorAccount,
+          paperCounter: await pda([PAPER_COUNTER_SEED, getAddressEncoder().encode(author.address)]),
+        }),
+        payer: author,
+      });
+      await sendAndConfirm({ ix: getPublishPaperInstruction({ author, researchPaper }), payer: author });
+      await sendAndConfirm({ ix: await openRoundIx(author, researchPaper), payer: author });
+    }
+  });
+
+  async function subscribeIx(signer: KeyPairSigner) {
+    return getSubscribeInstruction({
+      subscriberEncryptionKey: BUYER_ENCRYPTION_KEY,
+      subscriber: signer,
+      subscriptionPlan: plan,
+      subscription: await pda([SUBSCRIPTION_SEED, getAddressEncoder().encode(signer.address), getAddressEncoder().encode(plan)]),
+      authorUserAccount: authorAccount,
+      authorVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(author.address)]),
+      adminVault: await pda([VAULT_ADMIN_SEED]),
+      config: await pda([CONFIG_SEED]),
+    });
+  }
+
+  async function reviewIx(researchPaper: Address) {
+    const peerReview = await pda([REVIEW_SEED, getAddressEncoder().encode(subscriber.address), getAddressEncoder().encode(researchPaper)]);
+    return getReviewPaperInstruction({
+      reviewUrl: "ipfs://subscriber_review",
+      reviewHash: REVIEW_HASH,
+      scores: REVIEW_SCORES,
+      proposedReward: 0n,
+      reviewer: subscriber,
+      researchPaper,
+      reviewRound: await roundPda(researchPaper, 0),
+      roundVoter: await voterPda(await roundPda(researchPaper, 0), subscriber.address),
+      subscriptionPlan: plan,
+      subscription,
+      reviewerUserAccount: await pda([USER_SEED, getAddressEncoder().encode(subscriber.address)]),
+      peerReview,
+      stakeVault: await pda([VAULT_STAKE_SEED, getAddressEncoder().encode(peerReview)]),
+      config: await pda([CONFIG_SEED]),
+    });
+  }
+
+  it('Happy Path: Author creates a subscription plan for one paper', async () => {
+    await sendAndConfirm({
+      ix: getCreatePlanInstruction({
+        planId: 0,
+        price: PRICE,
+        duration: DURATION,
+        papers: [includedPaper],
+        coAuthors: [],
+        author,
+        userAccount: authorAccount,
+        subscriptionPlan: plan,
+      }),
+      payer: author,
+    });
+
+    const { data } = await fetchSubscriptionPlan(rpc, plan);
+    expect(data.active).toBe(true);
+    expect(data.papers).toEqual([includedPaper]);
+  });
+
+  it('Sad Path: Author cannot subscribe to their own plan', async () => {
+    await expect(sendAndConfirm({ ix: await subscribeIx(author), payer: author })).rejects.toThrow();
+  });
+
+  it('Happy Path: Reader subscribes and the author is paid right away', async () => {
+    await sendAndConfirm({ ix: await subscribeIx(subscriber), payer: subscriber });
+
+    const { data } = await fetchSubscription(rpc, subscription);
+    expect(data.expiresAt).toBeGreaterThan(0n);
+
+    const { data: authorUser } = await fetchUser(rpc, authorAccount);
+    expect(authorUser.earning).toBe(PRICE - (PRICE * BigInt(PURCHASE_FEE_BPS)) / 10000n);
+  });
+
+  it('Happy Path: Renewing early extends the running period', async () => {
+    const { data: before } = await fetchSubscription(rpc, subscription);
+
+    await sendAndConfirm({ ix: await subscribeIx(subscriber), payer: subscriber });
+
+    const { data: after } = await fetchSubscription(rpc, subscription);
+    expect(after.expiresAt).toBe(before.expiresAt + DURATION);
+  });
+
+  it('Sad Path: The subscription does not cover papers outside the plan', async () => {
+    await expect(sendAndConfirm({ ix: await reviewIx(excludedPaper), payer: subscriber })).rejects.toThrow();
+  });
+
+  it('Happy Path: Subscriber reviews a covered paper without a receipt', async () => {
+    await sendAndConfirm({ ix: await reviewIx(includedPaper), payer: subscriber });
+
+    const { data: paper } = await fetchResearchPaper(rpc, includedPaper);
+    expect(paper.reviews).toBe(1);
+  });
+
+  it('Happy Path: Author delivers a covered paper\'s key to the subscriber', async () => {
+    const keyDelivery = await subscriptionKeyDeliveryPda(subscription, includedPaper, 0);
+    const wrappedKey = new Uint8Array(48).fill(7); // sealed box to the subscriber's key
+
+    await sendAndConfirm({
+      ix: getDeliverSubscriptionKeyInstruction({
+        revision: 0,
+        wrappedKey,
+        author,
+        researchPaper: includedPaper,
+        subscriptionPlan: plan,
+        subscription,
+        keyDelivery,
+      }),
+      payer: author,
+    });
+
+    const { data } = await fetchKeyDelivery(rpc, keyDelivery);
+    expect(data.accessReceipt).toBe(subscription);
+    expect(data.buyer).toBe(subscriber.address);
+    expect(Uint8Array.from(data.wrappedKey)).toEqual(wrappedKey);
+  });
+
+  it('Sad Path: Author cannot deliver a subscriber key for a paper outside the plan', async () => {
+    const ix = getDeliverSubscriptionKeyInstruction({
+      revision: 0,
+      wrappedKey: new Uint8Array(48).fill(7),
+      author,
+      researchPaper: excludedPaper,
+      subscriptionPlan: plan,
+      subscription,
+      keyDelivery: await subscriptionKeyDeliveryPda(subscription, excludedPaper, 0),
+    });
+
+    await expect(sendAndConfirm({ ix, payer: author })).rejects.toThrow();
+  });
+
+  it('Sad Path: Subscriber cannot close a running subscription', async () => {
+    const ix = getCloseSubscriptionInstruction({ subscriber, subscription });
+
+    await expect(sendAndConfirm({ ix, payer: subscriber })).rejects.toThrow();
+  });
+
+  it('Sad Path: A deactivated plan cannot be renewed', async () => {
+    await sendAndConfirm({
+      ix: getUpdatePlanInstruction({
+        price: PRICE,
+        duration: DURATION,
+        papers: [includedPaper],
+        active: false,
+        author,
+        subscriptionPlan: plan,
+      }),
+      payer: author,
+    });
+
+    await expect(sendAndConfirm({ ix: await subscribeIx(subscriber), payer: subscriber })).rejects.toThrow();
+  });
+});
+
+describe('prismpapersdapp bundles', () => {
+  const DISCOUNT_BPS = 2_000; // 20%
+
+  let admin: KeyPairSigner;
+  let author: KeyPairSigner;
+  let bundleBuyer: KeyPairSigner;
+  let looseBuyer: KeyPairSigner;
+  let finalBuyer: KeyPairSigner;
+
+  let authorAccount: Address;
+  let papers: Address[];
+  let bundle: Address;
+
+  beforeAll(async () => {
+    admin = await loadKeypairSignerFromFile(process.env.ANCHOR_WALLET!);
+    author = await generateKeyPairSigner();
+    bundleBuyer = await generateKeyPairSigner();
+    looseBuyer = await generateKeyPairSigner();
+    finalBuyer = await generateKeyPairSigner();
+    for (const signer of [author, bundleBuyer, looseBuyer, finalBuyer]) {
+      await requestAirdrop(signer.address);
+      await sendAndConfirm({
+        ix: getInitUserInstruction({
+          name: "Bundle User",
+          owner: signer,
+          userAccount: await pda([USER_SEED, getAddressEncoder().encode(signer.address)]),
+          userVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(signer.address)]),
+        }),
+        payer: signer,
+      });
+    }
+
+    authorAccount = await pda([USER_SEED, getAddressEncoder().encode(author.address)]);
+    bundle = await pda([BUNDLE_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)]);
+    papers = [];
+    for (const index of [0, 1]) {
+      const researchPaper = await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(index)]);
+      await sendAndConfirm({
+        ix: getInitResearchInstruction({
+          title: "Bundled Research",
+          description: "Sold on its own or as part of a bundle.",
+          price: PRICE,
+          encryptedUrl: "arweave_cid",
+          contentHash: CONTENT_HASH,
+          ciphertextHash: null,
+          coAuthors: [],
+          author,
+          researchPaper,
+          paperRevision: await revisionPda(researchPaper, 0),
+          userAccount: authorAccount,
+          paperCounter: await pda([PAPER_COUNTER_SEED, getAddressEncoder().encode(author.address)]),
+        }),
+        payer: author,
+      });
+      await sendAndConfirm({ ix: getPublishPaperInstruction({ author, researchPaper }), payer: author });
+      papers.push(researchPaper);
+    }
+
+    // Bundle purchases honour the same day-long refund window as single purchases
+    await setRefundWindow(86_400n);
+  });
+
+  async function setRefundWindow(refundWindow: bigint) {
+    await sendAndConfirm({
+      ix: getUpdateConfigInstruction({
+        admins: [admin.address],
+        purchaseFeeBps: PURCHASE_FEE_BPS,
+        reviewFeeBps: REVIEW_FEE_BPS,
+        minFeeLamports: 0n,
+        treasury: admin.address,
+        refundWindow,
+        reviewStake: 0n,
+        paused: false,
+        admin,
+        config: await pda([CONFIG_SEED]),
+      }),
+      payer: admin,
+    });
+  }
+
+  async function bundleIx(buyer: KeyPairSigner, order: Address[], withBundle: boolean) {
+    const ix = getPurchaseBundleInstruction({
+      buyerEncryptionKey: BUYER_ENCRYPTION_KEY,
+      buyer,
+      buyerUserAccount: await pda([USER_SEED, getAddressEncoder().encode(buyer.address)]),
+      bundle: withBundle ? bundle : undefined,
+      config: await pda([CONFIG_SEED]),
+    });
+    // (paper, receipt, author user, escrow vault) groups go in remaining accounts
+    const groups = [];
+    for (const researchPaper of order) {
+      groups.push(
+        { address: researchPaper, role: AccountRole.WRITABLE },
+        { address: await pda([RECEIPT_SEED, getAddressEncoder().encode(buyer.address), getAddressEncoder().encode(researchPaper)]), role: AccountRole.WRITABLE },
+        { address: authorAccount, role: AccountRole.WRITABLE },
+        { address: await pda([VAULT_ESCROW_SEED, getAddressEncoder().encode(researchPaper)]), role: AccountRole.WRITABLE },
+      );
+    }
+    return { ...ix, accounts: [...ix.accounts, ...groups] };
+  }
+
+  it('Sad Path: A bundle cannot be given away for free', async () => {
+    const ix = getCreateBundleInstruction({
+      bundleId: 1,
+      papers,
+      discountBps: 10_000,
+      author,
+      userAccount: authorAccount,
+      bundle: await pda([BUNDLE_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(1)]),
+    });
+
+    await expect(sendAndConfirm({ ix, payer: author })).rejects.toThrow();
+  });
+
+  it('Happy Path: Author creates a discounted bundle', async () => {
+    await sendAndConfirm({
+      ix: getCreateBundleInstruction({
+        bundleId: 0,
+        papers,
+        discountBps: DISCOUNT_BPS,
+        author,
+        userAccount: authorAccount,
+        bundle,
+      }),
+      payer: author,
+    });
+
+    const { data } = await fetchBundle(rpc, bundle);
+    expect(data.papers).toEqual(papers);
+    expect(data.active).toBe(true);
+  });
+
+  it('Sad Path: Bundle papers must be passed in the bundle order', async () => {
+    const ix = await bundleIx(bundleBuyer, [...papers].reverse(), true);
+
+    await expect(sendAndConfirm({ ix, payer: bundleBuyer })).rejects.toThrow();
+  });
+
+  it('Happy Path: Buyer purchases the bundle in one transaction', async () => {
+    await sendAndConfirm({ ix: await bundleIx(bundleBuyer, papers, true), payer: bundleBuyer });
+
+    const discounted = PRICE - (PRICE * BigInt(DISCOUNT_BPS)) / 10000n;
+    for (const researchPaper of papers) {
+      const receipt = await pda([RECEIPT_SEED, getAddressEncoder().encode(bundleBuyer.address), getAddressEncoder().encode(researchPaper)]);
+      const { data } = await fetchAccessReceipt(rpc, receipt);
+      expect(data.amountPaid).toBe(discounted);
+      // The refund window is open, so the payment waits in escrow
+      expect(data.status).toBe(ReceiptStatus.Escrowed);
+      expect(data.refundDeadline).toBe(data.timestamp + 86_400n);
+
+      const { data: paper } = await fetchResearchPaper(rpc, researchPaper);
+      expect(paper.escrowed).toBe(discounted);
+    }
+
+    const { data: authorUser } = await fetchUser(rpc, authorAccount);
+    expect(authorUser.sold).toBe(2);
+    expect(authorUser.earning).toBe(0n);
+
+    const { data: bundleData } = await fetchBundle(rpc, bundle);
+    expect(bundleData.sales).toBe(1);
+  });
+
+  it('Happy Path: Any papers can be bought together at full price', async () => {
+    await sendAndConfirm({ ix: await bundleIx(looseBuyer, papers, false), payer: looseBuyer });
+
+    const receipt = await pda([RECEIPT_SEED, getAddressEncoder().encode(looseBuyer.address), getAddressEncoder().encode(papers[1])]);
+    const { data } = await fetchAccessReceipt(rpc, receipt);
+    expect(data.amountPaid).toBe(PRICE);
+
+    const { data: buyerUser } = await fetchUser(rpc, await pda([USER_SEED, getAddressEncoder().encode(looseBuyer.address)]));
+    expect(buyerUser.purchased).toBe(2);
+  });
+
+  it('Happy Path: Without a refund window bundle sales are still escrowed and settle right away, even on a pre-funded receipt', async () => {
+    await setRefundWindow(0n);
+    // Lamports sent to a receipt address ahead of time must not block the purchase
+    const receipt = await pda([RECEIPT_SEED, getAddressEncoder().encode(finalBuyer.address), getAddressEncoder().encode(papers[0])]);
+    await sendAndConfirm({
+      ix: getTransferSolInstruction({ source: looseBuyer, destination: receipt, amount: 1_000_000n }),
+      payer: looseBuyer,
+    });
+    const { data: before } = await fetchUser(rpc, authorAccount);
+
+    await sendAndConfirm({ ix: await bundleIx(finalBuyer, papers, false), payer: finalBuyer });
+
+    const { data: escrowed } = await fetchAccessReceipt(rpc, receipt);
+    expect(escrowed.status).toBe(ReceiptStatus.Escrowed);
+
+    // settle_purchase pays the author out, as it does for a single purchase
+    for (const researchPaper of papers) {
+      await sendAndConfirm({
+        ix: getSettlePurchaseInstruction({
+          payer: finalBuyer,
+          researchPaper,
+          accessReceipt: await pda([RECEIPT_SEED, getAddressEncoder().encode(finalBuyer.address), getAddressEncoder().encode(researchPaper)]),
+          authorUserAccount: authorAccount,
+          escrowVault: await pda([VAULT_ESCROW_SEED, getAddressEncoder().encode(researchPaper)]),
+          authorVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(author.address)]),
+          adminVault: await pda([VAULT_ADMIN_SEED]),
+        }),
+        payer: finalBuyer,
+      });
+    }
+
+    const { data } = await fetchAccessReceipt(rpc, receipt);
+    expect(data.status).toBe(ReceiptStatus.Settled);
+
+    const { data: after } = await fetchUser(rpc, authorAccount);
+    expect(after.earning - before.earning).toBe(2n * (PRICE - (PRICE * BigInt(PURCHASE_FEE_BPS)) / 10000n));
+  });
+});
+
+describe('prismpapersdapp co-authors', () => {
+  let admin: KeyPairSigner;
+  let author: KeyPairSigner;
+  let coAuthor: KeyPairSigner;
+  let buyer: KeyPairSigner;
+
+  let authorAccount: Address;
+  let coAuthorAccount: Address;
+  let coAuthorVault: Address;
+  let researchPaper: Address;
+  let receipt: Address;
+
+  beforeAll(async () => {
+    admin = await loadKeypairSignerFromFile(process.env.ANCHOR_WALLET!);
+    author = await generateKeyPairSigner();
+    coAuthor = await generateKeyPairSigner();
+    buyer = await generateKeyPairSigner();
+    for (const signer of [author, coAuthor, buyer]) {
+      await requestAirdrop(signer.address);
+      await sendAndConfirm({
+        ix: getInitUserInstruction({
+          name: "Co-author Suite",
+          owner: signer,
+          userAccount: await pda([USER_SEED, getAddressEncoder().encode(signer.address)]),
+          userVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(signer.address)]),
+        }),
+        payer: signer,
+      });
+    }
+
+    authorAccount = await pda([USER_SEED, getAddressEncoder().encode(author.address)]);
+    coAuthorAccount = await pda([USER_SEED, getAddressEncoder().encode(coAuthor.address)]);
+    coAuthorVault = await pda([VAULT_USER_SEED, getAddressEncoder().encode(coAuthor.address)]);
+    researchPaper = await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)]);
+    receipt = await pda([RECEIPT_SEED, getAddressEncoder().encode(buyer.address), getAddressEncoder().encode(researchPaper)]);
+
+    // The refunds suite opened a refund window, purchases here should settle right away
+    await sendAndConfirm({
+      ix: getUpdateConfigInstruction({
+        admins: [admin.address],
+        purchaseFeeBps: PURCHASE_FEE_BPS,
+        reviewFeeBps: REVIEW_FEE_BPS,
+        minFeeLamports: 0n,
+        treasury: admin.address,
+        refundWindow: 0n,
+        reviewStake: 0n,
+        paused: false,
+        admin,
+        config: await pda([CONFIG_SEED]),
+      }),
+      payer: admin,
+    });
+  });
+
+  async function settleIx(coAuthorAccounts: { address: Address; role: AccountRole }[]) {
+    const ix = getSettlePurchaseInstruction({
+      payer: buyer,
+      researchPaper,
+      accessReceipt: receipt,
+      authorUserAccount: authorAccount,
+      escrowVault: await pda([VAULT_ESCROW_SEED, getAddressEncoder().encode(researchPaper)]),
+      authorVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(author.address)]),
+      adminVault: await pda([VAULT_ADMIN_SEED]),
+    });
+    // (User account, user vault) pairs of the other co-authors go in remaining accounts
+    return { ...ix, accounts: [...ix.accounts, ...coAuthorAccounts] };
+  }
+
+  it('Sad Path: Co-author shares must total 10000 bps', async () => {
+    const ix = getInitResearchInstruction({
+      title: "Joint Research",
+      description: "Written by two authors.",
+      price: PRICE,
+      encryptedUrl: "arweave_cid",
+      contentHash: CONTENT_HASH,
+      ciphertextHash: null,
+      coAuthors: [
+        { wallet: author.address, shareBps: 6_000 },
+        { wallet: coAuthor.address, shareBps: 3_000 },
+      ],
+      author,
+      researchPaper,
+      paperRevision: await revisionPda(researchPaper, 0),
+      userAccount: authorAccount,
+      paperCounter: await pda([PAPER_COUNTER_SEED, getAddressEncoder().encode(author.address)]),
+    });
+
+    const accounts = [
+      { address: coAuthor.address, role: AccountRole.READONLY_SIGNER, signer: coAuthor },
+      { address: coAuthorAccount, role: AccountRole.WRITABLE },
+    ];
+
+    await expect(sendAndConfirm({ ix: { ...ix, accounts: [...ix.accounts, ...accounts] }, payer: author })).rejects.toThrow();
+  });
+
+  it('Sad Path: A co-author cannot be listed without signing', async () => {
+    const ix = getInitResearchInstruction({
+      title: "Joint Research",
+      description: "Written by two authors.",
+      price: PRICE,
+      encryptedUrl: "arweave_cid",
+      contentHash: CONTENT_HASH,
+      ciphertextHash: null,
+      coAuthors: [
+        { wallet: author.address, shareBps: 6_000 },
+        { wallet: coAuthor.address, shareBps: 4_000 },
+      ],
+      author,
+      researchPaper,
+      paperRevision: await revisionPda(researchPaper, 0),
+      userAccount: authorAccount,
+      paperCounter: await pda([PAPER_COUNTER_SEED, getAddressEncoder().encode(author.address)]),
+    });
+    const accounts = [
+      { address: coAuthor.address, role: AccountRole.READONLY },
+      { address: coAuthorAccount, role: AccountRole.WRITABLE },
+    ];
+
+    await expect(sendAndConfirm({ ix: { ...ix, accounts: [...ix.accounts, ...accounts] }, payer: author })).rejects.toThrow();
+  });
+
+  it('Happy Path: Author publishes a paper split 60/40 with a co-author', async () => {
+    const ix = getInitResearchInstruction({
+      title: "Joint Research",
+      description: "Written by two authors.",
+      price: PRICE,
+      encryptedUrl: "arweave_cid",
+      contentHash: CONTENT_HASH,
+      ciphertextHash: null,
+      coAuthors: [
+        { wallet: author.address, shareBps: 6_000 },
+        { wallet: coAuthor.address, shareBps: 4_000 },
+      ],
+      author,
+      researchPaper,
+      paperRevision: await revisionPda(researchPaper, 0),
+      userAccount: authorAccount,
+      paperCounter: await pda([PAPER_COUNTER_SEED, getAddressEncoder().encode(author.address)]),
+    });
+    // The co-author signs, then their User account follows in remaining accounts
+    await sendAndConfirm({
+      ix: {
+        ...ix,
+        accounts: [
+          ...ix.accounts,
+          { address: coAuthor.address, role: AccountRole.READONLY_SIGNER, signer: coAuthor },
+          { address: coAuthorAccount, role: AccountRole.WRITABLE },
+        ],
+      },
+      payer: author,
+    });
+    await sendAndConfirm({ ix: getPublishPaperInstruction({ author, researchPaper }), payer: author });
+
+    // The paper now keeps the co-author's profile open too
+    const { data: coAuthorUser } = await fetchUser(rpc, coAuthorAccount);
+    expect(coAuthorUser.livePapers).toBe(1);
+  });
+
+  it('Happy Path: Buyer purchases the co-authored paper', async () => {
+    await sendAndConfirm({
+      ix: getPurchaseAccessInstruction({
+        buyerEncryptionKey: BUYER_ENCRYPTION_KEY,
+        buyer,
+        researchPaper,
+        accessReceipt: receipt,
+        buyerUserAccount: await pda([USER_SEED, getAddressEncoder().encode(buyer.address)]),
+        buyerVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(buyer.address)]),
+        authorUserAccount: authorAccount,
+        escrowVault: await pda([VAULT_ESCROW_SEED, getAddressEncoder().encode(researchPaper)]),
+        config: await pda([CONFIG_SEED]),
+      }),
+      payer: buyer,
+    });
+  });
+
+  it('Sad Path: A co-author cannot buy their own paper in a bundle', async () => {
+    const ix = getPurchaseBundleInstruction({
+      buyerEncryptionKey: BUYER_ENCRYPTION_KEY,
+      buyer: coAuthor,
+      buyerUserAccount: coAuthorAccount,
+      config: await pda([CONFIG_SEED]),
+    });
+    const accounts = [
+      { address: researchPaper, role: AccountRole.WRITABLE },
+      { address: await pda([RECEIPT_SEED, getAddressEncoder().encode(coAuthor.address), getAddressEncoder().encode(researchPaper)]), role: AccountRole.WRITABLE },
+      { address: authorAccount, role: AccountRole.WRITABLE },
+      { address: await pda([VAULT_ESCROW_SEED, getAddressEncoder().encode(researchPaper)]), role: AccountRole.WRITABLE },
+    ];
+
+    await expect(sendAndConfirm({ ix: { ...ix, accounts: [...ix.accounts, ...accounts] }, payer: coAuthor })).rejects.toThrow();
+  });
+
+  it('Sad Path: Settlement needs every co-author account', async () => {
+    await expect(sendAndConfirm({ ix: await settleIx([]), payer: buyer })).rejects.toThrow();
+  });
+
+  it('Happy Path: Settlement splits the earning across the co-authors', async () => {
+    const ix = await settleIx([
+      { address: coAuthorAccount, role: AccountRole.WRITABLE },
+      { address: coAuthorVault, role: AccountRole.WRITABLE },
+    ]);
+    await sendAndConfirm({ ix, payer: buyer });
+
+    const earning = PRICE - (PRICE * BigInt(PURCHASE_FEE_BPS)) / 10000n;
+    const coAuthorShare = (earning * 4_000n) / 10000n;
+
+    const { data: coAuthorUser } = await fetchUser(rpc, coAuthorAccount);
+    expect(coAuthorUser.earning).toBe(coAuthorShare);
+
+    const { data: authorUser } = await fetchUser(rpc, authorAccount);
+    expect(authorUser.earning).toBe(earning - coAuthorShare);
+  });
+
+  it('Happy Path: A plan with the paper\'s split pays the co-author their share of subscriptions', async () => {
+    const plan = await pda([PLAN_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)]);
+    await sendAndConfirm({
+      ix: getCreatePlanInstruction({
+        planId: 0,
+        price: PRICE,
+        duration: 3_600n,
+        papers: [researchPaper],
+        coAuthors: [
+          { wallet: author.address, shareBps: 6_000 },
+          { wallet: coAuthor.address, shareBps: 4_000 },
+        ],
+        author,
+        userAccount: authorAccount,
+        subscriptionPlan: plan,
+      }),
+      payer: author,
+    });
+    const { data: before } = await fetchUser(rpc, coAuthorAccount);
+
+    const ix = getSubscribeInstruction({
+      subscriberEncryptionKey: BUYER_ENCRYPTION_KEY,
+      subscriber: buyer,
+      subscriptionPlan: plan,
+      subscription: await pda([SUBSCRIPTION_SEED, getAddressEncoder().encode(buyer.address), getAddressEncoder().encode(plan)]),
+      authorUserAccount: authorAccount,
+      authorVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(author.address)]),
+      adminVault: await pda([VAULT_ADMIN_SEED]),
+      config: await pda([CONFIG_SEED]),
+    });
+    await sendAndConfirm({
+      ix: {
+        ...ix,
+        accounts: [
+          ...ix.accounts,
+          { address: coAuthorAccount, role: AccountRole.WRITABLE },
+          { address: coAuthorVault, role: AccountRole.WRITABLE },
+        ],
+      },
+      payer: buyer,
+    });
+
+    const earning = PRICE - (PRICE * BigInt(PURCHASE_FEE_BPS)) / 10000n;
+    const { data: after } = await fetchUser(rpc, coAuthorAccount);
+    expect(after.earning - before.earning).toBe((earning * 4_000n) / 10000n);
+  });
+
+  it('Sad Path: The split cannot change without the co-author signing', async () => {
+    const ix = getSetCoAuthorsInstruction({
+      coAuthors: [{ wallet: author.address, shareBps: 10_000 }],
+      author,
+      researchPaper,
+    });
+    const unsigned = {
+      ...ix,
+      accounts: [
+        ...ix.accounts,
+        { address: coAuthor.address, role: AccountRole.READONLY },
+        { address: coAuthorAccount, role: AccountRole.WRITABLE },
+      ],
+    };
+
+    await expect(sendAndConfirm({ ix: unsigned, payer: author })).rejects.toThrow();
+  });
+
+  it('Happy Path: Co-authors jointly hand the whole paper to the author', async () => {
+    const ix = getSetCoAuthorsInstruction({
+      coAuthors: [{ wallet: author.address, shareBps: 10_000 }],
+      author,
+      researchPaper,
+    });
+    const signed = {
+      ...ix,
+      accounts: [
+        ...ix.accounts,
+        { address: coAuthor.address, role: AccountRole.READONLY_SIGNER, signer: coAuthor },
+        { address: coAuthorAccount, role: AccountRole.WRITABLE },
+      ],
+    };
+    await sendAndConfirm({ ix: signed, payer: author });
+
+    const { data: paper } = await fetchResearchPaper(rpc, researchPaper);
+    expect(paper.coAuthors).toEqual([{ wallet: author.address, shareBps: 10_000 }]);
+
+    const { data: coAuthorUser } = await fetchUser(rpc, coAuthorAccount);
+    expect(coAuthorUser.livePapers).toBe(0);
+  });
+
+  it('Happy Path: A running subscription still covers the paper after its split changes', async () => {
+    const plan = await pda([PLAN_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)]);
+    const subscription = await pda([SUBSCRIPTION_SEED, getAddressEncoder().encode(buyer.address), getAddressEncoder().encode(plan)]);
+    const keyDelivery = await subscriptionKeyDeliveryPda(subscription, researchPaper, 0);
+
+    // The plan keeps paying the split it was created with
+    await sendAndConfirm({
+      ix: getDeliverSubscriptionKeyInstruction({
+        revision: 0,
+        wrappedKey: new Uint8Array(48).fill(9),
+        author,
+        researchPaper,
+        subscriptionPlan: plan,
+        subscription,
+        keyDelivery,
+      }),
+      payer: author,
+    });
+
+    const { data } = await fetchKeyDelivery(rpc, keyDelivery);
+    expect(data.buyer).toBe(buyer.address);
+  });
+});
+
+describe('prismpapersdapp review stakes and reputation', () => {
+  const STAKE = 10_000_000n;
+
+  let admin: KeyPairSigner;
+  let author: KeyPairSigner;
+  let coAuthor: KeyPairSigner;
+  let spammer: KeyPairSigner;
+  let reviewer: KeyPairSigner;
+  let critic: KeyPairSigner;
+
+  let authorAccount: Address;
+  let coAuthorAccount: Address;
+  let researchPaper: Address;
+
+  beforeAll(async () => {
+    admin = await loadKeypairSignerFromFile(process.env.ANCHOR_WALLET!);
+    author = await generateKeyPairSigner();
+    coAuthor = await generateKeyPairSigner();
+    spammer = await generateKeyPairSigner();
+    reviewer = await generateKeyPairSigner();
+    critic = await generateKeyPairSigner();
+    for (const signer of [author, coAuthor, spammer, reviewer, critic]) {
+      await requestAirdrop(signer.address);
+      await sendAndConfirm({
+        ix: getInitUserInstruction({
+          name: "Stake Suite",
+          owner: signer,
+          userAccount: await pda([USER_SEED, getAddressEncoder().encode(signer.address)]),
+          userVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(signer.address)]),
+        }),
+        payer: signer,
+      });
+    }
+
+    authorAccount = await pda([USER_SEED, getAddressEncoder().encode(author.address)]);
+    coAuthorAccount = await pda([USER_SEED, getAddressEncoder().encode(coAuthor.address)]);
+    researchPaper = await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)]);
+
+    // Every review on the platform now locks a stake
+    await sendAndConfirm({
+      ix: getUpdateConfigInstruction({
+        admins: [admin.address],
+        purchaseFeeBps: PURCHASE_FEE_BPS,
+        reviewFeeBps: REVIEW_FEE_BPS,
+        minFeeLamports: 0n,
+        treasury: admin.address,
+        refundWindow: 0n,
+        reviewStake: STAKE,
+        paused: false,
+        admin,
+        config: await pda([CONFIG_SEED]),
+      }),
+      payer: admin,
+    });
+
+    // Co-authored 75/25, so slashed stakes are split like sales
+    const initIx = getInitResearchInstruction({
+      title: "Staked Research",
+      description: "Reviews of this paper lock a stake.",
+      price: PRICE,
+      encryptedUrl: "arweave_cid",
+      contentHash: CONTENT_HASH,
+      ciphertextHash: null,
+      coAuthors: [
+        { wallet: author.address, shareBps: 7_500 },
+        { wallet: coAuthor.address, shareBps: 2_500 },
+      ],
+      author,
+      researchPaper,
+      paperRevision: await revisionPda(researchPaper, 0),
+      userAccount: authorAccount,
+      paperCounter: await pda([PAPER_COUNTER_SEED, getAddressEncoder().encode(author.address)]),
+    });
+    await sendAndConfirm({
+      ix: {
+        ...initIx,
+        accounts: [
+          ...initIx.accounts,
+          { address: coAuthor.address, role: AccountRole.READONLY_SIGNER, signer: coAuthor },
+          { address: coAuthorAccount, role: AccountRole.WRITABLE },
+        ],
+      },
+      payer: author,
+    });
+    await sendAndConfirm({ ix: getPublishPaperInstruction({ author, researchPaper }), payer: author });
+    await sendAndConfirm({ ix: await openRoundIx(author, researchPaper), payer: author });
+
+    for (const buyer of [spammer, reviewer, critic]) {
+      await sendAndConfirm({
+        ix: getPurchaseAccessInstruction({
+          buyerEncryptionKey: BUYER_ENCRYPTION_KEY,
+          buyer,
+          researchPaper,
+          accessReceipt: await pda([RECEIPT_SEED, getAddressEncoder().encode(buyer.address), getAddressEncoder().encode(researchPaper)]),
+          buyerUserAccount: await pda([USER_SEED, getAddressEncoder().encode(buyer.address)]),
+          buyerVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(buyer.address)]),
+          authorUserAccount: authorAccount,
+          escrowVault: await pda([VAULT_ESCROW_SEED, getAddressEncoder().encode(researchPaper)]),
+          config: await pda([CONFIG_SEED]),
+        }),
+        payer: buyer,
+      });
+    }
+  });
+
+  async function reviewPda(signer: KeyPairSigner) {
+    return pda([REVIEW_SEED, getAddressEncoder().encode(signer.address), getAddressEncoder().encode(researchPaper)]);
+  }
+
+  async function stakeVaultPda(signer: KeyPairSigner) {
+    return pda([VAULT_STAKE_SEED, getAddressEncoder().encode(await reviewPda(signer))]);
+  }
+
+  async function reviewIx(signer: KeyPairSigner) {
+    return getReviewPaperInstruction({
+      reviewUrl: "ipfs://staked_review",
+      reviewHash: REVIEW_HASH,
+      scores: REVIEW_SCORES,
+      proposedReward: 0n,
+      reviewer: signer,
+      researchPaper,
+      reviewRound: await roundPda(researchPaper, 0),
+      roundVoter: await voterPda(await roundPda(researchPaper, 0), signer.address),
+      accessReceipt: await pda([RECEIPT_SEED, getAddressEncoder().encode(signer.address), getAddressEncoder().encode(researchPaper)]),
+      reviewerUserAccount: await pda([USER_SEED, getAddressEncoder().encode(signer.address)]),
+      peerReview: await reviewPda(signer),
+      stakeVault: await stakeVaultPda(signer),
+      config: await pda([CONFIG_SEED]),
+    });
+  }
+
+  async function flagIx(flagger: KeyPairSigner, slashToAuthor: boolean, target = spammer) {
+    const ix = getFlagReviewInstruction({
+      slashToAuthor,
+      admin: flagger,
+      peerReview: await reviewPda(target),
+      researchPaper,
+      reviewRound: await roundPda(researchPaper, 0),
+      reviewerUserAccount: await pda([USER_SEED, getAddressEncoder().encode(target.address)]),
+      authorUserAccount: authorAccount,
+      authorVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(author.address)]),
+      stakeVault: await stakeVaultPda(target),
+      treasury: admin.address,
+      config: await pda([CONFIG_SEED]),
+    });
+    // The co-author's (User account, user vault) pair receives their share of a slash
+    const coAuthorAccounts = [
+      { address: coAuthorAccount, role: AccountRole.WRITABLE },
+      { address: await pda([VAULT_USER_SEED, getAddressEncoder().encode(coAuthor.address)]), role: AccountRole.WRITABLE },
+    ];
+    return slashToAuthor ? { ...ix, accounts: [...ix.accounts, ...coAuthorAccounts] } : ix;
+  }
+
+  it('Happy Path: Submitting a review locks the platform-wide stake', async () => {
+    await sendAndConfirm({ ix: await reviewIx(spammer), payer: spammer });
+
+    const { data: review } = await fetchPeerReview(rpc, await reviewPda(spammer));
+    expect(review.stake).toBe(STAKE);
+
+    const { value } = await rpc.getBalance(await stakeVaultPda(spammer)).send();
+    expect(value).toBe(STAKE);
+  });
+
+  it('Sad Path: Only an admin can flag a review', async () => {
+    await expect(sendAndConfirm({ ix: await flagIx(author, true), payer: author })).rejects.toThrow();
+  });
+
+  it('Happy Path: Admin flags a spam review and slashes the stake to the authors', async () => {
+    const { data: before } = await fetchUser(rpc, authorAccount);
+    const { data: coAuthorBefore } = await fetchUser(rpc, coAuthorAccount);
+    await sendAndConfirm({ ix: await flagIx(admin, true), payer: admin });
+
+    const { data: review } = await fetchPeerReview(rpc, await reviewPda(spammer));
+    expect(review.status).toBe(ReviewStatus.Flagged);
+    expect(review.stake).toBe(0n);
+
+    const { data: after } = await fetchUser(rpc, authorAccount);
+    expect(after.earning).toBe(before.earning + (STAKE * 3n) / 4n);
+    const { data: coAuthorAfter } = await fetchUser(rpc, coAuthorAccount);
+    expect(coAuthorAfter.earning).toBe(coAuthorBefore.earning + STAKE / 4n);
+
+    const { data: spammerUser } = await fetchUser(rpc, await pda([USER_SEED, getAddressEncoder().encode(spammer.address)]));
+    expect(spammerUser.pendingReviews).toBe(0);
+    expect(spammerUser.reviewsFlagged).toBe(1);
+    expect(spammerUser.reputation).toBe(2_000);
+  });
+
+  it('Sad Path: A flagged review cannot be flagged again', async () => {
+    await expect(sendAndConfirm({ ix: await flagIx(admin, false), payer: admin })).rejects.toThrow();
+  });
+
+  it('Happy Path: The spammer can close the flagged review without a cooldown', async () => {
+    const ix = getCloseReviewInstruction({
+      reviewer: spammer,
+      peerReview: await reviewPda(spammer),
+      stakeVault: await stakeVaultPda(spammer),
+    });
+    await sendAndConfirm({ ix, payer: spammer });
+
+    const { value } = await rpc.getAccountInfo(await reviewPda(spammer)).send();
+    expect(value).toBeNull();
+  });
+
+  it('Sad Path: Minimum reputation is capped at 10000 bps', async () => {
+    await expect(
+      sendAndConfirm({ ix: getSetMinReputationInstruction({ minReputation: 10_001, author, researchPaper }), payer: author }),
+    ).rejects.toThrow();
+  });
+
+  it('Sad Path: A flagged reviewer falls below the paper minimum reputation', async () => {
+    await sendAndConfirm({
+      ix: getSetMinReputationInstruction({ minReputation: 5_000, author, researchPaper }),
+      payer: author,
+    });
+
+    await expect(sendAndConfirm({ ix: await reviewIx(spammer), payer: spammer })).rejects.toThrow();
+  });
+
+  it('Happy Path: Author raises the stake for their paper', async () => {
+    await sendAndConfirm({
+      ix: getSetReviewStakeInstruction({ stake: STAKE * 2n, author, researchPaper }),
+      payer: author,
+    });
+
+    const { data: paper } = await fetchResearchPaper(rpc, researchPaper);
+    expect(paper.reviewStake).toEqual(some(STAKE * 2n));
+  });
+
+  it('Happy Path: An accepted review gets its stake back', async () => {
+    await sendAndConfirm({ ix: await reviewIx(reviewer), payer: reviewer });
+    const { data: review } = await fetchPeerReview(rpc, await reviewPda(reviewer));
+    expect(review.stake).toBe(STAKE * 2n);
+
+    const { value: balanceBefore } = await rpc.getBalance(reviewer.address).send();
+    await sendAndConfirm({
+      ix: getVerifyReviewInstruction({
+        acceptProposedReview: true,
+        author,
+        researchPaper,
+        peerReview: await reviewPda(reviewer),
+        reviewer: reviewer.address,
+        stakeVault: await stakeVaultPda(reviewer),
+        bountyVault: await pda([VAULT_BOUNTY_SEED, getAddressEncoder().encode(researchPaper)]),
+        authorUserAccount: authorAccount,
+        authorVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(author.address)]),
+        reviewerUserAccount: await pda([USER_SEED, getAddressEncoder().encode(reviewer.address)]),
+        reviewerVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(reviewer.address)]),
+        adminVault: await pda([VAULT_ADMIN_SEED]),
+        config: await pda([CONFIG_SEED]),
+      }),
+      payer: author,
+    });
+
+    const { value: balanceAfter } = await rpc.getBalance(reviewer.address).send();
+    expect(balanceAfter).toBe(balanceBefore + STAKE * 2n);
+
+    const { value } = await rpc.getBalance(await stakeVaultPda(reviewer)).send();
+    expect(value).toBe(0n);
+
+    const { data: reviewerUser } = await fetchUser(rpc, await pda([USER_SEED, getAddressEncoder().encode(reviewer.address)]));
+    expect(reviewerUser.reviewsAccepted).toBe(1);
+    expect(reviewerUser.reputation).toBe(6_666);
+  });
+
+  it('Sad Path: A reviewer cannot close their profile while a rejected review can still be flagged', async () => {
+    await sendAndConfirm({ ix: await reviewIx(critic), payer: critic });
+    await sendAndConfirm({
+      ix: getVerifyReviewInstruction({
+        acceptProposedReview: false,
+        author,
+        researchPaper,
+        peerReview: await reviewPda(critic),
+        reviewer: critic.address,
+        stakeVault: await stakeVaultPda(critic),
+        bountyVault: await pda([VAULT_BOUNTY_SEED, getAddressEncoder().encode(researchPaper)]),
+        authorUserAccount: authorAccount,
+        authorVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(author.address)]),
+        reviewerUserAccount: await pda([USER_SEED, getAddressEncoder().encode(critic.address)]),
+        reviewerVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(critic.address)]),
+        adminVault: await pda([VAULT_ADMIN_SEED]),
+        config: await pda([CONFIG_SEED]),
+      }),
+      payer: author,
+    });
+
+    const criticAccount = await pda([USER_SEED, getAddressEncoder().encode(critic.address)]);
+    const { data: before } = await fetchUser(rpc, criticAccount);
+    expect(before.pendingReviews).toBe(0);
+    expect(before.rejectedOpen).toBe(1);
+
+    const closeIx = getCloseUserInstruction({
+      owner: critic,
+      userAccount: criticAccount,
+      userVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(critic.address)]),
+    });
+    await expect(sendAndConfirm({ ix: closeIx, payer: critic })).rejects.toThrow();
+
+    // The profile stays around, so the rejected review can still be flagged
+    await sendAndConfirm({ ix: await flagIx(admin, false, critic), payer: admin });
+    const { data: after } = await fetchUser(rpc, criticAccount);
+    expect(after.rejectedOpen).toBe(0);
+    expect(after.reviewsFlagged).toBe(1);
+  });
+});
+
+describe('prismpapersdapp review rounds', () => {
+  let author: KeyPairSigner;
+  let reviewers: KeyPairSigner[];
+
+  let authorAccount: Address;
+  let researchPaper: Address;
+
+  beforeAll(async () => {
+    author = await generateKeyPairSigner();
+    reviewers = [];
+    for (let i = 0; i < 4; i++) {
+      reviewers.push(await generateKeyPairSigner());
+    }
+    for (const signer of [author, ...reviewers]) {
+      await requestAirdrop(signer.address);
+      await sendAndConfirm({
+        ix: getInitUserInstruction({
+          name: "Round Suite",
+          owner: signer,
+          userAccount: await pda([USER_SEED, getAddressEncoder().encode(signer.address)]),
+          userVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(signer.address)]),
+        }),
+        payer: signer,
+      });
+    }
+
+    authorAccount = await pda([USER_SEED, getAddressEncoder().encode(author.address)]);
+    researchPaper = await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)]);
+
+    await sendAndConfirm({
+      ix: getInitResearchInstruction({
+        title: "Contested Research",
+        description: "Judged by a panel of reviewers.",
+        price: PRICE,
+        encryptedUrl: "arweave_cid",
+        contentHash: CONTENT_HASH,
+        ciphertextHash: null,
+        coAuthors: [],
+        author,
+        researchPaper,
+        paperRevision: await revisionPda(researchPaper, 0),
+        userAccount: authorAccount,
+        paperCounter: await pda([PAPER_COUNTER_SEED, getAddressEncoder().encode(author.address)]),
+      }),
+      payer: author,
+    });
+    await sendAndConfirm({ ix: getPublishPaperInstruction({ author, researchPaper }), payer: author });
+
+    for (const buyer of reviewers) {
+      await sendAndConfirm({
+        ix: getPurchaseAccessInstruction({
+          buyerEncryptionKey: BUYER_ENCRYPTION_KEY,
+          buyer,
+          researchPaper,
+          accessReceipt: await pda([RECEIPT_SEED, getAddressEncoder().encode(buyer.address), getAddressEncoder().encode(researchPaper)]),
+          buyerUserAccount: await pda([USER_SEED, getAddressEncoder().encode(buyer.address)]),
+          buyerVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(buyer.address)]),
+          authorUserAccount: authorAccount,
+          escrowVault: await pda([VAULT_ESCROW_SEED, getAddressEncoder().encode(researchPaper)]),
+          config: await pda([CONFIG_SEED]),
+        }),
+        payer: buyer,
+      });
+    }
+  });
+
+  // Three reviewers, at least two of them, and 60% must recommend acceptance
+  async function openIx(roundId: number, quorum = 2) {
+    return getOpenReviewRoundInstruction({
+      targetReviewers: 3,
+      quorum,
+      approvalBps: 6_000,
+      deadline: BigInt(Math.floor(Date.now() / 1000) + 3600),
+      author,
+      researchPaper,
+      reviewRound: await roundPda(researchPaper, roundId),
+    });
+  }
+
+  async function reviewIx(reviewer: KeyPairSigner, recommendation: Recommendation, novelty = 4) {
+    const peerReview = await pda([REVIEW_SEED, getAddressEncoder().encode(reviewer.address), getAddressEncoder().encode(researchPaper)]);
+    return getReviewPaperInstruction({
+      reviewUrl: "ipfs://panel_review",
+      reviewHash: REVIEW_HASH,
+      scores: { ...REVIEW_SCORES, novelty, recommendation },
+      proposedReward: 0n,
+      reviewer,
+      researchPaper,
+      reviewRound: await roundPda(researchPaper, 1),
+      roundVoter: await voterPda(await roundPda(researchPaper, 1), reviewer.address),
+      accessReceipt: await pda([RECEIPT_SEED, getAddressEncoder().encode(reviewer.address), getAddressEncoder().encode(researchPaper)]),
+      reviewerUserAccount: await pda([USER_SEED, getAddressEncoder().encode(reviewer.address)]),
+      peerReview,
+      stakeVault: await pda([VAULT_STAKE_SEED, getAddressEncoder().encode(peerReview)]),
+      config: await pda([CONFIG_SEED]),
+    });
+  }
+
+  async function finalizeIx(payer: KeyPairSigner) {
+    return getFinalizeReviewRoundInstruction({
+      payer,
+      researchPaper,
+      reviewRound: await roundPda(researchPaper, 1),
+    });
+  }
+
+  it('Sad Path: Quorum cannot exceed the target reviewer count', async () => {
+    await expect(sendAndConfirm({ ix: await openIx(0, 4), payer: author })).rejects.toThrow();
+  });
+
+  it('Happy Path: Author cancels a round nobody reviewed', async () => {
+    await sendAndConfirm({ ix: await openIx(0), payer: author });
+    await sendAndConfirm({
+      ix: getCloseReviewRoundInstruction({ author, researchPaper, reviewRound: await roundPda(researchPaper, 0) }),
+      payer: author,
+    });
+
+    const { data: paper } = await fetchResearchPaper(rpc, researchPaper);
+    expect(paper.status).toBe(PaperStatus.Published);
+    expect(paper.reviewRounds).toBe(1);
+  });
+
+  it('Sad Path: Review scores must be between 1 and 5', async () => {
+    await sendAndConfirm({ ix: await openIx(1), payer: author });
+    await expect(
+      sendAndConfirm({ ix: await reviewIx(reviewers[0], Recommendation.Accept, 6), payer: reviewers[0] }),
+    ).rejects.toThrow();
+  });
+
+  it('Happy Path: Reviewers vote in the next round', async () => {
+    await sendAndConfirm({ ix: await reviewIx(reviewers[0], Recommendation.Accept), payer: reviewers[0] });
+    await sendAndConfirm({ ix: await reviewIx(reviewers[1], Recommendation.Reject), payer: reviewers[1] });
+
+    const { data: round } = await fetchReviewRound(rpc, await roundPda(researchPaper, 1));
+    expect(round.reviews).toBe(2);
+    expect(round.approvals).toBe(1);
+  });
+
+  it('Sad Path: Author cannot cancel a round that has reviews', async () => {
+    const ix = getCloseReviewRoundInstruction({ author, researchPaper, reviewRound: await roundPda(researchPaper, 1) });
+    await expect(sendAndConfirm({ ix, payer: author })).rejects.toThrow();
+  });
+
+  it('Sad Path: The round cannot be finalized before its deadline while seats are open', async () => {
+    await expect(sendAndConfirm({ ix: await finalizeIx(reviewers[3]), payer: reviewers[3] })).rejects.toThrow();
+  });
+
+  it('Sad Path: A full round takes no more reviews', async () => {
+    // A minor revision still counts as a vote for the paper
+    await sendAndConfirm({ ix: await reviewIx(reviewers[2], Recommendation.MinorRevision), payer: reviewers[2] });
+    await expect(
+      sendAndConfirm({ ix: await reviewIx(reviewers[3], Recommendation.Accept), payer: reviewers[3] }),
+    ).rejects.toThrow();
+  });
+
+  it('Happy Path: Anyone finalizes the full round and the paper earns its badge', async () => {
+    await sendAndConfirm({ ix: await finalizeIx(reviewers[3]), payer: reviewers[3] });
+
+    const { data: round } = await fetchReviewRound(rpc, await roundPda(researchPaper, 1));
+    expect(round.outcome).toEqual(some(RoundOutcome.Passed));
+
+    const { data: paper } = await fetchResearchPaper(rpc, researchPaper);
+    expect(paper.reviewOutcome).toEqual(some(RoundOutcome.Passed));
+    expect(paper.status).toBe(PaperStatus.Published);
+  });
+});
+
+describe('prismpapersdapp anonymous reviews', () => {
+  const STAKE = 10_000_000n;
+  const REWARD = 100_000_000n;
+  const SALT = new Uint8Array(32).fill(9);
+
+  let admin: KeyPairSigner;
+  let author: KeyPairSigner;
+  let reviewer: KeyPairSigner;
+  let pseudonym: KeyPairSigner;
+
+  let authorAccount: Address;
+  let reviewerAccount: Address;
+  let researchPaper: Address;
+  let peerReview: Address;
+  let stakeVault: Address;
+  let plan: Address;
+
+  beforeAll(async () => {
+    admin = await loadKeypairSignerFromFile(process.env.ANCHOR_WALLET!);
+    author = await generateKeyPairSigner();
+    reviewer = await generateKeyPairSigner();
+    // A fresh keypair with no User account that could tie it to the reviewer
+    pseudonym = await generateKeyPairSigner();
+    await requestAirdrop(pseudonym.address);
+    for (const signer of [author, reviewer]) {
+      await requestAirdrop(signer.address);
+      await sendAndConfirm({
+        ix: getInitUserInstruction({
+          name: "Anonymous Suite",
+          owner: signer,
+          userAccount: await pda([USER_SEED, getAddressEncoder().encode(signer.address)]),
+          userVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(signer.address)]),
+        }),
+        payer: signer,
+      });
+    }
+
+    authorAccount = await pda([USER_SEED, getAddressEncoder().encode(author.address)]);
+    reviewerAccount = await pda([USER_SEED, getAddressEncoder().encode(reviewer.address)]);
+    researchPaper = await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)]);
+    peerReview = await pda([REVIEW_SEED, getAddressEncoder().encode(pseudonym.address), getAddressEncoder().encode(researchPaper)]);
+    stakeVault = await pda([VAULT_STAKE_SEED, getAddressEncoder().encode(peerReview)]);
+    plan = await pda([PLAN_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)]);
+
+    // Anonymous reviews need a stake
+    await sendAndConfirm({
+      ix: getUpdateConfigInstruction({
+        admins: [admin.address],
+        purchaseFeeBps: PURCHASE_FEE_BPS,
+        reviewFeeBps: REVIEW_FEE_BPS,
+        minFeeLamports: 0n,
+        treasury: admin.address,
+        refundWindow: 0n,
+        reviewStake: STAKE,
+        paused: false,
+        admin,
+        config: await pda([CONFIG_SEED]),
+      }),
+      payer: admin,
+    });
+
+    await sendAndConfirm({
+      ix: getInitResearchInstruction({
+        title: "Blind Review Research",
+        description: "Reviewed under pseudonyms.",
+        price: PRICE,
+        encryptedUrl: "arweave_cid",
+        contentHash: CONTENT_HASH,
+        ciphertextHash: null,
+        coAuthors: [],
+        author,
+        researchPaper,
+        paperRevision: await revisionPda(researchPaper, 0),
+        userAccount: authorAccount,
+        paperCounter: await pda([PAPER_COUNTER_SEED, getAddressEncoder().encode(author.address)]),
+      }),
+      payer: author,
+    });
+    await sendAndConfirm({ ix: getPublishPaperInstruction({ author, researchPaper }), payer: author });
+    await sendAndConfirm({
+      ix: getFundBountyInstruction({
+        amount: REWARD,
+        deadline: BigInt(Math.floor(Date.now() / 1000) + 3600),
+        author,
+        researchPaper,
+        bountyVault: await pda([VAULT_BOUNTY_SEED, getAddressEncoder().encode(researchPaper)]),
+      }),
+      payer: author,
+    });
+    await sendAndConfirm({ ix: await openRoundIx(author, researchPaper), payer: author });
+    await sendAndConfirm({
+      ix: getCreatePlanInstruction({
+        planId: 0,
+        price: PRICE,
+        duration: 3_600n,
+        papers: [researchPaper],
+        coAuthors: [],
+        author,
+        userAccount: authorAccount,
+        subscriptionPlan: plan,
+      }),
+      payer: author,
+    });
+  });
+
+  // sha256(wallet || salt), the reviewer keeps the salt to themselves until the reveal
+  function commitment(wallet: Address, salt: Uint8Array) {
+    return new Uint8Array(createHash('sha256').update(getAddressEncoder().encode(wallet)).update(salt).digest());
+  }
+
+  async function reviewPdaOf(signer: KeyPairSigner) {
+    return pda([REVIEW_SEED, getAddressEncoder().encode(signer.address), getAddressEncoder().encode(researchPaper)]);
+  }
+
+  async function subscribeIx(signer: KeyPairSigner) {
+    return getSubscribeInstruction({
+      subscriberEncryptionKey: BUYER_ENCRYPTION_KEY,
+      subscriber: signer,
+      subscriptionPlan: plan,
+      subscription: await pda([SUBSCRIPTION_SEED, getAddressEncoder().encode(signer.address), getAddressEncoder().encode(plan)]),
+      authorUserAccount: authorAccount,
+      authorVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(author.address)]),
+      adminVault: await pda([VAULT_ADMIN_SEED]),
+      config: await pda([CONFIG_SEED]),
+    });
+  }
+
+  async function submitIx(identityCommitment: Uint8Array, withAccess = true, signer = pseudonym, proposedReward = REWARD) {
+    const review = await reviewPdaOf(signer);
+    return getReviewPaperAnonymouslyInstruction({
+      reviewUrl: "ipfs://blind_review",
+      reviewHash: REVIEW_HASH,
+      scores: REVIEW_SCORES,
+      proposedReward,
+      identityCommitment,
+      pseudonym: signer,
+      researchPaper,
+      reviewRound: await roundPda(researchPaper, 0),
+      subscriptionPlan: withAccess ? plan : undefined,
+      subscription: withAccess
+        ? await pda([SUBSCRIPTION_SEED, getAddressEncoder().encode(signer.address), getAddressEncoder().encode(plan)])
+        : undefined,
+      peerReview: review,
+      stakeVault: await pda([VAULT_STAKE_SEED, getAddressEncoder().encode(review)]),
+      config: await pda([CONFIG_SEED]),
+    });
+  }
+
+  async function revealIx(salt: Uint8Array, signer = pseudonym) {
+    const review = await reviewPdaOf(signer);
+    return getRevealReviewInstruction({
+      salt,
+      reviewer,
+      peerReview: review,
+      researchPaper,
+      reviewRound: await roundPda(researchPaper, 0),
+      roundVoter: await voterPda(await roundPda(researchPaper, 0), reviewer.address),
+      reviewerUserAccount: reviewerAccount,
+      reviewerVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(reviewer.address)]),
+      stakeVault: await pda([VAULT_STAKE_SEED, getAddressEncoder().encode(review)]),
+    });
+  }
+
+  it('Sad Path: An anonymous review needs an identity commitment', async () => {
+    await expect(sendAndConfirm({ ix: await submitIx(new Uint8Array(32)), payer: pseudonym })).rejects.toThrow();
+  });
+
+  it('Sad Path: A pseudonym needs access to the paper to review it', async () => {
+    await expect(
+      sendAndConfirm({ ix: await submitIx(commitment(reviewer.address, SALT), false), payer: pseudonym }),
+    ).rejects.toThrow();
+  });
+
+  it('Happy Path: A subscribed pseudonym submits a review under a commitment', async () => {
+    // The pseudonym reads the paper through a subscription of its own
+    await sendAndConfirm({ ix: await subscribeIx(pseudonym), payer: pseudonym });
+    await sendAndConfirm({ ix: await submitIx(commitment(reviewer.address, SALT)), payer: pseudonym });
+
+    const { data: review } = await fetchPeerReview(rpc, peerReview);
+    expect(review.reviewer).toBe(pseudonym.address);
+    expect(review.identityCommitment).toEqual(some(commitment(reviewer.address, SALT)));
+    expect(review.stake).toBe(STAKE);
+
+    // Nothing lands on the reviewer's own account yet
+    const { data: user } = await fetchUser(rpc, reviewerAccount);
+    expect(user.reviewed).toBe(0);
+    expect(user.pendingReviews).toBe(0);
+
+    // nor on the round's tally
+    const { data: round } = await fetchReviewRound(rpc, await roundPda(researchPaper, 0));
+    expect(round.reviews).toBe(0);
+  });
+
+  it('Sad Path: The reviewer cannot reveal before the author decides', async () => {
+    await expect(sendAndConfirm({ ix: await revealIx(SALT), payer: reviewer })).rejects.toThrow();
+  });
+
+  it('Happy Path: Author accepts the review and the reward is escrowed with the stake', async () => {
+    await sendAndConfirm({
+      ix: getVerifyReviewInstruction({
+        acceptProposedReview: true,
+        author,
+        researchPaper,
+        peerReview,
+        reviewer: pseudonym.address,
+        stakeVault,
+        bountyVault: await pda([VAULT_BOUNTY_SEED, getAddressEncoder().encode(researchPaper)]),
+        authorUserAccount: authorAccount,
+        authorVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(author.address)]),
+        reviewerVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(pseudonym.address)]),
+        adminVault: await pda([VAULT_ADMIN_SEED]),
+        config: await pda([CONFIG_SEED]),
+      }),
+      payer: author,
+    });
+
+    const earning = REWARD - (REWARD * BigInt(REVIEW_FEE_BPS)) / 10000n;
+    const { data: review } = await fetchPeerReview(rpc, peerReview);
+    expect(review.status).toBe(ReviewStatus.Accepted);
+    expect(review.unclaimedReward).toBe(earning);
+
+    const { value } = await rpc.getBalance(stakeVault).send();
+    expect(value).toBe(STAKE + earning);
+  });
+
+  it('Sad Path: A wrong salt does not match the commitment', async () => {
+    await expect(sendAndConfirm({ ix: await revealIx(new Uint8Array(32).fill(8)), payer: reviewer })).rejects.toThrow();
+  });
+
+  it('Happy Path: The reviewer reveals and claims the reward to their vault', async () => {
+    await sendAndConfirm({ ix: await revealIx(SALT), payer: reviewer });
+
+    const earning = REWARD - (REWARD * BigInt(REVIEW_FEE_BPS)) / 10000n;
+    const { data: review } = await fetchPeerReview(rpc, peerReview);
+    expect(review.revealedReviewer).toEqual(some(reviewer.address));
+    expect(review.unclaimedReward).toBe(0n);
+    expect(review.stake).toBe(0n);
+
+    const { data: user } = await fetchUser(rpc, reviewerAccount);
+    expect(user.earning).toBe(earning);
+    expect(user.reviewRewards).toBe(earning);
+    expect(user.reviewsAccepted).toBe(1);
+    expect(user.reputation).toBe(6_666);
+
+    const { value } = await rpc.getBalance(stakeVault).send();
+    expect(value).toBe(0n);
+
+    // The vote joins the open round once revealed
+    const { data: round } = await fetchReviewRound(rpc, await roundPda(researchPaper, 0));
+    expect(round.reviews).toBe(1);
+    expect(round.approvals).toBe(1);
+  });
+
+  it('Sad Path: A review can only be revealed once', async () => {
+    await expect(sendAndConfirm({ ix: await revealIx(SALT), payer: reviewer })).rejects.toThrow();
+  });
+
+  it('Sad Path: A second pseudonym of the same wallet is neither credited to its own User nor revealed in the same round', async () => {
+    const SALT_2 = new Uint8Array(32).fill(10);
+    const pseudonym2 = await generateKeyPairSigner();
+    await requestAirdrop(pseudonym2.address);
+    await sendAndConfirm({ ix: await subscribeIx(pseudonym2), payer: pseudonym2 });
+    await sendAndConfirm({ ix: await submitIx(commitment(reviewer.address, SALT_2), true, pseudonym2, 0n), payer: pseudonym2 });
+
+    const review = await reviewPdaOf(pseudonym2);
+    const pseudonymAccount = await pda([USER_SEED, getAddressEncoder().encode(pseudonym2.address)]);
+    const pseudonymVault = await pda([VAULT_USER_SEED, getAddressEncoder().encode(pseudonym2.address)]);
+    const stakeVault2 = await pda([VAULT_STAKE_SEED, getAddressEncoder().encode(review)]);
+    const bountyVault = await pda([VAULT_BOUNTY_SEED, getAddressEncoder().encode(researchPaper)]);
+    const authorVault = await pda([VAULT_USER_SEED, getAddressEncoder().encode(author.address)]);
+    const adminVault = await pda([VAULT_ADMIN_SEED]);
+    const config = await pda([CONFIG_SEED]);
+    const verifyIx = (reviewerUserAccount?: Address) =>
+      getVerifyReviewInstruction({
+        acceptProposedReview: false,
+        author,
+        researchPaper,
+        peerReview: review,
+        reviewer: pseudonym2.address,
+        stakeVault: stakeVault2,
+        bountyVault,
+        authorUserAccount: authorAccount,
+        authorVault,
+        reviewerUserAccount,
+        reviewerVault: pseudonymVault,
+        adminVault,
+        config,
+      });
+
+    // A User account of the pseudonym's own is turned away, the review is only credited on reveal
+    await sendAndConfirm({
+      ix: getInitUserInstruction({ name: "Pseudonym", owner: pseudonym2, userAccount: pseudonymAccount, userVault: pseudonymVault }),
+      payer: pseudonym2,
+    });
+    await expect(sendAndConfirm({ ix: verifyIx(pseudonymAccount), payer: author })).rejects.toThrow();
+
+    await sendAndConfirm({ ix: verifyIx(), payer: author });
+
+    await expect(sendAndConfirm({ ix: await revealIx(SALT_2, pseudonym2), payer: reviewer })).rejects.toThrow();
+
+    // The round still holds the wallet's single vote
+    const { data: round } = await fetchReviewRound(rpc, await roundPda(researchPaper, 0));
+    expect(round.reviews).toBe(1);
+  });
+});
+
+describe('prismpapersdapp review expiry', () => {
+  const STAKE = 10_000_000n;
+  const REWARD = 100_000_000n;
+  const WINDOW = 3n; // seconds
+
+  let admin: KeyPairSigner;
+  let author: KeyPairSigner;
+  let reviewers: KeyPairSigner[];
+
+  let authorAccount: Address;
+  let researchPaper: Address;
+
+  beforeAll(async () => {
+    admin = await loadKeypairSignerFromFile(process.env.ANCHOR_WALLET!);
+    author = await generateKeyPairSigner();
+    reviewers = [await generateKeyPairSigner(), await generateKeyPairSigner(), await generateKeyPairSigner()];
+    for (const signer of [author, ...reviewers]) {
+      await requestAirdrop(signer.address);
+      await sendAndConfirm({
+        ix: getInitUserInstruction({
+          name: "Expiry Suite",
+          owner: signer,
+          userAccount: await pda([USER_SEED, getAddressEncoder().encode(signer.address)]),
+          userVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(signer.address)]),
+        }),
+        payer: signer,
+      });
+    }
+
+    authorAccount = await pda([USER_SEED, getAddressEncoder().encode(author.address)]);
+    researchPaper = await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)]);
+
+    await sendAndConfirm({ ix: await configIx(false), payer: admin });
+
+    await sendAndConfirm({
+      ix: getInitResearchInstruction({
+        title: "Slow Author Research",
+        description: "Reviews of this paper expire quickly.",
+        price: PRICE,
+        encryptedUrl: "arweave_cid",
+        contentHash: CONTENT_HASH,
+        ciphertextHash: null,
+        coAuthors: [],
+        author,
+        researchPaper,
+        paperRevision: await revisionPda(researchPaper, 0),
+        userAccount: authorAccount,
+        paperCounter: await pda([PAPER_COUNTER_SEED, getAddressEncoder().encode(author.address)]),
+      }),
+      payer: author,
+    });
+    await sendAndConfirm({ ix: getPublishPaperInstruction({ author, researchPaper }), payer: author });
+    await sendAndConfirm({
+      ix: getFundBountyInstruction({
+        amount: REWARD,
+        deadline: BigInt(Math.floor(Date.now() / 1000) + 3600),
+        author,
+        researchPaper,
+        bountyVault: await pda([VAULT_BOUNTY_SEED, getAddressEncoder().encode(researchPaper)]),
+      }),
+      payer: author,
+    });
+    await sendAndConfirm({ ix: await openRoundIx(author, researchPaper), payer: author });
+
+    for (const buyer of reviewers) {
+      await sendAndConfirm({
+        ix: getPurchaseAccessInstruction({
+          buyerEncryptionKey: BUYER_ENCRYPTION_KEY,
+          buyer,
+          researchPaper,
+          accessReceipt: await pda([RECEIPT_SEED, getAddressEncoder().encode(buyer.address), getAddressEncoder().encode(researchPaper)]),
+          buyerUserAccount: await pda([USER_SEED, getAddressEncoder().encode(buyer.address)]),
+          buyerVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(buyer.address)]),
+          authorUserAccount: authorAccount,
+          escrowVault: await pda([VAULT_ESCROW_SEED, getAddressEncoder().encode(researchPaper)]),
+          config: await pda([CONFIG_SEED]),
+        }),
+        payer: buyer,
+      });
+    }
+  });
+
+  async function configIx(paused: boolean) {
+    return getUpdateConfigInstruction({
+      admins: [admin.address],
+      purchaseFeeBps: PURCHASE_FEE_BPS,
+      reviewFeeBps: REVIEW_FEE_BPS,
+      minFeeLamports: 0n,
+      treasury: admin.address,
+      refundWindow: 0n,
+      reviewStake: STAKE,
+      paused,
+      admin,
+      config: await pda([CONFIG_SEED]),
+    });
+  }
+
+  async function reviewPda(reviewer: KeyPairSigner) {
+    return pda([REVIEW_SEED, getAddressEncoder().encode(reviewer.address), getAddressEncoder().encode(researchPaper)]);
+  }
+
+  function policyIx(responseWindow: bigint, expiryPolicy: ReviewExpiryPolicy) {
+    return getSetReviewResponsePolicyInstruction({ responseWindow, expiryPolicy, author, researchPaper });
+  }
+
+  async function reviewIx(reviewer: KeyPairSigner, proposedReward: bigint) {
+    const peerReview = await reviewPda(reviewer);
+    return getReviewPaperInstruction({
+      reviewUrl: "ipfs://waiting_review",
+      reviewHash: REVIEW_HASH,
+      scores: REVIEW_SCORES,
+      proposedReward,
+      reviewer,
+      researchPaper,
+      reviewRound: await roundPda(researchPaper, 0),
+      roundVoter: await voterPda(await roundPda(researchPaper, 0), reviewer.address),
+      accessReceipt: await pda([RECEIPT_SEED, getAddressEncoder().encode(reviewer.address), getAddressEncoder().encode(researchPaper)]),
+      reviewerUserAccount: await pda([USER_SEED, getAddressEncoder().encode(reviewer.address)]),
+      peerReview,
+      stakeVault: await pda([VAULT_STAKE_SEED, getAddressEncoder().encode(peerReview)]),
+      config: await pda([CONFIG_SEED]),
+    });
+  }
+
+  async function expireIx(payer: KeyPairSigner, reviewer: KeyPairSigner) {
+    const peerReview = await reviewPda(reviewer);
+    return getExpireReviewInstruction({
+      payer,
+      peerReview,
+      researchPaper,
+      reviewer: reviewer.address,
+      reviewerUserAccount: await pda([USER_SEED, getAddressEncoder().encode(reviewer.address)]),
+      reviewerVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(reviewer.address)]),
+      authorUserAccount: authorAccount,
+      stakeVault: await pda([VAULT_STAKE_SEED, getAddressEncoder().encode(peerReview)]),
+      bountyVault: await pda([VAULT_BOUNTY_SEED, getAddressEncoder().encode(researchPaper)]),
+      adminVault: await pda([VAULT_ADMIN_SEED]),
+      config: await pda([CONFIG_SEED]),
+    });
+  }
+
+  it('Sad Path: The response window must be positive', async () => {
+    await expect(sendAndConfirm({ ix: policyIx(0n, ReviewExpiryPolicy.Release), payer: author })).rejects.toThrow();
+  });
+
+  it('Sad Path: A review cannot be expired while the author can still respond', async () => {
+    await sendAndConfirm({ ix: policyIx(WINDOW, ReviewExpiryPolicy.AutoAccept), payer: author });
+    await sendAndConfirm({ ix: await reviewIx(reviewers[0], REWARD), payer: reviewers[0] });
+
+    const { data: review } = await fetchPeerReview(rpc, await reviewPda(reviewers[0]));
+    expect(review.expiryPolicy).toBe(ReviewExpiryPolicy.AutoAccept);
+    await expect(sendAndConfirm({ ix: await expireIx(admin, reviewers[0]), payer: admin })).rejects.toThrow();
+  });
+
+  it('Sad Path: A reward the bounty already reserved for a pending review cannot be claimed again', async () => {
+    await expect(sendAndConfirm({ ix: await reviewIx(reviewers[1], REWARD), payer: reviewers[1] })).rejects.toThrow();
+
+    const { data: paper } = await fetchResearchPaper(rpc, researchPaper);
+    expect(paper.bountyReserved).toBe(REWARD);
+  });
+
+  it('Happy Path: Anyone expires a stale review and the auto-accept policy pays it', async () => {
+    await new Promise(r => setTimeout(r, Number(WINDOW + 1n) * 1000));
+    await sendAndConfirm({ ix: await expireIx(admin, reviewers[0]), payer: admin });
+
+    const { data: review } = await fetchPeerReview(rpc, await reviewPda(reviewers[0]));
+    expect(review.status).toBe(ReviewStatus.Expired);
+    expect(review.stake).toBe(0n);
+
+    const { data: user } = await fetchUser(rpc, await pda([USER_SEED, getAddressEncoder().encode(reviewers[0].address)]));
+    expect(user.earning).toBe(REWARD - (REWARD * BigInt(REVIEW_FEE_BPS)) / 10000n);
+    expect(user.reviewsAccepted).toBe(1);
+    expect(user.pendingReviews).toBe(0);
+
+    const { data: paper } = await fetchResearchPaper(rpc, researchPaper);
+    expect(paper.bounty).toBe(0n);
+    expect(paper.bountyReserved).toBe(0n);
+    expect(paper.reviewScores.scoredReviews).toBe(1);
+  });
+
+  it('Sad Path: The author cannot verify a review once its window has passed', async () => {
+    await sendAndConfirm({ ix: policyIx(WINDOW, ReviewExpiryPolicy.Release), payer: author });
+    await sendAndConfirm({ ix: await reviewIx(reviewers[1], 0n), payer: reviewers[1] });
+    await new Promise(r => setTimeout(r, Number(WINDOW + 1n) * 1000));
+
+    const peerReview = await reviewPda(reviewers[1]);
+    const ix = getVerifyReviewInstruction({
+      acceptProposedReview: false,
+      author,
+      researchPaper,
+      peerReview,
+      reviewer: reviewers[1].address,
+      stakeVault: await pda([VAULT_STAKE_SEED, getAddressEncoder().encode(peerReview)]),
+      bountyVault: await pda([VAULT_BOUNTY_SEED, getAddressEncoder().encode(researchPaper)]),
+      authorUserAccount: authorAccount,
+      authorVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(author.address)]),
+      reviewerUserAccount: await pda([USER_SEED, getAddressEncoder().encode(reviewers[1].address)]),
+      reviewerVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(reviewers[1].address)]),
+      adminVault: await pda([VAULT_ADMIN_SEED]),
+      config: await pda([CONFIG_SEED]),
+    });
+    await expect(sendAndConfirm({ ix, payer: author })).rejects.toThrow();
+  });
+
+  it('Happy Path: A released review returns the stake and lowers the author score', async () => {
+    const { value: balanceBefore } = await rpc.getBalance(reviewers[1].address).send();
+    await sendAndConfirm({ ix: await expireIx(admin, reviewers[1]), payer: admin });
+    const { value: balanceAfter } = await rpc.getBalance(reviewers[1].address).send();
+    expect(balanceAfter).toBe(balanceBefore + STAKE);
+
+    const { data: authorUser } = await fetchUser(rpc, authorAccount);
+    expect(authorUser.reviewsExpired).toBe(1);
+    expect(authorUser.authorReputation).toBe(3_333);
+    // Reviewer-side reputation is a separate score
+    expect(authorUser.reputation).toBe(5_000);
+
+    // The reviewer is neither credited nor penalised
+    const { data: user } = await fetchUser(rpc, await pda([USER_SEED, getAddressEncoder().encode(reviewers[1].address)]));
+    expect(user.reviewsAccepted).toBe(0);
+    expect(user.reviewsRejected).toBe(0);
+    expect(user.pendingReviews).toBe(0);
+  });
+
+  it('Happy Path: The reviewer closes the released review right away', async () => {
+    const peerReview = await reviewPda(reviewers[1]);
+    await sendAndConfirm({
+      ix: getCloseReviewInstruction({
+        reviewer: reviewers[1],
+        peerReview,
+        stakeVault: await pda([VAULT_STAKE_SEED, getAddressEncoder().encode(peerReview)]),
+      }),
+      payer: reviewers[1],
+    });
+
+    const { value } = await rpc.getAccountInfo(peerReview).send();
+    expect(value).toBeNull();
+  });
+
+  it('Sad Path: A closed review cannot be submitted again in the same round', async () => {
+    await expect(sendAndConfirm({ ix: await reviewIx(reviewers[1], 0n), payer: reviewers[1] })).rejects.toThrow();
+
+    const { data: voter } = await fetchRoundVoter(rpc, await voterPda(await roundPda(researchPaper, 0), reviewers[1].address));
+    expect(voter.voter).toBe(reviewers[1].address);
+  });
+
+  it('Happy Path: Time spent paused pushes the response deadline back', async () => {
+    await sendAndConfirm({ ix: await reviewIx(reviewers[2], 0n), payer: reviewers[2] });
+    await sendAndConfirm({ ix: await configIx(true), payer: admin });
+    await new Promise(r => setTimeout(r, Number(WINDOW + 1n) * 1000));
+    await sendAndConfirm({ ix: await configIx(false), payer: admin });
+
+    // The window stood still during the pause, so the author can still respond
+    await expect(sendAndConfirm({ ix: await expireIx(admin, reviewers[2]), payer: admin })).rejects.toThrow();
+
+    await new Promise(r => setTimeout(r, Number(WINDOW + 1n) * 1000));
+    await sendAndConfirm({ ix: await expireIx(admin, reviewers[2]), payer: admin });
+
+    const { data: review } = await fetchPeerReview(rpc, await reviewPda(reviewers[2]));
+    expect(review.status).toBe(ReviewStatus.Expired);
+  });
+});
+
+describe('prismpapersdapp review negotiation', () => {
+  const STAKE = 10_000_000n;
+  const REWARD = 100_000_000n;
+  const COUNTER_OFFER = 60_000_000n;
+  const WINDOW = 3n; // seconds
+
+  let admin: KeyPairSigner;
+  let author: KeyPairSigner;
+  let reviewers: KeyPairSigner[];
+
+  let authorAccount: Address;
+  let researchPaper: Address;
+
+  beforeAll(async () => {
+    admin = await loadKeypairSignerFromFile(process.env.ANCHOR_WALLET!);
+    author = await generateKeyPairSigner();
+    reviewers = [await generateKeyPairSigner(), await generateKeyPairSigner(), await generateKeyPairSigner()];
+    for (const signer of [author, ...reviewers]) {
+      await requestAirdrop(signer.address);
+      await sendAndConfirm({
+        ix: getInitUserInstruction({
+          name: "Negotiation Suite",
+          owner: signer,
+          userAccount: await pda([USER_SEED, getAddressEncoder().encode(signer.address)]),
+          userVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(signer.address)]),
+        }),
+        payer: signer,
+      });
+    }
+
+    authorAccount = await pda([USER_SEED, getAddressEncoder().encode(author.address)]);
+    researchPaper = await pda([PAPER_SEED, getAddressEncoder().encode(author.address), getU16Encoder().encode(0)]);
+
+    await sendAndConfirm({
+      ix: getUpdateConfigInstruction({
+        admins: [admin.address],
+        purchaseFeeBps: PURCHASE_FEE_BPS,
+        reviewFeeBps: REVIEW_FEE_BPS,
+        minFeeLamports: 0n,
+        treasury: admin.address,
+        refundWindow: 0n,
+        reviewStake: STAKE,
+        paused: false,
+        admin,
+        config: await pda([CONFIG_SEED]),
+      }),
+      payer: admin,
+    });
+
+    await sendAndConfirm({
+      ix: getInitResearchInstruction({
+        title: "Negotiated Research",
+        description: "Review rewards on this paper are haggled over.",
+        price: PRICE,
+        encryptedUrl: "arweave_cid",
+        contentHash: CONTENT_HASH,
+        ciphertextHash: null,
+        coAuthors: [],
+        author,
+        researchPaper,
+        paperRevision: await revisionPda(researchPaper, 0),
+        userAccount: authorAccount,
+        paperCounter: await pda([PAPER_COUNTER_SEED, getAddressEncoder().encode(author.address)]),
+      }),
+      payer: author,
+    });
+    await sendAndConfirm({ ix: getPublishPaperInstruction({ author, researchPaper }), payer: author });
+    await sendAndConfirm({
+      ix: getFundBountyInstruction({
+        amount: REWARD * 2n,
+        deadline: BigInt(Math.floor(Date.now() / 1000) + 3600),
+        author,
+        researchPaper,
+        bountyVault: await pda([VAULT_BOUNTY_SEED, getAddressEncoder().encode(researchPaper)]),
+      }),
+      payer: author,
+    });
+    await sendAndConfirm({ ix: await openRoundIx(author, researchPaper), payer: author });
+
+    for (const reviewer of reviewers.slice(0, 2)) {
+      await submitReview(reviewer);
+    }
+  });
+
+  async function submitReview(reviewer: KeyPairSigner) {
+    await sendAndConfirm({
+      ix: getPurchaseAccessInstruction({
+        buyerEncryptionKey: BUYER_ENCRYPTION_KEY,
+        buyer: reviewer,
+        researchPaper,
+        accessReceipt: await pda([RECEIPT_SEED, getAddressEncoder().encode(reviewer.address), getAddressEncoder().encode(researchPaper)]),
+        buyerUserAccount: await pda([USER_SEED, getAddressEncoder().encode(reviewer.address)]),
+        buyerVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(reviewer.address)]),
+        authorUserAccount: authorAccount,
+        escrowVault: await pda([VAULT_ESCROW_SEED, getAddressEncoder().encode(researchPaper)]),
+        config: await pda([CONFIG_SEED]),
+      }),
+      payer: reviewer,
+    });
+
+    const peerReview = await reviewPda(reviewer);
+    await sendAndConfirm({
+      ix: getReviewPaperInstruction({
+        reviewUrl: "ipfs://negotiated_review",
+        reviewHash: REVIEW_HASH,
+        scores: REVIEW_SCORES,
+        proposedReward: REWARD,
+        reviewer,
+        researchPaper,
+        reviewRound: await roundPda(researchPaper, 0),
+        roundVoter: await voterPda(await roundPda(researchPaper, 0), reviewer.address),
+        accessReceipt: await pda([RECEIPT_SEED, getAddressEncoder().encode(reviewer.address), getAddressEncoder().encode(researchPaper)]),
+        reviewerUserAccount: await pda([USER_SEED, getAddressEncoder().encode(reviewer.address)]),
+        peerReview,
+        stakeVault: await pda([VAULT_STAKE_SEED, getAddressEncoder().encode(peerReview)]),
+        config: await pda([CONFIG_SEED]),
+      }),
+      payer: reviewer,
+    });
+  }
+
+  async function reviewPda(reviewer: KeyPairSigner) {
+    return pda([REVIEW_SEED, getAddressEncoder().encode(reviewer.address), getAddressEncoder().encode(researchPaper)]);
+  }
+
+  async function counterOfferIx(signer: KeyPairSigner, reviewer: KeyPairSigner, counterOffer: bigint) {
+    return getCounterOfferReviewInstruction({
+      counterOffer,
+      author: signer,
+      peerReview: await reviewPda(reviewer),
+      researchPaper,
+      authorUserAccount: await pda([USER_SEED, getAddressEncoder().encode(signer.address)]),
+      config: await pda([CONFIG_SEED]),
+    });
+  }
+
+  async function respondIx(reviewer: KeyPairSigner, acceptCounterOffer: boolean) {
+    const peerReview = await reviewPda(reviewer);
+    return getRespondToCounterOfferInstruction({
+      acceptCounterOffer,
+      reviewer,
+      peerReview,
+      researchPaper,
+      reviewerUserAccount: await pda([USER_SEED, getAddressEncoder().encode(reviewer.address)]),
+      reviewerVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(reviewer.address)]),
+      stakeVault: await pda([VAULT_STAKE_SEED, getAddressEncoder().encode(peerReview)]),
+      bountyVault: await pda([VAULT_BOUNTY_SEED, getAddressEncoder().encode(researchPaper)]),
+      adminVault: await pda([VAULT_ADMIN_SEED]),
+      config: await pda([CONFIG_SEED]),
+    });
+  }
+
+  async function expireIx(reviewer: KeyPairSigner) {
+    const peerReview = await reviewPda(reviewer);
+    return getExpireReviewInstruction({
+      payer: admin,
+      peerReview,
+      researchPaper,
+      reviewer: reviewer.address,
+      reviewerUserAccount: await pda([USER_SEED, getAddressEncoder().encode(reviewer.address)]),
+      reviewerVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(reviewer.address)]),
+      authorUserAccount: authorAccount,
+      stakeVault: await pda([VAULT_STAKE_SEED, getAddressEncoder().encode(peerReview)]),
+      bountyVault: await pda([VAULT_BOUNTY_SEED, getAddressEncoder().encode(researchPaper)]),
+      adminVault: await pda([VAULT_ADMIN_SEED]),
+      config: await pda([CONFIG_SEED]),
+    });
+  }
+
+  it('Happy Path: Submitted reviews reserve their proposed rewards in the bounty', async () => {
+    const { data: paper } = await fetchResearchPaper(rpc, researchPaper);
+    expect(paper.bounty).toBe(REWARD * 2n);
+    expect(paper.bountyReserved).toBe(REWARD * 2n);
+    // Both stay open, which keeps the paper from being closed
+    expect(paper.openReviews).toBe(2);
+  });
+
+  it('Sad Path: A reviewer cannot answer a counter-offer that was never made', async () => {
+    await expect(sendAndConfirm({ ix: await respondIx(reviewers[0], true), payer: reviewers[0] })).rejects.toThrow();
+  });
+
+  it('Sad Path: Only the author can counter-offer', async () => {
+    await expect(
+      sendAndConfirm({ ix: await counterOfferIx(reviewers[1], reviewers[0], COUNTER_OFFER), payer: reviewers[1] }),
+    ).rejects.toThrow();
+  });
+
+  it('Sad Path: A counter-offer must be lower than the proposed reward', async () => {
+    await expect(sendAndConfirm({ ix: await counterOfferIx(author, reviewers[0], REWARD), payer: author })).rejects.toThrow();
+  });
+
+  it('Happy Path: The author counter-offers a lower reward', async () => {
+    await sendAndConfirm({ ix: await counterOfferIx(author, reviewers[0], COUNTER_OFFER), payer: author });
+
+    const { data: review } = await fetchPeerReview(rpc, await reviewPda(reviewers[0]));
+    expect(review.status).toBe(ReviewStatus.Negotiating);
+    expect(review.proposedReward).toBe(REWARD);
+    expect(review.counterOffer).toEqual(some(COUNTER_OFFER));
+    expect(review.counteredAt).toBeGreaterThan(0n);
+  });
+
+  it('Sad Path: A review under negotiation can no longer be verified', async () => {
+    const peerReview = await reviewPda(reviewers[0]);
+    const ix = getVerifyReviewInstruction({
+      acceptProposedReview: true,
+      author,
+      researchPaper,
+      peerReview,
+      reviewer: reviewers[0].address,
+      stakeVault: await pda([VAULT_STAKE_SEED, getAddressEncoder().encode(peerReview)]),
+      bountyVault: await pda([VAULT_BOUNTY_SEED, getAddressEncoder().encode(researchPaper)]),
+      authorUserAccount: authorAccount,
+      authorVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(author.address)]),
+      reviewerUserAccount: await pda([USER_SEED, getAddressEncoder().encode(reviewers[0].address)]),
+      reviewerVault: await pda([VAULT_USER_SEED, getAddressEncoder().encode(reviewers[0].address)]),
+      adminVault: await pda([VAULT_ADMIN_SEED]),
+      config: await pda([CONFIG_SEED]),
+    });
+    await expect(sendAndConfirm({ ix, payer: author })).rejects.toThrow();
+  });
+
+  it('Happy Path: The reviewer accepts the counter-offer and is paid the agreed figure', async () => {
+    const { value: balanceBefore } = await rpc.getBalance(reviewers[0].address).send();
+    await sendAndConfirm({ ix: await respondIx(reviewers[0], true), payer: reviewers[0] });
+    const { value: balanceAfter } = await rpc.getBalance(reviewers[0].address).send();
+    // The stake comes back, less the transaction fee
+    expect(balanceAfter).toBeGreaterThan(balanceBefore + STAKE - 10_000n);
+
+    const { data: review } = await fetchPeerReview(rpc, await reviewPda(reviewers[0]));
+    expect(review.status).toBe(ReviewStatus.Accepted);
+    expect(review.stake).toBe(0n);
+
+    const { data: user } = await fetchUser(rpc, await pda([USER_SEED, getAddressEncoder().encode(reviewers[0].address)]));
+    expect(user.earning).toBe(COUNTER_OFFER - (COUNTER_OFFER * BigInt(REVIEW_FEE_BPS)) / 10000n);
+    expect(user.reviewsAccepted).toBe(1);
+    expect(user.pendingReviews).toBe(0);
+
+    // The whole proposed reward is released, the unpaid part goes back to the open bounty
+    const { data: paper } = await fetchResearchPaper(rpc, researchPaper);
+    expect(paper.bounty).toBe(REWARD * 2n - COUNTER_OFFER);
+    expect(paper.bountyReserved).toBe(REWARD);
+    expect(paper.openReviews).toBe(1);
+    expect(paper.reviewScores.scoredReviews).toBe(1);
+  });
+
+  it('Happy Path: The reviewer withdraws from a counter-offer and gets the stake back', async () => {
+    await sendAndConfirm({ ix: await counterOfferIx(author, reviewers[1], 0n), payer: author });
+    await sendAndConfirm({ ix: await respondIx(reviewers[1], false), payer: reviewers[1] });
+
+    const { data: review } = await fetchPeerReview(rpc, await reviewPda(reviewers[1]));
+    expect(review.status).toBe(ReviewStatus.Withdrawn);
+    expect(review.stake).toBe(0n);
+
+    // Nobody's reputation moves and the bounty is untouched
+    const { data: user } = await fetchUser(rpc, await pda([USER_SEED, getAddressEncoder().encode(reviewers[1].address)]));
+    expect(user.earning).toBe(0n);
+    expect(user.reviewsAccepted).toBe(0);
+    expect(user.reviewsRejected).toBe(0);
+    expect(user.pendingReviews).toBe(0);
+
+    const { data: paper } = await fetchResearchPaper(rpc, researchPaper);
+    expect(paper.bounty).toBe(REWARD * 2n - COUNTER_OFFER);
+    expect(paper.bountyReserved).toBe(0n);
+    expect(paper.openReviews).toBe(0);
+  });
+
+  it('Happy Path: The reviewer closes the withdrawn review right away', async () => {
+    const peerReview = await reviewPda(reviewers[1]);
+    await sendAndConfirm({
+      ix: getCloseReviewInstruction({
+        reviewer: reviewers[1],
+        peerReview,
+        stakeVault: await pda([VAULT_STAKE_SEED, getAddressEncoder().encode(peerReview)]),
+      }),
+      payer: reviewers[1],
+    });
+
+    const { value } = await rpc.getAccountInfo(peerReview).send();
+    expect(value).toBeNull();
+  });
+
+  it('Sad Path: A counter-offer cannot be expired while the reviewer can still answer', async () => {
+    await submitReview(reviewers[2]);
+    await sendAndConfirm({
+      ix: getSetReviewResponsePolicyInstruction({
+        responseWindow: WINDOW,
+        expiryPolicy: ReviewExpiryPolicy.Release,
+        author,
+        researchPaper,
+      }),
+      payer: author,
+    });
+    await sendAndConfirm({ ix: await counterOfferIx(author, reviewers[2], COUNTER_OFFER), payer: author });
+
+    await expect(sendAndConfirm({ ix: await expireIx(reviewers[2]), payer: admin })).rejects.toThrow();
+  });
+
+  it('Sad Path: The reviewer cannot answer a counter-offer once its window has passed', async () => {
+    await new Promise(r => setTimeout(r, Number(WINDOW + 1n) * 1000));
+    await expect(sendAndConfirm({ ix: await respondIx(reviewers[2], true), payer: reviewers[2] })).rejects.toThrow();
+  });
+
+  it('Happy Path: Anyone expires a lapsed counter-offer, which withdraws the review', async () => {
+    const { value: balanceBefore } = await rpc.getBalance(reviewers[2].address).send();
+    await sendAndConfirm({ ix: await expireIx(reviewers[2]), payer: admin });
+    const { value: balanceAfter } = await rpc.getBalance(reviewers[2].address).send();
+    expect(balanceAfter).toBe(balanceBefore + STAKE);
+
+    const { data: review } = await fetchPeerReview(rpc, await reviewPda(reviewers[2]));
+    expect(review.status).toBe(ReviewStatus.Withdrawn);
+    expect(review.stake).toBe(0n);
+
+    // Neither side's record moves, and the reservation goes back to the bounty
+    const { data: user } = await fetchUser(rpc, await pda([USER_SEED, getAddressEncoder().encode(reviewers[2].address)]));
+    expect(user.earning).toBe(0n);
+    expect(user.reviewsAccepted).toBe(0);
+    expect(user.reviewsRejected).toBe(0);
+    expect(user.pendingReviews).toBe(0);
+
+    const { data: authorUser } = await fetchUser(rpc, authorAccount);
+    expect(authorUser.reviewsExpired).toBe(0);
+    // Every counter-offer was an answer in time
+    expect(authorUser.reviewsAnswered).toBe(3);
+    expect(authorUser.authorReputation).toBe(8_000);
+
+    const { data: paper } = await fetchResearchPaper(rpc, researchPaper);
+    expect(paper.bounty).toBe(REWARD * 2n - COUNTER_OFFER);
+    expect(paper.bountyReserved).toBe(0n);
+    expect(paper.openReviews).toBe(0);
+  });
+});
+
+// -----------------------------------------------------------------------------
+// HELPERS
+// -----------------------------------------------------------------------------
+
+let latestBlockhash: Awaited<ReturnType<typeof getLatestBlockhash>> | undefined
+async function getLatestBlockhash(): Promise<Readonly<{ blockhash: Blockhash; lastValidBlockHeight: bigint }>> {
+  if (latestBlockhash) {
+    return latestBlockhash
+  }
+  return await rpc.getLatestBlockhash().send().then(({ value }) => value)
+}
+
+async function sendAndConfirm({ ix, payer }: { ix: Instruction; payer: KeyPairSigner }) {
+  const tx = createTransaction({
+    feePayer: payer,
+    instructions: [ix],
+    version: 'legacy',
+    latestBlockhash: await getLatestBlockhash(),
+  })
+  const signedTransaction = await signTransactionMessageWithSigners(tx)
+  return await sendAndConfirmTransaction(signedTransaction)
+}
+
+async function sendTransaction(tx: Parameters<typeof signTransactionMessageWithSigners>[0]) {
+  const signedTransaction = await signTransactionMessageWithSigners(tx)
+  return await sendAndConfirmTransaction(signedTransaction)
+}
+
+async function requestAirdrop(address: Address) {
+  await rpc.requestAirdrop(address, 10000000000n as any).send();
+  await new Promise(r => setTimeout(r, 1000));
+}
+
+async function revisionPda(researchPaper: Address, revision: number): Promise<Address> {
+  return pda([REVISION_SEED, getAddressEncoder().encode(researchPaper), getU32Encoder().encode(revision)])
+}
+
+async function keyDeliveryPda(receipt: Address, revision: number): Promise<Address> {
+  return pda([KEY_DELIVERY_SEED, getAddressEncoder().encode(receipt), getU32Encoder().encode(revision)])
+}
+
+async function subscriptionKeyDeliveryPda(subscription: Address, researchPaper: Address, revision: number): Promise<Address> {
+  return pda([
+    KEY_DELIVERY_SEED,
+    getAddressEncoder().encode(subscription),
+    getAddressEncoder().encode(researchPaper),
+    getU32Encoder().encode(revision),
+  ])
+}
+
+async function roundPda(researchPaper: Address, roundId: number): Promise<Address> {
+  return pda([ROUND_SEED, getAddressEncoder().encode(researchPaper), getU16Encoder().encode(roundId)])
+}
+
+async function voterPda(reviewRound: Address, voter: Address): Promise<Address> {
+  return pda([VOTER_SEED, getAddressEncoder().encode(reviewRound), getAddressEncoder().encode(voter)])
+}
+
+// Opens the paper's first round, wide enough that a single review never fills or decides it
+async function openRoundIx(author: KeyPairSigner, researchPaper: Address) {
+  return getOpenReviewRoundInstruction({
+    targetReviewers: 10,
+    quorum: 1,
+    approvalBps: 5_000,
+    deadline: BigInt(Math.floor(Date.now() / 1000) + 3600),
+    author,
+    researchPaper,
+    reviewRound: await roundPda(researchPaper, 0),
+  })
+}
+
+async function pda(seeds: ReadonlyUint8Array[], programAddress: Address = PROGRAM_ID): Promise<Address> {
+  const [address] = await getProgramDerivedAddress({ programAddress, seeds })
+  return address
+}